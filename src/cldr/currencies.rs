@@ -0,0 +1,296 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Currency data from the CLDR `currencyData.json` supplemental data
+//! and the `currencies.json` locale data.
+//!
+//! Currencies are identified by their [ISO 4217] code.
+//!
+//! [ISO 4217]: https://www.iso.org/iso-4217-currency-codes.html
+
+use cldr::PluralForms;
+use PluralCategory::{Few, Many, One, Other};
+
+/// The currency in use in each region, keyed by region code.
+pub const REGION_CURRENCIES: &[(&str, &str)] = &[
+    (
+        "AD AT BE CY DE EE ES FI FR GR HR IE IT LT LU LV MC ME MT NL PT SI SK SM VA XK",
+        "EUR",
+    ),
+    ("AS EC FM GU MH MP PR PW SV TC US VG VI", "USD"),
+    ("AE", "AED"),
+    ("AR", "ARS"),
+    ("AU KI NR TV", "AUD"),
+    ("BD", "BDT"),
+    ("BH", "BHD"),
+    ("BR", "BRL"),
+    ("CA", "CAD"),
+    ("CH LI", "CHF"),
+    ("CL", "CLP"),
+    ("CN", "CNY"),
+    ("CO", "COP"),
+    ("CZ", "CZK"),
+    ("DK FO GL", "DKK"),
+    ("DZ", "DZD"),
+    ("EG", "EGP"),
+    ("EH MA", "MAD"),
+    ("GB GG IM JE", "GBP"),
+    ("HK", "HKD"),
+    ("HU", "HUF"),
+    ("ID", "IDR"),
+    ("IL PS", "ILS"),
+    ("IN", "INR"),
+    ("IQ", "IQD"),
+    ("IR", "IRR"),
+    ("IS", "ISK"),
+    ("JO", "JOD"),
+    ("JP", "JPY"),
+    ("KR", "KRW"),
+    ("KW", "KWD"),
+    ("LY", "LYD"),
+    ("MX", "MXN"),
+    ("MY", "MYR"),
+    ("NO SJ", "NOK"),
+    ("NP", "NPR"),
+    ("NZ", "NZD"),
+    ("OM", "OMR"),
+    ("PL", "PLN"),
+    ("RO", "RON"),
+    ("RU", "RUB"),
+    ("SA", "SAR"),
+    ("SE", "SEK"),
+    ("SG", "SGD"),
+    ("TH", "THB"),
+    ("TN", "TND"),
+    ("TR", "TRY"),
+    ("TW", "TWD"),
+    ("UA", "UAH"),
+    ("VN", "VND"),
+    ("ZA", "ZAR"),
+];
+
+/// The number of fraction digits normally shown for each currency,
+/// keyed by currency code. Currencies without an entry use the
+/// `root` value.
+pub const DIGITS: &[(&str, usize)] = &[
+    ("root", 2),
+    (
+        "BIF CLP DJF GNF ISK JPY KMF KRW PYG RWF UGX UYI VND VUV XAF XOF XPF",
+        0,
+    ),
+    ("BHD IQD JOD KWD LYD OMR TND", 3),
+];
+
+/// The smallest amount that is used in cash, for currencies where
+/// this isn't the smallest amount in the currency's normal number of
+/// fraction digits, keyed by currency code.
+pub const CASH_INCREMENTS: &[(&str, &str)] = &[
+    ("CAD CHF", "0.05"),
+    ("DKK", "0.50"),
+    ("CRC CZK HUF IDR NOK PKR SEK TWD", "1"),
+];
+
+/// Narrow currency symbols, keyed by currency code. These are used
+/// where the context makes the currency clear, so `$` may be any
+/// kind of dollar.
+pub const NARROW_SYMBOLS: &[(&str, &str)] = &[
+    ("AUD CAD HKD MXN NZD SGD TWD USD", "$"),
+    ("CNY JPY", "¥"),
+    ("DKK NOK SEK", "kr"),
+    ("BRL", "R$"),
+    ("CZK", "Kč"),
+    ("EUR", "€"),
+    ("GBP", "£"),
+    ("ILS", "₪"),
+    ("INR", "₹"),
+    ("KRW", "₩"),
+    ("PLN", "zł"),
+    ("RUB", "₽"),
+    ("THB", "฿"),
+    ("TRY", "₺"),
+    ("UAH", "₴"),
+    ("VND", "₫"),
+];
+
+/// Currency symbols, keyed by locale, then by currency code. Codes
+/// without a symbol in any fallback locale are displayed as is.
+pub const SYMBOLS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "root",
+        &[
+            ("AUD", "A$"),
+            ("BRL", "R$"),
+            ("CAD", "CA$"),
+            ("CNY", "CN¥"),
+            ("EUR", "€"),
+            ("GBP", "£"),
+            ("HKD", "HK$"),
+            ("ILS", "₪"),
+            ("INR", "₹"),
+            ("JPY", "JP¥"),
+            ("KRW", "₩"),
+            ("MXN", "MX$"),
+            ("NZD", "NZ$"),
+            ("TWD", "NT$"),
+            ("USD", "US$"),
+            ("VND", "₫"),
+            ("XAF", "FCFA"),
+            ("XXX", "¤"),
+        ],
+    ),
+    ("en", &[("JPY", "¥"), ("USD", "$")]),
+    ("en-GB en-IN", &[("USD", "US$")]),
+    ("en-AU", &[("AUD", "$"), ("USD", "US$")]),
+    ("en-CA", &[("CAD", "$"), ("USD", "US$")]),
+    ("en-NZ", &[("NZD", "$"), ("USD", "US$")]),
+    ("en-SG", &[("SGD", "$"), ("USD", "US$")]),
+    ("en-ZA", &[("USD", "US$"), ("ZAR", "R")]),
+    (
+        "ar",
+        &[
+            ("AED", "د.إ.\u{200f}"),
+            ("EGP", "ج.م.\u{200f}"),
+            ("SAR", "ر.س.\u{200f}"),
+        ],
+    ),
+    ("bn", &[("BDT", "৳")]),
+    ("cs", &[("CZK", "Kč")]),
+    ("da", &[("DKK", "kr.")]),
+    ("es", &[("USD", "US$")]),
+    ("es-MX", &[("MXN", "$"), ("USD", "USD")]),
+    ("es-US", &[("USD", "$")]),
+    ("fa", &[("IRR", "ریال")]),
+    ("fr", &[("CAD", "$CA"), ("USD", "$US")]),
+    ("fr-CA", &[("CAD", "$"), ("USD", "$\u{a0}US")]),
+    ("ja", &[("CNY", "元"), ("JPY", "￥"), ("USD", "$")]),
+    ("ko", &[("KRW", "₩")]),
+    ("nb nn no", &[("NOK", "kr")]),
+    ("ne", &[("NPR", "नेरू")]),
+    ("pl", &[("PLN", "zł")]),
+    ("pt", &[("USD", "US$")]),
+    ("ru", &[("RUB", "₽"), ("UAH", "₴")]),
+    ("sv", &[("SEK", "kr")]),
+    ("th", &[("THB", "฿")]),
+    ("tr", &[("TRY", "₺")]),
+    ("uk", &[("UAH", "₴")]),
+    ("zh", &[("CNY", "¥"), ("JPY", "JP¥")]),
+    ("zh-Hant zh-TW", &[("TWD", "$")]),
+];
+
+/// The patterns for a number followed by a currency's name, where `{0}`
+/// is the number and `{1}` the name, keyed by locale.
+pub const NAME_PATTERNS: &[(&str, &str)] = &[("root", "{0} {1}"), ("ja zh", "{0}{1}")];
+
+/// Currency names for each plural category, keyed by locale, then by
+/// currency code. Codes without a name in any fallback locale are
+/// displayed as is.
+pub const NAMES: &[(&str, &[(&str, PluralForms)])] = &[
+    (
+        "en",
+        &[
+            (
+                "BHD",
+                &[(One, "Bahraini dinar"), (Other, "Bahraini dinars")],
+            ),
+            (
+                "CAD",
+                &[(One, "Canadian dollar"), (Other, "Canadian dollars")],
+            ),
+            ("CHF", &[(One, "Swiss franc"), (Other, "Swiss francs")]),
+            ("CNY", &[(Other, "Chinese yuan")]),
+            ("EUR", &[(One, "euro"), (Other, "euros")]),
+            ("GBP", &[(One, "British pound"), (Other, "British pounds")]),
+            ("INR", &[(One, "Indian rupee"), (Other, "Indian rupees")]),
+            ("JPY", &[(Other, "Japanese yen")]),
+            ("USD", &[(One, "US dollar"), (Other, "US dollars")]),
+        ],
+    ),
+    (
+        "de",
+        &[
+            ("CHF", &[(Other, "Schweizer Franken")]),
+            ("EUR", &[(Other, "Euro")]),
+            (
+                "GBP",
+                &[(One, "Britisches Pfund"), (Other, "Britische Pfund")],
+            ),
+            (
+                "JPY",
+                &[(One, "Japanischer Yen"), (Other, "Japanische Yen")],
+            ),
+            ("USD", &[(Other, "US-Dollar")]),
+        ],
+    ),
+    (
+        "es",
+        &[
+            ("EUR", &[(One, "euro"), (Other, "euros")]),
+            (
+                "GBP",
+                &[(One, "libra esterlina"), (Other, "libras esterlinas")],
+            ),
+            ("JPY", &[(One, "yen"), (Other, "yenes")]),
+            (
+                "USD",
+                &[
+                    (One, "dólar estadounidense"),
+                    (Other, "dólares estadounidenses"),
+                ],
+            ),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            ("CHF", &[(One, "franc suisse"), (Other, "francs suisses")]),
+            ("EUR", &[(One, "euro"), (Other, "euros")]),
+            (
+                "GBP",
+                &[(One, "livre sterling"), (Other, "livres sterling")],
+            ),
+            ("JPY", &[(One, "yen japonais"), (Other, "yens japonais")]),
+            (
+                "USD",
+                &[
+                    (One, "dollar des États-Unis"),
+                    (Other, "dollars des États-Unis"),
+                ],
+            ),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            ("EUR", &[(Other, "ユーロ")]),
+            ("JPY", &[(Other, "円")]),
+            ("USD", &[(Other, "米ドル")]),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            ("EUR", &[(Other, "евро")]),
+            (
+                "RUB",
+                &[
+                    (One, "российский рубль"),
+                    (Few, "российских рубля"),
+                    (Many, "российских рублей"),
+                    (Other, "российского рубля"),
+                ],
+            ),
+            (
+                "USD",
+                &[
+                    (One, "доллар США"),
+                    (Few, "доллара США"),
+                    (Many, "долларов США"),
+                    (Other, "доллара США"),
+                ],
+            ),
+        ],
+    ),
+];
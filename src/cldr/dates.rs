@@ -0,0 +1,572 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Gregorian calendar names and patterns from the CLDR `ca-gregorian.json`
+//! and `timeZoneNames.json` data.
+//!
+//! Patterns use the [CLDR pattern syntax]: runs of ASCII letters are
+//! fields, like `MMM` for an abbreviated month name, and text within
+//! apostrophes is literal.
+//!
+//! [CLDR pattern syntax]: http://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+
+/// Names in each of the widths used by patterns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Names<T> {
+    /// Abbreviated names, used for fields of 3 letters or fewer.
+    pub abbreviated: T,
+    /// Full names, used for fields of 4 letters.
+    pub wide: T,
+    /// Names of usually a single character, used for fields of 5
+    /// letters.
+    pub narrow: T,
+}
+
+/// The date and time formatting data for a locale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DateData {
+    /// Month names, starting with January.
+    pub months: Names<[&'static str; 12]>,
+    /// Weekday names, starting with Sunday.
+    pub weekdays: Names<[&'static str; 7]>,
+    /// Short weekday names, used for `EEEEEE`.
+    pub short_weekdays: [&'static str; 7],
+    /// Era names, before and after the start of the Common Era.
+    pub eras: Names<[&'static str; 2]>,
+    /// The names of the AM and PM periods of the day.
+    pub day_periods: [&'static str; 2],
+    /// The full, long, medium and short date patterns.
+    pub date_formats: [&'static str; 4],
+    /// The full, long, medium and short time patterns.
+    pub time_formats: [&'static str; 4],
+    /// The full, long, medium and short patterns for combining a date
+    /// and a time, where `{1}` is the date and `{0}` the time.
+    pub date_time_formats: [&'static str; 4],
+    /// The pattern for a time zone offset from GMT, where `{0}` is the
+    /// offset, like `GMT{0}`.
+    pub gmt_format: &'static str,
+    /// The text for a time zone with no offset from GMT.
+    pub gmt_zero_format: &'static str,
+    /// The hour field preferred by the locale, `h` for a 12 hour clock
+    /// or `H` for a 24 hour clock.
+    pub hour: char,
+    /// Patterns for skeletons, keyed by skeleton.
+    pub available_formats: &'static [(&'static str, &'static str)],
+}
+
+const ENGLISH_MONTHS: Names<[&str; 12]> = Names {
+    abbreviated: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    wide: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    narrow: ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+};
+
+const ENGLISH_WEEKDAYS: Names<[&str; 7]> = Names {
+    abbreviated: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    wide: [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+    narrow: ["S", "M", "T", "W", "T", "F", "S"],
+};
+
+const ROOT: DateData = DateData {
+    months: Names {
+        abbreviated: [
+            "M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12",
+        ],
+        wide: [
+            "M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12",
+        ],
+        narrow: [
+            "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+        ],
+    },
+    weekdays: Names {
+        abbreviated: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        wide: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        narrow: ["S", "M", "T", "W", "T", "F", "S"],
+    },
+    short_weekdays: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    eras: Names {
+        abbreviated: ["BCE", "CE"],
+        wide: ["BCE", "CE"],
+        narrow: ["BCE", "CE"],
+    },
+    day_periods: ["AM", "PM"],
+    date_formats: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    time_formats: ["HH:mm:ss zzzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+    date_time_formats: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+    gmt_format: "GMT{0}",
+    gmt_zero_format: "GMT",
+    hour: 'H',
+    available_formats: &[
+        ("d", "d"),
+        ("E", "ccc"),
+        ("Ed", "d, E"),
+        ("EHm", "E HH:mm"),
+        ("Gy", "G y"),
+        ("GyMMM", "G y MMM"),
+        ("GyMMMd", "G y MMM d"),
+        ("h", "h a"),
+        ("H", "HH"),
+        ("hm", "h:mm a"),
+        ("Hm", "HH:mm"),
+        ("hms", "h:mm:ss a"),
+        ("Hms", "HH:mm:ss"),
+        ("M", "L"),
+        ("Md", "MM-dd"),
+        ("MEd", "MM-dd, E"),
+        ("MMM", "LLL"),
+        ("MMMd", "MMM d"),
+        ("MMMEd", "MMM d, E"),
+        ("MMMMd", "MMMM d"),
+        ("ms", "mm:ss"),
+        ("y", "y"),
+        ("yM", "y-MM"),
+        ("yMd", "y-MM-dd"),
+        ("yMEd", "y-MM-dd, E"),
+        ("yMMM", "y MMM"),
+        ("yMMMd", "y MMM d"),
+        ("yMMMEd", "y MMM d, E"),
+        ("yMMMM", "y MMMM"),
+    ],
+};
+
+const ENGLISH: DateData = DateData {
+    months: ENGLISH_MONTHS,
+    weekdays: ENGLISH_WEEKDAYS,
+    short_weekdays: ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"],
+    eras: Names {
+        abbreviated: ["BC", "AD"],
+        wide: ["Before Christ", "Anno Domini"],
+        narrow: ["B", "A"],
+    },
+    date_formats: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+    time_formats: [
+        "h:mm:ss\u{202f}a zzzz",
+        "h:mm:ss\u{202f}a z",
+        "h:mm:ss\u{202f}a",
+        "h:mm\u{202f}a",
+    ],
+    date_time_formats: ["{1} 'at' {0}", "{1} 'at' {0}", "{1}, {0}", "{1}, {0}"],
+    hour: 'h',
+    available_formats: &[
+        ("d", "d"),
+        ("E", "ccc"),
+        ("Ed", "d E"),
+        ("Ehm", "E h:mm\u{202f}a"),
+        ("EHm", "E HH:mm"),
+        ("Ehms", "E h:mm:ss\u{202f}a"),
+        ("EHms", "E HH:mm:ss"),
+        ("Gy", "y G"),
+        ("GyMMM", "MMM y G"),
+        ("GyMMMd", "MMM d, y G"),
+        ("GyMMMEd", "E, MMM d, y G"),
+        ("h", "h\u{202f}a"),
+        ("H", "HH"),
+        ("hm", "h:mm\u{202f}a"),
+        ("Hm", "HH:mm"),
+        ("hms", "h:mm:ss\u{202f}a"),
+        ("Hms", "HH:mm:ss"),
+        ("M", "L"),
+        ("Md", "M/d"),
+        ("MEd", "E, M/d"),
+        ("MMM", "LLL"),
+        ("MMMd", "MMM d"),
+        ("MMMEd", "E, MMM d"),
+        ("MMMMd", "MMMM d"),
+        ("ms", "mm:ss"),
+        ("y", "y"),
+        ("yM", "M/y"),
+        ("yMd", "M/d/y"),
+        ("yMEd", "E, M/d/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "MMM d, y"),
+        ("yMMMEd", "E, MMM d, y"),
+        ("yMMMM", "MMMM y"),
+    ],
+    ..ROOT
+};
+
+/// Gregorian calendar data, keyed by locale.
+pub const GREGORIAN: &[(&str, DateData)] = &[
+    ("root", ROOT),
+    ("en", ENGLISH),
+    (
+        "en-GB",
+        DateData {
+            day_periods: ["am", "pm"],
+            date_formats: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+            time_formats: ["HH:mm:ss zzzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+            hour: 'H',
+            available_formats: &[
+                ("d", "d"),
+                ("E", "ccc"),
+                ("Ed", "E d"),
+                ("Ehm", "E h:mm\u{202f}a"),
+                ("EHm", "E HH:mm"),
+                ("Ehms", "E h:mm:ss\u{202f}a"),
+                ("EHms", "E HH:mm:ss"),
+                ("Gy", "y G"),
+                ("GyMMM", "MMM y G"),
+                ("GyMMMd", "d MMM y G"),
+                ("GyMMMEd", "E, d MMM y G"),
+                ("h", "h\u{202f}a"),
+                ("H", "HH"),
+                ("hm", "h:mm\u{202f}a"),
+                ("Hm", "HH:mm"),
+                ("hms", "h:mm:ss\u{202f}a"),
+                ("Hms", "HH:mm:ss"),
+                ("M", "L"),
+                ("Md", "dd/MM"),
+                ("MEd", "E dd/MM"),
+                ("MMM", "LLL"),
+                ("MMMd", "d MMM"),
+                ("MMMEd", "E d MMM"),
+                ("MMMMd", "d MMMM"),
+                ("ms", "mm:ss"),
+                ("y", "y"),
+                ("yM", "MM/y"),
+                ("yMd", "dd/MM/y"),
+                ("yMEd", "E, dd/MM/y"),
+                ("yMMM", "MMM y"),
+                ("yMMMd", "d MMM y"),
+                ("yMMMEd", "E, d MMM y"),
+                ("yMMMM", "MMMM y"),
+            ],
+            ..ENGLISH
+        },
+    ),
+    (
+        "de",
+        DateData {
+            months: Names {
+                abbreviated: [
+                    "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                    "Nov.", "Dez.",
+                ],
+                wide: [
+                    "Januar",
+                    "Februar",
+                    "März",
+                    "April",
+                    "Mai",
+                    "Juni",
+                    "Juli",
+                    "August",
+                    "September",
+                    "Oktober",
+                    "November",
+                    "Dezember",
+                ],
+                narrow: ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+            },
+            weekdays: Names {
+                abbreviated: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+                wide: [
+                    "Sonntag",
+                    "Montag",
+                    "Dienstag",
+                    "Mittwoch",
+                    "Donnerstag",
+                    "Freitag",
+                    "Samstag",
+                ],
+                narrow: ["S", "M", "D", "M", "D", "F", "S"],
+            },
+            short_weekdays: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+            eras: Names {
+                abbreviated: ["v. Chr.", "n. Chr."],
+                wide: ["v. Chr.", "n. Chr."],
+                narrow: ["v. Chr.", "n. Chr."],
+            },
+            date_formats: ["EEEE, d. MMMM y", "d. MMMM y", "dd.MM.y", "dd.MM.yy"],
+            date_time_formats: ["{1} 'um' {0}", "{1} 'um' {0}", "{1}, {0}", "{1}, {0}"],
+            available_formats: &[
+                ("d", "d"),
+                ("E", "ccc"),
+                ("Ed", "E, d."),
+                ("EHm", "E, HH:mm"),
+                ("Gy", "y G"),
+                ("GyMMM", "MMM y G"),
+                ("GyMMMd", "d. MMM y G"),
+                ("h", "h 'Uhr' a"),
+                ("H", "HH 'Uhr'"),
+                ("hm", "h:mm a"),
+                ("Hm", "HH:mm"),
+                ("hms", "h:mm:ss a"),
+                ("Hms", "HH:mm:ss"),
+                ("M", "L"),
+                ("Md", "d.M."),
+                ("MEd", "E, d.M."),
+                ("MMM", "LLL"),
+                ("MMMd", "d. MMM"),
+                ("MMMEd", "E, d. MMM"),
+                ("MMMMd", "d. MMMM"),
+                ("ms", "mm:ss"),
+                ("y", "y"),
+                ("yM", "M.y"),
+                ("yMd", "d.M.y"),
+                ("yMEd", "E, d.M.y"),
+                ("yMMM", "MMM y"),
+                ("yMMMd", "d. MMM y"),
+                ("yMMMEd", "E, d. MMM y"),
+                ("yMMMM", "MMMM y"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "es",
+        DateData {
+            months: Names {
+                abbreviated: [
+                    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov",
+                    "dic",
+                ],
+                wide: [
+                    "enero",
+                    "febrero",
+                    "marzo",
+                    "abril",
+                    "mayo",
+                    "junio",
+                    "julio",
+                    "agosto",
+                    "septiembre",
+                    "octubre",
+                    "noviembre",
+                    "diciembre",
+                ],
+                narrow: ["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+            },
+            weekdays: Names {
+                abbreviated: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+                wide: [
+                    "domingo",
+                    "lunes",
+                    "martes",
+                    "miércoles",
+                    "jueves",
+                    "viernes",
+                    "sábado",
+                ],
+                narrow: ["D", "L", "M", "X", "J", "V", "S"],
+            },
+            short_weekdays: ["DO", "LU", "MA", "MI", "JU", "VI", "SA"],
+            eras: Names {
+                abbreviated: ["a. C.", "d. C."],
+                wide: ["antes de Cristo", "después de Cristo"],
+                narrow: ["a. C.", "d. C."],
+            },
+            day_periods: ["a.\u{a0}m.", "p.\u{a0}m."],
+            date_formats: [
+                "EEEE, d 'de' MMMM 'de' y",
+                "d 'de' MMMM 'de' y",
+                "d MMM y",
+                "d/M/yy",
+            ],
+            time_formats: ["H:mm:ss (zzzz)", "H:mm:ss z", "H:mm:ss", "H:mm"],
+            date_time_formats: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+            available_formats: &[
+                ("d", "d"),
+                ("E", "ccc"),
+                ("Ed", "E d"),
+                ("EHm", "E, H:mm"),
+                ("Gy", "y G"),
+                ("GyMMM", "MMM y G"),
+                ("GyMMMd", "d MMM y G"),
+                ("h", "h a"),
+                ("H", "H"),
+                ("hm", "h:mm a"),
+                ("Hm", "H:mm"),
+                ("hms", "h:mm:ss a"),
+                ("Hms", "H:mm:ss"),
+                ("M", "L"),
+                ("Md", "d/M"),
+                ("MEd", "E, d/M"),
+                ("MMM", "LLL"),
+                ("MMMd", "d MMM"),
+                ("MMMEd", "E, d MMM"),
+                ("MMMMd", "d 'de' MMMM"),
+                ("ms", "mm:ss"),
+                ("y", "y"),
+                ("yM", "M/y"),
+                ("yMd", "d/M/y"),
+                ("yMEd", "EEE, d/M/y"),
+                ("yMMM", "MMM y"),
+                ("yMMMd", "d MMM y"),
+                ("yMMMEd", "EEE, d MMM y"),
+                ("yMMMM", "MMMM 'de' y"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "fr",
+        DateData {
+            months: Names {
+                abbreviated: [
+                    "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.",
+                    "oct.", "nov.", "déc.",
+                ],
+                wide: [
+                    "janvier",
+                    "février",
+                    "mars",
+                    "avril",
+                    "mai",
+                    "juin",
+                    "juillet",
+                    "août",
+                    "septembre",
+                    "octobre",
+                    "novembre",
+                    "décembre",
+                ],
+                narrow: ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+            },
+            weekdays: Names {
+                abbreviated: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+                wide: [
+                    "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+                ],
+                narrow: ["D", "L", "M", "M", "J", "V", "S"],
+            },
+            short_weekdays: ["di", "lu", "ma", "me", "je", "ve", "sa"],
+            eras: Names {
+                abbreviated: ["av. J.-C.", "ap. J.-C."],
+                wide: ["avant Jésus-Christ", "après Jésus-Christ"],
+                narrow: ["av. J.-C.", "ap. J.-C."],
+            },
+            date_formats: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+            date_time_formats: ["{1} 'à' {0}", "{1} 'à' {0}", "{1}, {0}", "{1} {0}"],
+            gmt_format: "UTC{0}",
+            gmt_zero_format: "UTC",
+            available_formats: &[
+                ("d", "d"),
+                ("E", "E"),
+                ("Ed", "E d"),
+                ("EHm", "E HH:mm"),
+                ("Gy", "y G"),
+                ("GyMMM", "MMM y G"),
+                ("GyMMMd", "d MMM y G"),
+                ("h", "h a"),
+                ("H", "HH 'h'"),
+                ("hm", "h:mm a"),
+                ("Hm", "HH:mm"),
+                ("hms", "h:mm:ss a"),
+                ("Hms", "HH:mm:ss"),
+                ("M", "L"),
+                ("Md", "dd/MM"),
+                ("MEd", "E dd/MM"),
+                ("MMM", "LLL"),
+                ("MMMd", "d MMM"),
+                ("MMMEd", "E d MMM"),
+                ("MMMMd", "d MMMM"),
+                ("ms", "mm:ss"),
+                ("y", "y"),
+                ("yM", "MM/y"),
+                ("yMd", "dd/MM/y"),
+                ("yMEd", "E dd/MM/y"),
+                ("yMMM", "MMM y"),
+                ("yMMMd", "d MMM y"),
+                ("yMMMEd", "E d MMM y"),
+                ("yMMMM", "MMMM y"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "ja",
+        DateData {
+            months: Names {
+                abbreviated: [
+                    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                    "12月",
+                ],
+                wide: [
+                    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                    "12月",
+                ],
+                ..ROOT.months
+            },
+            weekdays: Names {
+                abbreviated: ["日", "月", "火", "水", "木", "金", "土"],
+                wide: [
+                    "日曜日",
+                    "月曜日",
+                    "火曜日",
+                    "水曜日",
+                    "木曜日",
+                    "金曜日",
+                    "土曜日",
+                ],
+                narrow: ["日", "月", "火", "水", "木", "金", "土"],
+            },
+            short_weekdays: ["日", "月", "火", "水", "木", "金", "土"],
+            eras: Names {
+                abbreviated: ["紀元前", "西暦"],
+                wide: ["紀元前", "西暦"],
+                narrow: ["BC", "AD"],
+            },
+            day_periods: ["午前", "午後"],
+            date_formats: ["y年M月d日EEEE", "y年M月d日", "y/MM/dd", "y/MM/dd"],
+            time_formats: ["H時mm分ss秒 zzzz", "H:mm:ss z", "H:mm:ss", "H:mm"],
+            available_formats: &[
+                ("d", "d日"),
+                ("E", "ccc"),
+                ("Ed", "d日(E)"),
+                ("Gy", "Gy年"),
+                ("GyMMM", "Gy年M月"),
+                ("GyMMMd", "Gy年M月d日"),
+                ("h", "aK時"),
+                ("H", "H時"),
+                ("hm", "aK:mm"),
+                ("Hm", "H:mm"),
+                ("hms", "aK:mm:ss"),
+                ("Hms", "H:mm:ss"),
+                ("M", "M月"),
+                ("Md", "M/d"),
+                ("MEd", "M/d(E)"),
+                ("MMM", "M月"),
+                ("MMMd", "M月d日"),
+                ("MMMEd", "M月d日(E)"),
+                ("MMMMd", "M月d日"),
+                ("ms", "mm:ss"),
+                ("y", "y年"),
+                ("yM", "y/M"),
+                ("yMd", "y/M/d"),
+                ("yMEd", "y/M/d(E)"),
+                ("yMMM", "y年M月"),
+                ("yMMMd", "y年M月d日"),
+                ("yMMMEd", "y年M月d日(E)"),
+                ("yMMMM", "y年M月"),
+            ],
+            ..ROOT
+        },
+    ),
+];
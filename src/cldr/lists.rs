@@ -0,0 +1,1105 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! List patterns from the CLDR `listPatterns.json` locale data.
+//!
+//! Patterns are keyed by type, which is `standard` for lists joined
+//! with "and", `or` for lists joined with "or" and `unit` for lists of
+//! measurements, with a `-short` or `-narrow` suffix for the shorter
+//! widths. A width that a locale doesn't have uses the next wider one.
+//!
+//! The locales are those with number and currency data. Other locales
+//! use the patterns of their parent, falling back to the root locale's
+//! `{0}, {1}`.
+
+/// The patterns for joining the items of a list, where `{0}` and `{1}`
+/// are the items or lists being joined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ListPatterns {
+    /// The pattern joining the first item to the rest of a list of
+    /// three or more items.
+    pub start: &'static str,
+    /// The pattern joining an item in the middle of a list to the
+    /// rest of the list.
+    pub middle: &'static str,
+    /// The pattern joining the last two items of a list of three or
+    /// more items.
+    pub end: &'static str,
+    /// The pattern joining the items of a list of two items.
+    pub two: &'static str,
+}
+
+/// List patterns, keyed by locale, then by type and width.
+pub const PATTERNS: &[(&str, &[(&str, ListPatterns)])] = &[
+    (
+        "root",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "ar",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0} و{1}",
+                    middle: "{0} و{1}",
+                    end: "{0} و{1}",
+                    two: "{0} و{1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0} أو {1}",
+                    middle: "{0} أو {1}",
+                    end: "{0} أو {1}",
+                    two: "{0} أو {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}، و{1}",
+                    middle: "{0}، و{1}",
+                    end: "{0}، و{1}",
+                    two: "{0} و{1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "bn",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} এবং {1}",
+                    two: "{0} এবং {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} বা {1}",
+                    two: "{0} বা {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "cs",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} a {1}",
+                    two: "{0} a {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} nebo {1}",
+                    two: "{0} nebo {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} a {1}",
+                    two: "{0} a {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "da",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} og {1}",
+                    two: "{0} og {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} eller {1}",
+                    two: "{0} eller {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} og {1}",
+                    two: "{0} og {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "de",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} und {1}",
+                    two: "{0} und {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} oder {1}",
+                    two: "{0} oder {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} und {1}",
+                    two: "{0} und {1}",
+                },
+            ),
+            (
+                "unit-short",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "en",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, and {1}",
+                    two: "{0} and {1}",
+                },
+            ),
+            (
+                "standard-short",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, & {1}",
+                    two: "{0} & {1}",
+                },
+            ),
+            (
+                "standard-narrow",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, or {1}",
+                    two: "{0} or {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "en-GB en-AU en-IN",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} and {1}",
+                    two: "{0} and {1}",
+                },
+            ),
+            (
+                "standard-short",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} and {1}",
+                    two: "{0} and {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} or {1}",
+                    two: "{0} or {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "es",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} y {1}",
+                    two: "{0} y {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} o {1}",
+                    two: "{0} o {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} y {1}",
+                    two: "{0} y {1}",
+                },
+            ),
+            (
+                "unit-short",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0} y {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "fa",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}،\u{200f} {1}",
+                    middle: "{0}،\u{200f} {1}",
+                    end: "{0}، و {1}",
+                    two: "{0} و {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}،\u{200f} {1}",
+                    middle: "{0}،\u{200f} {1}",
+                    end: "{0}، یا {1}",
+                    two: "{0} یا {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}،\u{200f} {1}",
+                    middle: "{0}،\u{200f} {1}",
+                    end: "{0}، و {1}",
+                    two: "{0} و {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "fi",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} ja {1}",
+                    two: "{0} ja {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} tai {1}",
+                    two: "{0} tai {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} ja {1}",
+                    two: "{0} ja {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} et {1}",
+                    two: "{0} et {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} ou {1}",
+                    two: "{0} ou {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} et {1}",
+                    two: "{0} et {1}",
+                },
+            ),
+            (
+                "unit-short",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "he",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} ו{1}",
+                    two: "{0} ו{1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} או {1}",
+                    two: "{0} או {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} ו{1}",
+                    two: "{0} ו{1}",
+                },
+            ),
+            (
+                "unit-short",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "hi",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, और {1}",
+                    two: "{0} और {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} या {1}",
+                    two: "{0} या {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "it",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} e {1}",
+                    two: "{0} e {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} o {1}",
+                    two: "{0} o {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} e {1}",
+                    two: "{0} e {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}、{1}",
+                    middle: "{0}、{1}",
+                    end: "{0}、{1}",
+                    two: "{0}、{1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}、{1}",
+                    middle: "{0}、{1}",
+                    end: "{0}、または{1}",
+                    two: "{0}または{1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0}{1}",
+                    middle: "{0}{1}",
+                    end: "{0}{1}",
+                    two: "{0}{1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "ko",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} 및 {1}",
+                    two: "{0} 및 {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} 또는 {1}",
+                    two: "{0} 또는 {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "mr",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} आणि {1}",
+                    two: "{0} आणि {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} किंवा {1}",
+                    two: "{0} किंवा {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "nb nn no",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} og {1}",
+                    two: "{0} og {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} eller {1}",
+                    two: "{0} eller {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} og {1}",
+                    two: "{0} og {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "ne",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} र {1}",
+                    two: "{0} र {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} वा {1}",
+                    two: "{0} वा {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "nl",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} en {1}",
+                    two: "{0} en {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} of {1}",
+                    two: "{0} of {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} en {1}",
+                    two: "{0} en {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "pl",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} i {1}",
+                    two: "{0} i {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} lub {1}",
+                    two: "{0} lub {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} i {1}",
+                    two: "{0} i {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} e {1}",
+                    two: "{0} e {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} ou {1}",
+                    two: "{0} ou {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} e {1}",
+                    two: "{0} e {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} и {1}",
+                    two: "{0} и {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} или {1}",
+                    two: "{0} или {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} и {1}",
+                    two: "{0} и {1}",
+                },
+            ),
+            (
+                "unit-short",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0}, {1}",
+                    two: "{0}, {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} {1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "sv",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} och {1}",
+                    two: "{0} och {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} eller {1}",
+                    two: "{0} eller {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} och {1}",
+                    two: "{0} och {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "th",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} และ{1}",
+                    two: "{0}และ{1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} หรือ {1}",
+                    two: "{0} หรือ {1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} และ {1}",
+                    two: "{0} {1}",
+                },
+            ),
+            (
+                "unit-short",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0} และ {1}",
+                    two: "{0} และ {1}",
+                },
+            ),
+            (
+                "unit-narrow",
+                ListPatterns {
+                    start: "{0} {1}",
+                    middle: "{0} {1}",
+                    end: "{0}และ{1}",
+                    two: "{0} {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "tr",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} ve {1}",
+                    two: "{0} ve {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} veya {1}",
+                    two: "{0} veya {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "uk",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} і {1}",
+                    two: "{0} і {1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}, {1}",
+                    middle: "{0}, {1}",
+                    end: "{0} або {1}",
+                    two: "{0} або {1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "zh",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}、{1}",
+                    middle: "{0}、{1}",
+                    end: "{0}和{1}",
+                    two: "{0}和{1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}、{1}",
+                    middle: "{0}、{1}",
+                    end: "{0}或{1}",
+                    two: "{0}或{1}",
+                },
+            ),
+            (
+                "unit",
+                ListPatterns {
+                    start: "{0}{1}",
+                    middle: "{0}{1}",
+                    end: "{0}{1}",
+                    two: "{0}{1}",
+                },
+            ),
+        ],
+    ),
+    (
+        "zh-Hant zh-TW",
+        &[
+            (
+                "standard",
+                ListPatterns {
+                    start: "{0}、{1}",
+                    middle: "{0}、{1}",
+                    end: "{0}和{1}",
+                    two: "{0}和{1}",
+                },
+            ),
+            (
+                "or",
+                ListPatterns {
+                    start: "{0}、{1}",
+                    middle: "{0}、{1}",
+                    end: "{0}或{1}",
+                    two: "{0}或{1}",
+                },
+            ),
+        ],
+    ),
+];
@@ -0,0 +1,204 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Locale data derived from the [Unicode CLDR].
+//!
+//! The data here is vendored so that no network access or external
+//! data files are needed at runtime. Tables are keyed by a
+//! space-separated list of locale identifiers, in the same way as
+//! the CLDR supplemental data files.
+//!
+//! [Unicode CLDR]: http://cldr.unicode.org/
+
+use language_tags::LanguageTag;
+
+use PluralCategory;
+
+pub mod currencies;
+pub mod dates;
+pub mod likely_subtags;
+pub mod lists;
+pub mod numbers;
+pub mod plurals;
+pub mod rbnf;
+pub mod relative_times;
+pub mod time_zones;
+pub mod units;
+
+/// Text with a form for each plural category, which must include
+/// `Other`.
+pub type PluralForms = &'static [(PluralCategory, &'static str)];
+
+/// The form of a pattern or name for a plural category, or for `Other`
+/// if it has no form for that category.
+pub fn plural_form(forms: PluralForms, category: PluralCategory) -> Option<&'static str> {
+    forms
+        .iter()
+        .find(|&&(count, _)| count == category)
+        .or_else(|| {
+            forms
+                .iter()
+                .find(|&&(count, _)| count == PluralCategory::Other)
+        })
+        .map(|&(_, form)| form)
+}
+
+/// The locale identifiers to try, in order, when looking up data
+/// for a language tag.
+///
+/// For `pt-Latn-BR`, this is `pt-Latn-BR`, `pt-Latn`, `pt-BR`, `pt`
+/// and finally `root`.
+pub fn fallbacks(language_tag: &LanguageTag) -> Vec<String> {
+    let mut fallbacks = vec![];
+    if let Some(ref language) = language_tag.language {
+        let language = language.to_lowercase();
+        let script = language_tag.script.as_ref().map(|script| {
+            let mut chars = script.chars();
+            chars.next().map_or(String::new(), |first| {
+                first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect()
+            })
+        });
+        let region = language_tag
+            .region
+            .as_ref()
+            .map(|region| region.to_uppercase());
+        if let (Some(script), Some(region)) = (script.as_ref(), region.as_ref()) {
+            fallbacks.push(format!("{}-{}-{}", language, script, region));
+        }
+        if let Some(ref script) = script {
+            fallbacks.push(format!("{}-{}", language, script));
+        }
+        if let Some(ref region) = region {
+            fallbacks.push(format!("{}-{}", language, region));
+        }
+        fallbacks.push(language);
+    }
+    fallbacks.push("root".to_string());
+    fallbacks
+}
+
+/// Find the data for the most specific locale matching `language_tag`.
+///
+/// Every table is expected to have an entry for `root`, so this only
+/// returns `None` if the table is incomplete.
+pub fn lookup<T: Copy>(table: &[(&str, T)], language_tag: &LanguageTag) -> Option<T> {
+    fallbacks(language_tag)
+        .iter()
+        .filter_map(|locale| find(table, locale))
+        .next()
+}
+
+/// Look up the data for the first of `keys` that `language_tag` or any
+/// of its fallbacks has, in a table keyed by locale and then by key.
+///
+/// This is used for data with shorter widths, which are keyed like
+/// `day-narrow`, `day-short` and `day`, so that a locale's own wider
+/// form is preferred over a fallback locale's narrower one.
+pub fn lookup_keys<T: Copy>(
+    table: &[(&str, &[(&str, T)])],
+    language_tag: &LanguageTag,
+    keys: &[String],
+) -> Option<T> {
+    let fallbacks = fallbacks(language_tag);
+    keys.iter()
+        .filter_map(|key| {
+            fallbacks
+                .iter()
+                .filter_map(|locale| find(table, locale))
+                .filter_map(|data| find(data, key))
+                .next()
+        })
+        .next()
+}
+
+/// The region for `language_tag`: its own region if it has one,
+/// otherwise the most likely region for its language.
+pub fn region(language_tag: &LanguageTag) -> Option<String> {
+    match language_tag.region {
+        Some(ref region) => Some(region.to_uppercase()),
+        None => lookup(likely_subtags::REGIONS, language_tag).map(str::to_string),
+    }
+}
+
+/// The value of a Unicode locale extension keyword, like `latn` for
+/// the `nu` key in `ar-EG-u-nu-latn`.
+pub fn unicode_extension(language_tag: &LanguageTag, key: &str) -> Option<String> {
+    let subtags = language_tag
+        .extensions
+        .get(&b'u')
+        .or_else(|| language_tag.extensions.get(&b'U'))?;
+    let index = subtags
+        .iter()
+        .position(|subtag| subtag.eq_ignore_ascii_case(key))?;
+    let values: Vec<String> = subtags[index + 1..]
+        .iter()
+        .take_while(|subtag| subtag.len() > 2)
+        .map(|subtag| subtag.to_lowercase())
+        .collect();
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.join("-"))
+    }
+}
+
+/// Find the data for exactly `locale`.
+pub fn find<T: Copy>(table: &[(&str, T)], locale: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|&&(locales, _)| locales.split_whitespace().any(|l| l == locale))
+        .map(|&(_, data)| data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallbacks_work() {
+        let tag = "pt-BR".parse().unwrap();
+        assert_eq!(fallbacks(&tag), vec!["pt-BR", "pt", "root"]);
+
+        let tag = "ZH-hant-tw".parse().unwrap();
+        assert_eq!(
+            fallbacks(&tag),
+            vec!["zh-Hant-TW", "zh-Hant", "zh-TW", "zh", "root"]
+        );
+
+        assert_eq!(fallbacks(&LanguageTag::default()), vec!["root"]);
+    }
+
+    #[test]
+    fn lookup_works() {
+        let table = &[("root", 0), ("pt", 1), ("pt-PT", 2)];
+        assert_eq!(lookup(table, &"pt-BR".parse().unwrap()), Some(1));
+        assert_eq!(lookup(table, &"pt-PT".parse().unwrap()), Some(2));
+        assert_eq!(lookup(table, &"de".parse().unwrap()), Some(0));
+    }
+
+    #[test]
+    fn region_works() {
+        assert_eq!(region(&"en-gb".parse().unwrap()), Some("GB".to_string()));
+        assert_eq!(region(&"en".parse().unwrap()), Some("US".to_string()));
+        assert_eq!(region(&"zh-Hant".parse().unwrap()), Some("TW".to_string()));
+        assert_eq!(region(&LanguageTag::default()), None);
+    }
+
+    #[test]
+    fn unicode_extension_works() {
+        let tag = "ar-EG-u-ca-islamic-civil-nu-latn".parse().unwrap();
+        assert_eq!(unicode_extension(&tag, "nu"), Some("latn".to_string()));
+        assert_eq!(
+            unicode_extension(&tag, "ca"),
+            Some("islamic-civil".to_string())
+        );
+        assert_eq!(unicode_extension(&tag, "hc"), None);
+        assert_eq!(unicode_extension(&"en".parse().unwrap(), "nu"), None);
+    }
+}
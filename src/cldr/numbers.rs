@@ -0,0 +1,727 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Number symbols and patterns from the CLDR `numbers.json` data.
+//!
+//! Patterns use the [CLDR pattern syntax]: `#` and `0` are digits,
+//! `,` marks the grouping positions, `.` the decimal separator, and
+//! `%`, `¤`, `-` and `+` are replaced by the locale's symbols.
+//!
+//! [CLDR pattern syntax]: http://unicode.org/reports/tr35/tr35-numbers.html#Number_Patterns
+
+use cldr::PluralForms;
+use PluralCategory::{Few, Many, One, Other};
+
+/// The symbols used when writing numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Symbols {
+    /// The decimal separator.
+    pub decimal: &'static str,
+    /// The grouping separator.
+    pub group: &'static str,
+    /// The percent sign.
+    pub percent: &'static str,
+    /// The per mille sign.
+    pub permille: &'static str,
+    /// The minus sign.
+    pub minus: &'static str,
+    /// The plus sign.
+    pub plus: &'static str,
+    /// The separator between the mantissa and exponent in scientific
+    /// notation.
+    pub exponential: &'static str,
+}
+
+/// The number formatting data for a locale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberData {
+    /// The default numbering system, such as `latn` or `arab`.
+    pub numbering_system: &'static str,
+    /// The symbols for the default numbering system.
+    pub symbols: Symbols,
+    /// The pattern for plain decimal numbers.
+    pub decimal_pattern: &'static str,
+    /// The pattern for percentages.
+    pub percent_pattern: &'static str,
+    /// The pattern for currency amounts.
+    pub currency_pattern: &'static str,
+    /// The pattern for currency amounts in accounting, if it differs
+    /// from `currency_pattern`. This usually puts negative amounts in
+    /// parentheses.
+    pub accounting_pattern: Option<&'static str>,
+    /// The number of digits that must be before the first grouping
+    /// separator for grouping to be used at all, so that Spanish
+    /// writes `1234` but `12.345`.
+    pub minimum_grouping_digits: usize,
+}
+
+/// A numbering system: the digits `0` to `9`, and the symbols to use
+/// with them when the locale doesn't have its own.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberingSystem {
+    /// The ten digits, in order.
+    pub digits: &'static str,
+    /// The default symbols for the numbering system, if they differ
+    /// from the Latin ones.
+    pub symbols: Option<Symbols>,
+}
+
+/// The symbols used with Latin digits in the `root` locale.
+pub const LATN: Symbols = Symbols {
+    decimal: ".",
+    group: ",",
+    percent: "%",
+    permille: "‰",
+    minus: "-",
+    plus: "+",
+    exponential: "E",
+};
+
+const COMMA_DOT: Symbols = Symbols {
+    decimal: ",",
+    group: ".",
+    ..LATN
+};
+
+const COMMA_SPACE: Symbols = Symbols {
+    decimal: ",",
+    group: "\u{a0}",
+    ..LATN
+};
+
+const COMMA_SPACE_MINUS: Symbols = Symbols {
+    decimal: ",",
+    group: "\u{a0}",
+    minus: "\u{2212}",
+    ..LATN
+};
+
+const ARAB: Symbols = Symbols {
+    decimal: "\u{66b}",
+    group: "\u{66c}",
+    percent: "\u{66a}\u{61c}",
+    permille: "\u{609}\u{61c}",
+    minus: "\u{61c}-",
+    plus: "\u{61c}+",
+    exponential: "\u{623}\u{633}",
+};
+
+const ARABEXT: Symbols = Symbols {
+    decimal: "\u{66b}",
+    group: "\u{66c}",
+    percent: "\u{66a}",
+    permille: "\u{609}",
+    minus: "\u{200e}\u{2212}",
+    plus: "\u{200e}+",
+    exponential: "\u{d7}\u{6f1}\u{6f0}^",
+};
+
+const DEFAULT: NumberData = NumberData {
+    numbering_system: "latn",
+    symbols: LATN,
+    decimal_pattern: "#,##0.###",
+    percent_pattern: "#,##0%",
+    currency_pattern: "¤#,##0.00",
+    accounting_pattern: None,
+    minimum_grouping_digits: 1,
+};
+
+const ENGLISH: NumberData = NumberData {
+    accounting_pattern: Some("¤#,##0.00;(¤#,##0.00)"),
+    ..DEFAULT
+};
+
+const INDIAN: NumberData = NumberData {
+    decimal_pattern: "#,##,##0.###",
+    percent_pattern: "#,##,##0%",
+    currency_pattern: "¤#,##,##0.00",
+    accounting_pattern: Some("¤#,##,##0.00;(¤#,##,##0.00)"),
+    ..DEFAULT
+};
+
+/// Number formatting data, keyed by locale.
+pub const NUMBERS: &[(&str, NumberData)] = &[
+    ("root", DEFAULT),
+    ("en ja ko th zh", ENGLISH),
+    ("en-IN hi", INDIAN),
+    (
+        "ar",
+        NumberData {
+            numbering_system: "arab",
+            symbols: ARAB,
+            currency_pattern: "\u{200f}#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "ar-DZ ar-EH ar-LY ar-MA ar-TN",
+        NumberData {
+            symbols: Symbols {
+                percent: "\u{200e}%\u{200e}",
+                minus: "\u{200e}-",
+                plus: "\u{200e}+",
+                ..COMMA_DOT
+            },
+            currency_pattern: "\u{200f}#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "bn",
+        NumberData {
+            numbering_system: "beng",
+            currency_pattern: "#,##,##0.00¤",
+            accounting_pattern: None,
+            ..INDIAN
+        },
+    ),
+    (
+        "cs",
+        NumberData {
+            symbols: COMMA_SPACE,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "da",
+        NumberData {
+            symbols: COMMA_DOT,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "de",
+        NumberData {
+            symbols: COMMA_DOT,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "de-AT",
+        NumberData {
+            symbols: COMMA_SPACE,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "¤\u{a0}#,##0.00",
+            ..DEFAULT
+        },
+    ),
+    (
+        "de-CH de-LI",
+        NumberData {
+            symbols: Symbols {
+                group: "\u{2019}",
+                ..LATN
+            },
+            currency_pattern: "¤\u{a0}#,##0.00;¤-#,##0.00",
+            ..DEFAULT
+        },
+    ),
+    (
+        "es",
+        NumberData {
+            symbols: COMMA_DOT,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            minimum_grouping_digits: 2,
+            ..DEFAULT
+        },
+    ),
+    (
+        "fa",
+        NumberData {
+            numbering_system: "arabext",
+            symbols: ARABEXT,
+            currency_pattern: "\u{200e}¤#,##0.00",
+            ..DEFAULT
+        },
+    ),
+    (
+        "fi",
+        NumberData {
+            symbols: COMMA_SPACE_MINUS,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "fr",
+        NumberData {
+            symbols: Symbols {
+                group: "\u{202f}",
+                ..COMMA_DOT
+            },
+            percent_pattern: "#,##0\u{202f}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "fr-CA",
+        NumberData {
+            symbols: COMMA_SPACE,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "he",
+        NumberData {
+            symbols: Symbols {
+                minus: "\u{200e}-",
+                plus: "\u{200e}+",
+                ..LATN
+            },
+            currency_pattern: "\u{200f}#,##0.00\u{a0}¤;\u{200f}-#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "it",
+        NumberData {
+            symbols: COMMA_DOT,
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "mr",
+        NumberData {
+            numbering_system: "deva",
+            percent_pattern: "#,##0%",
+            currency_pattern: "¤#,##0.00",
+            accounting_pattern: None,
+            ..INDIAN
+        },
+    ),
+    (
+        "nb nn no",
+        NumberData {
+            symbols: COMMA_SPACE_MINUS,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "ne",
+        NumberData {
+            numbering_system: "deva",
+            currency_pattern: "¤\u{a0}#,##,##0.00",
+            accounting_pattern: None,
+            ..INDIAN
+        },
+    ),
+    (
+        "nl",
+        NumberData {
+            symbols: COMMA_DOT,
+            currency_pattern: "¤\u{a0}#,##0.00;¤\u{a0}-#,##0.00",
+            accounting_pattern: Some("¤\u{a0}#,##0.00;(¤\u{a0}#,##0.00)"),
+            ..DEFAULT
+        },
+    ),
+    (
+        "pl",
+        NumberData {
+            symbols: COMMA_SPACE,
+            currency_pattern: "#,##0.00\u{a0}¤",
+            minimum_grouping_digits: 2,
+            ..DEFAULT
+        },
+    ),
+    (
+        "pt",
+        NumberData {
+            symbols: COMMA_DOT,
+            currency_pattern: "¤\u{a0}#,##0.00",
+            ..DEFAULT
+        },
+    ),
+    (
+        "pt-PT",
+        NumberData {
+            symbols: COMMA_SPACE,
+            currency_pattern: "#,##0.00\u{a0}¤",
+            minimum_grouping_digits: 2,
+            ..DEFAULT
+        },
+    ),
+    (
+        "ru uk",
+        NumberData {
+            symbols: COMMA_SPACE,
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "sv",
+        NumberData {
+            symbols: Symbols {
+                exponential: "\u{d7}10^",
+                ..COMMA_SPACE_MINUS
+            },
+            percent_pattern: "#,##0\u{a0}%",
+            currency_pattern: "#,##0.00\u{a0}¤",
+            ..DEFAULT
+        },
+    ),
+    (
+        "tr",
+        NumberData {
+            symbols: COMMA_DOT,
+            percent_pattern: "%#,##0",
+            ..DEFAULT
+        },
+    ),
+];
+
+/// Compact decimal patterns, like `0K` for thousands. Each entry has
+/// the exponent of the smallest power of ten it is used for, and a
+/// pattern for each plural category.
+///
+/// The number of `0`s in a pattern is the number of integer digits
+/// shown, so `00K` is used for tens of thousands. An exponent that
+/// would have the same pattern as the entry before it, with another
+/// `0`, is left out.
+pub type CompactPatterns = &'static [(usize, PluralForms)];
+
+/// Short compact decimal patterns, like `1.2K`, keyed by locale.
+pub const COMPACT_SHORT: &[(&str, CompactPatterns)] = &[
+    (
+        "root",
+        &[
+            (3, &[(Other, "0K")]),
+            (6, &[(Other, "0M")]),
+            (9, &[(Other, "0G")]),
+            (12, &[(Other, "0T")]),
+        ],
+    ),
+    (
+        "en",
+        &[
+            (3, &[(Other, "0K")]),
+            (6, &[(Other, "0M")]),
+            (9, &[(Other, "0B")]),
+            (12, &[(Other, "0T")]),
+        ],
+    ),
+    (
+        "de",
+        &[
+            (6, &[(Other, "0\u{a0}Mio'.'")]),
+            (9, &[(Other, "0\u{a0}Mrd'.'")]),
+            (12, &[(Other, "0\u{a0}Bio'.'")]),
+        ],
+    ),
+    (
+        "es",
+        &[
+            (3, &[(Other, "0\u{a0}mil")]),
+            (6, &[(Other, "0\u{a0}M")]),
+            (10, &[(Other, "00\u{a0}mil\u{a0}M")]),
+            (12, &[(Other, "0\u{a0}B")]),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            (3, &[(Other, "0\u{a0}k")]),
+            (6, &[(Other, "0\u{a0}M")]),
+            (9, &[(Other, "0\u{a0}Md")]),
+            (12, &[(Other, "0\u{a0}Bn")]),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            (4, &[(Other, "0万")]),
+            (8, &[(Other, "0億")]),
+            (12, &[(Other, "0兆")]),
+        ],
+    ),
+    (
+        "ko",
+        &[
+            (3, &[(Other, "0천")]),
+            (4, &[(Other, "0만")]),
+            (8, &[(Other, "0억")]),
+            (12, &[(Other, "0조")]),
+        ],
+    ),
+    (
+        "nl",
+        &[
+            (3, &[(Other, "0K")]),
+            (6, &[(Other, "0\u{a0}mln'.'")]),
+            (9, &[(Other, "0\u{a0}mld'.'")]),
+            (12, &[(Other, "0\u{a0}bln'.'")]),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            (3, &[(Other, "0\u{a0}mil")]),
+            (6, &[(Other, "0\u{a0}mi")]),
+            (9, &[(Other, "0\u{a0}bi")]),
+            (12, &[(Other, "0\u{a0}tri")]),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            (3, &[(Other, "0\u{a0}тыс'.'")]),
+            (6, &[(Other, "0\u{a0}млн")]),
+            (9, &[(Other, "0\u{a0}млрд")]),
+            (12, &[(Other, "0\u{a0}трлн")]),
+        ],
+    ),
+    (
+        "sv",
+        &[
+            (3, &[(Other, "0\u{a0}tn")]),
+            (6, &[(Other, "0\u{a0}mn")]),
+            (9, &[(Other, "0\u{a0}md")]),
+            (12, &[(Other, "0\u{a0}bn")]),
+        ],
+    ),
+    (
+        "zh",
+        &[
+            (4, &[(Other, "0万")]),
+            (8, &[(Other, "0亿")]),
+            (12, &[(Other, "0万亿")]),
+        ],
+    ),
+    (
+        "zh-Hant",
+        &[
+            (4, &[(Other, "0萬")]),
+            (8, &[(Other, "0億")]),
+            (12, &[(Other, "0兆")]),
+        ],
+    ),
+];
+
+/// Long compact decimal patterns, like `1.2 thousand`, keyed by
+/// locale. Locales without long patterns use their short ones.
+pub const COMPACT_LONG: &[(&str, CompactPatterns)] = &[
+    (
+        "en",
+        &[
+            (3, &[(Other, "0 thousand")]),
+            (6, &[(Other, "0 million")]),
+            (9, &[(Other, "0 billion")]),
+            (12, &[(Other, "0 trillion")]),
+        ],
+    ),
+    (
+        "de",
+        &[
+            (3, &[(Other, "0 Tausend")]),
+            (6, &[(One, "0 Million"), (Other, "0 Millionen")]),
+            (9, &[(One, "0 Milliarde"), (Other, "0 Milliarden")]),
+            (12, &[(One, "0 Billion"), (Other, "0 Billionen")]),
+        ],
+    ),
+    (
+        "es",
+        &[
+            (3, &[(Other, "0 mil")]),
+            (6, &[(One, "0 millón"), (Other, "0 millones")]),
+            (9, &[(Other, "0 mil millones")]),
+            (12, &[(One, "0 billón"), (Other, "0 billones")]),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            (3, &[(Other, "0 mille")]),
+            (6, &[(One, "0 million"), (Other, "0 millions")]),
+            (9, &[(One, "0 milliard"), (Other, "0 milliards")]),
+            (12, &[(One, "0 billion"), (Other, "0 billions")]),
+        ],
+    ),
+    (
+        "nl",
+        &[
+            (3, &[(Other, "0 duizend")]),
+            (6, &[(Other, "0 miljoen")]),
+            (9, &[(Other, "0 miljard")]),
+            (12, &[(Other, "0 biljoen")]),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            (3, &[(Other, "0 mil")]),
+            (6, &[(One, "0 milhão"), (Other, "0 milhões")]),
+            (9, &[(One, "0 bilhão"), (Other, "0 bilhões")]),
+            (12, &[(One, "0 trilhão"), (Other, "0 trilhões")]),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            (
+                3,
+                &[
+                    (One, "0 тысяча"),
+                    (Few, "0 тысячи"),
+                    (Many, "0 тысяч"),
+                    (Other, "0 тысячи"),
+                ],
+            ),
+            (
+                6,
+                &[
+                    (One, "0 миллион"),
+                    (Few, "0 миллиона"),
+                    (Many, "0 миллионов"),
+                    (Other, "0 миллиона"),
+                ],
+            ),
+            (
+                9,
+                &[
+                    (One, "0 миллиард"),
+                    (Few, "0 миллиарда"),
+                    (Many, "0 миллиардов"),
+                    (Other, "0 миллиарда"),
+                ],
+            ),
+            (
+                12,
+                &[
+                    (One, "0 триллион"),
+                    (Few, "0 триллиона"),
+                    (Many, "0 триллионов"),
+                    (Other, "0 триллиона"),
+                ],
+            ),
+        ],
+    ),
+    (
+        "sv",
+        &[
+            (3, &[(Other, "0 tusen")]),
+            (6, &[(One, "0 miljon"), (Other, "0 miljoner")]),
+            (9, &[(One, "0 miljard"), (Other, "0 miljarder")]),
+            (12, &[(One, "0 biljon"), (Other, "0 biljoner")]),
+        ],
+    ),
+];
+
+/// Numbering systems, keyed by their CLDR identifier.
+pub const NUMBERING_SYSTEMS: &[(&str, NumberingSystem)] = &[
+    (
+        "latn",
+        NumberingSystem {
+            digits: "0123456789",
+            symbols: None,
+        },
+    ),
+    (
+        "arab",
+        NumberingSystem {
+            digits: "٠١٢٣٤٥٦٧٨٩",
+            symbols: Some(ARAB),
+        },
+    ),
+    (
+        "arabext",
+        NumberingSystem {
+            digits: "۰۱۲۳۴۵۶۷۸۹",
+            symbols: Some(ARABEXT),
+        },
+    ),
+    (
+        "beng",
+        NumberingSystem {
+            digits: "০১২৩৪৫৬৭৮৯",
+            symbols: None,
+        },
+    ),
+    (
+        "deva",
+        NumberingSystem {
+            digits: "०१२३४५६७८९",
+            symbols: None,
+        },
+    ),
+    (
+        "fullwide",
+        NumberingSystem {
+            digits: "０１２３４５６７８９",
+            symbols: None,
+        },
+    ),
+    (
+        "hanidec",
+        NumberingSystem {
+            digits: "〇一二三四五六七八九",
+            symbols: None,
+        },
+    ),
+    (
+        "tamldec",
+        NumberingSystem {
+            digits: "௦௧௨௩௪௫௬௭௮௯",
+            symbols: None,
+        },
+    ),
+    (
+        "thai",
+        NumberingSystem {
+            digits: "๐๑๒๓๔๕๖๗๘๙",
+            symbols: None,
+        },
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cldr::{find, lookup};
+
+    #[test]
+    fn lookup_works() {
+        let data = lookup(NUMBERS, &"de-DE".parse().unwrap()).unwrap();
+        assert_eq!(data.symbols.decimal, ",");
+
+        let data = lookup(NUMBERS, &"ar-MA".parse().unwrap()).unwrap();
+        assert_eq!(data.numbering_system, "latn");
+
+        let data = lookup(NUMBERS, &"ar-EG".parse().unwrap()).unwrap();
+        assert_eq!(data.numbering_system, "arab");
+    }
+
+    #[test]
+    fn numbering_systems_work() {
+        for &(_, data) in NUMBERS {
+            let system = find(NUMBERING_SYSTEMS, data.numbering_system).unwrap();
+            assert_eq!(system.digits.chars().count(), 10);
+        }
+    }
+
+    #[test]
+    fn compact_patterns_work() {
+        for &(_, patterns) in COMPACT_SHORT.iter().chain(COMPACT_LONG) {
+            for &(_, counts) in patterns {
+                assert!(counts.iter().any(|&(count, _)| count == Other));
+            }
+        }
+    }
+}
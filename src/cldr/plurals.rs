@@ -0,0 +1,620 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Plural rules from the CLDR `plurals.xml` and `ordinals.xml`
+//! supplemental data.
+//!
+//! The rules are kept in the CLDR plural rule syntax, along with their
+//! samples, and are parsed by [`PluralRules`] when first used. Locales
+//! without an entry use the `root` rules.
+//!
+//! [`PluralRules`]: ../../struct.PluralRules.html
+
+/// Cardinal plural rules, keyed by locale.
+pub const CARDINAL: &[(&str, &str)] = &[
+    (
+        "root bm bo dz hnj id ig ii in ja jbo jv jw kde kea km ko lkt lo ms my nqo osa \
+         sah ses sg su th to tpi vi wo yo yue zh",
+        "other: @integer 0~15, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "af an asa az bal bem bez bg brx ce cgg chr ckb dv ee el eo eu fo fur gsw ha \
+         haw hu jgo jmc ka kaj kcg kk kkj kl ks ksb ku ky lb lg mas mgo ml mn mr nah nb \
+         nd ne nn nnh no nr ny nyn om or os pap ps rm rof rwk saq sd sdh seh sn so sq ss \
+         ssy st syr ta te teo tig tk tn tr ts ug uz ve vo vun wae xh xog",
+        "one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "ast de en et fi fy gl ia io lij nl sc scn sv sw ur yi",
+        "one: i = 1 and v = 0 @integer 1; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "da",
+        "one: n = 1 or t != 0 and i = 0,1 @integer 1 @decimal 0.1~1.6; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 2.0~3.4, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "is",
+        "one: t = 0 and i % 10 = 1 and i % 100 != 11 or t % 10 = 1 and t % 100 != 11 \
+         @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … \
+         @decimal 0.1, 1.0, 1.1, 2.1, 3.1, 4.1, 5.1, 6.1, 7.1, 10.1, 100.1, 1000.1, …; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 0.2~0.9, 1.2~1.8, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "mk",
+        "one: v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11 \
+         @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … \
+         @decimal 0.1, 1.1, 2.1, 3.1, 4.1, 5.1, 6.1, 7.1, 10.1, 100.1, 1000.1, …; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 0.2~1.0, 1.2~1.7, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "am as bn doi fa gu hi kn pcm zu",
+        "one: i = 0 or n = 1 @integer 0, 1 @decimal 0.0~1.0, 0.00~0.04; \
+         other: @integer 2~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 1.1~2.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "ff hy kab",
+        "one: i = 0,1 @integer 0, 1 @decimal 0.0~1.5; \
+         other: @integer 2~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 2.0~3.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "si",
+        "one: n = 0,1 or i = 0 and f = 1 @integer 0, 1 \
+         @decimal 0.0, 0.1, 1.0, 0.00, 0.01, 1.00, 0.000, 0.001, 1.000, 0.0000, 0.0001, 1.0000; \
+         other: @integer 2~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.2~0.9, 1.1~1.8, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "ak bho guw ln mg nso pa ti wa",
+        "one: n = 0..1 @integer 0, 1 \
+         @decimal 0.0, 1.0, 0.00, 1.00, 0.000, 1.000, 0.0000, 1.0000; \
+         other: @integer 2~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.1~0.9, 1.1~1.7, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "es",
+        "one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 \
+         @integer 1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, … \
+         @decimal 1.0000001c6, 1.1c6, 2.0000001c6, 2.1c6, 3.0000001c6, 3.1c6, …; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1c3, 2c3, 3c3, 4c3, 5c3, 6c3, … \
+         @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, \
+         1.0001c3, 1.1c3, 2.0001c3, 2.1c3, 3.0001c3, 3.1c3, …",
+    ),
+    (
+        "fr pt",
+        "one: i = 0,1 @integer 0, 1 @decimal 0.0~1.5; \
+         many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 \
+         @integer 1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, … \
+         @decimal 1.0000001c6, 1.1c6, 2.0000001c6, 2.1c6, 3.0000001c6, 3.1c6, …; \
+         other: @integer 2~17, 100, 1000, 10000, 100000, 1c3, 2c3, 3c3, 4c3, 5c3, 6c3, … \
+         @decimal 2.0~3.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, \
+         1.0001c3, 1.1c3, 2.0001c3, 2.1c3, 3.0001c3, 3.1c3, …",
+    ),
+    (
+        "ca it pt-PT vec",
+        "one: i = 1 and v = 0 @integer 1; \
+         many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 \
+         @integer 1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, … \
+         @decimal 1.0000001c6, 1.1c6, 2.0000001c6, 2.1c6, 3.0000001c6, 3.1c6, …; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1c3, 2c3, 3c3, 4c3, 5c3, 6c3, … \
+         @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, \
+         1.0001c3, 1.1c3, 2.0001c3, 2.1c3, 3.0001c3, 3.1c3, …",
+    ),
+    (
+        "ceb fil tl",
+        "one: v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9 \
+         @integer 0~3, 5, 7, 8, 10~13, 15, 17, 18, 20, 21, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0~0.3, 0.5, 0.7, 0.8, 1.0~1.3, 1.5, 1.7, 1.8, 2.0, 2.1, 10.0, 100.0, \
+         1000.0, 10000.0, 100000.0, 1000000.0, …; \
+         other: @integer 4, 6, 9, 14, 16, 19, 24, 26, 104, 1004, … \
+         @decimal 0.4, 0.6, 0.9, 1.4, 1.6, 1.9, 2.4, 2.6, 10.4, 100.4, 1000.4, …",
+    ),
+    (
+        "lv prg",
+        "zero: n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19 \
+         @integer 0, 10~20, 30, 40, 50, 60, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 100.0, 1000.0, 10000.0, \
+         100000.0, 1000000.0, …; \
+         one: n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 \
+         or v != 2 and f % 10 = 1 \
+         @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … \
+         @decimal 0.1, 1.0, 1.1, 2.1, 3.1, 4.1, 5.1, 6.1, 7.1, 10.1, 100.1, 1000.1, …; \
+         other: @integer 2~9, 22~29, 102, 1002, … \
+         @decimal 0.2~0.9, 1.2~1.9, 10.2, 100.2, 1000.2, …",
+    ),
+    (
+        "lag",
+        "zero: n = 0 @integer 0 @decimal 0.0, 0.00, 0.000, 0.0000; \
+         one: i = 0,1 and n != 0 @integer 1 @decimal 0.1~1.6; \
+         other: @integer 2~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 2.0~3.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "ksh",
+        "zero: n = 0 @integer 0 @decimal 0.0, 0.00, 0.000, 0.0000; \
+         one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         other: @integer 2~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.1~0.9, 1.1~1.7, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "he iw",
+        "one: i = 1 and v = 0 or i = 0 and v != 0 @integer 1 @decimal 0.0~0.9, 0.00~0.05; \
+         two: i = 2 and v = 0 @integer 2; \
+         other: @integer 0, 3~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 1.0~2.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "iu naq sat se sma smi smj smn sms",
+        "one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         two: n = 2 @integer 2 @decimal 2.0, 2.00, 2.000, 2.0000; \
+         other: @integer 0, 3~17, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "shi",
+        "one: i = 0 or n = 1 @integer 0, 1 @decimal 0.0~1.0, 0.00~0.04; \
+         few: n = 2..10 @integer 2~10 \
+         @decimal 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 2.00, 3.00, 4.00, 5.00, \
+         6.00, 7.00, 8.00; \
+         other: @integer 11~26, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 1.1~1.9, 2.1~2.7, 10.1, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "mo ro",
+        "one: i = 1 and v = 0 @integer 1; \
+         few: v != 0 or n = 0 or n != 1 and n % 100 = 1..19 @integer 0, 2~16, 101, 1001, … \
+         @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …; \
+         other: @integer 20~35, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "bs hr sh sr",
+        "one: v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11 \
+         @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … \
+         @decimal 0.1, 1.1, 2.1, 3.1, 4.1, 5.1, 6.1, 7.1, 10.1, 100.1, 1000.1, …; \
+         few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14 \
+         or f % 10 = 2..4 and f % 100 != 12..14 \
+         @integer 2~4, 22~24, 32~34, 42~44, 52~54, 62, 102, 1002, … \
+         @decimal 0.2~0.4, 1.2~1.4, 2.2~2.4, 3.2~3.4, 4.2~4.4, 5.2, 10.2, 100.2, 1000.2, …; \
+         other: @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 0.5~1.0, 1.5~2.0, 2.5~2.7, 10.0, 100.0, 1000.0, 10000.0, 100000.0, \
+         1000000.0, …",
+    ),
+    (
+        "gd",
+        "one: n = 1,11 @integer 1, 11 \
+         @decimal 1.0, 11.0, 1.00, 11.00, 1.000, 11.000, 1.0000; \
+         two: n = 2,12 @integer 2, 12 \
+         @decimal 2.0, 12.0, 2.00, 12.00, 2.000, 12.000, 2.0000; \
+         few: n = 3..10,13..19 @integer 3~10, 13~19 \
+         @decimal 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 13.0, 14.0, 15.0, 16.0, 17.0, \
+         18.0, 19.0, 3.00; \
+         other: @integer 0, 20~34, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0~0.9, 1.1~1.6, 10.1, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "sl",
+        "one: v = 0 and i % 100 = 1 @integer 1, 101, 201, 301, 401, 501, 601, 701, 1001, …; \
+         two: v = 0 and i % 100 = 2 @integer 2, 102, 202, 302, 402, 502, 602, 702, 1002, …; \
+         few: v = 0 and i % 100 = 3..4 or v != 0 \
+         @integer 3, 4, 103, 104, 203, 204, 303, 304, 403, 404, 503, 504, 603, 604, 703, \
+         704, 1003, … \
+         @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …; \
+         other: @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "dsb hsb",
+        "one: v = 0 and i % 100 = 1 or f % 100 = 1 \
+         @integer 1, 101, 201, 301, 401, 501, 601, 701, 1001, … \
+         @decimal 0.1, 1.1, 2.1, 3.1, 4.1, 5.1, 6.1, 7.1, 10.1, 100.1, 1000.1, …; \
+         two: v = 0 and i % 100 = 2 or f % 100 = 2 \
+         @integer 2, 102, 202, 302, 402, 502, 602, 702, 1002, … \
+         @decimal 0.2, 1.2, 2.2, 3.2, 4.2, 5.2, 6.2, 7.2, 10.2, 100.2, 1000.2, …; \
+         few: v = 0 and i % 100 = 3..4 or f % 100 = 3..4 \
+         @integer 3, 4, 103, 104, 203, 204, 303, 304, 403, 404, 503, 504, 603, 604, 703, \
+         704, 1003, … \
+         @decimal 0.3, 0.4, 1.3, 1.4, 2.3, 2.4, 3.3, 3.4, 4.3, 4.4, 5.3, 5.4, 6.3, 6.4, \
+         7.3, 7.4, 10.3, 100.3, 1000.3, …; \
+         other: @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 0.5~1.0, 1.5~2.0, 2.5~2.7, 10.0, 100.0, 1000.0, 10000.0, 100000.0, \
+         1000000.0, …",
+    ),
+    (
+        "cs sk",
+        "one: i = 1 and v = 0 @integer 1; \
+         few: i = 2..4 and v = 0 @integer 2~4; \
+         many: v != 0 @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …; \
+         other: @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "pl",
+        "one: i = 1 and v = 0 @integer 1; \
+         few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14 \
+         @integer 2~4, 22~24, 32~34, 42~44, 52~54, 62, 102, 1002, …; \
+         many: v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 \
+         or v = 0 and i % 100 = 12..14 \
+         @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, …; \
+         other: @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "be",
+        "one: n % 10 = 1 and n % 100 != 11 \
+         @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … \
+         @decimal 1.0, 21.0, 31.0, 41.0, 51.0, 61.0, 71.0, 81.0, 101.0, 1001.0, …; \
+         few: n % 10 = 2..4 and n % 100 != 12..14 \
+         @integer 2~4, 22~24, 32~34, 42~44, 52~54, 62, 102, 1002, … \
+         @decimal 2.0, 3.0, 4.0, 22.0, 23.0, 24.0, 32.0, 33.0, 102.0, 1002.0, …; \
+         many: n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14 \
+         @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 100.0, 1000.0, 10000.0, \
+         100000.0, 1000000.0, …; \
+         other: @decimal 0.1~0.9, 1.1~1.7, 10.1, 100.1, 1000.1, …",
+    ),
+    (
+        "ru uk",
+        "one: v = 0 and i % 10 = 1 and i % 100 != 11 \
+         @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, …; \
+         few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14 \
+         @integer 2~4, 22~24, 32~34, 42~44, 52~54, 62, 102, 1002, …; \
+         many: v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14 \
+         @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, …; \
+         other: @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "lt",
+        "one: n % 10 = 1 and n % 100 != 11..19 \
+         @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … \
+         @decimal 1.0, 21.0, 31.0, 41.0, 51.0, 61.0, 71.0, 81.0, 101.0, 1001.0, …; \
+         few: n % 10 = 2..9 and n % 100 != 11..19 @integer 2~9, 22~29, 102, 1002, … \
+         @decimal 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 22.0, 102.0, 1002.0, …; \
+         many: f != 0 @decimal 0.1~0.9, 1.1~1.7, 10.1, 100.1, 1000.1, …; \
+         other: @integer 0, 10~20, 30, 40, 50, 60, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 100.0, 1000.0, 10000.0, \
+         100000.0, 1000000.0, …",
+    ),
+    (
+        "br",
+        "one: n % 10 = 1 and n % 100 != 11,71,91 \
+         @integer 1, 21, 31, 41, 51, 61, 81, 101, 1001, … \
+         @decimal 1.0, 21.0, 31.0, 41.0, 51.0, 61.0, 81.0, 101.0, 1001.0, …; \
+         two: n % 10 = 2 and n % 100 != 12,72,92 \
+         @integer 2, 22, 32, 42, 52, 62, 82, 102, 1002, … \
+         @decimal 2.0, 22.0, 32.0, 42.0, 52.0, 62.0, 82.0, 102.0, 1002.0, …; \
+         few: n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99 \
+         @integer 3, 4, 9, 23, 24, 29, 33, 34, 39, 43, 44, 49, 103, 1003, … \
+         @decimal 3.0, 4.0, 9.0, 23.0, 24.0, 29.0, 33.0, 34.0, 103.0, 1003.0, …; \
+         many: n != 0 and n % 1000000 = 0 @integer 1000000, … \
+         @decimal 1000000.0, 1000000.00, 1000000.000, 1000000.0000, …; \
+         other: @integer 0, 5~8, 10~20, 100, 1000, 10000, 100000, … \
+         @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, …",
+    ),
+    (
+        "mt",
+        "one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         two: n = 2 @integer 2 @decimal 2.0, 2.00, 2.000, 2.0000; \
+         few: n = 0 or n % 100 = 3..10 @integer 0, 3~10, 103~109, 1003, … \
+         @decimal 0.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 103.0, 1003.0, …; \
+         many: n % 100 = 11..19 @integer 11~19, 111~117, 1011, … \
+         @decimal 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 111.0, 1011.0, …; \
+         other: @integer 20~35, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.1~0.9, 1.1~1.7, 10.1, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "ga",
+        "one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         two: n = 2 @integer 2 @decimal 2.0, 2.00, 2.000, 2.0000; \
+         few: n = 3..6 @integer 3~6 \
+         @decimal 3.0, 4.0, 5.0, 6.0, 3.00, 4.00, 5.00, 6.00, 3.000, 4.000, 5.000, 6.000, \
+         3.0000, 4.0000, 5.0000, 6.0000; \
+         many: n = 7..10 @integer 7~10 \
+         @decimal 7.0, 8.0, 9.0, 10.0, 7.00, 8.00, 9.00, 10.00, 7.000, 8.000, 9.000, \
+         10.000, 7.0000, 8.0000, 9.0000, 10.0000; \
+         other: @integer 0, 11~25, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.0~0.9, 1.1~1.6, 10.1, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "gv",
+        "one: v = 0 and i % 10 = 1 @integer 1, 11, 21, 31, 41, 51, 61, 71, 101, 1001, …; \
+         two: v = 0 and i % 10 = 2 @integer 2, 12, 22, 32, 42, 52, 62, 72, 102, 1002, …; \
+         few: v = 0 and i % 100 = 0,20,40,60,80 \
+         @integer 0, 20, 40, 60, 80, 100, 120, 140, 1000, 10000, 100000, 1000000, …; \
+         many: v != 0 @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …; \
+         other: @integer 3~10, 13~19, 23, 103, 1003, …",
+    ),
+    (
+        "kw",
+        "zero: n = 0 @integer 0 @decimal 0.0, 0.00, 0.000, 0.0000; \
+         one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         two: n % 100 = 2,22,42,62,82 \
+         or n % 1000 = 0 and n % 100000 = 1000..20000,40000,60000,80000 \
+         or n != 0 and n % 1000000 = 100000 \
+         @integer 2, 22, 42, 62, 82, 102, 122, 142, 1000, 10000, 100000, … \
+         @decimal 2.0, 22.0, 42.0, 62.0, 82.0, 102.0, 122.0, 142.0, 1000.0, 10000.0, \
+         100000.0, …; \
+         few: n % 100 = 3,23,43,63,83 @integer 3, 23, 43, 63, 83, 103, 123, 143, 1003, … \
+         @decimal 3.0, 23.0, 43.0, 63.0, 83.0, 103.0, 123.0, 143.0, 1003.0, …; \
+         many: n != 1 and n % 100 = 1,21,41,61,81 \
+         @integer 21, 41, 61, 81, 101, 121, 141, 161, 1001, … \
+         @decimal 21.0, 41.0, 61.0, 81.0, 101.0, 121.0, 141.0, 161.0, 1001.0, …; \
+         other: @integer 4~19, 100, 1000000, … \
+         @decimal 0.1~0.9, 1.1~1.7, 10.0, 100.0, 1000000.0, …",
+    ),
+    (
+        "ar ars",
+        "zero: n = 0 @integer 0 @decimal 0.0, 0.00, 0.000, 0.0000; \
+         one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         two: n = 2 @integer 2 @decimal 2.0, 2.00, 2.000, 2.0000; \
+         few: n % 100 = 3..10 @integer 3~10, 103~110, 1003, … \
+         @decimal 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 103.0, 1003.0, …; \
+         many: n % 100 = 11..99 @integer 11~26, 111, 1011, … \
+         @decimal 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 111.0, 1011.0, …; \
+         other: @integer 100~102, 200~202, 300~302, 400~402, 500~502, 600, 1000, 10000, \
+         100000, 1000000, … \
+         @decimal 0.1~0.9, 1.1~1.7, 10.1, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "cy",
+        "zero: n = 0 @integer 0 @decimal 0.0, 0.00, 0.000, 0.0000; \
+         one: n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000; \
+         two: n = 2 @integer 2 @decimal 2.0, 2.00, 2.000, 2.0000; \
+         few: n = 3 @integer 3 @decimal 3.0, 3.00, 3.000, 3.0000; \
+         many: n = 6 @integer 6 @decimal 6.0, 6.00, 6.000, 6.0000; \
+         other: @integer 4, 5, 7~20, 100, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.1~0.9, 1.1~1.7, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+    (
+        "tzm",
+        "one: n = 0..1 or n = 11..99 @integer 0, 1, 11~24 \
+         @decimal 0.0, 1.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 19.0, 20.0, \
+         21.0, 22.0, 23.0, 24.0; \
+         other: @integer 2~10, 100~106, 1000, 10000, 100000, 1000000, … \
+         @decimal 0.1~0.9, 1.1~1.7, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …",
+    ),
+];
+
+/// Ordinal plural rules, keyed by locale.
+pub const ORDINAL: &[(&str, &str)] = &[
+    (
+        "root af am an ar bg bs ce cs da de dsb el es et eu fa fi fy gl gsw he hr hsb ia \
+         id in is iw ja km kn ko ky lt lv ml mn my nb nl no pa pl prg ps pt ru sd sh si \
+         sk sl sr sw ta te th tpi tr ur uz yue zh zu",
+        "other: @integer 0~15, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "en",
+        "one: n % 10 = 1 and n % 100 != 11 @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, …; \
+         two: n % 10 = 2 and n % 100 != 12 @integer 2, 22, 32, 42, 52, 62, 72, 82, 102, 1002, …; \
+         few: n % 10 = 3 and n % 100 != 13 @integer 3, 23, 33, 43, 53, 63, 73, 83, 103, 1003, …; \
+         other: @integer 0, 4~18, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "bal fil fr ga hy lo mo ms ro tl vi",
+        "one: n = 1 @integer 1; \
+         other: @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "it sc scn",
+        "many: n = 11,8,80,800 @integer 8, 11, 80, 800; \
+         other: @integer 0~7, 9, 10, 12~17, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "sv",
+        "one: n % 10 = 1,2 and n % 100 != 11,12 \
+         @integer 1, 2, 21, 22, 31, 32, 41, 42, 51, 52, 61, 62, 71, 72, 81, 82, 101, 1001, …; \
+         other: @integer 0, 3~17, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "hu",
+        "one: n = 1,5 @integer 1, 5; \
+         other: @integer 0, 2~4, 6~17, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "ne",
+        "one: n = 1..4 @integer 1~4; \
+         other: @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "be",
+        "few: n % 10 = 2,3 and n % 100 != 12,13 \
+         @integer 2, 3, 22, 23, 32, 33, 42, 43, 52, 53, 62, 63, 72, 73, 82, 83, 102, 1002, …; \
+         other: @integer 0, 1, 4~17, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "uk",
+        "few: n % 10 = 3 and n % 100 != 13 @integer 3, 23, 33, 43, 53, 63, 73, 83, 103, 1003, …; \
+         other: @integer 0~2, 4~16, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "tk",
+        "few: n % 10 = 6,9 or n = 10 @integer 6, 9, 10, 16, 19, 26, 29, 36, 39, 106, 1006, …; \
+         other: @integer 0~5, 7, 8, 11~15, 17, 18, 20, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "kk",
+        "many: n % 10 = 6 or n % 10 = 9 or n % 10 = 0 and n != 0 \
+         @integer 6, 9, 10, 16, 19, 20, 26, 29, 30, 36, 39, 40, 100, 1000, 10000, 100000, \
+         1000000, …; \
+         other: @integer 0~5, 7, 8, 11~15, 17, 18, 21, 101, 1001, …",
+    ),
+    (
+        "lij",
+        "many: n = 11,8,80..89,800..899 @integer 8, 11, 80~89, 800~803; \
+         other: @integer 0~7, 9, 10, 12~17, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "ka",
+        "one: i = 1 @integer 1; \
+         many: i = 0 or i % 100 = 2..20,40,60,80 @integer 0, 2~16, 102, 1002, …; \
+         other: @integer 21~36, 101, 1001, …",
+    ),
+    (
+        "sq",
+        "one: n = 1 @integer 1; \
+         many: n % 10 = 4 and n % 100 != 14 @integer 4, 24, 34, 44, 54, 64, 74, 84, 104, 1004, …; \
+         other: @integer 0, 2, 3, 5~17, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "kw",
+        "one: n = 1..4 or n % 100 = 1..4,21..24,41..44,61..64,81..84 \
+         @integer 1~4, 21~24, 41~44, 61~64, 101, 1001, …; \
+         many: n = 5 or n % 100 = 5 @integer 5, 105, 205, 305, 405, 505, 605, 705, 1005, …; \
+         other: @integer 0, 6~20, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "mr",
+        "one: n = 1 @integer 1; \
+         two: n = 2,3 @integer 2, 3; \
+         few: n = 4 @integer 4; \
+         other: @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "gd",
+        "one: n = 1,11 @integer 1, 11; \
+         two: n = 2,12 @integer 2, 12; \
+         few: n = 3,13 @integer 3, 13; \
+         other: @integer 0, 4~10, 14~21, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "ca",
+        "one: n = 1,3 @integer 1, 3; \
+         two: n = 2 @integer 2; \
+         few: n = 4 @integer 4; \
+         other: @integer 0, 5~19, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "mk",
+        "one: i % 10 = 1 and i % 100 != 11 @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, …; \
+         two: i % 10 = 2 and i % 100 != 12 @integer 2, 22, 32, 42, 52, 62, 72, 82, 102, 1002, …; \
+         many: i % 10 = 7,8 and i % 100 != 17,18 \
+         @integer 7, 8, 27, 28, 37, 38, 47, 48, 57, 58, 67, 68, 77, 78, 87, 88, 107, 1007, …; \
+         other: @integer 0, 3~6, 9~19, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "az",
+        "one: i % 10 = 1,2,5,7,8 or i % 100 = 20,50,70,80 \
+         @integer 1, 2, 5, 7, 8, 11, 12, 15, 17, 18, 20~22, 25, 101, 1001, …; \
+         few: i % 10 = 3,4 or i % 1000 = 100,200,300,400,500,600,700,800,900 \
+         @integer 3, 4, 13, 14, 23, 24, 33, 34, 43, 44, 53, 54, 63, 64, 73, 74, 100, 1003, …; \
+         many: i = 0 or i % 10 = 6 or i % 100 = 40,60,90 \
+         @integer 0, 6, 16, 26, 36, 40, 46, 56, 106, 1006, …; \
+         other: @integer 9, 10, 19, 29, 30, 39, 49, 59, 69, 79, 109, 1000, 10000, 100000, \
+         1000000, …",
+    ),
+    (
+        "gu hi",
+        "one: n = 1 @integer 1; \
+         two: n = 2,3 @integer 2, 3; \
+         few: n = 4 @integer 4; \
+         many: n = 6 @integer 6; \
+         other: @integer 0, 5, 7~20, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "as bn",
+        "one: n = 1,5,7,8,9,10 @integer 1, 5, 7~10; \
+         two: n = 2,3 @integer 2, 3; \
+         few: n = 4 @integer 4; \
+         many: n = 6 @integer 6; \
+         other: @integer 0, 11~25, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "or",
+        "one: n = 1,5,7..9 @integer 1, 5, 7~9; \
+         two: n = 2,3 @integer 2, 3; \
+         few: n = 4 @integer 4; \
+         many: n = 6 @integer 6; \
+         other: @integer 0, 10~24, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+    (
+        "cy",
+        "zero: n = 0,7,8,9 @integer 0, 7~9; \
+         one: n = 1 @integer 1; \
+         two: n = 2 @integer 2; \
+         few: n = 3,4 @integer 3, 4; \
+         many: n = 5,6 @integer 5, 6; \
+         other: @integer 10~25, 100, 1000, 10000, 100000, 1000000, …",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cldr::lookup;
+    use PluralCategory::{self, Few, Many, One, Other, Two, Zero};
+    use PluralRules;
+
+    fn categories(table: &[(&str, &str)], locale: &str, values: &[i64]) -> Vec<PluralCategory> {
+        let rules = PluralRules::parse(lookup(table, &locale.parse().unwrap()).unwrap()).unwrap();
+        values.iter().map(|&value| rules.select(value)).collect()
+    }
+
+    #[test]
+    fn cardinals_work() {
+        let values = &[0, 1, 2, 3, 5, 11, 12, 21, 22, 25, 101, 1_000_000];
+        assert_eq!(
+            categories(CARDINAL, "ru", values),
+            vec![Many, One, Few, Few, Many, Many, Many, One, Few, Many, One, Many]
+        );
+        assert_eq!(
+            categories(CARDINAL, "pl", values),
+            vec![Many, One, Few, Few, Many, Many, Many, Many, Few, Many, Many, Many]
+        );
+        assert_eq!(
+            categories(CARDINAL, "ar", values),
+            vec![Zero, One, Two, Few, Few, Many, Many, Many, Many, Many, Other, Other]
+        );
+        assert_eq!(
+            categories(CARDINAL, "cy", values),
+            vec![Zero, One, Two, Few, Other, Other, Other, Other, Other, Other, Other, Other]
+        );
+        assert_eq!(
+            categories(CARDINAL, "fr", values),
+            vec![One, One, Other, Other, Other, Other, Other, Other, Other, Other, Other, Many]
+        );
+        assert_eq!(
+            categories(CARDINAL, "is", values),
+            vec![Other, One, Other, Other, Other, Other, Other, One, Other, Other, One, Other]
+        );
+        assert_eq!(
+            categories(CARDINAL, "ja", values),
+            vec![Other; values.len()]
+        );
+    }
+
+    #[test]
+    fn regional_cardinals_work() {
+        assert_eq!(categories(CARDINAL, "pt-BR", &[0, 1]), vec![One, One]);
+        assert_eq!(categories(CARDINAL, "pt-PT", &[0, 1]), vec![Other, One]);
+        assert_eq!(categories(CARDINAL, "en-GB", &[0, 1]), vec![Other, One]);
+    }
+
+    #[test]
+    fn ordinals_work() {
+        let values = &[0, 1, 2, 3, 4, 5, 6, 10, 11, 21, 100, 1000];
+        assert_eq!(
+            categories(ORDINAL, "en", values),
+            vec![Other, One, Two, Few, Other, Other, Other, Other, Other, One, Other, Other]
+        );
+        assert_eq!(
+            categories(ORDINAL, "az", values),
+            vec![Many, One, One, Few, Few, One, Many, Other, One, One, Few, Other]
+        );
+        assert_eq!(
+            categories(ORDINAL, "kw", values),
+            vec![Other, One, One, One, One, Many, Other, Other, Other, One, Other, Other]
+        );
+        assert_eq!(categories(ORDINAL, "de", values), vec![Other; values.len()]);
+    }
+}
@@ -0,0 +1,774 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Rule-based number format rules, adapted from the ICU `rbnf` data.
+//!
+//! The rules are kept in ICU's rule syntax, with one rule per
+//! line, and are parsed by [`NumberRules`] when first used. Rule sets
+//! whose names start with `%%` are private, and only used by other
+//! rule sets. Locales without an entry use the `root` rules.
+//!
+//! [`NumberRules`]: ../../struct.NumberRules.html
+
+/// Rules for spelling out numbers in words, like `forty-two`, keyed by
+/// locale.
+pub const SPELLOUT: &[(&str, &str)] = &[
+    (
+        "root",
+        "%spellout-numbering: \
+         -x: −>>; \
+         x.x: =#,##0.#=; \
+         0: =#,##0=; \
+         %spellout-cardinal: \
+         -x: −>>; \
+         x.x: =#,##0.#=; \
+         0: =#,##0=;",
+    ),
+    (
+        "de",
+        "%spellout-numbering: \
+         -x: minus >>; \
+         x.x: << Komma >>; \
+         0: null; \
+         1: eins; \
+         2: zwei; \
+         3: drei; \
+         4: vier; \
+         5: fünf; \
+         6: sechs; \
+         7: sieben; \
+         8: acht; \
+         9: neun; \
+         10: zehn; \
+         11: elf; \
+         12: zwölf; \
+         13: dreizehn; \
+         14: vierzehn; \
+         15: fünfzehn; \
+         16: sechzehn; \
+         17: siebzehn; \
+         18: achtzehn; \
+         19: neunzehn; \
+         20: [>%%ein>und]zwanzig; \
+         30: [>%%ein>und]dreißig; \
+         40: [>%%ein>und]vierzig; \
+         50: [>%%ein>und]fünfzig; \
+         60: [>%%ein>und]sechzig; \
+         70: [>%%ein>und]siebzig; \
+         80: [>%%ein>und]achtzig; \
+         90: [>%%ein>und]neunzig; \
+         100: <%%ein<hundert[>>]; \
+         1000: <%%ein<tausend[>>]; \
+         1000000: eine Million[ >>]; \
+         2000000: <%spellout-numbering< Millionen[ >>]; \
+         1000000000: eine Milliarde[ >>]; \
+         2000000000: <%spellout-numbering< Milliarden[ >>]; \
+         1000000000000: eine Billion[ >>]; \
+         2000000000000: <%spellout-numbering< Billionen[ >>]; \
+         1000000000000000: eine Billiarde[ >>]; \
+         2000000000000000: <%spellout-numbering< Billiarden[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-cardinal-masculine: \
+         -x: minus >>; \
+         x.x: << Komma >>; \
+         0: null; \
+         1: ein; \
+         2: =%spellout-numbering=; \
+         100: <%%ein<hundert[>>]; \
+         1000: <%%ein<tausend[>>]; \
+         1000000: eine Million[ >>]; \
+         2000000: <%spellout-numbering< Millionen[ >>]; \
+         1000000000: eine Milliarde[ >>]; \
+         2000000000: <%spellout-numbering< Milliarden[ >>]; \
+         1000000000000: eine Billion[ >>]; \
+         2000000000000: <%spellout-numbering< Billionen[ >>]; \
+         1000000000000000: eine Billiarde[ >>]; \
+         2000000000000000: <%spellout-numbering< Billiarden[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-cardinal-feminine: \
+         -x: minus >>; \
+         x.x: << Komma >>; \
+         0: null; \
+         1: eine; \
+         2: =%spellout-numbering=; \
+         100: <%%ein<hundert[>>]; \
+         1000: <%%ein<tausend[>>]; \
+         1000000: eine Million[ >>]; \
+         2000000: <%spellout-numbering< Millionen[ >>]; \
+         1000000000: eine Milliarde[ >>]; \
+         2000000000: <%spellout-numbering< Milliarden[ >>]; \
+         1000000000000: eine Billion[ >>]; \
+         2000000000000: <%spellout-numbering< Billionen[ >>]; \
+         1000000000000000: eine Billiarde[ >>]; \
+         2000000000000000: <%spellout-numbering< Billiarden[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-cardinal-neuter: \
+         -x: minus >>; \
+         x.x: << Komma >>; \
+         0: null; \
+         1: ein; \
+         2: =%spellout-numbering=; \
+         100: <%%ein<hundert[>>]; \
+         1000: <%%ein<tausend[>>]; \
+         1000000: eine Million[ >>]; \
+         2000000: <%spellout-numbering< Millionen[ >>]; \
+         1000000000: eine Milliarde[ >>]; \
+         2000000000: <%spellout-numbering< Milliarden[ >>]; \
+         1000000000000: eine Billion[ >>]; \
+         2000000000000: <%spellout-numbering< Billionen[ >>]; \
+         1000000000000000: eine Billiarde[ >>]; \
+         2000000000000000: <%spellout-numbering< Billiarden[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-ordinal: \
+         -x: minus >>; \
+         x.x: =#,##0.#=; \
+         0: nullte; \
+         1: erste; \
+         2: zweite; \
+         3: dritte; \
+         4: =%spellout-numbering=te; \
+         7: siebte; \
+         8: achte; \
+         9: =%spellout-numbering=te; \
+         20: =%spellout-numbering=ste; \
+         100: <%%ein<hundert>%%ste>; \
+         1000: <%%ein<tausend>%%ste>; \
+         1000000: =#,##0=.; \
+         %%ein: \
+         1: ein; \
+         2: =%spellout-numbering=; \
+         %%ste: \
+         0: ste; \
+         1: =%spellout-ordinal=;",
+    ),
+    (
+        "en",
+        "%spellout-numbering: \
+         -x: minus >>; \
+         x.x: << point >>; \
+         0: =%spellout-cardinal=; \
+         %spellout-cardinal: \
+         -x: minus >>; \
+         x.x: << point >>; \
+         0: zero; \
+         1: one; \
+         2: two; \
+         3: three; \
+         4: four; \
+         5: five; \
+         6: six; \
+         7: seven; \
+         8: eight; \
+         9: nine; \
+         10: ten; \
+         11: eleven; \
+         12: twelve; \
+         13: thirteen; \
+         14: fourteen; \
+         15: fifteen; \
+         16: sixteen; \
+         17: seventeen; \
+         18: eighteen; \
+         19: nineteen; \
+         20: twenty[->>]; \
+         30: thirty[->>]; \
+         40: forty[->>]; \
+         50: fifty[->>]; \
+         60: sixty[->>]; \
+         70: seventy[->>]; \
+         80: eighty[->>]; \
+         90: ninety[->>]; \
+         100: << hundred[ >>]; \
+         1000: << thousand[ >>]; \
+         1000000: << million[ >>]; \
+         1000000000: << billion[ >>]; \
+         1000000000000: << trillion[ >>]; \
+         1000000000000000: << quadrillion[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-ordinal: \
+         -x: minus >>; \
+         x.x: =#,##0.#=; \
+         0: zeroth; \
+         1: first; \
+         2: second; \
+         3: third; \
+         4: fourth; \
+         5: fifth; \
+         6: sixth; \
+         7: seventh; \
+         8: eighth; \
+         9: ninth; \
+         10: tenth; \
+         11: eleventh; \
+         12: twelfth; \
+         13: =%spellout-numbering=th; \
+         20: twen>%%tieth>; \
+         30: thir>%%tieth>; \
+         40: for>%%tieth>; \
+         50: fif>%%tieth>; \
+         60: six>%%tieth>; \
+         70: seven>%%tieth>; \
+         80: eigh>%%tieth>; \
+         90: nine>%%tieth>; \
+         100: <%spellout-numbering< hundred>%%th>; \
+         1000: <%spellout-numbering< thousand>%%th>; \
+         1000000: <%spellout-numbering< million>%%th>; \
+         1000000000: <%spellout-numbering< billion>%%th>; \
+         1000000000000: <%spellout-numbering< trillion>%%th>; \
+         1000000000000000: <%spellout-numbering< quadrillion>%%th>; \
+         1000000000000000000: =#,##0=$(ordinal,one{st}two{nd}few{rd}other{th})$; \
+         %%tieth: \
+         0: tieth; \
+         1: ty-=%spellout-ordinal=; \
+         %%th: \
+         0: th; \
+         1: ' =%spellout-ordinal=;",
+    ),
+    (
+        "es",
+        "%spellout-numbering: \
+         -x: menos >>; \
+         x.x: << coma >>; \
+         0: cero; \
+         1: uno; \
+         2: dos; \
+         3: tres; \
+         4: cuatro; \
+         5: cinco; \
+         6: seis; \
+         7: siete; \
+         8: ocho; \
+         9: nueve; \
+         10: diez; \
+         11: once; \
+         12: doce; \
+         13: trece; \
+         14: catorce; \
+         15: quince; \
+         16: dieciséis; \
+         17: diecisiete; \
+         18: dieciocho; \
+         19: diecinueve; \
+         20: veinte; \
+         21: veintiuno; \
+         22: veintidós; \
+         23: veintitrés; \
+         24: veinticuatro; \
+         25: veinticinco; \
+         26: veintiséis; \
+         27: veintisiete; \
+         28: veintiocho; \
+         29: veintinueve; \
+         30: treinta[ y >>]; \
+         40: cuarenta[ y >>]; \
+         50: cincuenta[ y >>]; \
+         60: sesenta[ y >>]; \
+         70: setenta[ y >>]; \
+         80: ochenta[ y >>]; \
+         90: noventa[ y >>]; \
+         100: cien; \
+         101: ciento >>; \
+         200: doscientos[ >>]; \
+         300: trescientos[ >>]; \
+         400: cuatrocientos[ >>]; \
+         500: quinientos[ >>]; \
+         600: seiscientos[ >>]; \
+         700: setecientos[ >>]; \
+         800: ochocientos[ >>]; \
+         900: novecientos[ >>]; \
+         1000: mil[ >>]; \
+         2000: <%spellout-cardinal-masculine< mil[ >>]; \
+         1000000: un millón[ >>]; \
+         2000000: <%spellout-cardinal-masculine< millones[ >>]; \
+         1000000000000: un billón[ >>]; \
+         2000000000000: <%spellout-cardinal-masculine< billones[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-cardinal-masculine: \
+         -x: menos >>; \
+         x.x: << coma >>; \
+         0: cero; \
+         1: un; \
+         2: dos; \
+         3: tres; \
+         4: cuatro; \
+         5: cinco; \
+         6: seis; \
+         7: siete; \
+         8: ocho; \
+         9: nueve; \
+         10: diez; \
+         11: once; \
+         12: doce; \
+         13: trece; \
+         14: catorce; \
+         15: quince; \
+         16: dieciséis; \
+         17: diecisiete; \
+         18: dieciocho; \
+         19: diecinueve; \
+         20: veinte; \
+         21: veintiún; \
+         22: veintidós; \
+         23: veintitrés; \
+         24: veinticuatro; \
+         25: veinticinco; \
+         26: veintiséis; \
+         27: veintisiete; \
+         28: veintiocho; \
+         29: veintinueve; \
+         30: treinta[ y >>]; \
+         40: cuarenta[ y >>]; \
+         50: cincuenta[ y >>]; \
+         60: sesenta[ y >>]; \
+         70: setenta[ y >>]; \
+         80: ochenta[ y >>]; \
+         90: noventa[ y >>]; \
+         100: cien; \
+         101: ciento >>; \
+         200: doscientos[ >>]; \
+         300: trescientos[ >>]; \
+         400: cuatrocientos[ >>]; \
+         500: quinientos[ >>]; \
+         600: seiscientos[ >>]; \
+         700: setecientos[ >>]; \
+         800: ochocientos[ >>]; \
+         900: novecientos[ >>]; \
+         1000: mil[ >>]; \
+         2000: <%spellout-cardinal-masculine< mil[ >>]; \
+         1000000: un millón[ >>]; \
+         2000000: <%spellout-cardinal-masculine< millones[ >>]; \
+         1000000000000: un billón[ >>]; \
+         2000000000000: <%spellout-cardinal-masculine< billones[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-cardinal-feminine: \
+         -x: menos >>; \
+         x.x: << coma >>; \
+         0: cero; \
+         1: una; \
+         2: dos; \
+         3: tres; \
+         4: cuatro; \
+         5: cinco; \
+         6: seis; \
+         7: siete; \
+         8: ocho; \
+         9: nueve; \
+         10: diez; \
+         11: once; \
+         12: doce; \
+         13: trece; \
+         14: catorce; \
+         15: quince; \
+         16: dieciséis; \
+         17: diecisiete; \
+         18: dieciocho; \
+         19: diecinueve; \
+         20: veinte; \
+         21: veintiuna; \
+         22: veintidós; \
+         23: veintitrés; \
+         24: veinticuatro; \
+         25: veinticinco; \
+         26: veintiséis; \
+         27: veintisiete; \
+         28: veintiocho; \
+         29: veintinueve; \
+         30: treinta[ y >>]; \
+         40: cuarenta[ y >>]; \
+         50: cincuenta[ y >>]; \
+         60: sesenta[ y >>]; \
+         70: setenta[ y >>]; \
+         80: ochenta[ y >>]; \
+         90: noventa[ y >>]; \
+         100: cien; \
+         101: ciento >>; \
+         200: doscientas[ >>]; \
+         300: trescientas[ >>]; \
+         400: cuatrocientas[ >>]; \
+         500: quinientas[ >>]; \
+         600: seiscientas[ >>]; \
+         700: setecientas[ >>]; \
+         800: ochocientas[ >>]; \
+         900: novecientas[ >>]; \
+         1000: mil[ >>]; \
+         2000: <%spellout-cardinal-feminine< mil[ >>]; \
+         1000000: un millón[ >>]; \
+         2000000: <%spellout-cardinal-masculine< millones[ >>]; \
+         1000000000000: un billón[ >>]; \
+         2000000000000: <%spellout-cardinal-masculine< billones[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-ordinal-masculine: \
+         -x: menos >>; \
+         x.x: =#,##0.#=; \
+         0: cero; \
+         1: primero; \
+         2: segundo; \
+         3: tercero; \
+         4: cuarto; \
+         5: quinto; \
+         6: sexto; \
+         7: séptimo; \
+         8: octavo; \
+         9: noveno; \
+         10: décimo; \
+         11: undécimo; \
+         12: duodécimo; \
+         13: decimotercero; \
+         14: decimocuarto; \
+         15: decimoquinto; \
+         16: decimosexto; \
+         17: decimoséptimo; \
+         18: decimoctavo; \
+         19: decimonoveno; \
+         20: vigésimo[ >>]; \
+         30: trigésimo[ >>]; \
+         40: cuadragésimo[ >>]; \
+         50: quincuagésimo[ >>]; \
+         60: sexagésimo[ >>]; \
+         70: septuagésimo[ >>]; \
+         80: octogésimo[ >>]; \
+         90: nonagésimo[ >>]; \
+         100: centésimo[ >>]; \
+         200: ducentésimo[ >>]; \
+         300: tricentésimo[ >>]; \
+         400: cuadringentésimo[ >>]; \
+         500: quingentésimo[ >>]; \
+         600: sexcentésimo[ >>]; \
+         700: septingentésimo[ >>]; \
+         800: octingentésimo[ >>]; \
+         900: noningentésimo[ >>]; \
+         1000: milésimo[ >>]; \
+         2000: <%spellout-cardinal-masculine< milésimo[ >>]; \
+         1000000: =#,##0=º; \
+         %spellout-ordinal-feminine: \
+         -x: menos >>; \
+         x.x: =#,##0.#=; \
+         0: cero; \
+         1: primera; \
+         2: segunda; \
+         3: tercera; \
+         4: cuarta; \
+         5: quinta; \
+         6: sexta; \
+         7: séptima; \
+         8: octava; \
+         9: novena; \
+         10: décima; \
+         11: undécima; \
+         12: duodécima; \
+         13: decimotercera; \
+         14: decimocuarta; \
+         15: decimoquinta; \
+         16: decimosexta; \
+         17: decimoséptima; \
+         18: decimoctava; \
+         19: decimonovena; \
+         20: vigésima[ >>]; \
+         30: trigésima[ >>]; \
+         40: cuadragésima[ >>]; \
+         50: quincuagésima[ >>]; \
+         60: sexagésima[ >>]; \
+         70: septuagésima[ >>]; \
+         80: octogésima[ >>]; \
+         90: nonagésima[ >>]; \
+         100: centésima[ >>]; \
+         200: ducentésima[ >>]; \
+         300: tricentésima[ >>]; \
+         400: cuadringentésima[ >>]; \
+         500: quingentésima[ >>]; \
+         600: sexcentésima[ >>]; \
+         700: septingentésima[ >>]; \
+         800: octingentésima[ >>]; \
+         900: noningentésima[ >>]; \
+         1000: milésima[ >>]; \
+         2000: <%spellout-cardinal-masculine< milésima[ >>]; \
+         1000000: =#,##0=ª;",
+    ),
+    (
+        "fr",
+        "%spellout-numbering: \
+         -x: moins >>; \
+         x.x: << virgule >>; \
+         0: =%spellout-cardinal-masculine=; \
+         %spellout-cardinal-masculine: \
+         -x: moins >>; \
+         x.x: << virgule >>; \
+         0: zéro; \
+         1: un; \
+         2: deux; \
+         3: trois; \
+         4: quatre; \
+         5: cinq; \
+         6: six; \
+         7: sept; \
+         8: huit; \
+         9: neuf; \
+         10: dix; \
+         11: onze; \
+         12: douze; \
+         13: treize; \
+         14: quatorze; \
+         15: quinze; \
+         16: seize; \
+         17: dix->>; \
+         20: vingt[->%%et-un>]; \
+         30: trente[->%%et-un>]; \
+         40: quarante[->%%et-un>]; \
+         50: cinquante[->%%et-un>]; \
+         60/20: soixante[->%%et-un>]; \
+         80/20: quatre-vingt>%%vingts>; \
+         100: cent[ >>]; \
+         200: << cent>%%cents>; \
+         1000: mille[ >>]; \
+         2000: <%%mille< mille[ >>]; \
+         1000000: un million[ >>]; \
+         2000000: <%spellout-cardinal-masculine< millions[ >>]; \
+         1000000000: un milliard[ >>]; \
+         2000000000: <%spellout-cardinal-masculine< milliards[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-cardinal-feminine: \
+         -x: moins >>; \
+         x.x: << virgule >>; \
+         0: zéro; \
+         1: une; \
+         2: deux; \
+         3: trois; \
+         4: quatre; \
+         5: cinq; \
+         6: six; \
+         7: sept; \
+         8: huit; \
+         9: neuf; \
+         10: dix; \
+         11: onze; \
+         12: douze; \
+         13: treize; \
+         14: quatorze; \
+         15: quinze; \
+         16: seize; \
+         17: dix->>; \
+         20: vingt[->%%et-un-feminine>]; \
+         30: trente[->%%et-un-feminine>]; \
+         40: quarante[->%%et-un-feminine>]; \
+         50: cinquante[->%%et-un-feminine>]; \
+         60/20: soixante[->%%et-un-feminine>]; \
+         80/20: quatre-vingt>%%vingts-feminine>; \
+         100: cent[ >>]; \
+         200: << cent>%%cents-feminine>; \
+         1000: mille[ >>]; \
+         2000: <%%mille< mille[ >>]; \
+         1000000: un million[ >>]; \
+         2000000: <%spellout-cardinal-masculine< millions[ >>]; \
+         1000000000: un milliard[ >>]; \
+         2000000000: <%spellout-cardinal-masculine< milliards[ >>]; \
+         1000000000000000000: =#,##0=; \
+         %spellout-ordinal-masculine: \
+         -x: moins >>; \
+         x.x: =#,##0.#=; \
+         0: zéroième; \
+         1: premier; \
+         2: deuxième; \
+         3: troisième; \
+         4: quatrième; \
+         5: cinquième; \
+         6: sixième; \
+         7: septième; \
+         8: huitième; \
+         9: neuvième; \
+         10: dixième; \
+         11: onzième; \
+         12: douzième; \
+         13: treizième; \
+         14: quatorzième; \
+         15: quinzième; \
+         16: seizième; \
+         17: dix->%%ordinal>; \
+         20: vingt>%%ieme-et>; \
+         30: trent>%%e-ieme-et>; \
+         40: quarant>%%e-ieme-et>; \
+         50: cinquant>%%e-ieme-et>; \
+         60/20: soixant>%%e-ieme-et>; \
+         80/20: quatre-vingt>%%ieme-vingt>; \
+         100: cent>%%ieme-cent>; \
+         200: <%%mille< cent>%%ieme-cent>; \
+         1000: mill>%%ieme-mille>; \
+         2000: <%%mille< mill>%%ieme-mille>; \
+         1000000: =#,##0=e; \
+         %spellout-ordinal-feminine: \
+         -x: moins >>; \
+         x.x: =#,##0.#=; \
+         0: zéroième; \
+         1: première; \
+         2: deuxième; \
+         3: troisième; \
+         4: quatrième; \
+         5: cinquième; \
+         6: sixième; \
+         7: septième; \
+         8: huitième; \
+         9: neuvième; \
+         10: dixième; \
+         11: onzième; \
+         12: douzième; \
+         13: treizième; \
+         14: quatorzième; \
+         15: quinzième; \
+         16: seizième; \
+         17: dix->%%ordinal>; \
+         20: vingt>%%ieme-et>; \
+         30: trent>%%e-ieme-et>; \
+         40: quarant>%%e-ieme-et>; \
+         50: cinquant>%%e-ieme-et>; \
+         60/20: soixant>%%e-ieme-et>; \
+         80/20: quatre-vingt>%%ieme-vingt>; \
+         100: cent>%%ieme-cent>; \
+         200: <%%mille< cent>%%ieme-cent>; \
+         1000: mill>%%ieme-mille>; \
+         2000: <%%mille< mill>%%ieme-mille>; \
+         1000000: =#,##0=e; \
+         %%et-un: \
+         1: et-un; \
+         2: =%spellout-cardinal-masculine=; \
+         11: et-onze; \
+         12: =%spellout-cardinal-masculine=; \
+         %%et-un-feminine: \
+         1: et-une; \
+         2: =%spellout-cardinal-feminine=; \
+         11: et-onze; \
+         12: =%spellout-cardinal-feminine=; \
+         %%vingts: \
+         0: s; \
+         1: -=%spellout-cardinal-masculine=; \
+         %%vingts-feminine: \
+         0: s; \
+         1: -=%spellout-cardinal-feminine=; \
+         %%cents: \
+         0: s; \
+         1: ' =%spellout-cardinal-masculine=; \
+         %%cents-feminine: \
+         0: s; \
+         1: ' =%spellout-cardinal-feminine=; \
+         %%mille: \
+         1: un; \
+         2: =%spellout-cardinal-masculine=; \
+         80: quatre-vingt[->%spellout-cardinal-masculine>]; \
+         100: cent[ >>]; \
+         200: <%spellout-cardinal-masculine< cent[ >>]; \
+         %%ordinal: \
+         0: zéroième; \
+         1: unième; \
+         2: =%spellout-ordinal-masculine=; \
+         %%ieme-et: \
+         0: ième; \
+         1: -et-unième; \
+         2: -=%spellout-ordinal-masculine=; \
+         11: -et-onzième; \
+         12: -=%spellout-ordinal-masculine=; \
+         %%e-ieme-et: \
+         0: ième; \
+         1: e-et-unième; \
+         2: e-=%spellout-ordinal-masculine=; \
+         11: e-et-onzième; \
+         12: e-=%spellout-ordinal-masculine=; \
+         %%ieme-vingt: \
+         0: ième; \
+         1: -=%%ordinal=; \
+         %%ieme-cent: \
+         0: ième; \
+         1: ' =%%ordinal=; \
+         %%ieme-mille: \
+         0: ième; \
+         1: e =%%ordinal=;",
+    ),
+];
+
+/// Rules for ordinal numbers with digits, like `42nd`, keyed by locale.
+pub const ORDINAL: &[(&str, &str)] = &[
+    (
+        "root",
+        "%digits-ordinal: \
+         -x: −>>; \
+         0: =#,##0=;",
+    ),
+    (
+        "de",
+        "%digits-ordinal: \
+         -x: −>>; \
+         0: =#,##0=.;",
+    ),
+    (
+        "en",
+        "%digits-ordinal: \
+         -x: −>>; \
+         0: =#,##0=$(ordinal,one{st}two{nd}few{rd}other{th})$;",
+    ),
+    (
+        "es",
+        "%digits-ordinal-masculine: \
+         -x: −>>; \
+         0: =#,##0=º; \
+         %digits-ordinal-feminine: \
+         -x: −>>; \
+         0: =#,##0=ª; \
+         %digits-ordinal: \
+         =%digits-ordinal-masculine=;",
+    ),
+    (
+        "fr",
+        "%digits-ordinal-masculine: \
+         -x: −>>; \
+         0: =#,##0=$(ordinal,one{er}other{e})$; \
+         %digits-ordinal-feminine: \
+         -x: −>>; \
+         0: =#,##0=$(ordinal,one{re}other{e})$; \
+         %digits-ordinal: \
+         =%digits-ordinal-masculine=;",
+    ),
+];
+
+/// Rules for durations in seconds, like `1:05:07`, keyed by locale.
+pub const DURATION: &[(&str, &str)] = &[
+    (
+        "root",
+        "%%min-sec: \
+         0: :=00=; \
+         60/60: <0<>>; \
+         %%hr-min-sec: \
+         0: :=00=; \
+         60/60: <00<>>; \
+         3600/60: <#,##0<:>>>; \
+         %in-numerals: \
+         0: =0=; \
+         60: =%%min-sec=; \
+         3600: =%%hr-min-sec=; \
+         %duration: \
+         =%in-numerals=;",
+    ),
+    (
+        "en",
+        "%with-words: \
+         0: =0= sec.; \
+         60/60: <%%min<[, >>]; \
+         3600/60: <%%hr<[, >>>]; \
+         %%min: \
+         0: =0= min.; \
+         %%hr: \
+         0: =0= hr.; \
+         %%min-sec: \
+         0: :=00=; \
+         60/60: <0<>>; \
+         %%hr-min-sec: \
+         0: :=00=; \
+         60/60: <00<>>; \
+         3600/60: <#,##0<:>>>; \
+         %in-numerals: \
+         0: =0= sec.; \
+         60: =%%min-sec=; \
+         3600: =%%hr-min-sec=; \
+         %duration: \
+         =%in-numerals=;",
+    ),
+];
@@ -0,0 +1,1150 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Relative time data from the CLDR `dateFields.json` locale data.
+//!
+//! Fields are keyed by unit, like `day`, with a `-short` or `-narrow`
+//! suffix for the shorter widths. A width that a locale doesn't have
+//! uses the next wider one, so `day-narrow` falls back to `day-short`
+//! and then to `day`.
+
+use cldr::PluralForms;
+use PluralCategory::{Few, Many, One, Other};
+
+/// The ways of writing a time relative to now in one unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RelativeTimes {
+    /// Names for particular distances from now, like `-1` for
+    /// `yesterday` or `0` for `this year`.
+    pub relative: &'static [(i64, &'static str)],
+    /// Patterns for a time in the future, like `in {0} days`, where
+    /// `{0}` is the number of units.
+    pub future: PluralForms,
+    /// Patterns for a time in the past, like `{0} days ago`.
+    pub past: PluralForms,
+}
+
+/// Relative time fields, keyed by locale, then by unit and width.
+pub const FIELDS: &[(&str, &[(&str, RelativeTimes)])] = &[
+    (
+        "root",
+        &[
+            (
+                "year",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} y")],
+                    past: &[(Other, "-{0} y")],
+                },
+            ),
+            (
+                "quarter",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} Q")],
+                    past: &[(Other, "-{0} Q")],
+                },
+            ),
+            (
+                "month",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} m")],
+                    past: &[(Other, "-{0} m")],
+                },
+            ),
+            (
+                "week",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} w")],
+                    past: &[(Other, "-{0} w")],
+                },
+            ),
+            (
+                "day",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} d")],
+                    past: &[(Other, "-{0} d")],
+                },
+            ),
+            (
+                "hour",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} h")],
+                    past: &[(Other, "-{0} h")],
+                },
+            ),
+            (
+                "minute",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} min")],
+                    past: &[(Other, "-{0} min")],
+                },
+            ),
+            (
+                "second",
+                RelativeTimes {
+                    relative: &[],
+                    future: &[(Other, "+{0} s")],
+                    past: &[(Other, "-{0} s")],
+                },
+            ),
+        ],
+    ),
+    (
+        "en",
+        &[
+            (
+                "year",
+                RelativeTimes {
+                    relative: &[(-1, "last year"), (0, "this year"), (1, "next year")],
+                    future: &[(One, "in {0} year"), (Other, "in {0} years")],
+                    past: &[(One, "{0} year ago"), (Other, "{0} years ago")],
+                },
+            ),
+            (
+                "quarter",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "last quarter"),
+                        (0, "this quarter"),
+                        (1, "next quarter"),
+                    ],
+                    future: &[(One, "in {0} quarter"), (Other, "in {0} quarters")],
+                    past: &[(One, "{0} quarter ago"), (Other, "{0} quarters ago")],
+                },
+            ),
+            (
+                "month",
+                RelativeTimes {
+                    relative: &[(-1, "last month"), (0, "this month"), (1, "next month")],
+                    future: &[(One, "in {0} month"), (Other, "in {0} months")],
+                    past: &[(One, "{0} month ago"), (Other, "{0} months ago")],
+                },
+            ),
+            (
+                "week",
+                RelativeTimes {
+                    relative: &[(-1, "last week"), (0, "this week"), (1, "next week")],
+                    future: &[(One, "in {0} week"), (Other, "in {0} weeks")],
+                    past: &[(One, "{0} week ago"), (Other, "{0} weeks ago")],
+                },
+            ),
+            (
+                "day",
+                RelativeTimes {
+                    relative: &[(-1, "yesterday"), (0, "today"), (1, "tomorrow")],
+                    future: &[(One, "in {0} day"), (Other, "in {0} days")],
+                    past: &[(One, "{0} day ago"), (Other, "{0} days ago")],
+                },
+            ),
+            (
+                "hour",
+                RelativeTimes {
+                    relative: &[(0, "this hour")],
+                    future: &[(One, "in {0} hour"), (Other, "in {0} hours")],
+                    past: &[(One, "{0} hour ago"), (Other, "{0} hours ago")],
+                },
+            ),
+            (
+                "minute",
+                RelativeTimes {
+                    relative: &[(0, "this minute")],
+                    future: &[(One, "in {0} minute"), (Other, "in {0} minutes")],
+                    past: &[(One, "{0} minute ago"), (Other, "{0} minutes ago")],
+                },
+            ),
+            (
+                "second",
+                RelativeTimes {
+                    relative: &[(0, "now")],
+                    future: &[(One, "in {0} second"), (Other, "in {0} seconds")],
+                    past: &[(One, "{0} second ago"), (Other, "{0} seconds ago")],
+                },
+            ),
+            (
+                "year-short",
+                RelativeTimes {
+                    relative: &[(-1, "last yr."), (0, "this yr."), (1, "next yr.")],
+                    future: &[(Other, "in {0} yr.")],
+                    past: &[(Other, "{0} yr. ago")],
+                },
+            ),
+            (
+                "quarter-short",
+                RelativeTimes {
+                    relative: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")],
+                    future: &[(One, "in {0} qtr."), (Other, "in {0} qtrs.")],
+                    past: &[(One, "{0} qtr. ago"), (Other, "{0} qtrs. ago")],
+                },
+            ),
+            (
+                "month-short",
+                RelativeTimes {
+                    relative: &[(-1, "last mo."), (0, "this mo."), (1, "next mo.")],
+                    future: &[(Other, "in {0} mo.")],
+                    past: &[(Other, "{0} mo. ago")],
+                },
+            ),
+            (
+                "week-short",
+                RelativeTimes {
+                    relative: &[(-1, "last wk."), (0, "this wk."), (1, "next wk.")],
+                    future: &[(Other, "in {0} wk.")],
+                    past: &[(Other, "{0} wk. ago")],
+                },
+            ),
+            (
+                "hour-short",
+                RelativeTimes {
+                    relative: &[(0, "this hour")],
+                    future: &[(Other, "in {0} hr.")],
+                    past: &[(Other, "{0} hr. ago")],
+                },
+            ),
+            (
+                "minute-short",
+                RelativeTimes {
+                    relative: &[(0, "this minute")],
+                    future: &[(Other, "in {0} min.")],
+                    past: &[(Other, "{0} min. ago")],
+                },
+            ),
+            (
+                "second-short",
+                RelativeTimes {
+                    relative: &[(0, "now")],
+                    future: &[(Other, "in {0} sec.")],
+                    past: &[(Other, "{0} sec. ago")],
+                },
+            ),
+            (
+                "year-narrow",
+                RelativeTimes {
+                    relative: &[(-1, "last yr."), (0, "this yr."), (1, "next yr.")],
+                    future: &[(Other, "in {0}y")],
+                    past: &[(Other, "{0}y ago")],
+                },
+            ),
+            (
+                "quarter-narrow",
+                RelativeTimes {
+                    relative: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")],
+                    future: &[(Other, "in {0}q")],
+                    past: &[(Other, "{0}q ago")],
+                },
+            ),
+            (
+                "month-narrow",
+                RelativeTimes {
+                    relative: &[(-1, "last mo."), (0, "this mo."), (1, "next mo.")],
+                    future: &[(Other, "in {0}mo")],
+                    past: &[(Other, "{0}mo ago")],
+                },
+            ),
+            (
+                "week-narrow",
+                RelativeTimes {
+                    relative: &[(-1, "last wk."), (0, "this wk."), (1, "next wk.")],
+                    future: &[(Other, "in {0}w")],
+                    past: &[(Other, "{0}w ago")],
+                },
+            ),
+            (
+                "day-narrow",
+                RelativeTimes {
+                    relative: &[(-1, "yesterday"), (0, "today"), (1, "tomorrow")],
+                    future: &[(Other, "in {0}d")],
+                    past: &[(Other, "{0}d ago")],
+                },
+            ),
+            (
+                "hour-narrow",
+                RelativeTimes {
+                    relative: &[(0, "this hour")],
+                    future: &[(Other, "in {0}h")],
+                    past: &[(Other, "{0}h ago")],
+                },
+            ),
+            (
+                "minute-narrow",
+                RelativeTimes {
+                    relative: &[(0, "this minute")],
+                    future: &[(Other, "in {0}m")],
+                    past: &[(Other, "{0}m ago")],
+                },
+            ),
+            (
+                "second-narrow",
+                RelativeTimes {
+                    relative: &[(0, "now")],
+                    future: &[(Other, "in {0}s")],
+                    past: &[(Other, "{0}s ago")],
+                },
+            ),
+        ],
+    ),
+    (
+        "de",
+        &[
+            (
+                "year",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letztes Jahr"),
+                        (0, "dieses Jahr"),
+                        (1, "nächstes Jahr"),
+                    ],
+                    future: &[(One, "in {0} Jahr"), (Other, "in {0} Jahren")],
+                    past: &[(One, "vor {0} Jahr"), (Other, "vor {0} Jahren")],
+                },
+            ),
+            (
+                "quarter",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letztes Quartal"),
+                        (0, "dieses Quartal"),
+                        (1, "nächstes Quartal"),
+                    ],
+                    future: &[(One, "in {0} Quartal"), (Other, "in {0} Quartalen")],
+                    past: &[(One, "vor {0} Quartal"), (Other, "vor {0} Quartalen")],
+                },
+            ),
+            (
+                "month",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letzten Monat"),
+                        (0, "diesen Monat"),
+                        (1, "nächsten Monat"),
+                    ],
+                    future: &[(One, "in {0} Monat"), (Other, "in {0} Monaten")],
+                    past: &[(One, "vor {0} Monat"), (Other, "vor {0} Monaten")],
+                },
+            ),
+            (
+                "week",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letzte Woche"),
+                        (0, "diese Woche"),
+                        (1, "nächste Woche"),
+                    ],
+                    future: &[(One, "in {0} Woche"), (Other, "in {0} Wochen")],
+                    past: &[(One, "vor {0} Woche"), (Other, "vor {0} Wochen")],
+                },
+            ),
+            (
+                "day",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "vorgestern"),
+                        (-1, "gestern"),
+                        (0, "heute"),
+                        (1, "morgen"),
+                        (2, "übermorgen"),
+                    ],
+                    future: &[(One, "in {0} Tag"), (Other, "in {0} Tagen")],
+                    past: &[(One, "vor {0} Tag"), (Other, "vor {0} Tagen")],
+                },
+            ),
+            (
+                "hour",
+                RelativeTimes {
+                    relative: &[(0, "in dieser Stunde")],
+                    future: &[(One, "in {0} Stunde"), (Other, "in {0} Stunden")],
+                    past: &[(One, "vor {0} Stunde"), (Other, "vor {0} Stunden")],
+                },
+            ),
+            (
+                "minute",
+                RelativeTimes {
+                    relative: &[(0, "in dieser Minute")],
+                    future: &[(One, "in {0} Minute"), (Other, "in {0} Minuten")],
+                    past: &[(One, "vor {0} Minute"), (Other, "vor {0} Minuten")],
+                },
+            ),
+            (
+                "second",
+                RelativeTimes {
+                    relative: &[(0, "jetzt")],
+                    future: &[(One, "in {0} Sekunde"), (Other, "in {0} Sekunden")],
+                    past: &[(One, "vor {0} Sekunde"), (Other, "vor {0} Sekunden")],
+                },
+            ),
+            (
+                "year-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letztes Jahr"),
+                        (0, "dieses Jahr"),
+                        (1, "nächstes Jahr"),
+                    ],
+                    future: &[(Other, "in {0} J.")],
+                    past: &[(Other, "vor {0} J.")],
+                },
+            ),
+            (
+                "quarter-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letztes Quartal"),
+                        (0, "dieses Quartal"),
+                        (1, "nächstes Quartal"),
+                    ],
+                    future: &[(Other, "in {0} Quart.")],
+                    past: &[(Other, "vor {0} Quart.")],
+                },
+            ),
+            (
+                "month-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letzten Monat"),
+                        (0, "diesen Monat"),
+                        (1, "nächsten Monat"),
+                    ],
+                    future: &[(Other, "in {0} Mon.")],
+                    past: &[(Other, "vor {0} Mon.")],
+                },
+            ),
+            (
+                "week-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "letzte Woche"),
+                        (0, "diese Woche"),
+                        (1, "nächste Woche"),
+                    ],
+                    future: &[(Other, "in {0} Woche")],
+                    past: &[(Other, "vor {0} Woche")],
+                },
+            ),
+            (
+                "hour-short",
+                RelativeTimes {
+                    relative: &[(0, "in dieser Stunde")],
+                    future: &[(Other, "in {0} Std.")],
+                    past: &[(Other, "vor {0} Std.")],
+                },
+            ),
+            (
+                "minute-short",
+                RelativeTimes {
+                    relative: &[(0, "in dieser Minute")],
+                    future: &[(Other, "in {0} Min.")],
+                    past: &[(Other, "vor {0} Min.")],
+                },
+            ),
+            (
+                "second-short",
+                RelativeTimes {
+                    relative: &[(0, "jetzt")],
+                    future: &[(Other, "in {0} Sek.")],
+                    past: &[(Other, "vor {0} Sek.")],
+                },
+            ),
+        ],
+    ),
+    (
+        "es",
+        &[
+            (
+                "year",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "el año pasado"),
+                        (0, "este año"),
+                        (1, "el próximo año"),
+                    ],
+                    future: &[(One, "dentro de {0} año"), (Other, "dentro de {0} años")],
+                    past: &[(One, "hace {0} año"), (Other, "hace {0} años")],
+                },
+            ),
+            (
+                "quarter",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "el trimestre pasado"),
+                        (0, "este trimestre"),
+                        (1, "el próximo trimestre"),
+                    ],
+                    future: &[
+                        (One, "dentro de {0} trimestre"),
+                        (Other, "dentro de {0} trimestres"),
+                    ],
+                    past: &[(One, "hace {0} trimestre"), (Other, "hace {0} trimestres")],
+                },
+            ),
+            (
+                "month",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "el mes pasado"),
+                        (0, "este mes"),
+                        (1, "el próximo mes"),
+                    ],
+                    future: &[(One, "dentro de {0} mes"), (Other, "dentro de {0} meses")],
+                    past: &[(One, "hace {0} mes"), (Other, "hace {0} meses")],
+                },
+            ),
+            (
+                "week",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "la semana pasada"),
+                        (0, "esta semana"),
+                        (1, "la próxima semana"),
+                    ],
+                    future: &[
+                        (One, "dentro de {0} semana"),
+                        (Other, "dentro de {0} semanas"),
+                    ],
+                    past: &[(One, "hace {0} semana"), (Other, "hace {0} semanas")],
+                },
+            ),
+            (
+                "day",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "anteayer"),
+                        (-1, "ayer"),
+                        (0, "hoy"),
+                        (1, "mañana"),
+                        (2, "pasado mañana"),
+                    ],
+                    future: &[(One, "dentro de {0} día"), (Other, "dentro de {0} días")],
+                    past: &[(One, "hace {0} día"), (Other, "hace {0} días")],
+                },
+            ),
+            (
+                "hour",
+                RelativeTimes {
+                    relative: &[(0, "esta hora")],
+                    future: &[(One, "dentro de {0} hora"), (Other, "dentro de {0} horas")],
+                    past: &[(One, "hace {0} hora"), (Other, "hace {0} horas")],
+                },
+            ),
+            (
+                "minute",
+                RelativeTimes {
+                    relative: &[(0, "este minuto")],
+                    future: &[
+                        (One, "dentro de {0} minuto"),
+                        (Other, "dentro de {0} minutos"),
+                    ],
+                    past: &[(One, "hace {0} minuto"), (Other, "hace {0} minutos")],
+                },
+            ),
+            (
+                "second",
+                RelativeTimes {
+                    relative: &[(0, "ahora")],
+                    future: &[
+                        (One, "dentro de {0} segundo"),
+                        (Other, "dentro de {0} segundos"),
+                    ],
+                    past: &[(One, "hace {0} segundo"), (Other, "hace {0} segundos")],
+                },
+            ),
+            (
+                "year-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "el año pasado"),
+                        (0, "este año"),
+                        (1, "el próximo año"),
+                    ],
+                    future: &[(Other, "dentro de {0} a")],
+                    past: &[(Other, "hace {0} a")],
+                },
+            ),
+            (
+                "quarter-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "el trim. pasado"),
+                        (0, "este trim."),
+                        (1, "el próximo trim."),
+                    ],
+                    future: &[(Other, "dentro de {0} trim.")],
+                    past: &[(Other, "hace {0} trim.")],
+                },
+            ),
+            (
+                "month-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "el mes pasado"),
+                        (0, "este mes"),
+                        (1, "el próximo mes"),
+                    ],
+                    future: &[(Other, "dentro de {0} m")],
+                    past: &[(Other, "hace {0} m")],
+                },
+            ),
+            (
+                "week-short",
+                RelativeTimes {
+                    relative: &[(-1, "sem. ant."), (0, "esta sem."), (1, "próx. sem.")],
+                    future: &[(Other, "dentro de {0} sem.")],
+                    past: &[(Other, "hace {0} sem.")],
+                },
+            ),
+            (
+                "day-short",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "anteayer"),
+                        (-1, "ayer"),
+                        (0, "hoy"),
+                        (1, "mañana"),
+                        (2, "pasado mañana"),
+                    ],
+                    future: &[(Other, "dentro de {0} d")],
+                    past: &[(Other, "hace {0} d")],
+                },
+            ),
+            (
+                "hour-short",
+                RelativeTimes {
+                    relative: &[(0, "esta hora")],
+                    future: &[(Other, "dentro de {0} h")],
+                    past: &[(Other, "hace {0} h")],
+                },
+            ),
+            (
+                "minute-short",
+                RelativeTimes {
+                    relative: &[(0, "este minuto")],
+                    future: &[(Other, "dentro de {0} min")],
+                    past: &[(Other, "hace {0} min")],
+                },
+            ),
+            (
+                "second-short",
+                RelativeTimes {
+                    relative: &[(0, "ahora")],
+                    future: &[(Other, "dentro de {0} s")],
+                    past: &[(Other, "hace {0} s")],
+                },
+            ),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            (
+                "year",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "l’année dernière"),
+                        (0, "cette année"),
+                        (1, "l’année prochaine"),
+                    ],
+                    future: &[(One, "dans {0} an"), (Other, "dans {0} ans")],
+                    past: &[(One, "il y a {0} an"), (Other, "il y a {0} ans")],
+                },
+            ),
+            (
+                "quarter",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "le trimestre dernier"),
+                        (0, "ce trimestre"),
+                        (1, "le trimestre prochain"),
+                    ],
+                    future: &[(One, "dans {0} trimestre"), (Other, "dans {0} trimestres")],
+                    past: &[
+                        (One, "il y a {0} trimestre"),
+                        (Other, "il y a {0} trimestres"),
+                    ],
+                },
+            ),
+            (
+                "month",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "le mois dernier"),
+                        (0, "ce mois-ci"),
+                        (1, "le mois prochain"),
+                    ],
+                    future: &[(Other, "dans {0} mois")],
+                    past: &[(Other, "il y a {0} mois")],
+                },
+            ),
+            (
+                "week",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "la semaine dernière"),
+                        (0, "cette semaine"),
+                        (1, "la semaine prochaine"),
+                    ],
+                    future: &[(One, "dans {0} semaine"), (Other, "dans {0} semaines")],
+                    past: &[(One, "il y a {0} semaine"), (Other, "il y a {0} semaines")],
+                },
+            ),
+            (
+                "day",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "avant-hier"),
+                        (-1, "hier"),
+                        (0, "aujourd’hui"),
+                        (1, "demain"),
+                        (2, "après-demain"),
+                    ],
+                    future: &[(One, "dans {0} jour"), (Other, "dans {0} jours")],
+                    past: &[(One, "il y a {0} jour"), (Other, "il y a {0} jours")],
+                },
+            ),
+            (
+                "hour",
+                RelativeTimes {
+                    relative: &[(0, "cette heure-ci")],
+                    future: &[(One, "dans {0} heure"), (Other, "dans {0} heures")],
+                    past: &[(One, "il y a {0} heure"), (Other, "il y a {0} heures")],
+                },
+            ),
+            (
+                "minute",
+                RelativeTimes {
+                    relative: &[(0, "cette minute-ci")],
+                    future: &[(One, "dans {0} minute"), (Other, "dans {0} minutes")],
+                    past: &[(One, "il y a {0} minute"), (Other, "il y a {0} minutes")],
+                },
+            ),
+            (
+                "second",
+                RelativeTimes {
+                    relative: &[(0, "maintenant")],
+                    future: &[(One, "dans {0} seconde"), (Other, "dans {0} secondes")],
+                    past: &[(One, "il y a {0} seconde"), (Other, "il y a {0} secondes")],
+                },
+            ),
+            (
+                "year-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "l’année dernière"),
+                        (0, "cette année"),
+                        (1, "l’année prochaine"),
+                    ],
+                    future: &[(Other, "dans {0} a")],
+                    past: &[(Other, "il y a {0} a")],
+                },
+            ),
+            (
+                "quarter-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "le trimestre dernier"),
+                        (0, "ce trimestre"),
+                        (1, "le trimestre prochain"),
+                    ],
+                    future: &[(Other, "dans {0} trim.")],
+                    past: &[(Other, "il y a {0} trim.")],
+                },
+            ),
+            (
+                "month-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "le mois dernier"),
+                        (0, "ce mois-ci"),
+                        (1, "le mois prochain"),
+                    ],
+                    future: &[(Other, "dans {0} m.")],
+                    past: &[(Other, "il y a {0} m.")],
+                },
+            ),
+            (
+                "week-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "la semaine dernière"),
+                        (0, "cette semaine"),
+                        (1, "la semaine prochaine"),
+                    ],
+                    future: &[(Other, "dans {0} sem.")],
+                    past: &[(Other, "il y a {0} sem.")],
+                },
+            ),
+            (
+                "day-short",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "avant-hier"),
+                        (-1, "hier"),
+                        (0, "aujourd’hui"),
+                        (1, "demain"),
+                        (2, "après-demain"),
+                    ],
+                    future: &[(Other, "dans {0} j")],
+                    past: &[(Other, "il y a {0} j")],
+                },
+            ),
+            (
+                "hour-short",
+                RelativeTimes {
+                    relative: &[(0, "cette heure-ci")],
+                    future: &[(Other, "dans {0} h")],
+                    past: &[(Other, "il y a {0} h")],
+                },
+            ),
+            (
+                "minute-short",
+                RelativeTimes {
+                    relative: &[(0, "cette minute-ci")],
+                    future: &[(Other, "dans {0} min")],
+                    past: &[(Other, "il y a {0} min")],
+                },
+            ),
+            (
+                "second-short",
+                RelativeTimes {
+                    relative: &[(0, "maintenant")],
+                    future: &[(Other, "dans {0} s")],
+                    past: &[(Other, "il y a {0} s")],
+                },
+            ),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            (
+                "year",
+                RelativeTimes {
+                    relative: &[(-1, "昨年"), (0, "今年"), (1, "来年")],
+                    future: &[(Other, "{0} 年後")],
+                    past: &[(Other, "{0} 年前")],
+                },
+            ),
+            (
+                "quarter",
+                RelativeTimes {
+                    relative: &[(-1, "前四半期"), (0, "今四半期"), (1, "翌四半期")],
+                    future: &[(Other, "{0} 四半期後")],
+                    past: &[(Other, "{0} 四半期前")],
+                },
+            ),
+            (
+                "month",
+                RelativeTimes {
+                    relative: &[(-1, "先月"), (0, "今月"), (1, "来月")],
+                    future: &[(Other, "{0} か月後")],
+                    past: &[(Other, "{0} か月前")],
+                },
+            ),
+            (
+                "week",
+                RelativeTimes {
+                    relative: &[(-1, "先週"), (0, "今週"), (1, "来週")],
+                    future: &[(Other, "{0} 週間後")],
+                    past: &[(Other, "{0} 週間前")],
+                },
+            ),
+            (
+                "day",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "一昨日"),
+                        (-1, "昨日"),
+                        (0, "今日"),
+                        (1, "明日"),
+                        (2, "明後日"),
+                    ],
+                    future: &[(Other, "{0} 日後")],
+                    past: &[(Other, "{0} 日前")],
+                },
+            ),
+            (
+                "hour",
+                RelativeTimes {
+                    relative: &[(0, "1 時間以内")],
+                    future: &[(Other, "{0} 時間後")],
+                    past: &[(Other, "{0} 時間前")],
+                },
+            ),
+            (
+                "minute",
+                RelativeTimes {
+                    relative: &[(0, "1 分以内")],
+                    future: &[(Other, "{0} 分後")],
+                    past: &[(Other, "{0} 分前")],
+                },
+            ),
+            (
+                "second",
+                RelativeTimes {
+                    relative: &[(0, "今")],
+                    future: &[(Other, "{0} 秒後")],
+                    past: &[(Other, "{0} 秒前")],
+                },
+            ),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            (
+                "year",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "в прошлом году"),
+                        (0, "в этом году"),
+                        (1, "в следующем году"),
+                    ],
+                    future: &[
+                        (One, "через {0} год"),
+                        (Few, "через {0} года"),
+                        (Many, "через {0} лет"),
+                        (Other, "через {0} года"),
+                    ],
+                    past: &[
+                        (One, "{0} год назад"),
+                        (Few, "{0} года назад"),
+                        (Many, "{0} лет назад"),
+                        (Other, "{0} года назад"),
+                    ],
+                },
+            ),
+            (
+                "quarter",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "в прошлом квартале"),
+                        (0, "в текущем квартале"),
+                        (1, "в следующем квартале"),
+                    ],
+                    future: &[
+                        (One, "через {0} квартал"),
+                        (Few, "через {0} квартала"),
+                        (Many, "через {0} кварталов"),
+                        (Other, "через {0} квартала"),
+                    ],
+                    past: &[
+                        (One, "{0} квартал назад"),
+                        (Few, "{0} квартала назад"),
+                        (Many, "{0} кварталов назад"),
+                        (Other, "{0} квартала назад"),
+                    ],
+                },
+            ),
+            (
+                "month",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "в прошлом месяце"),
+                        (0, "в этом месяце"),
+                        (1, "в следующем месяце"),
+                    ],
+                    future: &[
+                        (One, "через {0} месяц"),
+                        (Few, "через {0} месяца"),
+                        (Many, "через {0} месяцев"),
+                        (Other, "через {0} месяца"),
+                    ],
+                    past: &[
+                        (One, "{0} месяц назад"),
+                        (Few, "{0} месяца назад"),
+                        (Many, "{0} месяцев назад"),
+                        (Other, "{0} месяца назад"),
+                    ],
+                },
+            ),
+            (
+                "week",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "на прошлой неделе"),
+                        (0, "на этой неделе"),
+                        (1, "на следующей неделе"),
+                    ],
+                    future: &[
+                        (One, "через {0} неделю"),
+                        (Few, "через {0} недели"),
+                        (Many, "через {0} недель"),
+                        (Other, "через {0} недели"),
+                    ],
+                    past: &[
+                        (One, "{0} неделю назад"),
+                        (Few, "{0} недели назад"),
+                        (Many, "{0} недель назад"),
+                        (Other, "{0} недели назад"),
+                    ],
+                },
+            ),
+            (
+                "day",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "позавчера"),
+                        (-1, "вчера"),
+                        (0, "сегодня"),
+                        (1, "завтра"),
+                        (2, "послезавтра"),
+                    ],
+                    future: &[
+                        (One, "через {0} день"),
+                        (Few, "через {0} дня"),
+                        (Many, "через {0} дней"),
+                        (Other, "через {0} дня"),
+                    ],
+                    past: &[
+                        (One, "{0} день назад"),
+                        (Few, "{0} дня назад"),
+                        (Many, "{0} дней назад"),
+                        (Other, "{0} дня назад"),
+                    ],
+                },
+            ),
+            (
+                "hour",
+                RelativeTimes {
+                    relative: &[(0, "в этот час")],
+                    future: &[
+                        (One, "через {0} час"),
+                        (Few, "через {0} часа"),
+                        (Many, "через {0} часов"),
+                        (Other, "через {0} часа"),
+                    ],
+                    past: &[
+                        (One, "{0} час назад"),
+                        (Few, "{0} часа назад"),
+                        (Many, "{0} часов назад"),
+                        (Other, "{0} часа назад"),
+                    ],
+                },
+            ),
+            (
+                "minute",
+                RelativeTimes {
+                    relative: &[(0, "в эту минуту")],
+                    future: &[
+                        (One, "через {0} минуту"),
+                        (Few, "через {0} минуты"),
+                        (Many, "через {0} минут"),
+                        (Other, "через {0} минуты"),
+                    ],
+                    past: &[
+                        (One, "{0} минуту назад"),
+                        (Few, "{0} минуты назад"),
+                        (Many, "{0} минут назад"),
+                        (Other, "{0} минуты назад"),
+                    ],
+                },
+            ),
+            (
+                "second",
+                RelativeTimes {
+                    relative: &[(0, "сейчас")],
+                    future: &[
+                        (One, "через {0} секунду"),
+                        (Few, "через {0} секунды"),
+                        (Many, "через {0} секунд"),
+                        (Other, "через {0} секунды"),
+                    ],
+                    past: &[
+                        (One, "{0} секунду назад"),
+                        (Few, "{0} секунды назад"),
+                        (Many, "{0} секунд назад"),
+                        (Other, "{0} секунды назад"),
+                    ],
+                },
+            ),
+            (
+                "year-short",
+                RelativeTimes {
+                    relative: &[(-1, "в прошлом г."), (0, "в этом г."), (1, "в след. г.")],
+                    future: &[(Other, "через {0} г.")],
+                    past: &[(Other, "{0} г. назад")],
+                },
+            ),
+            (
+                "quarter-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "последний кв."),
+                        (0, "текущий кв."),
+                        (1, "следующий кв."),
+                    ],
+                    future: &[(Other, "через {0} кв.")],
+                    past: &[(Other, "{0} кв. назад")],
+                },
+            ),
+            (
+                "month-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "в прошлом мес."),
+                        (0, "в этом мес."),
+                        (1, "в следующем мес."),
+                    ],
+                    future: &[(Other, "через {0} мес.")],
+                    past: &[(Other, "{0} мес. назад")],
+                },
+            ),
+            (
+                "week-short",
+                RelativeTimes {
+                    relative: &[
+                        (-1, "на прошлой нед."),
+                        (0, "на этой нед."),
+                        (1, "на следующей нед."),
+                    ],
+                    future: &[(Other, "через {0} нед.")],
+                    past: &[(Other, "{0} нед. назад")],
+                },
+            ),
+            (
+                "day-short",
+                RelativeTimes {
+                    relative: &[
+                        (-2, "позавчера"),
+                        (-1, "вчера"),
+                        (0, "сегодня"),
+                        (1, "завтра"),
+                        (2, "послезавтра"),
+                    ],
+                    future: &[(Other, "через {0} дн.")],
+                    past: &[(Other, "{0} дн. назад")],
+                },
+            ),
+            (
+                "hour-short",
+                RelativeTimes {
+                    relative: &[(0, "в этот час")],
+                    future: &[(Other, "через {0} ч")],
+                    past: &[(Other, "{0} ч назад")],
+                },
+            ),
+            (
+                "minute-short",
+                RelativeTimes {
+                    relative: &[(0, "в эту минуту")],
+                    future: &[(Other, "через {0} мин.")],
+                    past: &[(Other, "{0} мин. назад")],
+                },
+            ),
+            (
+                "second-short",
+                RelativeTimes {
+                    relative: &[(0, "сейчас")],
+                    future: &[(Other, "через {0} сек.")],
+                    past: &[(Other, "{0} сек. назад")],
+                },
+            ),
+        ],
+    ),
+];
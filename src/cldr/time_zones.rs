@@ -0,0 +1,825 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Time zone names from the CLDR `timeZoneNames.json` and
+//! `metaZones.json` data, along with the current rules of widely used
+//! zones from the [IANA time zone database] (2024a).
+//!
+//! Names are usually shared by the zones of a metazone, like
+//! `America_Pacific` for `America/Los_Angeles` and `America/Vancouver`.
+//! A name of `∅∅∅` means that the locale has no such name, even if a
+//! fallback locale does.
+//!
+//! [IANA time zone database]: https://www.iana.org/time-zones
+
+/// The names of a zone or metazone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoneNames {
+    /// The generic, standard and daylight saving time names, like
+    /// `Pacific Time`, `Pacific Standard Time` and `Pacific Daylight
+    /// Time`. Names that are missing are empty.
+    pub long: [&'static str; 3],
+    /// The abbreviated generic, standard and daylight saving time
+    /// names, like `PT`, `PST` and `PDT`. Names that are missing are
+    /// empty.
+    pub short: [&'static str; 3],
+}
+
+/// The current POSIX `TZ` rules, keyed by zone identifier, for use
+/// where the system has no zoneinfo directory.
+pub const RULES: &[(&str, &str)] = &[
+    ("Etc/UTC Etc/GMT Etc/Universal Etc/Zulu UTC GMT", "UTC0"),
+    (
+        "America/New_York America/Detroit America/Toronto America/Nassau \
+         America/Indiana/Indianapolis America/Kentucky/Louisville",
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Chicago America/Winnipeg America/Indiana/Knox America/Menominee",
+        "CST6CDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Denver America/Boise America/Edmonton",
+        "MST7MDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Phoenix", "MST7"),
+    (
+        "America/Los_Angeles America/Vancouver America/Tijuana",
+        "PST8PDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Anchorage America/Juneau",
+        "AKST9AKDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Adak", "HST10HDT,M3.2.0,M11.1.0"),
+    ("Pacific/Honolulu", "HST10"),
+    ("America/Halifax Atlantic/Bermuda", "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Puerto_Rico", "AST4"),
+    ("America/St_Johns", "NST3:30NDT,M3.2.0,M11.1.0"),
+    ("America/Mexico_City", "CST6"),
+    ("America/Bogota", "<-05>5"),
+    ("America/Lima", "<-05>5"),
+    ("America/Santiago", "<-04>4<-03>,M9.1.6/24,M4.1.6/24"),
+    ("America/Sao_Paulo America/Argentina/Buenos_Aires", "<-03>3"),
+    ("Europe/London", "GMT0BST,M3.5.0/1,M10.5.0"),
+    (
+        "Europe/Lisbon Atlantic/Canary Atlantic/Madeira",
+        "WET0WEST,M3.5.0/1,M10.5.0",
+    ),
+    (
+        "Europe/Paris Europe/Berlin Europe/Madrid Europe/Rome Europe/Amsterdam \
+         Europe/Brussels Europe/Vienna Europe/Stockholm Europe/Oslo Europe/Copenhagen \
+         Europe/Warsaw Europe/Prague Europe/Zurich Europe/Budapest Europe/Belgrade",
+        "CET-1CEST,M3.5.0,M10.5.0/3",
+    ),
+    (
+        "Europe/Athens Europe/Helsinki Europe/Kyiv Europe/Kiev Europe/Bucharest Europe/Sofia",
+        "EET-2EEST,M3.5.0/3,M10.5.0/4",
+    ),
+    ("Europe/Istanbul", "<+03>-3"),
+    ("Europe/Moscow", "MSK-3"),
+    ("Africa/Cairo", "EET-2EEST,M4.5.5/0,M10.5.4/24"),
+    ("Africa/Johannesburg", "SAST-2"),
+    ("Africa/Lagos", "WAT-1"),
+    ("Africa/Nairobi", "EAT-3"),
+    ("Asia/Dubai", "<+04>-4"),
+    ("Asia/Tehran", "<+0330>-3:30"),
+    ("Asia/Jerusalem", "IST-2IDT,M3.4.4/26,M10.5.0"),
+    ("Asia/Karachi", "PKT-5"),
+    ("Asia/Kolkata Asia/Calcutta", "IST-5:30"),
+    ("Asia/Kathmandu", "<+0545>-5:45"),
+    ("Asia/Dhaka", "<+06>-6"),
+    ("Asia/Bangkok Asia/Ho_Chi_Minh", "<+07>-7"),
+    ("Asia/Jakarta", "WIB-7"),
+    ("Asia/Shanghai Asia/Taipei", "CST-8"),
+    ("Asia/Hong_Kong", "HKT-8"),
+    ("Asia/Singapore", "<+08>-8"),
+    ("Asia/Manila", "PST-8"),
+    ("Asia/Seoul", "KST-9"),
+    ("Asia/Tokyo", "JST-9"),
+    ("Australia/Perth", "AWST-8"),
+    ("Australia/Darwin", "ACST-9:30"),
+    ("Australia/Adelaide", "ACST-9:30ACDT,M10.1.0,M4.1.0/3"),
+    ("Australia/Brisbane", "AEST-10"),
+    (
+        "Australia/Sydney Australia/Melbourne Australia/Hobart",
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    ("Pacific/Auckland", "NZST-12NZDT,M9.5.0,M4.1.0/3"),
+];
+
+/// The metazone of each zone, keyed by zone identifier.
+pub const META_ZONES: &[(&str, &str)] = &[
+    (
+        "America/New_York America/Detroit America/Toronto America/Nassau \
+         America/Indiana/Indianapolis America/Kentucky/Louisville",
+        "America_Eastern",
+    ),
+    (
+        "America/Chicago America/Winnipeg America/Indiana/Knox America/Menominee \
+         America/Mexico_City",
+        "America_Central",
+    ),
+    (
+        "America/Denver America/Boise America/Edmonton America/Phoenix",
+        "America_Mountain",
+    ),
+    (
+        "America/Los_Angeles America/Vancouver America/Tijuana",
+        "America_Pacific",
+    ),
+    ("America/Anchorage America/Juneau", "Alaska"),
+    ("America/Adak Pacific/Honolulu", "Hawaii_Aleutian"),
+    (
+        "America/Halifax Atlantic/Bermuda America/Puerto_Rico",
+        "Atlantic",
+    ),
+    (
+        "Etc/UTC Etc/GMT Etc/Universal Etc/Zulu UTC GMT Europe/London",
+        "GMT",
+    ),
+    (
+        "Europe/Lisbon Atlantic/Canary Atlantic/Madeira",
+        "Europe_Western",
+    ),
+    (
+        "Europe/Paris Europe/Berlin Europe/Madrid Europe/Rome Europe/Amsterdam \
+         Europe/Brussels Europe/Vienna Europe/Stockholm Europe/Oslo Europe/Copenhagen \
+         Europe/Warsaw Europe/Prague Europe/Zurich Europe/Budapest Europe/Belgrade",
+        "Europe_Central",
+    ),
+    (
+        "Europe/Athens Europe/Helsinki Europe/Kyiv Europe/Kiev Europe/Bucharest Europe/Sofia \
+         Africa/Cairo",
+        "Europe_Eastern",
+    ),
+    ("Europe/Moscow", "Moscow"),
+    ("Asia/Kolkata Asia/Calcutta", "India"),
+    ("Asia/Shanghai", "China"),
+    ("Asia/Seoul", "Korea"),
+    ("Asia/Tokyo", "Japan"),
+    ("Australia/Perth", "Australia_Western"),
+    ("Australia/Adelaide Australia/Darwin", "Australia_Central"),
+    (
+        "Australia/Brisbane Australia/Sydney Australia/Melbourne Australia/Hobart",
+        "Australia_Eastern",
+    ),
+    ("Pacific/Auckland", "New_Zealand"),
+];
+
+/// The pattern for a zone's generic location name, where `{0}` is
+/// the name of its exemplar city, keyed by locale.
+pub const REGION_FORMATS: &[(&str, &str)] = &[
+    ("root", "{0}"),
+    ("en", "{0} Time"),
+    ("de", "{0} (Ortszeit)"),
+    ("es", "hora de {0}"),
+    ("fr", "heure : {0}"),
+    ("ja", "{0}時間"),
+];
+
+/// The names of the exemplar cities of zones, keyed by locale, then
+/// by zone identifier. Zones without a name in any fallback locale
+/// use the last part of their identifier, with spaces for underscores.
+pub const EXEMPLAR_CITIES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "de",
+        &[
+            ("Asia/Tokyo", "Tokio"),
+            ("Europe/Athens", "Athen"),
+            ("Europe/Brussels", "Brüssel"),
+            ("Europe/Copenhagen", "Kopenhagen"),
+            ("Europe/Lisbon", "Lissabon"),
+            ("Europe/Moscow", "Moskau"),
+            ("Europe/Prague", "Prag"),
+            ("Europe/Rome", "Rom"),
+            ("Europe/Vienna", "Wien"),
+            ("Europe/Warsaw", "Warschau"),
+            ("Europe/Zurich", "Zürich"),
+        ],
+    ),
+    (
+        "es",
+        &[
+            ("America/Los_Angeles", "Los Ángeles"),
+            ("America/New_York", "Nueva York"),
+            ("Asia/Tokyo", "Tokio"),
+            ("Europe/Berlin", "Berlín"),
+            ("Europe/London", "Londres"),
+            ("Europe/Paris", "París"),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            ("Europe/Brussels", "Bruxelles"),
+            ("Europe/London", "Londres"),
+            ("Europe/Vienna", "Vienne"),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            ("America/Los_Angeles", "ロサンゼルス"),
+            ("America/New_York", "ニューヨーク"),
+            ("Asia/Tokyo", "東京"),
+            ("Europe/Berlin", "ベルリン"),
+            ("Europe/London", "ロンドン"),
+            ("Europe/Paris", "パリ"),
+        ],
+    ),
+];
+
+/// Names of zones and metazones, keyed by locale, then by zone
+/// identifier or metazone. A zone's own names take precedence over
+/// those of its metazone.
+pub const NAMES: &[(&str, &[(&str, ZoneNames)])] = &[
+    (
+        "en",
+        &[
+            (
+                "Etc/UTC Etc/Universal Etc/Zulu UTC",
+                ZoneNames {
+                    long: ["", "Coordinated Universal Time", ""],
+                    short: ["", "UTC", ""],
+                },
+            ),
+            (
+                "Europe/London",
+                ZoneNames {
+                    long: ["", "", "British Summer Time"],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Pacific/Honolulu",
+                ZoneNames {
+                    long: ["", "", ""],
+                    short: ["HST", "HST", "HDT"],
+                },
+            ),
+            (
+                "America_Eastern",
+                ZoneNames {
+                    long: [
+                        "Eastern Time",
+                        "Eastern Standard Time",
+                        "Eastern Daylight Time",
+                    ],
+                    short: ["ET", "EST", "EDT"],
+                },
+            ),
+            (
+                "America_Central",
+                ZoneNames {
+                    long: [
+                        "Central Time",
+                        "Central Standard Time",
+                        "Central Daylight Time",
+                    ],
+                    short: ["CT", "CST", "CDT"],
+                },
+            ),
+            (
+                "America_Mountain",
+                ZoneNames {
+                    long: [
+                        "Mountain Time",
+                        "Mountain Standard Time",
+                        "Mountain Daylight Time",
+                    ],
+                    short: ["MT", "MST", "MDT"],
+                },
+            ),
+            (
+                "America_Pacific",
+                ZoneNames {
+                    long: [
+                        "Pacific Time",
+                        "Pacific Standard Time",
+                        "Pacific Daylight Time",
+                    ],
+                    short: ["PT", "PST", "PDT"],
+                },
+            ),
+            (
+                "Alaska",
+                ZoneNames {
+                    long: [
+                        "Alaska Time",
+                        "Alaska Standard Time",
+                        "Alaska Daylight Time",
+                    ],
+                    short: ["AKT", "AKST", "AKDT"],
+                },
+            ),
+            (
+                "Hawaii_Aleutian",
+                ZoneNames {
+                    long: [
+                        "Hawaii-Aleutian Time",
+                        "Hawaii-Aleutian Standard Time",
+                        "Hawaii-Aleutian Daylight Time",
+                    ],
+                    short: ["HAT", "HAST", "HADT"],
+                },
+            ),
+            (
+                "Atlantic",
+                ZoneNames {
+                    long: [
+                        "Atlantic Time",
+                        "Atlantic Standard Time",
+                        "Atlantic Daylight Time",
+                    ],
+                    short: ["AT", "AST", "ADT"],
+                },
+            ),
+            (
+                "GMT",
+                ZoneNames {
+                    long: ["", "Greenwich Mean Time", ""],
+                    short: ["", "GMT", ""],
+                },
+            ),
+            (
+                "Europe_Western",
+                ZoneNames {
+                    long: [
+                        "Western European Time",
+                        "Western European Standard Time",
+                        "Western European Summer Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Central",
+                ZoneNames {
+                    long: [
+                        "Central European Time",
+                        "Central European Standard Time",
+                        "Central European Summer Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Eastern",
+                ZoneNames {
+                    long: [
+                        "Eastern European Time",
+                        "Eastern European Standard Time",
+                        "Eastern European Summer Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Moscow",
+                ZoneNames {
+                    long: ["Moscow Time", "Moscow Standard Time", "Moscow Summer Time"],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "India",
+                ZoneNames {
+                    long: ["", "India Standard Time", ""],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "China",
+                ZoneNames {
+                    long: ["China Time", "China Standard Time", "China Daylight Time"],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Korea",
+                ZoneNames {
+                    long: [
+                        "Korean Time",
+                        "Korean Standard Time",
+                        "Korean Daylight Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Japan",
+                ZoneNames {
+                    long: ["Japan Time", "Japan Standard Time", "Japan Daylight Time"],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Australia_Western",
+                ZoneNames {
+                    long: [
+                        "Western Australia Time",
+                        "Australian Western Standard Time",
+                        "Australian Western Daylight Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Australia_Central",
+                ZoneNames {
+                    long: [
+                        "Central Australia Time",
+                        "Australian Central Standard Time",
+                        "Australian Central Daylight Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Australia_Eastern",
+                ZoneNames {
+                    long: [
+                        "Eastern Australia Time",
+                        "Australian Eastern Standard Time",
+                        "Australian Eastern Daylight Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "New_Zealand",
+                ZoneNames {
+                    long: [
+                        "New Zealand Time",
+                        "New Zealand Standard Time",
+                        "New Zealand Daylight Time",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+        ],
+    ),
+    (
+        "en-GB",
+        &[
+            (
+                "Europe/London",
+                ZoneNames {
+                    long: ["", "", ""],
+                    short: ["", "", "BST"],
+                },
+            ),
+            (
+                "America_Eastern America_Central America_Mountain America_Pacific Alaska \
+                 Atlantic",
+                ZoneNames {
+                    long: ["", "", ""],
+                    short: ["∅∅∅", "∅∅∅", "∅∅∅"],
+                },
+            ),
+            (
+                "Europe_Western",
+                ZoneNames {
+                    long: ["", "", ""],
+                    short: ["WET", "WET", "WEST"],
+                },
+            ),
+            (
+                "Europe_Central",
+                ZoneNames {
+                    long: ["", "", ""],
+                    short: ["CET", "CET", "CEST"],
+                },
+            ),
+            (
+                "Europe_Eastern",
+                ZoneNames {
+                    long: ["", "", ""],
+                    short: ["EET", "EET", "EEST"],
+                },
+            ),
+        ],
+    ),
+    (
+        "en-IN",
+        &[(
+            "India",
+            ZoneNames {
+                long: ["", "", ""],
+                short: ["", "IST", ""],
+            },
+        )],
+    ),
+    (
+        "de",
+        &[
+            (
+                "America_Eastern",
+                ZoneNames {
+                    long: [
+                        "Nordamerikanische Ostküstenzeit",
+                        "Nordamerikanische Ostküsten-Normalzeit",
+                        "Nordamerikanische Ostküsten-Sommerzeit",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "America_Pacific",
+                ZoneNames {
+                    long: [
+                        "Nordamerikanische Westküstenzeit",
+                        "Nordamerikanische Westküsten-Normalzeit",
+                        "Nordamerikanische Westküsten-Sommerzeit",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "GMT",
+                ZoneNames {
+                    long: ["", "Mittlere Greenwich-Zeit", ""],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Western",
+                ZoneNames {
+                    long: [
+                        "Westeuropäische Zeit",
+                        "Westeuropäische Normalzeit",
+                        "Westeuropäische Sommerzeit",
+                    ],
+                    short: ["WEZ", "WEZ", "WESZ"],
+                },
+            ),
+            (
+                "Europe_Central",
+                ZoneNames {
+                    long: [
+                        "Mitteleuropäische Zeit",
+                        "Mitteleuropäische Normalzeit",
+                        "Mitteleuropäische Sommerzeit",
+                    ],
+                    short: ["MEZ", "MEZ", "MESZ"],
+                },
+            ),
+            (
+                "Europe_Eastern",
+                ZoneNames {
+                    long: [
+                        "Osteuropäische Zeit",
+                        "Osteuropäische Normalzeit",
+                        "Osteuropäische Sommerzeit",
+                    ],
+                    short: ["OEZ", "OEZ", "OESZ"],
+                },
+            ),
+            (
+                "Japan",
+                ZoneNames {
+                    long: [
+                        "Japanische Zeit",
+                        "Japanische Normalzeit",
+                        "Japanische Sommerzeit",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+        ],
+    ),
+    (
+        "es",
+        &[
+            (
+                "America_Eastern",
+                ZoneNames {
+                    long: [
+                        "hora oriental",
+                        "hora estándar oriental",
+                        "hora de verano oriental",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "America_Pacific",
+                ZoneNames {
+                    long: [
+                        "hora del Pacífico",
+                        "hora estándar del Pacífico",
+                        "hora de verano del Pacífico",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "GMT",
+                ZoneNames {
+                    long: ["", "hora del meridiano de Greenwich", ""],
+                    short: ["", "GMT", ""],
+                },
+            ),
+            (
+                "Europe_Western",
+                ZoneNames {
+                    long: [
+                        "hora de Europa occidental",
+                        "hora estándar de Europa occidental",
+                        "hora de verano de Europa occidental",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Central",
+                ZoneNames {
+                    long: [
+                        "hora de Europa central",
+                        "hora estándar de Europa central",
+                        "hora de verano de Europa central",
+                    ],
+                    short: ["CET", "CET", "CEST"],
+                },
+            ),
+            (
+                "Europe_Eastern",
+                ZoneNames {
+                    long: [
+                        "hora de Europa oriental",
+                        "hora estándar de Europa oriental",
+                        "hora de verano de Europa oriental",
+                    ],
+                    short: ["EET", "EET", "EEST"],
+                },
+            ),
+            (
+                "Japan",
+                ZoneNames {
+                    long: [
+                        "hora de Japón",
+                        "hora estándar de Japón",
+                        "hora de verano de Japón",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            (
+                "America_Eastern",
+                ZoneNames {
+                    long: [
+                        "heure de l’Est nord-américain",
+                        "heure normale de l’Est nord-américain",
+                        "heure d’été de l’Est nord-américain",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "America_Pacific",
+                ZoneNames {
+                    long: [
+                        "heure du Pacifique nord-américain",
+                        "heure normale du Pacifique nord-américain",
+                        "heure d’été du Pacifique nord-américain",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "GMT",
+                ZoneNames {
+                    long: ["", "heure moyenne de Greenwich", ""],
+                    short: ["", "UTC", ""],
+                },
+            ),
+            (
+                "Europe_Western",
+                ZoneNames {
+                    long: [
+                        "heure d’Europe de l’Ouest",
+                        "heure normale d’Europe de l’Ouest",
+                        "heure d’été d’Europe de l’Ouest",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Central",
+                ZoneNames {
+                    long: [
+                        "heure d’Europe centrale",
+                        "heure normale d’Europe centrale",
+                        "heure d’été d’Europe centrale",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Eastern",
+                ZoneNames {
+                    long: [
+                        "heure d’Europe de l’Est",
+                        "heure normale d’Europe de l’Est",
+                        "heure d’été d’Europe de l’Est",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Japan",
+                ZoneNames {
+                    long: [
+                        "heure du Japon",
+                        "heure normale du Japon",
+                        "heure d’été du Japon",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            (
+                "America_Eastern",
+                ZoneNames {
+                    long: [
+                        "アメリカ東部時間",
+                        "アメリカ東部標準時",
+                        "アメリカ東部夏時間",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "America_Pacific",
+                ZoneNames {
+                    long: [
+                        "アメリカ太平洋時間",
+                        "アメリカ太平洋標準時",
+                        "アメリカ太平洋夏時間",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "GMT",
+                ZoneNames {
+                    long: ["", "グリニッジ標準時", ""],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Western",
+                ZoneNames {
+                    long: [
+                        "西ヨーロッパ時間",
+                        "西ヨーロッパ標準時",
+                        "西ヨーロッパ夏時間",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Central",
+                ZoneNames {
+                    long: [
+                        "中央ヨーロッパ時間",
+                        "中央ヨーロッパ標準時",
+                        "中央ヨーロッパ夏時間",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Europe_Eastern",
+                ZoneNames {
+                    long: [
+                        "東ヨーロッパ時間",
+                        "東ヨーロッパ標準時",
+                        "東ヨーロッパ夏時間",
+                    ],
+                    short: ["", "", ""],
+                },
+            ),
+            (
+                "Japan",
+                ZoneNames {
+                    long: ["日本時間", "日本標準時", "日本夏時間"],
+                    short: ["JST", "JST", "JDT"],
+                },
+            ),
+        ],
+    ),
+];
@@ -0,0 +1,872 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unit data from the CLDR `units.json` locale data.
+//!
+//! Units are keyed by their short CLDR identifier, like `hour`, with a
+//! `-short` or `-narrow` suffix for the shorter widths. A width that a
+//! locale doesn't have uses the next wider one, so `hour-narrow` falls
+//! back to `hour-short` and then to `hour`. Compound units, like
+//! `kilometer-per-hour`, have their own patterns only where CLDR does,
+//! and are otherwise built from `PER_UNIT_PATTERNS` or `PER_PATTERNS`.
+
+use cldr::PluralForms;
+use PluralCategory::{Few, Many, One, Other};
+
+/// The patterns for durations written with digits, like `1:05:07`,
+/// keyed by locale. These are for hours, minutes and seconds, for
+/// hours and minutes, and for minutes and seconds.
+pub const DIGITAL_PATTERNS: &[(&str, [&str; 3])] = &[("root", ["h:mm:ss", "h:mm", "m:ss"])];
+
+/// The patterns for an amount of each unit, where `{0}` is the amount,
+/// keyed by locale, then by unit and width.
+pub const PATTERNS: &[(&str, &[(&str, PluralForms)])] = &[
+    (
+        "root",
+        &[
+            ("week", &[(Other, "{0} w")]),
+            ("day", &[(Other, "{0} d")]),
+            ("hour", &[(Other, "{0} h")]),
+            ("minute", &[(Other, "{0} min")]),
+            ("second", &[(Other, "{0} s")]),
+            ("millisecond", &[(Other, "{0} ms")]),
+            ("meter", &[(Other, "{0} m")]),
+            ("kilometer", &[(Other, "{0} km")]),
+            ("centimeter", &[(Other, "{0} cm")]),
+            ("mile", &[(Other, "{0} mi")]),
+            ("foot", &[(Other, "{0} ft")]),
+            ("inch", &[(Other, "{0} in")]),
+            ("kilogram", &[(Other, "{0} kg")]),
+            ("gram", &[(Other, "{0} g")]),
+            ("pound", &[(Other, "{0} lb")]),
+            ("liter", &[(Other, "{0} L")]),
+            ("kilocalorie", &[(Other, "{0} kcal")]),
+            ("celsius", &[(Other, "{0}°C")]),
+            ("fahrenheit", &[(Other, "{0}°F")]),
+            ("kilometer-per-hour", &[(Other, "{0} km/h")]),
+            ("mile-per-hour", &[(Other, "{0} mi/h")]),
+            ("meter-per-second", &[(Other, "{0} m/s")]),
+        ],
+    ),
+    (
+        "en",
+        &[
+            ("week", &[(One, "{0} week"), (Other, "{0} weeks")]),
+            ("day", &[(One, "{0} day"), (Other, "{0} days")]),
+            ("hour", &[(One, "{0} hour"), (Other, "{0} hours")]),
+            ("minute", &[(One, "{0} minute"), (Other, "{0} minutes")]),
+            ("second", &[(One, "{0} second"), (Other, "{0} seconds")]),
+            (
+                "millisecond",
+                &[(One, "{0} millisecond"), (Other, "{0} milliseconds")],
+            ),
+            ("week-short", &[(One, "{0} wk"), (Other, "{0} wks")]),
+            ("day-short", &[(One, "{0} day"), (Other, "{0} days")]),
+            ("hour-short", &[(Other, "{0} hr")]),
+            ("minute-short", &[(Other, "{0} min")]),
+            ("second-short", &[(Other, "{0} sec")]),
+            ("millisecond-short", &[(Other, "{0} ms")]),
+            ("week-narrow", &[(Other, "{0}w")]),
+            ("day-narrow", &[(Other, "{0}d")]),
+            ("hour-narrow", &[(Other, "{0}h")]),
+            ("minute-narrow", &[(Other, "{0}m")]),
+            ("second-narrow", &[(Other, "{0}s")]),
+            ("millisecond-narrow", &[(Other, "{0}ms")]),
+            ("meter", &[(One, "{0} meter"), (Other, "{0} meters")]),
+            (
+                "kilometer",
+                &[(One, "{0} kilometer"), (Other, "{0} kilometers")],
+            ),
+            (
+                "centimeter",
+                &[(One, "{0} centimeter"), (Other, "{0} centimeters")],
+            ),
+            ("mile", &[(One, "{0} mile"), (Other, "{0} miles")]),
+            ("foot", &[(One, "{0} foot"), (Other, "{0} feet")]),
+            ("inch", &[(One, "{0} inch"), (Other, "{0} inches")]),
+            (
+                "kilogram",
+                &[(One, "{0} kilogram"), (Other, "{0} kilograms")],
+            ),
+            ("gram", &[(One, "{0} gram"), (Other, "{0} grams")]),
+            ("pound", &[(One, "{0} pound"), (Other, "{0} pounds")]),
+            ("liter", &[(One, "{0} liter"), (Other, "{0} liters")]),
+            (
+                "kilocalorie",
+                &[(One, "{0} kilocalorie"), (Other, "{0} kilocalories")],
+            ),
+            (
+                "celsius",
+                &[(One, "{0} degree Celsius"), (Other, "{0} degrees Celsius")],
+            ),
+            (
+                "fahrenheit",
+                &[
+                    (One, "{0} degree Fahrenheit"),
+                    (Other, "{0} degrees Fahrenheit"),
+                ],
+            ),
+            (
+                "kilometer-per-hour",
+                &[
+                    (One, "{0} kilometer per hour"),
+                    (Other, "{0} kilometers per hour"),
+                ],
+            ),
+            (
+                "mile-per-hour",
+                &[(One, "{0} mile per hour"), (Other, "{0} miles per hour")],
+            ),
+            (
+                "meter-per-second",
+                &[
+                    (One, "{0} meter per second"),
+                    (Other, "{0} meters per second"),
+                ],
+            ),
+            ("meter-short", &[(Other, "{0} m")]),
+            ("kilometer-short", &[(Other, "{0} km")]),
+            ("centimeter-short", &[(Other, "{0} cm")]),
+            ("mile-short", &[(Other, "{0} mi")]),
+            ("foot-short", &[(Other, "{0} ft")]),
+            ("inch-short", &[(Other, "{0} in")]),
+            ("kilogram-short", &[(Other, "{0} kg")]),
+            ("gram-short", &[(Other, "{0} g")]),
+            ("pound-short", &[(Other, "{0} lb")]),
+            ("liter-short", &[(Other, "{0} L")]),
+            ("kilocalorie-short", &[(Other, "{0} kcal")]),
+            ("celsius-short", &[(Other, "{0}°C")]),
+            ("fahrenheit-short", &[(Other, "{0}°F")]),
+            ("kilometer-per-hour-short", &[(Other, "{0} km/h")]),
+            ("mile-per-hour-short", &[(Other, "{0} mph")]),
+            ("meter-per-second-short", &[(Other, "{0} m/s")]),
+            ("meter-narrow", &[(Other, "{0}m")]),
+            ("kilometer-narrow", &[(Other, "{0}km")]),
+            ("centimeter-narrow", &[(Other, "{0}cm")]),
+            ("mile-narrow", &[(Other, "{0}mi")]),
+            ("foot-narrow", &[(Other, "{0}′")]),
+            ("inch-narrow", &[(Other, "{0}″")]),
+            ("kilogram-narrow", &[(Other, "{0}kg")]),
+            ("gram-narrow", &[(Other, "{0}g")]),
+            ("pound-narrow", &[(Other, "{0}lb")]),
+            ("liter-narrow", &[(Other, "{0}L")]),
+            ("kilocalorie-narrow", &[(Other, "{0}kcal")]),
+            ("celsius-narrow", &[(Other, "{0}°C")]),
+            ("fahrenheit-narrow", &[(Other, "{0}°")]),
+            ("kilometer-per-hour-narrow", &[(Other, "{0}km/h")]),
+            ("mile-per-hour-narrow", &[(Other, "{0}mph")]),
+            ("meter-per-second-narrow", &[(Other, "{0}m/s")]),
+        ],
+    ),
+    (
+        "de",
+        &[
+            ("week", &[(One, "{0} Woche"), (Other, "{0} Wochen")]),
+            ("day", &[(One, "{0} Tag"), (Other, "{0} Tage")]),
+            ("hour", &[(One, "{0} Stunde"), (Other, "{0} Stunden")]),
+            ("minute", &[(One, "{0} Minute"), (Other, "{0} Minuten")]),
+            ("second", &[(One, "{0} Sekunde"), (Other, "{0} Sekunden")]),
+            (
+                "millisecond",
+                &[(One, "{0} Millisekunde"), (Other, "{0} Millisekunden")],
+            ),
+            ("week-short", &[(Other, "{0} Wo.")]),
+            ("day-short", &[(Other, "{0} Tg.")]),
+            ("hour-short", &[(Other, "{0} Std.")]),
+            ("minute-short", &[(Other, "{0} Min.")]),
+            ("second-short", &[(Other, "{0} Sek.")]),
+            ("millisecond-short", &[(Other, "{0} ms")]),
+            ("week-narrow", &[(Other, "{0} W.")]),
+            ("day-narrow", &[(Other, "{0} T.")]),
+            ("meter", &[(Other, "{0} Meter")]),
+            ("kilometer", &[(Other, "{0} Kilometer")]),
+            ("centimeter", &[(Other, "{0} Zentimeter")]),
+            ("mile", &[(One, "{0} Meile"), (Other, "{0} Meilen")]),
+            ("foot", &[(Other, "{0} Fuß")]),
+            ("inch", &[(Other, "{0} Zoll")]),
+            ("kilogram", &[(Other, "{0} Kilogramm")]),
+            ("gram", &[(Other, "{0} Gramm")]),
+            ("pound", &[(Other, "{0} Pfund")]),
+            ("liter", &[(Other, "{0} Liter")]),
+            (
+                "kilocalorie",
+                &[(One, "{0} Kilokalorie"), (Other, "{0} Kilokalorien")],
+            ),
+            ("celsius", &[(Other, "{0} Grad Celsius")]),
+            ("fahrenheit", &[(Other, "{0} Grad Fahrenheit")]),
+            ("kilometer-per-hour", &[(Other, "{0} Kilometer pro Stunde")]),
+            (
+                "mile-per-hour",
+                &[
+                    (One, "{0} Meile pro Stunde"),
+                    (Other, "{0} Meilen pro Stunde"),
+                ],
+            ),
+            ("meter-per-second", &[(Other, "{0} Meter pro Sekunde")]),
+            ("meter-short", &[(Other, "{0} m")]),
+            ("kilometer-short", &[(Other, "{0} km")]),
+            ("centimeter-short", &[(Other, "{0} cm")]),
+            ("mile-short", &[(Other, "{0} mi")]),
+            ("foot-short", &[(Other, "{0} ft")]),
+            ("inch-short", &[(Other, "{0} in")]),
+            ("kilogram-short", &[(Other, "{0} kg")]),
+            ("gram-short", &[(Other, "{0} g")]),
+            ("pound-short", &[(Other, "{0} lb")]),
+            ("liter-short", &[(Other, "{0} l")]),
+            ("kilocalorie-short", &[(Other, "{0} kcal")]),
+            ("celsius-short", &[(Other, "{0} °C")]),
+            ("fahrenheit-short", &[(Other, "{0} °F")]),
+            ("kilometer-per-hour-short", &[(Other, "{0} km/h")]),
+            ("mile-per-hour-short", &[(Other, "{0} mi/h")]),
+            ("meter-per-second-short", &[(Other, "{0} m/s")]),
+        ],
+    ),
+    (
+        "es",
+        &[
+            ("week", &[(One, "{0} semana"), (Other, "{0} semanas")]),
+            ("day", &[(One, "{0} día"), (Other, "{0} días")]),
+            ("hour", &[(One, "{0} hora"), (Other, "{0} horas")]),
+            ("minute", &[(One, "{0} minuto"), (Other, "{0} minutos")]),
+            ("second", &[(One, "{0} segundo"), (Other, "{0} segundos")]),
+            (
+                "millisecond",
+                &[(One, "{0} milisegundo"), (Other, "{0} milisegundos")],
+            ),
+            ("week-short", &[(Other, "{0} sem.")]),
+            ("day-short", &[(One, "{0} d"), (Other, "{0} d")]),
+            ("hour-short", &[(Other, "{0} h")]),
+            ("minute-short", &[(Other, "{0} min")]),
+            ("second-short", &[(Other, "{0} s")]),
+            ("millisecond-short", &[(Other, "{0} ms")]),
+            ("week-narrow", &[(Other, "{0}sem")]),
+            ("day-narrow", &[(Other, "{0}d")]),
+            ("hour-narrow", &[(Other, "{0}h")]),
+            ("minute-narrow", &[(Other, "{0}min")]),
+            ("second-narrow", &[(Other, "{0}s")]),
+            ("millisecond-narrow", &[(Other, "{0}ms")]),
+            ("meter", &[(One, "{0} metro"), (Other, "{0} metros")]),
+            (
+                "kilometer",
+                &[(One, "{0} kilómetro"), (Other, "{0} kilómetros")],
+            ),
+            (
+                "centimeter",
+                &[(One, "{0} centímetro"), (Other, "{0} centímetros")],
+            ),
+            ("mile", &[(One, "{0} milla"), (Other, "{0} millas")]),
+            ("foot", &[(One, "{0} pie"), (Other, "{0} pies")]),
+            ("inch", &[(One, "{0} pulgada"), (Other, "{0} pulgadas")]),
+            (
+                "kilogram",
+                &[(One, "{0} kilogramo"), (Other, "{0} kilogramos")],
+            ),
+            ("gram", &[(One, "{0} gramo"), (Other, "{0} gramos")]),
+            ("pound", &[(One, "{0} libra"), (Other, "{0} libras")]),
+            ("liter", &[(One, "{0} litro"), (Other, "{0} litros")]),
+            (
+                "kilocalorie",
+                &[(One, "{0} kilocaloría"), (Other, "{0} kilocalorías")],
+            ),
+            (
+                "celsius",
+                &[(One, "{0} grado Celsius"), (Other, "{0} grados Celsius")],
+            ),
+            (
+                "fahrenheit",
+                &[
+                    (One, "{0} grado Fahrenheit"),
+                    (Other, "{0} grados Fahrenheit"),
+                ],
+            ),
+            (
+                "kilometer-per-hour",
+                &[
+                    (One, "{0} kilómetro por hora"),
+                    (Other, "{0} kilómetros por hora"),
+                ],
+            ),
+            (
+                "mile-per-hour",
+                &[(One, "{0} milla por hora"), (Other, "{0} millas por hora")],
+            ),
+            (
+                "meter-per-second",
+                &[
+                    (One, "{0} metro por segundo"),
+                    (Other, "{0} metros por segundo"),
+                ],
+            ),
+            ("meter-short", &[(Other, "{0} m")]),
+            ("kilometer-short", &[(Other, "{0} km")]),
+            ("centimeter-short", &[(Other, "{0} cm")]),
+            ("mile-short", &[(Other, "{0} mi")]),
+            ("foot-short", &[(Other, "{0} ft")]),
+            ("inch-short", &[(Other, "{0} in")]),
+            ("kilogram-short", &[(Other, "{0} kg")]),
+            ("gram-short", &[(Other, "{0} g")]),
+            ("pound-short", &[(Other, "{0} lb")]),
+            ("liter-short", &[(Other, "{0} l")]),
+            ("kilocalorie-short", &[(Other, "{0} kcal")]),
+            ("celsius-short", &[(Other, "{0} °C")]),
+            ("fahrenheit-short", &[(Other, "{0} °F")]),
+            ("kilometer-per-hour-short", &[(Other, "{0} km/h")]),
+            ("mile-per-hour-short", &[(Other, "{0} mi/h")]),
+            ("meter-per-second-short", &[(Other, "{0} m/s")]),
+            ("meter-narrow", &[(Other, "{0}m")]),
+            ("kilometer-narrow", &[(Other, "{0}km")]),
+            ("centimeter-narrow", &[(Other, "{0}cm")]),
+            ("mile-narrow", &[(Other, "{0}mi")]),
+            ("foot-narrow", &[(Other, "{0}ft")]),
+            ("inch-narrow", &[(Other, "{0}in")]),
+            ("kilogram-narrow", &[(Other, "{0}kg")]),
+            ("gram-narrow", &[(Other, "{0}g")]),
+            ("pound-narrow", &[(Other, "{0}lb")]),
+            ("liter-narrow", &[(Other, "{0}l")]),
+            ("kilocalorie-narrow", &[(Other, "{0}kcal")]),
+            ("celsius-narrow", &[(Other, "{0}°C")]),
+            ("fahrenheit-narrow", &[(Other, "{0}°F")]),
+            ("kilometer-per-hour-narrow", &[(Other, "{0}km/h")]),
+            ("mile-per-hour-narrow", &[(Other, "{0}mi/h")]),
+            ("meter-per-second-narrow", &[(Other, "{0}m/s")]),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            ("week", &[(One, "{0} semaine"), (Other, "{0} semaines")]),
+            ("day", &[(One, "{0} jour"), (Other, "{0} jours")]),
+            ("hour", &[(One, "{0} heure"), (Other, "{0} heures")]),
+            ("minute", &[(One, "{0} minute"), (Other, "{0} minutes")]),
+            ("second", &[(One, "{0} seconde"), (Other, "{0} secondes")]),
+            (
+                "millisecond",
+                &[(One, "{0} milliseconde"), (Other, "{0} millisecondes")],
+            ),
+            ("week-short", &[(Other, "{0} sem.")]),
+            ("day-short", &[(Other, "{0} j")]),
+            ("hour-short", &[(Other, "{0} h")]),
+            ("minute-short", &[(Other, "{0} min")]),
+            ("second-short", &[(Other, "{0} s")]),
+            ("millisecond-short", &[(Other, "{0} ms")]),
+            ("week-narrow", &[(Other, "{0}sem.")]),
+            ("day-narrow", &[(Other, "{0}j")]),
+            ("hour-narrow", &[(Other, "{0}h")]),
+            ("minute-narrow", &[(Other, "{0}min")]),
+            ("second-narrow", &[(Other, "{0}s")]),
+            ("millisecond-narrow", &[(Other, "{0}ms")]),
+            ("meter", &[(One, "{0} mètre"), (Other, "{0} mètres")]),
+            (
+                "kilometer",
+                &[(One, "{0} kilomètre"), (Other, "{0} kilomètres")],
+            ),
+            (
+                "centimeter",
+                &[(One, "{0} centimètre"), (Other, "{0} centimètres")],
+            ),
+            ("mile", &[(One, "{0} mille"), (Other, "{0} milles")]),
+            ("foot", &[(One, "{0} pied"), (Other, "{0} pieds")]),
+            ("inch", &[(One, "{0} pouce"), (Other, "{0} pouces")]),
+            (
+                "kilogram",
+                &[(One, "{0} kilogramme"), (Other, "{0} kilogrammes")],
+            ),
+            ("gram", &[(One, "{0} gramme"), (Other, "{0} grammes")]),
+            ("pound", &[(One, "{0} livre"), (Other, "{0} livres")]),
+            ("liter", &[(One, "{0} litre"), (Other, "{0} litres")]),
+            (
+                "kilocalorie",
+                &[(One, "{0} kilocalorie"), (Other, "{0} kilocalories")],
+            ),
+            (
+                "celsius",
+                &[(One, "{0} degré Celsius"), (Other, "{0} degrés Celsius")],
+            ),
+            (
+                "fahrenheit",
+                &[
+                    (One, "{0} degré Fahrenheit"),
+                    (Other, "{0} degrés Fahrenheit"),
+                ],
+            ),
+            (
+                "kilometer-per-hour",
+                &[
+                    (One, "{0} kilomètre par heure"),
+                    (Other, "{0} kilomètres par heure"),
+                ],
+            ),
+            (
+                "mile-per-hour",
+                &[
+                    (One, "{0} mille à l’heure"),
+                    (Other, "{0} milles à l’heure"),
+                ],
+            ),
+            (
+                "meter-per-second",
+                &[
+                    (One, "{0} mètre par seconde"),
+                    (Other, "{0} mètres par seconde"),
+                ],
+            ),
+            ("meter-short", &[(Other, "{0} m")]),
+            ("kilometer-short", &[(Other, "{0} km")]),
+            ("centimeter-short", &[(Other, "{0} cm")]),
+            ("mile-short", &[(Other, "{0} mi")]),
+            ("foot-short", &[(Other, "{0} pi")]),
+            ("inch-short", &[(Other, "{0} po")]),
+            ("kilogram-short", &[(Other, "{0} kg")]),
+            ("gram-short", &[(Other, "{0} g")]),
+            ("pound-short", &[(Other, "{0} lb")]),
+            ("liter-short", &[(Other, "{0} l")]),
+            ("kilocalorie-short", &[(Other, "{0} kcal")]),
+            ("celsius-short", &[(Other, "{0} °C")]),
+            ("fahrenheit-short", &[(Other, "{0} °F")]),
+            ("kilometer-per-hour-short", &[(Other, "{0} km/h")]),
+            ("mile-per-hour-short", &[(Other, "{0} mi/h")]),
+            ("meter-per-second-short", &[(Other, "{0} m/s")]),
+            ("meter-narrow", &[(Other, "{0}m")]),
+            ("kilometer-narrow", &[(Other, "{0}km")]),
+            ("centimeter-narrow", &[(Other, "{0}cm")]),
+            ("mile-narrow", &[(Other, "{0}mi")]),
+            ("foot-narrow", &[(Other, "{0}′")]),
+            ("inch-narrow", &[(Other, "{0}″")]),
+            ("kilogram-narrow", &[(Other, "{0}kg")]),
+            ("gram-narrow", &[(Other, "{0}g")]),
+            ("pound-narrow", &[(Other, "{0}lb")]),
+            ("liter-narrow", &[(Other, "{0}l")]),
+            ("kilocalorie-narrow", &[(Other, "{0}kcal")]),
+            ("celsius-narrow", &[(Other, "{0}°C")]),
+            ("fahrenheit-narrow", &[(Other, "{0}°F")]),
+            ("kilometer-per-hour-narrow", &[(Other, "{0}km/h")]),
+            ("mile-per-hour-narrow", &[(Other, "{0}mi/h")]),
+            ("meter-per-second-narrow", &[(Other, "{0}m/s")]),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            ("week", &[(Other, "{0} 週間")]),
+            ("day", &[(Other, "{0} 日")]),
+            ("hour", &[(Other, "{0} 時間")]),
+            ("minute", &[(Other, "{0} 分")]),
+            ("second", &[(Other, "{0} 秒")]),
+            ("millisecond", &[(Other, "{0} ミリ秒")]),
+            ("week-narrow", &[(Other, "{0}週間")]),
+            ("day-narrow", &[(Other, "{0}日")]),
+            ("hour-narrow", &[(Other, "{0}時間")]),
+            ("minute-narrow", &[(Other, "{0}分")]),
+            ("second-narrow", &[(Other, "{0}秒")]),
+            ("millisecond-narrow", &[(Other, "{0}ms")]),
+            ("meter", &[(Other, "{0} メートル")]),
+            ("kilometer", &[(Other, "{0} キロメートル")]),
+            ("centimeter", &[(Other, "{0} センチメートル")]),
+            ("mile", &[(Other, "{0} マイル")]),
+            ("foot", &[(Other, "{0} フィート")]),
+            ("inch", &[(Other, "{0} インチ")]),
+            ("kilogram", &[(Other, "{0} キログラム")]),
+            ("gram", &[(Other, "{0} グラム")]),
+            ("pound", &[(Other, "{0} ポンド")]),
+            ("liter", &[(Other, "{0} リットル")]),
+            ("kilocalorie", &[(Other, "{0} キロカロリー")]),
+            ("celsius", &[(Other, "摂氏 {0} 度")]),
+            ("fahrenheit", &[(Other, "華氏 {0} 度")]),
+            ("kilometer-per-hour", &[(Other, "時速 {0} キロメートル")]),
+            ("mile-per-hour", &[(Other, "時速 {0} マイル")]),
+            ("meter-per-second", &[(Other, "秒速 {0} メートル")]),
+            ("meter-short", &[(Other, "{0} m")]),
+            ("kilometer-short", &[(Other, "{0} km")]),
+            ("centimeter-short", &[(Other, "{0} cm")]),
+            ("mile-short", &[(Other, "{0} mi")]),
+            ("foot-short", &[(Other, "{0} ft")]),
+            ("inch-short", &[(Other, "{0} in")]),
+            ("kilogram-short", &[(Other, "{0} kg")]),
+            ("gram-short", &[(Other, "{0} g")]),
+            ("pound-short", &[(Other, "{0} lb")]),
+            ("liter-short", &[(Other, "{0} L")]),
+            ("kilocalorie-short", &[(Other, "{0} kcal")]),
+            ("celsius-short", &[(Other, "{0}°C")]),
+            ("fahrenheit-short", &[(Other, "{0}°F")]),
+            ("kilometer-per-hour-short", &[(Other, "{0} km/h")]),
+            ("mile-per-hour-short", &[(Other, "{0} mph")]),
+            ("meter-per-second-short", &[(Other, "{0} m/s")]),
+            ("meter-narrow", &[(Other, "{0}m")]),
+            ("kilometer-narrow", &[(Other, "{0}km")]),
+            ("centimeter-narrow", &[(Other, "{0}cm")]),
+            ("mile-narrow", &[(Other, "{0}mi")]),
+            ("foot-narrow", &[(Other, "{0}ft")]),
+            ("inch-narrow", &[(Other, "{0}in")]),
+            ("kilogram-narrow", &[(Other, "{0}kg")]),
+            ("gram-narrow", &[(Other, "{0}g")]),
+            ("pound-narrow", &[(Other, "{0}lb")]),
+            ("liter-narrow", &[(Other, "{0}L")]),
+            ("kilocalorie-narrow", &[(Other, "{0}kcal")]),
+            ("celsius-narrow", &[(Other, "{0}°C")]),
+            ("fahrenheit-narrow", &[(Other, "{0}°F")]),
+            ("kilometer-per-hour-narrow", &[(Other, "{0}km/h")]),
+            ("mile-per-hour-narrow", &[(Other, "{0}mph")]),
+            ("meter-per-second-narrow", &[(Other, "{0}m/s")]),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            (
+                "week",
+                &[
+                    (One, "{0} неделя"),
+                    (Few, "{0} недели"),
+                    (Many, "{0} недель"),
+                    (Other, "{0} недели"),
+                ],
+            ),
+            (
+                "day",
+                &[
+                    (One, "{0} день"),
+                    (Few, "{0} дня"),
+                    (Many, "{0} дней"),
+                    (Other, "{0} дня"),
+                ],
+            ),
+            (
+                "hour",
+                &[
+                    (One, "{0} час"),
+                    (Few, "{0} часа"),
+                    (Many, "{0} часов"),
+                    (Other, "{0} часа"),
+                ],
+            ),
+            (
+                "minute",
+                &[
+                    (One, "{0} минута"),
+                    (Few, "{0} минуты"),
+                    (Many, "{0} минут"),
+                    (Other, "{0} минуты"),
+                ],
+            ),
+            (
+                "second",
+                &[
+                    (One, "{0} секунда"),
+                    (Few, "{0} секунды"),
+                    (Many, "{0} секунд"),
+                    (Other, "{0} секунды"),
+                ],
+            ),
+            (
+                "millisecond",
+                &[
+                    (One, "{0} миллисекунда"),
+                    (Few, "{0} миллисекунды"),
+                    (Many, "{0} миллисекунд"),
+                    (Other, "{0} миллисекунды"),
+                ],
+            ),
+            ("week-short", &[(Other, "{0} нед.")]),
+            ("day-short", &[(Other, "{0} дн.")]),
+            ("hour-short", &[(Other, "{0} ч")]),
+            ("minute-short", &[(Other, "{0} мин")]),
+            ("second-short", &[(Other, "{0} с")]),
+            ("millisecond-short", &[(Other, "{0} мс")]),
+            ("week-narrow", &[(Other, "{0} н.")]),
+            ("day-narrow", &[(Other, "{0} д")]),
+            (
+                "meter",
+                &[
+                    (One, "{0} метр"),
+                    (Few, "{0} метра"),
+                    (Many, "{0} метров"),
+                    (Other, "{0} метра"),
+                ],
+            ),
+            (
+                "kilometer",
+                &[
+                    (One, "{0} километр"),
+                    (Few, "{0} километра"),
+                    (Many, "{0} километров"),
+                    (Other, "{0} километра"),
+                ],
+            ),
+            (
+                "centimeter",
+                &[
+                    (One, "{0} сантиметр"),
+                    (Few, "{0} сантиметра"),
+                    (Many, "{0} сантиметров"),
+                    (Other, "{0} сантиметра"),
+                ],
+            ),
+            (
+                "mile",
+                &[
+                    (One, "{0} миля"),
+                    (Few, "{0} мили"),
+                    (Many, "{0} миль"),
+                    (Other, "{0} мили"),
+                ],
+            ),
+            (
+                "foot",
+                &[
+                    (One, "{0} фут"),
+                    (Few, "{0} фута"),
+                    (Many, "{0} футов"),
+                    (Other, "{0} фута"),
+                ],
+            ),
+            (
+                "inch",
+                &[
+                    (One, "{0} дюйм"),
+                    (Few, "{0} дюйма"),
+                    (Many, "{0} дюймов"),
+                    (Other, "{0} дюйма"),
+                ],
+            ),
+            (
+                "kilogram",
+                &[
+                    (One, "{0} килограмм"),
+                    (Few, "{0} килограмма"),
+                    (Many, "{0} килограммов"),
+                    (Other, "{0} килограмма"),
+                ],
+            ),
+            (
+                "gram",
+                &[
+                    (One, "{0} грамм"),
+                    (Few, "{0} грамма"),
+                    (Many, "{0} граммов"),
+                    (Other, "{0} грамма"),
+                ],
+            ),
+            (
+                "pound",
+                &[
+                    (One, "{0} фунт"),
+                    (Few, "{0} фунта"),
+                    (Many, "{0} фунтов"),
+                    (Other, "{0} фунта"),
+                ],
+            ),
+            (
+                "liter",
+                &[
+                    (One, "{0} литр"),
+                    (Few, "{0} литра"),
+                    (Many, "{0} литров"),
+                    (Other, "{0} литра"),
+                ],
+            ),
+            (
+                "kilocalorie",
+                &[
+                    (One, "{0} килокалория"),
+                    (Few, "{0} килокалории"),
+                    (Many, "{0} килокалорий"),
+                    (Other, "{0} килокалории"),
+                ],
+            ),
+            (
+                "celsius",
+                &[
+                    (One, "{0} градус Цельсия"),
+                    (Few, "{0} градуса Цельсия"),
+                    (Many, "{0} градусов Цельсия"),
+                    (Other, "{0} градуса Цельсия"),
+                ],
+            ),
+            (
+                "fahrenheit",
+                &[
+                    (One, "{0} градус Фаренгейта"),
+                    (Few, "{0} градуса Фаренгейта"),
+                    (Many, "{0} градусов Фаренгейта"),
+                    (Other, "{0} градуса Фаренгейта"),
+                ],
+            ),
+            (
+                "kilometer-per-hour",
+                &[
+                    (One, "{0} километр в час"),
+                    (Few, "{0} километра в час"),
+                    (Many, "{0} километров в час"),
+                    (Other, "{0} километра в час"),
+                ],
+            ),
+            (
+                "mile-per-hour",
+                &[
+                    (One, "{0} миля в час"),
+                    (Few, "{0} мили в час"),
+                    (Many, "{0} миль в час"),
+                    (Other, "{0} мили в час"),
+                ],
+            ),
+            (
+                "meter-per-second",
+                &[
+                    (One, "{0} метр в секунду"),
+                    (Few, "{0} метра в секунду"),
+                    (Many, "{0} метров в секунду"),
+                    (Other, "{0} метра в секунду"),
+                ],
+            ),
+            ("meter-short", &[(Other, "{0} м")]),
+            ("kilometer-short", &[(Other, "{0} км")]),
+            ("centimeter-short", &[(Other, "{0} см")]),
+            ("mile-short", &[(Other, "{0} ми")]),
+            ("foot-short", &[(Other, "{0} фт")]),
+            ("inch-short", &[(Other, "{0} дюйм.")]),
+            ("kilogram-short", &[(Other, "{0} кг")]),
+            ("gram-short", &[(Other, "{0} г")]),
+            ("pound-short", &[(Other, "{0} фнт")]),
+            ("liter-short", &[(Other, "{0} л")]),
+            ("kilocalorie-short", &[(Other, "{0} ккал")]),
+            ("celsius-short", &[(Other, "{0} °C")]),
+            ("fahrenheit-short", &[(Other, "{0} °F")]),
+            ("kilometer-per-hour-short", &[(Other, "{0} км/ч")]),
+            ("mile-per-hour-short", &[(Other, "{0} ми/ч")]),
+            ("meter-per-second-short", &[(Other, "{0} м/с")]),
+        ],
+    ),
+];
+
+/// The patterns for an amount per one of a unit, like `{0}/h`, keyed by
+/// locale, then by unit and width.
+pub const PER_UNIT_PATTERNS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "root",
+        &[
+            ("hour", "{0}/h"),
+            ("minute", "{0}/min"),
+            ("second", "{0}/s"),
+            ("kilogram", "{0}/kg"),
+            ("meter", "{0}/m"),
+            ("kilometer", "{0}/km"),
+        ],
+    ),
+    (
+        "en",
+        &[
+            ("hour", "{0} per hour"),
+            ("minute", "{0} per minute"),
+            ("second", "{0} per second"),
+            ("kilogram", "{0} per kilogram"),
+            ("meter", "{0} per meter"),
+            ("kilometer", "{0} per kilometer"),
+            ("hour-short", "{0}/h"),
+            ("minute-short", "{0}/min"),
+            ("second-short", "{0}/s"),
+            ("kilogram-short", "{0}/kg"),
+            ("meter-short", "{0}/m"),
+            ("kilometer-short", "{0}/km"),
+        ],
+    ),
+    (
+        "de",
+        &[
+            ("hour", "{0} pro Stunde"),
+            ("minute", "{0} pro Minute"),
+            ("second", "{0} pro Sekunde"),
+            ("kilogram", "{0} pro Kilogramm"),
+            ("meter", "{0} pro Meter"),
+            ("kilometer", "{0} pro Kilometer"),
+            ("hour-short", "{0}/h"),
+            ("minute-short", "{0}/min"),
+            ("second-short", "{0}/s"),
+            ("kilogram-short", "{0}/kg"),
+            ("meter-short", "{0}/m"),
+            ("kilometer-short", "{0}/km"),
+        ],
+    ),
+    (
+        "es",
+        &[
+            ("hour", "{0} por hora"),
+            ("minute", "{0} por minuto"),
+            ("second", "{0} por segundo"),
+            ("kilogram", "{0} por kilogramo"),
+            ("meter", "{0} por metro"),
+            ("kilometer", "{0} por kilómetro"),
+            ("hour-short", "{0}/h"),
+            ("minute-short", "{0}/min"),
+            ("second-short", "{0}/s"),
+            ("kilogram-short", "{0}/kg"),
+            ("meter-short", "{0}/m"),
+            ("kilometer-short", "{0}/km"),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            ("hour", "{0} par heure"),
+            ("minute", "{0} par minute"),
+            ("second", "{0} par seconde"),
+            ("kilogram", "{0} par kilogramme"),
+            ("meter", "{0} par mètre"),
+            ("kilometer", "{0} par kilomètre"),
+            ("hour-short", "{0}/h"),
+            ("minute-short", "{0}/min"),
+            ("second-short", "{0}/s"),
+            ("kilogram-short", "{0}/kg"),
+            ("meter-short", "{0}/m"),
+            ("kilometer-short", "{0}/km"),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            ("hour", "{0}/時間"),
+            ("minute", "{0}/分"),
+            ("second", "{0}/秒"),
+            ("kilogram", "{0}/キログラム"),
+            ("meter", "{0}/メートル"),
+            ("kilometer", "{0}/キロメートル"),
+            ("hour-short", "{0}/h"),
+            ("minute-short", "{0}/min"),
+            ("second-short", "{0}/s"),
+            ("kilogram-short", "{0}/kg"),
+            ("meter-short", "{0}/m"),
+            ("kilometer-short", "{0}/km"),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            ("hour", "{0} в час"),
+            ("minute", "{0} в минуту"),
+            ("second", "{0} в секунду"),
+            ("kilogram", "{0} на килограмм"),
+            ("meter", "{0} на метр"),
+            ("kilometer", "{0} на километр"),
+            ("hour-short", "{0}/ч"),
+            ("minute-short", "{0}/мин"),
+            ("second-short", "{0}/с"),
+            ("kilogram-short", "{0}/кг"),
+            ("meter-short", "{0}/м"),
+            ("kilometer-short", "{0}/км"),
+        ],
+    ),
+];
+
+/// The patterns for an amount per a unit that has no pattern in
+/// `PER_UNIT_PATTERNS`, like `{0} per {1}`, where `{1}` is the name of
+/// the unit, keyed by locale, then by width.
+pub const PER_PATTERNS: &[(&str, &[(&str, &str)])] = &[
+    ("root", &[("per", "{0}/{1}")]),
+    ("en", &[("per", "{0} per {1}"), ("per-short", "{0}/{1}")]),
+    ("de", &[("per", "{0} pro {1}"), ("per-short", "{0}/{1}")]),
+    ("es", &[("per", "{0} por {1}"), ("per-short", "{0}/{1}")]),
+    ("fr", &[("per", "{0} par {1}"), ("per-short", "{0}/{1}")]),
+    ("ru", &[("per", "{0} на {1}"), ("per-short", "{0}/{1}")]),
+];
@@ -6,41 +6,268 @@
 
 use language_tags::LanguageTag;
 use std::fmt;
+use std::sync::{Arc, Mutex};
 
-use {Args, Message};
+use {Args, Decimal, FormatError, Message, TimeZone};
+
+/// What to do with an argument that has no value, or a value of the
+/// wrong type, when formatting a message.
+///
+/// ```
+/// use message_format::{icu, Context, MissingArgumentPolicy};
+///
+/// let m = icu::parse("Hello, {name}!").unwrap();
+///
+/// let ctx = Context::default();
+/// assert!(ctx.try_format(&m, None).is_err());
+///
+/// let ctx = Context::default().with_missing_argument_policy(MissingArgumentPolicy::Source);
+/// assert_eq!(ctx.format(&m, None), "Hello, {name}!");
+///
+/// let ctx = Context::default()
+///     .with_missing_argument_policy(MissingArgumentPolicy::Marker("???".to_string()));
+/// assert_eq!(ctx.format(&m, None), "Hello, ???!");
+///
+/// let ctx = Context::default().with_missing_argument_policy(MissingArgumentPolicy::callback(
+///     |error, source| {
+///         eprintln!("{}: {}", source, error);
+///         "friend".to_string()
+///     },
+/// ));
+/// assert_eq!(ctx.format(&m, None), "Hello, friend!");
+/// ```
+#[derive(Clone, Default)]
+pub enum MissingArgumentPolicy {
+    /// Stop formatting the message, with a [`FormatError`].
+    ///
+    /// [`FormatError`]: enum.FormatError.html
+    #[default]
+    Error,
+    /// Write the argument as it appears in the message source, like
+    /// `{d, date, short}`. An argument of a part with no source text
+    /// is written as its name in braces, like `{name}`, and a missing
+    /// `#` as `#`.
+    Source,
+    /// Write the given marker text instead of the argument.
+    Marker(String),
+    /// Call a function with the error and the text that `Source` would
+    /// write, which may log them, and write the text that it returns
+    /// instead of the argument.
+    Callback(Arc<Callback>),
+}
+
+/// A function called by `MissingArgumentPolicy::Callback`.
+type Callback = Fn(&FormatError, &str) -> String + Send + Sync;
+
+impl MissingArgumentPolicy {
+    /// Construct a `MissingArgumentPolicy::Callback` from a function.
+    pub fn callback<F>(callback: F) -> Self
+    where
+        F: Fn(&FormatError, &str) -> String + Send + Sync + 'static,
+    {
+        MissingArgumentPolicy::Callback(Arc::new(callback))
+    }
+
+    /// The text to write instead of the argument for which `error` was
+    /// reported, or `None` if formatting should stop. `source` is the
+    /// text of the argument in the message source, if it is known.
+    ///
+    /// Only missing arguments and type mismatches are recovered from.
+    pub fn fallback(&self, error: &FormatError, source: Option<&str>) -> Option<String> {
+        let name = match *error {
+            FormatError::MissingArgument { ref name } => name,
+            FormatError::TypeMismatch { ref name, .. } => name,
+            _ => return None,
+        };
+        let source = match source {
+            Some(source) => source.to_string(),
+            // The value of a plural is written as `#`.
+            None if name == "#" => "#".to_string(),
+            None => format!("{{{}}}", name),
+        };
+        match *self {
+            MissingArgumentPolicy::Error => None,
+            MissingArgumentPolicy::Source => Some(source),
+            MissingArgumentPolicy::Marker(ref marker) => Some(marker.clone()),
+            MissingArgumentPolicy::Callback(ref callback) => Some(callback(error, &source)),
+        }
+    }
+}
+
+impl fmt::Debug for MissingArgumentPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            MissingArgumentPolicy::Error => write!(f, "Error"),
+            MissingArgumentPolicy::Source => write!(f, "Source"),
+            MissingArgumentPolicy::Marker(ref marker) => write!(f, "Marker({:?})", marker),
+            MissingArgumentPolicy::Callback(_) => write!(f, "Callback(..)"),
+        }
+    }
+}
 
 /// Contextual configuration data.
+///
+/// The default context is for English (`en`).
 #[derive(Clone, Debug)]
 pub struct Context {
-    /// The language being localized for.
+    /// The language being localized for. This determines the plural
+    /// rules that are used.
     pub language_tag: LanguageTag,
     /// The value to use in a `PlaceholderFormat`.
-    pub placeholder_value: Option<i64>,
+    pub placeholder_value: Option<Decimal>,
+    /// The time zone in which to show dates and times that don't have
+    /// their own offset from UTC. Without one, they are shown in UTC.
+    pub time_zone: Option<TimeZone>,
+    /// What to do with an argument that has no value, or a value of
+    /// the wrong type.
+    pub missing_argument_policy: MissingArgumentPolicy,
 }
 
 impl Context {
     /// Create a new instance of `Context`.
-    pub fn new(language: LanguageTag, placeholder_value: Option<i64>) -> Self {
+    pub fn new(language: LanguageTag, placeholder_value: Option<Decimal>) -> Self {
         Context {
             language_tag: language,
             placeholder_value: placeholder_value,
+            time_zone: None,
+            missing_argument_policy: MissingArgumentPolicy::default(),
+        }
+    }
+
+    /// Show dates and times in `time_zone`.
+    ///
+    /// ```
+    /// use message_format::{Context, TimeZone};
+    ///
+    /// let ctx = Context::default().with_time_zone(TimeZone::new("Asia/Tokyo").unwrap());
+    /// assert_eq!(ctx.time_zone.unwrap().id(), "Asia/Tokyo");
+    /// ```
+    pub fn with_time_zone(self, time_zone: TimeZone) -> Self {
+        Context {
+            time_zone: Some(time_zone),
+            ..self
+        }
+    }
+
+    /// Handle arguments that are missing or have the wrong type with
+    /// `policy`.
+    pub fn with_missing_argument_policy(self, policy: MissingArgumentPolicy) -> Self {
+        Context {
+            missing_argument_policy: policy,
+            ..self
         }
     }
 
     /// Format a message, returning a string.
+    ///
+    /// If part of the message can't be formatted, like an argument
+    /// that has no value and a [`MissingArgumentPolicy::Error`] policy,
+    /// the message is formatted up to that part. Use [`try_format`] to
+    /// find out why.
+    ///
+    /// [`MissingArgumentPolicy::Error`]: enum.MissingArgumentPolicy.html#variant.Error
+    /// [`try_format`]: #method.try_format
     pub fn format<'f>(&self, message: &Message, args: Option<&Args<'f>>) -> String {
         let mut output = String::new();
         let _ = message.write_message(self, &mut output, args);
         output
     }
 
+    /// Format a message, returning a string, or the error that stopped
+    /// part of it from being formatted.
+    ///
+    /// ```
+    /// use message_format::{arg, icu, Context, FormatError};
+    ///
+    /// let ctx = Context::default();
+    /// let m = icu::parse("{count, number} files").unwrap();
+    /// assert_eq!(ctx.try_format(&m, Some(&arg("count", 3))), Ok("3 files".to_string()));
+    ///
+    /// let error = ctx.try_format(&m, Some(&arg("count", "three"))).unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     FormatError::TypeMismatch {
+    ///         name: "count".to_string(),
+    ///         expected: "a number".to_string(),
+    ///         actual: "a string".to_string(),
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "argument `count` is a string, but a number was expected"
+    /// );
+    /// ```
+    pub fn try_format<'f>(
+        &self,
+        message: &Message,
+        args: Option<&Args<'f>>,
+    ) -> Result<String, FormatError> {
+        let mut output = String::new();
+        try!(message.write_message(self, &mut output, args));
+        Ok(output)
+    }
+
+    /// Format a message, returning a string along with the errors
+    /// that were recovered from by the missing argument policy.
+    ///
+    /// If an error stops the message from being formatted, it is the
+    /// last error, and the string is the message up to the part that
+    /// couldn't be formatted.
+    ///
+    /// ```
+    /// use message_format::{arg, icu, Context, FormatError, MissingArgumentPolicy};
+    ///
+    /// let ctx = Context::default().with_missing_argument_policy(MissingArgumentPolicy::Source);
+    /// let m = icu::parse("{a} and {b}").unwrap();
+    /// let (output, errors) = ctx.format_with_errors(&m, Some(&arg("a", "Ana")));
+    /// assert_eq!(output, "Ana and {b}");
+    /// assert_eq!(
+    ///     errors,
+    ///     vec![FormatError::MissingArgument {
+    ///         name: "b".to_string(),
+    ///     }]
+    /// );
+    /// ```
+    pub fn format_with_errors<'f>(
+        &self,
+        message: &Message,
+        args: Option<&Args<'f>>,
+    ) -> (String, Vec<FormatError>) {
+        let mut output = String::new();
+        let recovered = Arc::new(Mutex::new(vec![]));
+        let result = match self.missing_argument_policy {
+            MissingArgumentPolicy::Error => message.write_message(self, &mut output, args),
+            ref policy => {
+                // Record each error as the policy recovers from it.
+                let policy = policy.clone();
+                let errors = recovered.clone();
+                let recording = MissingArgumentPolicy::callback(move |error, source| {
+                    if let Ok(mut errors) = errors.lock() {
+                        errors.push(error.clone());
+                    }
+                    policy.fallback(error, Some(source)).unwrap_or_default()
+                });
+                let ctx = self.clone().with_missing_argument_policy(recording);
+                message.write_message(&ctx, &mut output, args)
+            }
+        };
+        let mut errors = recovered
+            .lock()
+            .map(|errors| errors.clone())
+            .unwrap_or_default();
+        if let Err(error) = result {
+            errors.push(error);
+        }
+        (output, errors)
+    }
+
     /// Write a message to a stream.
     pub fn write<'f>(
         &self,
         message: &Message,
         stream: &mut fmt::Write,
         args: Option<&Args<'f>>,
-    ) -> fmt::Result {
+    ) -> Result<(), FormatError> {
         message.write_message(self, stream, args)
     }
 }
@@ -48,8 +275,13 @@ impl Context {
 impl Default for Context {
     fn default() -> Self {
         Context {
-            language_tag: Default::default(),
+            language_tag: LanguageTag {
+                language: Some("en".to_string()),
+                ..LanguageTag::default()
+            },
             placeholder_value: None,
+            time_zone: None,
+            missing_argument_policy: MissingArgumentPolicy::default(),
         }
     }
 }
@@ -0,0 +1,320 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+/// A point in time, for formatting with `date` and `time` arguments.
+///
+/// A `DateTime` is a timestamp, which is the same everywhere, along
+/// with an optional offset from UTC giving the local time at which it
+/// should be shown. Without an offset, it is shown in UTC.
+///
+/// ```
+/// use message_format::DateTime;
+///
+/// let date_time = DateTime::new(1_709_647_629_000);
+/// assert_eq!(date_time.to_string(), "2024-03-05T14:07:09Z");
+/// assert_eq!(
+///     date_time.with_offset(-5 * 3600).to_string(),
+///     "2024-03-05T09:07:09-05:00"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateTime {
+    /// The number of milliseconds since the Unix epoch,
+    /// 1970-01-01T00:00:00Z.
+    pub timestamp: i64,
+    /// The offset of the local time from UTC in seconds, which is
+    /// positive east of Greenwich.
+    pub offset: Option<i32>,
+}
+
+impl DateTime {
+    /// Construct a `DateTime` from the number of milliseconds since the
+    /// Unix epoch, to be shown in UTC.
+    pub fn new(timestamp: i64) -> Self {
+        DateTime {
+            timestamp: timestamp,
+            offset: None,
+        }
+    }
+
+    /// The same point in time, to be shown at an offset from UTC in
+    /// seconds.
+    pub fn with_offset(self, offset: i32) -> Self {
+        DateTime {
+            offset: Some(offset),
+            ..self
+        }
+    }
+}
+
+impl From<SystemTime> for DateTime {
+    /// Convert a `SystemTime`, rounding down to the millisecond.
+    ///
+    /// Times too far from the Unix epoch for a timestamp saturate to
+    /// `i64::MAX` or `i64::MIN` milliseconds.
+    fn from(time: SystemTime) -> DateTime {
+        let timestamp = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => i64::try_from(duration.as_millis()).unwrap_or(i64::MAX),
+            Err(e) => {
+                // Round down to the millisecond, as for later times.
+                let duration = e.duration();
+                let partial = duration.subsec_nanos() % 1_000_000 != 0;
+                i64::try_from(duration.as_millis())
+                    .ok()
+                    .and_then(|millis| (-millis).checked_sub(partial as i64))
+                    .unwrap_or(i64::MIN)
+            }
+        };
+        DateTime::new(timestamp)
+    }
+}
+
+impl fmt::Display for DateTime {
+    /// Write the date and time in the ISO 8601 format, like
+    /// `2024-03-05T14:07:09Z`.
+    ///
+    /// If the local time at the offset is out of range, the date and
+    /// time are written in UTC instead.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (local, offset) = match LocalTime::new(self) {
+            Some(local) => (local, self.offset),
+            None => (LocalTime::utc(self.timestamp), None),
+        };
+        if local.year < 0 {
+            try!(write!(f, "-{:04}", -local.year));
+        } else if local.year > 9999 {
+            try!(write!(f, "+{}", local.year));
+        } else {
+            try!(write!(f, "{:04}", local.year));
+        }
+        try!(write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            local.month, local.day, local.hour, local.minute, local.second
+        ));
+        if local.millisecond != 0 {
+            try!(write!(f, ".{:03}", local.millisecond));
+        }
+        match offset {
+            None => write!(f, "Z"),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                try!(write!(
+                    f,
+                    "{}{:02}:{:02}",
+                    sign,
+                    offset / 3600,
+                    offset / 60 % 60
+                ));
+                if offset % 60 != 0 {
+                    try!(write!(f, ":{:02}", offset % 60));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The fields of a `DateTime` in its local time, in the proleptic
+/// Gregorian calendar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalTime {
+    /// The year, where 0 is 1 BC.
+    pub year: i64,
+    /// The month, from 1 to 12.
+    pub month: u32,
+    /// The day of the month, from 1.
+    pub day: u32,
+    /// The day of the week, from 0 for Sunday to 6 for Saturday.
+    pub weekday: usize,
+    /// The hour, from 0 to 23.
+    pub hour: u32,
+    /// The minute, from 0 to 59.
+    pub minute: u32,
+    /// The second, from 0 to 59.
+    pub second: u32,
+    /// The millisecond, from 0 to 999.
+    pub millisecond: u32,
+}
+
+impl LocalTime {
+    /// Find the local time of `date_time`, or `None` if shifting its
+    /// timestamp by its offset overflows.
+    pub fn new(date_time: &DateTime) -> Option<Self> {
+        let offset = i64::from(date_time.offset.unwrap_or(0)) * 1000;
+        date_time.timestamp.checked_add(offset).map(LocalTime::utc)
+    }
+
+    /// Find the time in UTC of `timestamp`.
+    pub fn utc(timestamp: i64) -> Self {
+        let days = timestamp.div_euclid(MILLISECONDS_PER_DAY);
+        let time = timestamp.rem_euclid(MILLISECONDS_PER_DAY) as u32;
+        let (year, month, day) = civil_from_days(days);
+        LocalTime {
+            year: year,
+            month: month,
+            day: day,
+            // 1970-01-01 was a Thursday.
+            weekday: (days + 4).rem_euclid(7) as usize,
+            hour: time / 3_600_000,
+            minute: time / 60_000 % 60,
+            second: time / 1000 % 60,
+            millisecond: time % 1000,
+        }
+    }
+}
+
+/// The year, month and day of a number of days since 1970-01-01.
+///
+/// This uses the algorithm from Howard Hinnant's [`chrono`-Compatible
+/// Low-Level Date Algorithms], based on 400 year eras starting on
+/// March 1st, so that leap days come at the end of each year.
+///
+/// [`chrono`-Compatible Low-Level Date Algorithms]: http://howardhinnant.github.io/date_algorithms.html
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// The number of days since 1970-01-01 of a year, month and day, the
+/// reverse of `civil_from_days`.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = i64::from((month + 9) % 12);
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn local_time_works() {
+        let local = LocalTime::new(&DateTime::new(1_709_647_629_123)).unwrap();
+        assert_eq!(
+            local,
+            LocalTime {
+                year: 2024,
+                month: 3,
+                day: 5,
+                weekday: 2,
+                hour: 14,
+                minute: 7,
+                second: 9,
+                millisecond: 123,
+            }
+        );
+
+        // The offset moves this to the next day.
+        let local =
+            LocalTime::new(&DateTime::new(1_709_647_629_123).with_offset(11 * 3600)).unwrap();
+        assert_eq!((local.day, local.weekday, local.hour), (6, 3, 1));
+
+        let local = LocalTime::new(&DateTime::new(-1)).unwrap();
+        assert_eq!((local.year, local.month, local.day), (1969, 12, 31));
+        assert_eq!((local.hour, local.millisecond, local.weekday), (23, 999, 3));
+
+        // A leap day, and a day in 1 BC.
+        let local = LocalTime::new(&DateTime::new(951_782_400_000)).unwrap();
+        assert_eq!((local.year, local.month, local.day), (2000, 2, 29));
+        let local = LocalTime::new(&DateTime::new(-62_135_596_800_001)).unwrap();
+        assert_eq!((local.year, local.month, local.day), (0, 12, 31));
+    }
+
+    #[test]
+    fn extreme_timestamps_work() {
+        let local = LocalTime::new(&DateTime::new(i64::MAX)).unwrap();
+        assert_eq!((local.year, local.month, local.day), (292_278_994, 8, 17));
+        let local = LocalTime::new(&DateTime::new(i64::MIN)).unwrap();
+        assert_eq!((local.year, local.month, local.day), (-292_275_055, 5, 16));
+
+        // Shifting these by the offset overflows.
+        assert_eq!(
+            LocalTime::new(&DateTime::new(i64::MAX).with_offset(3600)),
+            None
+        );
+        assert_eq!(
+            LocalTime::new(&DateTime::new(i64::MIN).with_offset(-3600)),
+            None
+        );
+        assert!(LocalTime::new(&DateTime::new(i64::MAX).with_offset(-3600)).is_some());
+
+        // Display falls back to UTC.
+        assert_eq!(
+            DateTime::new(i64::MAX).with_offset(3600).to_string(),
+            "+292278994-08-17T07:12:55.807Z"
+        );
+
+        let time = UNIX_EPOCH + Duration::from_millis(1_709_647_629_123);
+        assert_eq!(DateTime::from(time).timestamp, 1_709_647_629_123);
+        let time = UNIX_EPOCH - Duration::from_micros(1500);
+        assert_eq!(DateTime::from(time).timestamp, -2);
+    }
+
+    #[test]
+    fn days_from_civil_works() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 2, 29), 11_016);
+        assert_eq!(days_from_civil(0, 12, 31), -719_163);
+        for days in (-800_000..800_000).step_by(997) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn display_works() {
+        let date_time = DateTime::new(1_709_647_629_000);
+        assert_eq!(date_time.to_string(), "2024-03-05T14:07:09Z");
+        assert_eq!(
+            date_time.with_offset(19_800).to_string(),
+            "2024-03-05T19:37:09+05:30"
+        );
+        assert_eq!(
+            date_time.with_offset(0).to_string(),
+            "2024-03-05T14:07:09+00:00"
+        );
+        assert_eq!(
+            DateTime::new(-62_135_596_800_001).to_string(),
+            "0000-12-31T23:59:59.999Z"
+        );
+    }
+
+    #[test]
+    fn from_system_time_works() {
+        use std::time::Duration;
+
+        let time = UNIX_EPOCH + Duration::from_millis(1_709_647_629_123);
+        assert_eq!(DateTime::from(time).timestamp, 1_709_647_629_123);
+        let time = UNIX_EPOCH - Duration::from_micros(1500);
+        assert_eq!(DateTime::from(time).timestamp, -2);
+    }
+}
@@ -0,0 +1,1068 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use language_tags::LanguageTag;
+use std::error::Error;
+use std::fmt;
+use std::mem;
+
+use cldr;
+use cldr::dates::{self, DateData, Names};
+use cldr::numbers;
+use cldr::time_zones;
+use date_time::LocalTime;
+use {DateTime, Decimal, Grouping, IntegerWidth, NumberFormatOptions, TimeZone};
+
+/// The fields that may be used in patterns.
+const PATTERN_LETTERS: &str = "GyMLdEecahHkKmsSzZOvVXx";
+
+/// The fields that may be used in skeletons, which also include the
+/// locale's preferred hour field, `j`, and its variants.
+const SKELETON_LETTERS: &str = "GyMLdEecahHkKmsSzZOvVXxjJC";
+
+/// The fields that belong to the date, rather than the time, when
+/// splitting a skeleton.
+const DATE_FIELDS: &str = "GyMdE";
+
+/// The length of a keyword date or time format.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DateTimeStyle {
+    /// The most detailed format, like `Tuesday, March 5, 2024`.
+    Full,
+    /// A format with full names, like `March 5, 2024`.
+    Long,
+    /// A format with abbreviated names, like `Mar 5, 2024`.
+    #[default]
+    Medium,
+    /// A mostly numeric format, like `3/5/24`.
+    Short,
+}
+
+impl DateTimeStyle {
+    /// The index of the style in the CLDR format lists.
+    fn index(self) -> usize {
+        match self {
+            DateTimeStyle::Full => 0,
+            DateTimeStyle::Long => 1,
+            DateTimeStyle::Medium => 2,
+            DateTimeStyle::Short => 3,
+        }
+    }
+}
+
+/// An error resulting from `DateTimeFormatOptions::from_skeleton` or
+/// `DateTimeFormatOptions::from_pattern`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DateTimePatternError {
+    /// The byte offset into the skeleton or pattern of the invalid
+    /// field.
+    pub offset: usize,
+    /// The invalid field, the rest of the pattern from an unclosed
+    /// apostrophe, or an empty string for an empty skeleton.
+    pub field: String,
+}
+
+impl Error for DateTimePatternError {
+    fn description(&self) -> &str {
+        "Invalid date/time field."
+    }
+}
+
+impl fmt::Display for DateTimePatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if self.field.is_empty() {
+            write!(f, "empty date/time skeleton")
+        } else {
+            write!(f, "invalid date/time field `{}`", self.field)
+        }
+    }
+}
+
+/// How to format a `DateTime`, following the [CLDR] conventions for
+/// the Gregorian calendar.
+///
+/// The keyword styles and skeletons use the locale's own patterns,
+/// while a pattern gives the exact fields to show:
+///
+/// ```
+/// use message_format::{DateTime, DateTimeFormatOptions, DateTimeStyle};
+///
+/// let value = DateTime::new(1_709_647_629_000);
+/// let en = "en".parse().unwrap();
+/// let de = "de".parse().unwrap();
+///
+/// let options = DateTimeFormatOptions::Date(DateTimeStyle::Long);
+/// assert_eq!(options.format(&en, &value).unwrap(), "March 5, 2024");
+/// assert_eq!(options.format(&de, &value).unwrap(), "5. März 2024");
+///
+/// let options = DateTimeFormatOptions::from_skeleton("yMMMEd").unwrap();
+/// assert_eq!(options.format(&en, &value).unwrap(), "Tue, Mar 5, 2024");
+///
+/// let options = DateTimeFormatOptions::from_pattern("y-MM-dd 'at' HH:mm").unwrap();
+/// assert_eq!(options.format(&en, &value).unwrap(), "2024-03-05 at 14:07");
+/// ```
+///
+/// [CLDR]: http://cldr.unicode.org/
+#[derive(Clone, Debug, PartialEq)]
+pub enum DateTimeFormatOptions {
+    /// Format the date in a keyword style.
+    Date(DateTimeStyle),
+    /// Format the time in a keyword style.
+    Time(DateTimeStyle),
+    /// Format the date and the time in keyword styles.
+    DateTime(DateTimeStyle, DateTimeStyle),
+    /// Format the fields of a [skeleton], like `yMMMd`, in the order
+    /// and with the punctuation used by the locale.
+    ///
+    /// [skeleton]: http://unicode.org/reports/tr35/tr35-dates.html#availableFormats_appendItems
+    Skeleton(String),
+    /// Format using a [pattern], like `EEEE, d MMMM y`. A pattern that
+    /// isn't valid is formatted as an empty string.
+    ///
+    /// [pattern]: http://unicode.org/reports/tr35/tr35-dates.html#Date_Format_Patterns
+    Pattern(String),
+}
+
+impl Default for DateTimeFormatOptions {
+    fn default() -> Self {
+        DateTimeFormatOptions::Date(DateTimeStyle::default())
+    }
+}
+
+impl DateTimeFormatOptions {
+    /// Check and wrap a skeleton, like `yMMMd` or `jmm`.
+    ///
+    /// The supported fields are eras (`G`), years (`y`), months (`M`
+    /// and `L`), days (`d`), weekdays (`E`, `c` and `e`), hours (`h`,
+    /// `H`, `k`, `K` and the locale's preferred `j`), minutes (`m`),
+    /// seconds (`s`), fractions of a second (`S`) and time zones (`z`,
+    /// `v`, `V`, `Z`, `O`, `X` and `x`).
+    ///
+    /// Time zone names, like `Pacific Standard Time` for `zzzz` or
+    /// `Los Angeles Time` for `VVVV`, are only available when formatting
+    /// in a [`TimeZone`]. Otherwise, zones are shown as offsets from GMT.
+    ///
+    /// [`TimeZone`]: struct.TimeZone.html
+    pub fn from_skeleton(skeleton: &str) -> Result<Self, DateTimePatternError> {
+        if skeleton.is_empty() {
+            return Err(DateTimePatternError {
+                offset: 0,
+                field: String::new(),
+            });
+        }
+        let mut chars = skeleton.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let mut end = offset + c.len_utf8();
+            while let Some(&(next, _)) = chars.peek().filter(|&&(_, next)| next == c) {
+                chars.next();
+                end = next + c.len_utf8();
+            }
+            if !SKELETON_LETTERS.contains(c) {
+                return Err(DateTimePatternError {
+                    offset: offset,
+                    field: skeleton[offset..end].to_string(),
+                });
+            }
+        }
+        Ok(DateTimeFormatOptions::Skeleton(skeleton.to_string()))
+    }
+
+    /// Check and wrap a pattern, like `EEEE, d MMMM y`.
+    ///
+    /// The supported fields are those of [`from_skeleton`], without
+    /// `j` and its variants, along with the AM or PM marker (`a`).
+    ///
+    /// [`from_skeleton`]: #method.from_skeleton
+    pub fn from_pattern(pattern: &str) -> Result<Self, DateTimePatternError> {
+        try!(items(pattern));
+        Ok(DateTimeFormatOptions::Pattern(pattern.to_string()))
+    }
+
+    /// The pattern used for the locale given by `language_tag`.
+    ///
+    /// ```
+    /// use message_format::{DateTimeFormatOptions, DateTimeStyle};
+    ///
+    /// let options = DateTimeFormatOptions::Date(DateTimeStyle::Short);
+    /// assert_eq!(options.pattern(&"en".parse().unwrap()), "M/d/yy");
+    ///
+    /// let options = DateTimeFormatOptions::from_skeleton("jmm").unwrap();
+    /// assert_eq!(options.pattern(&"fr".parse().unwrap()), "HH:mm");
+    /// ```
+    pub fn pattern(&self, language_tag: &LanguageTag) -> String {
+        let data =
+            cldr::lookup(dates::GREGORIAN, language_tag).expect("date data for the root locale");
+        match *self {
+            DateTimeFormatOptions::Date(style) => data.date_formats[style.index()].to_string(),
+            DateTimeFormatOptions::Time(style) => data.time_formats[style.index()].to_string(),
+            DateTimeFormatOptions::DateTime(date, time) => data.date_time_formats[date.index()]
+                .replace("{1}", data.date_formats[date.index()])
+                .replace("{0}", data.time_formats[time.index()]),
+            DateTimeFormatOptions::Skeleton(ref skeleton) => {
+                skeleton_pattern(&data, language_tag, skeleton)
+            }
+            DateTimeFormatOptions::Pattern(ref pattern) => pattern.clone(),
+        }
+    }
+
+    /// Format `value` for the locale given by `language_tag`.
+    ///
+    /// Returns `None` if the local time of `value` is out of range,
+    /// which happens only for timestamps within a day of `i64::MIN` or
+    /// `i64::MAX` milliseconds.
+    pub fn format(&self, language_tag: &LanguageTag, value: &DateTime) -> Option<String> {
+        let zone = Zone {
+            offset: value.offset.unwrap_or(0),
+            time_zone: None,
+            daylight: false,
+        };
+        self.format_zoned(language_tag, value, &zone)
+    }
+
+    /// Format `value` for the locale given by `language_tag`, at its
+    /// local time in `time_zone`, which replaces any offset it has.
+    ///
+    /// Returns `None` if the local time of `value` is out of range, as
+    /// for [`format`].
+    ///
+    /// ```
+    /// use message_format::{DateTime, DateTimeFormatOptions, DateTimeStyle, TimeZone};
+    ///
+    /// let value = DateTime::new(1_719_835_200_000);
+    /// let zone = TimeZone::new("America/Los_Angeles").unwrap();
+    ///
+    /// let options = DateTimeFormatOptions::Time(DateTimeStyle::Full);
+    /// let en = "en".parse().unwrap();
+    /// assert_eq!(
+    ///     options.format_in_time_zone(&en, &value, &zone).unwrap(),
+    ///     "5:00:00\u{202f}AM Pacific Daylight Time"
+    /// );
+    ///
+    /// let options = DateTimeFormatOptions::from_pattern("HH:mm VVVV").unwrap();
+    /// let de = "de".parse().unwrap();
+    /// assert_eq!(
+    ///     options.format_in_time_zone(&de, &value, &zone).unwrap(),
+    ///     "05:00 Los Angeles (Ortszeit)"
+    /// );
+    /// ```
+    ///
+    /// [`format`]: #method.format
+    pub fn format_in_time_zone(
+        &self,
+        language_tag: &LanguageTag,
+        value: &DateTime,
+        time_zone: &TimeZone,
+    ) -> Option<String> {
+        let offset = time_zone.offset(value.timestamp);
+        let zone = Zone {
+            offset: offset,
+            time_zone: Some(time_zone),
+            daylight: time_zone.is_daylight(value.timestamp),
+        };
+        self.format_zoned(language_tag, &value.with_offset(offset), &zone)
+    }
+
+    /// Format `value`, which is at the offset of `zone`.
+    fn format_zoned(
+        &self,
+        language_tag: &LanguageTag,
+        value: &DateTime,
+        zone: &Zone,
+    ) -> Option<String> {
+        let data =
+            cldr::lookup(dates::GREGORIAN, language_tag).expect("date data for the root locale");
+        LocalTime::new(value).map(|local| {
+            let mut output = String::new();
+            for item in items(&self.pattern(language_tag)).unwrap_or_default() {
+                match item {
+                    Item::Literal(text) => output.push_str(&text),
+                    Item::Field(letter, count) => {
+                        output.push_str(&field(letter, count, &local, zone, &data, language_tag))
+                    }
+                }
+            }
+            output
+        })
+    }
+}
+
+/// The offset from UTC and the time zone, if any, of a value being
+/// formatted.
+struct Zone<'a> {
+    /// The offset from UTC in seconds.
+    offset: i32,
+    /// The zone whose names may be shown.
+    time_zone: Option<&'a TimeZone>,
+    /// Whether daylight saving time is in effect in the zone.
+    daylight: bool,
+}
+
+/// A part of a pattern.
+#[derive(Clone, Debug, PartialEq)]
+enum Item {
+    /// Text to be written as is.
+    Literal(String),
+    /// A field letter and the number of times it is repeated.
+    Field(char, usize),
+}
+
+/// Split a pattern into literal text and fields.
+fn items(pattern: &str) -> Result<Vec<Item>, DateTimePatternError> {
+    let mut items = vec![];
+    let mut literal = String::new();
+    let mut chars = pattern.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        if c == '\'' {
+            if chars.peek().map(|&(_, next)| next) == Some('\'') {
+                chars.next();
+                literal.push('\'');
+                continue;
+            }
+            let mut closed = false;
+            while let Some((_, c)) = chars.next() {
+                if c != '\'' {
+                    literal.push(c);
+                } else if chars.peek().map(|&(_, next)| next) == Some('\'') {
+                    chars.next();
+                    literal.push('\'');
+                } else {
+                    closed = true;
+                    break;
+                }
+            }
+            if !closed {
+                return Err(DateTimePatternError {
+                    offset: offset,
+                    field: pattern[offset..].to_string(),
+                });
+            }
+        } else if c.is_ascii_alphabetic() {
+            let mut count = 1;
+            while chars.peek().map(|&(_, next)| next) == Some(c) {
+                chars.next();
+                count += 1;
+            }
+            if !PATTERN_LETTERS.contains(c) {
+                return Err(DateTimePatternError {
+                    offset: offset,
+                    field: pattern[offset..offset + count].to_string(),
+                });
+            }
+            if !literal.is_empty() {
+                items.push(Item::Literal(mem::take(&mut literal)));
+            }
+            items.push(Item::Field(c, count));
+        } else {
+            literal.push(c);
+        }
+    }
+    if !literal.is_empty() {
+        items.push(Item::Literal(literal));
+    }
+    Ok(items)
+}
+
+/// Write items back into a pattern, quoting letters and apostrophes in
+/// literal text.
+fn write_pattern(items: &[Item]) -> String {
+    let mut pattern = String::new();
+    for item in items {
+        match *item {
+            Item::Literal(ref text) => {
+                let mut quoted = false;
+                for c in text.chars() {
+                    if c.is_ascii_alphabetic() != quoted {
+                        pattern.push('\'');
+                        quoted = !quoted;
+                    }
+                    if c == '\'' {
+                        pattern.push('\'');
+                    }
+                    pattern.push(c);
+                }
+                if quoted {
+                    pattern.push('\'');
+                }
+            }
+            Item::Field(letter, count) => pattern.extend((0..count).map(|_| letter)),
+        }
+    }
+    pattern
+}
+
+/// A field of a skeleton.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Field {
+    /// The letter used for this kind of field in skeletons, so that
+    /// `L` is `M` and `j` is the locale's preferred hour field.
+    kind: char,
+    /// The letter as written.
+    letter: char,
+    /// The number of times the letter is repeated.
+    count: usize,
+}
+
+/// The letter used in skeletons for fields written with `letter`.
+fn kind(letter: char, hour: char) -> char {
+    match letter {
+        'L' => 'M',
+        'c' | 'e' => 'E',
+        'K' => 'h',
+        'k' => 'H',
+        'j' | 'J' | 'C' => hour,
+        'Z' | 'O' | 'v' | 'V' | 'X' | 'x' => 'z',
+        _ => letter,
+    }
+}
+
+/// The fields of a skeleton, without any AM or PM marker, which is
+/// added by the patterns as needed.
+fn skeleton_fields(skeleton: &str, hour: char) -> Vec<Field> {
+    let mut fields: Vec<Field> = vec![];
+    for letter in skeleton.chars() {
+        match fields.last_mut() {
+            Some(field) if field.letter == letter => field.count += 1,
+            _ => fields.push(Field {
+                kind: kind(letter, hour),
+                letter: letter,
+                count: 1,
+            }),
+        }
+    }
+    fields.retain(|field| field.kind != 'a');
+    fields
+}
+
+/// The pattern for a skeleton, following the locale's patterns for
+/// the same fields, or joining the patterns for the date and the time.
+fn skeleton_pattern(data: &DateData, language_tag: &LanguageTag, skeleton: &str) -> String {
+    let hour = match cldr::unicode_extension(language_tag, "hc").as_deref() {
+        Some("h11") | Some("h12") => 'h',
+        Some("h23") | Some("h24") => 'H',
+        _ => data.hour,
+    };
+    let mut fields = skeleton_fields(skeleton, hour);
+    let fraction = fields.iter().position(|field| field.kind == 'S');
+    let fraction = fraction.map(|index| fields.remove(index));
+
+    let (date, time): (Vec<Field>, Vec<Field>) = fields
+        .iter()
+        .partition(|field| DATE_FIELDS.contains(field.kind));
+    let pattern = match best_match(data, &fields) {
+        Some(pattern) => pattern,
+        None if !date.is_empty() && !time.is_empty() => {
+            let month = date.iter().find(|field| field.kind == 'M');
+            let weekday = date.iter().any(|field| field.kind == 'E');
+            let style = match month.map(|field| field.count) {
+                Some(count) if count >= 4 && weekday => DateTimeStyle::Full,
+                Some(count) if count >= 4 => DateTimeStyle::Long,
+                Some(3) => DateTimeStyle::Medium,
+                _ => DateTimeStyle::Short,
+            };
+            data.date_time_formats[style.index()]
+                .replace("{1}", &cover(data, &date))
+                .replace("{0}", &cover(data, &time))
+        }
+        None => cover(data, &fields),
+    };
+
+    match fraction {
+        Some(fraction) => {
+            // Fractions of a second follow the seconds, after the
+            // locale's decimal separator.
+            let decimal = cldr::lookup(numbers::NUMBERS, language_tag)
+                .expect("number data for the root locale")
+                .symbols
+                .decimal;
+            let mut items = items(&pattern).unwrap_or_default();
+            let seconds = items
+                .iter()
+                .position(|item| matches!(*item, Item::Field('s', _)));
+            let fraction = Item::Field('S', fraction.count);
+            match seconds {
+                Some(index) => {
+                    items.insert(index + 1, fraction);
+                    items.insert(index + 1, Item::Literal(decimal.to_string()));
+                }
+                None => {
+                    items.push(Item::Literal(" ".to_string()));
+                    items.push(fraction);
+                }
+            }
+            write_pattern(&items)
+        }
+        None => pattern,
+    }
+}
+
+/// The locale's pattern for exactly the kinds of fields in `fields`,
+/// adjusted to their lengths, if there is one.
+fn best_match(data: &DateData, fields: &[Field]) -> Option<String> {
+    data.available_formats
+        .iter()
+        .map(|&(skeleton, pattern)| (skeleton_fields(skeleton, data.hour), pattern))
+        .filter(|(available, _)| {
+            available.len() == fields.len()
+                && fields
+                    .iter()
+                    .all(|field| available.iter().any(|a| a.kind == field.kind))
+        })
+        .min_by_key(|(available, _)| distance(fields, available))
+        .map(|(_, pattern)| adjust(pattern, fields))
+}
+
+/// The patterns for as many fields at a time as possible, joined by
+/// spaces. Fields that no pattern covers are used as is.
+fn cover(data: &DateData, fields: &[Field]) -> String {
+    let mut remaining = fields.to_vec();
+    let mut patterns = vec![];
+    while !remaining.is_empty() {
+        let best = data
+            .available_formats
+            .iter()
+            .map(|&(skeleton, pattern)| (skeleton_fields(skeleton, data.hour), pattern))
+            .filter(|(available, _)| {
+                available
+                    .iter()
+                    .all(|a| remaining.iter().any(|field| field.kind == a.kind))
+            })
+            .min_by_key(|(available, _)| {
+                (
+                    remaining.len() - available.len(),
+                    distance(&remaining, available),
+                )
+            });
+        match best {
+            Some((available, pattern)) => {
+                patterns.push(adjust(pattern, &remaining));
+                remaining.retain(|field| available.iter().all(|a| a.kind != field.kind));
+            }
+            None => {
+                let field = remaining.remove(0);
+                patterns.push((0..field.count).map(|_| field.letter).collect());
+            }
+        }
+    }
+    patterns.join(" ")
+}
+
+/// How far the lengths of the fields in an available skeleton are from
+/// those requested. Numeric and text months are furthest apart.
+fn distance(requested: &[Field], available: &[Field]) -> usize {
+    requested
+        .iter()
+        .filter_map(|field| {
+            let a = available.iter().find(|a| a.kind == field.kind)?;
+            if field.kind == 'M' && (field.count >= 3) != (a.count >= 3) {
+                Some(100)
+            } else {
+                Some((field.count as isize - a.count as isize).unsigned_abs())
+            }
+        })
+        .sum()
+}
+
+/// Change the lengths of the fields in `pattern` to those requested,
+/// without changing numeric months to text or the reverse.
+fn adjust(pattern: &str, requested: &[Field]) -> String {
+    let items: Vec<Item> = items(pattern)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| match item {
+            Item::Field(letter, count) => {
+                let field = requested
+                    .iter()
+                    .find(|field| field.kind == kind(letter, 'H'));
+                let count = match field {
+                    Some(field) => match field.kind {
+                        'M' if (field.count >= 3) != (count >= 3) => count,
+                        'E' if field.count < 4 => count,
+                        'G' | 'M' | 'E' | 'y' | 'z' => field.count,
+                        _ => field.count.max(count),
+                    },
+                    None => count,
+                };
+                Item::Field(letter, count)
+            }
+            item => item,
+        })
+        .collect();
+    write_pattern(&items)
+}
+
+/// Format a single field.
+fn field(
+    letter: char,
+    count: usize,
+    local: &LocalTime,
+    zone: &Zone,
+    data: &DateData,
+    language_tag: &LanguageTag,
+) -> String {
+    let number = |value: i64, width: usize| number(language_tag, value, width);
+    match letter {
+        'G' => names(&data.eras, count)[if local.year > 0 { 1 } else { 0 }].to_string(),
+        'y' => {
+            // The year within the era, so that 1 BC follows 1 AD.
+            let year = if local.year > 0 {
+                local.year
+            } else {
+                1 - local.year
+            };
+            if count == 2 {
+                number(year % 100, 2)
+            } else {
+                number(year, count)
+            }
+        }
+        'M' | 'L' if count <= 2 => number(i64::from(local.month), count),
+        'M' | 'L' => names(&data.months, count)[local.month as usize - 1].to_string(),
+        'd' => number(i64::from(local.day), count),
+        'c' | 'e' if count <= 2 => number(local.weekday as i64 + 1, count),
+        'E' | 'c' | 'e' if count == 6 => data.short_weekdays[local.weekday].to_string(),
+        'E' | 'c' | 'e' => names(&data.weekdays, count)[local.weekday].to_string(),
+        'a' => data.day_periods[if local.hour < 12 { 0 } else { 1 }].to_string(),
+        'h' => number(i64::from((local.hour + 11) % 12 + 1), count),
+        'H' => number(i64::from(local.hour), count),
+        'K' => number(i64::from(local.hour % 12), count),
+        'k' => number(
+            i64::from(if local.hour == 0 { 24 } else { local.hour }),
+            count,
+        ),
+        'm' => number(i64::from(local.minute), count),
+        's' => number(i64::from(local.second), count),
+        'S' if count <= 3 => {
+            let millisecond = i64::from(local.millisecond);
+            number(millisecond / 10_i64.pow(3 - count as u32), count)
+        }
+        // Longer fractions are padded with zeros as text, since their
+        // value may not fit in an `i64`.
+        'S' => number(i64::from(local.millisecond), 3) + &number(0, count - 3),
+        'z' | 'O' | 'v' | 'V' | 'Z' | 'X' | 'x' => {
+            zone_field(letter, count, zone, data, language_tag)
+        }
+        _ => String::new(),
+    }
+}
+
+/// The names for a field of `count` letters.
+fn names<T: Copy>(names: &Names<T>, count: usize) -> T {
+    match count {
+        1..=3 => names.abbreviated,
+        4 => names.wide,
+        _ => names.narrow,
+    }
+}
+
+/// Format a non-negative integer with at least `width` digits, using
+/// the locale's digits.
+fn number(language_tag: &LanguageTag, value: i64, width: usize) -> String {
+    let options = NumberFormatOptions {
+        grouping: Grouping::Off,
+        integer_width: IntegerWidth {
+            minimum: width,
+            maximum: None,
+        },
+        ..NumberFormatOptions::default()
+    };
+    options.format(language_tag, &Decimal::from(value))
+}
+
+/// Format a time zone field.
+fn zone_field(
+    letter: char,
+    count: usize,
+    zone: &Zone,
+    data: &DateData,
+    language_tag: &LanguageTag,
+) -> String {
+    let offset = zone.offset;
+    let sign = if offset < 0 { '-' } else { '+' };
+    let seconds = offset.unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    let iso = |count: usize| {
+        let mut iso = format!("{}{:02}", sign, hours);
+        let separator = if count == 3 || count == 5 { ":" } else { "" };
+        if count != 1 || minutes != 0 {
+            iso.push_str(&format!("{}{:02}", separator, minutes));
+        }
+        if count >= 4 && seconds != 0 {
+            iso.push_str(&format!("{}{:02}", separator, seconds));
+        }
+        iso
+    };
+    let gmt = |long: bool| {
+        if offset == 0 {
+            return data.gmt_zero_format.to_string();
+        }
+        let number = |value: u32, width: usize| number(language_tag, i64::from(value), width);
+        let mut gmt = format!("{}{}", sign, number(hours, if long { 2 } else { 1 }));
+        if long || minutes != 0 {
+            gmt.push_str(&format!(":{}", number(minutes, 2)));
+        }
+        if seconds != 0 {
+            gmt.push_str(&format!(":{}", number(seconds, 2)));
+        }
+        data.gmt_format.replace("{0}", &gmt)
+    };
+    let id = zone.time_zone.map(TimeZone::id);
+    let name =
+        |long: bool, index: usize| id.and_then(|id| zone_name(language_tag, id, long, index));
+    let specific = if zone.daylight { 2 } else { 1 };
+    let location = || id.and_then(|id| location(language_tag, id));
+    match (letter, count) {
+        ('z', 1..=3) => name(false, specific).unwrap_or_else(|| gmt(false)),
+        ('z', _) => name(true, specific).unwrap_or_else(|| gmt(true)),
+        ('v', 1) => name(false, 0)
+            .or_else(location)
+            .unwrap_or_else(|| gmt(false)),
+        ('v', _) => name(true, 0).or_else(location).unwrap_or_else(|| gmt(true)),
+        ('V', 1..=2) => id.map(str::to_string).unwrap_or_else(|| gmt(true)),
+        ('V', 3) => id
+            .and_then(|id| city(language_tag, id))
+            .unwrap_or_else(|| gmt(true)),
+        ('V', _) => location().unwrap_or_else(|| gmt(true)),
+        ('O', 1) => gmt(false),
+        ('O', _) | ('Z', 4) => gmt(true),
+        ('Z', 1..=3) => iso(4),
+        ('Z', _) | ('X', _) if offset == 0 => "Z".to_string(),
+        ('Z', _) => iso(5),
+        (_, count) => iso(count),
+    }
+}
+
+/// A name of the zone `id` or of its metazone, which is generic for
+/// `index` 0, standard for 1 and daylight saving time for 2.
+fn zone_name(language_tag: &LanguageTag, id: &str, long: bool, index: usize) -> Option<String> {
+    let fallbacks = cldr::fallbacks(language_tag);
+    let meta_zone = cldr::find(time_zones::META_ZONES, id);
+    for key in Some(id).into_iter().chain(meta_zone) {
+        for locale in &fallbacks {
+            let names =
+                cldr::find(time_zones::NAMES, locale).and_then(|names| cldr::find(names, key));
+            let name = match names {
+                Some(names) if long => names.long[index],
+                Some(names) => names.short[index],
+                None => continue,
+            };
+            match name {
+                "" => continue,
+                "∅∅∅" => break,
+                name => return Some(name.to_string()),
+            }
+        }
+    }
+    None
+}
+
+/// The name of the exemplar city of the zone `id`, unless it is not a
+/// geographic zone, like `Etc/UTC`.
+fn city(language_tag: &LanguageTag, id: &str) -> Option<String> {
+    if id.starts_with("Etc/") || !id.contains('/') {
+        return None;
+    }
+    let city = cldr::fallbacks(language_tag)
+        .iter()
+        .filter_map(|locale| cldr::find(time_zones::EXEMPLAR_CITIES, locale))
+        .filter_map(|cities| cities.iter().find(|&&(zone, _)| zone == id))
+        .map(|&(_, city)| city.to_string())
+        .next();
+    city.or_else(|| id.rsplit('/').next().map(|city| city.replace('_', " ")))
+}
+
+/// The generic location name of the zone `id`, like `Los Angeles Time`.
+fn location(language_tag: &LanguageTag, id: &str) -> Option<String> {
+    let format = cldr::lookup(time_zones::REGION_FORMATS, language_tag)
+        .expect("region format for the root locale");
+    city(language_tag, id).map(|city| format.replace("{0}", &city))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(options: &DateTimeFormatOptions, locale: &str, value: &DateTime) -> String {
+        options.format(&locale.parse().unwrap(), value).unwrap()
+    }
+
+    fn pattern(skeleton: &str, locale: &str) -> String {
+        let options = DateTimeFormatOptions::from_skeleton(skeleton).unwrap();
+        options.pattern(&locale.parse().unwrap())
+    }
+
+    // 2024-03-05T14:07:09.123Z, a Tuesday.
+    const TIMESTAMP: i64 = 1_709_647_629_123;
+
+    #[test]
+    fn styles_work() {
+        let value = DateTime::new(TIMESTAMP);
+        let date = |style| DateTimeFormatOptions::Date(style);
+        let time = |style| DateTimeFormatOptions::Time(style);
+
+        assert_eq!(
+            format(&date(DateTimeStyle::Full), "en", &value),
+            "Tuesday, March 5, 2024"
+        );
+        assert_eq!(
+            format(&date(DateTimeStyle::Long), "en", &value),
+            "March 5, 2024"
+        );
+        assert_eq!(
+            format(&date(DateTimeStyle::Medium), "en", &value),
+            "Mar 5, 2024"
+        );
+        assert_eq!(format(&date(DateTimeStyle::Short), "en", &value), "3/5/24");
+        assert_eq!(
+            format(&date(DateTimeStyle::Short), "en-GB", &value),
+            "05/03/2024"
+        );
+        assert_eq!(
+            format(&date(DateTimeStyle::Medium), "de", &value),
+            "05.03.2024"
+        );
+        assert_eq!(
+            format(&date(DateTimeStyle::Full), "fr", &value),
+            "mardi 5 mars 2024"
+        );
+        assert_eq!(
+            format(&date(DateTimeStyle::Long), "es", &value),
+            "5 de marzo de 2024"
+        );
+        assert_eq!(
+            format(&date(DateTimeStyle::Full), "ja", &value),
+            "2024年3月5日火曜日"
+        );
+        assert_eq!(
+            format(&date(DateTimeStyle::Medium), "und", &value),
+            "2024 M03 5"
+        );
+
+        assert_eq!(
+            format(&time(DateTimeStyle::Medium), "en", &value),
+            "2:07:09\u{202f}PM"
+        );
+        assert_eq!(format(&time(DateTimeStyle::Short), "de", &value), "14:07");
+        assert_eq!(
+            format(&time(DateTimeStyle::Full), "en", &value),
+            "2:07:09\u{202f}PM GMT"
+        );
+        assert_eq!(
+            format(&time(DateTimeStyle::Long), "fr", &value.with_offset(3600)),
+            "15:07:09 UTC+1"
+        );
+        assert_eq!(
+            format(&time(DateTimeStyle::Full), "ja", &value.with_offset(19_800)),
+            "19時37分09秒 GMT+05:30"
+        );
+
+        let both = DateTimeFormatOptions::DateTime(DateTimeStyle::Long, DateTimeStyle::Short);
+        assert_eq!(
+            format(&both, "en", &value),
+            "March 5, 2024 at 2:07\u{202f}PM"
+        );
+        assert_eq!(format(&both, "de", &value), "5. März 2024 um 14:07");
+    }
+
+    #[test]
+    fn skeletons_work() {
+        assert_eq!(pattern("yMMMd", "en"), "MMM d, y");
+        assert_eq!(pattern("yMMMMd", "en"), "MMMM d, y");
+        assert_eq!(pattern("yMMMMEEEEd", "en"), "EEEE, MMMM d, y");
+        assert_eq!(pattern("yMMMMd", "ja"), "y年M月d日");
+        assert_eq!(pattern("MMMd", "de"), "d. MMM");
+        assert_eq!(pattern("jmm", "en"), "h:mm\u{202f}a");
+        assert_eq!(pattern("jmm", "en-GB"), "HH:mm");
+        assert_eq!(pattern("jmm", "en-u-hc-h23"), "HH:mm");
+        assert_eq!(pattern("Hmm", "es"), "H:mm");
+        assert_eq!(pattern("hh", "en"), "hh\u{202f}a");
+        assert_eq!(pattern("Ehm", "en"), "E h:mm\u{202f}a");
+        assert_eq!(pattern("yMMMdjm", "en"), "MMM d, y, h:mm\u{202f}a");
+        assert_eq!(pattern("yMMMMdjm", "en"), "MMMM d, y 'at' h:mm\u{202f}a");
+        assert_eq!(pattern("Hmsz", "en"), "HH:mm:ss z");
+        assert_eq!(pattern("HmsSSS", "de"), "HH:mm:ss,SSS");
+        assert_eq!(pattern("MMMMd", "es"), "d 'de' MMMM");
+
+        let value = DateTime::new(TIMESTAMP);
+        let options = DateTimeFormatOptions::from_skeleton("yMMMEd").unwrap();
+        assert_eq!(format(&options, "en", &value), "Tue, Mar 5, 2024");
+        assert_eq!(format(&options, "fr", &value), "mar. 5 mars 2024");
+        assert_eq!(format(&options, "ja", &value), "2024年3月5日(火)");
+        let options = DateTimeFormatOptions::from_skeleton("jms").unwrap();
+        assert_eq!(format(&options, "ja", &value), "14:07:09");
+        let options = DateTimeFormatOptions::from_skeleton("hms").unwrap();
+        assert_eq!(format(&options, "ja", &value), "午後2:07:09");
+        assert_eq!(format(&options, "es", &value), "2:07:09 p.\u{a0}m.");
+    }
+
+    #[test]
+    fn patterns_work() {
+        let value = DateTime::new(TIMESTAMP).with_offset(-(5 * 3600 + 30 * 60));
+        let options = |pattern| DateTimeFormatOptions::from_pattern(pattern).unwrap();
+
+        assert_eq!(
+            format(&options("yyyy-MM-dd'T'HH:mm:ss.SSSXXX"), "en", &value),
+            "2024-03-05T08:37:09.123-05:30"
+        );
+        assert_eq!(
+            format(&options("EEEEE EEEEEE MMMMM LLLL GGGG GGGGG"), "en", &value),
+            "T Tu M March Anno Domini A"
+        );
+        assert_eq!(
+            format(&options("yy y yyyyy"), "en", &value),
+            "24 2024 02024"
+        );
+        assert_eq!(
+            format(&options("h hh K k H a"), "en", &value),
+            "8 08 8 8 8 AM"
+        );
+        assert_eq!(format(&options("S SS SSSS"), "en", &value), "1 12 1230");
+        let long = DateTimeFormatOptions::from_pattern(&"S".repeat(25)).unwrap();
+        assert_eq!(
+            format(&long, "en", &value),
+            format!("123{}", "0".repeat(22))
+        );
+        assert_eq!(format(&options("SSSSSS"), "ar", &value), "١٢٣٠٠٠");
+        assert_eq!(format(&options("e cc ccc"), "en", &value), "3 03 Tue");
+        assert_eq!(
+            format(&options("z zzzz O OOOO Z ZZZZ ZZZZZ"), "en", &value),
+            "GMT-5:30 GMT-05:30 GMT-5:30 GMT-05:30 -0530 GMT-05:30 -05:30"
+        );
+        assert_eq!(
+            format(&options("X XX XXX x"), "en", &value),
+            "-0530 -0530 -05:30 -0530"
+        );
+        assert_eq!(
+            format(&options("X XXX x xxx Z ZZZZZ O"), "en", &DateTime::new(0)),
+            "Z Z +00 +00:00 +0000 Z GMT"
+        );
+        assert_eq!(format(&options("'o''clock' ''"), "en", &value), "o'clock '");
+        assert_eq!(format(&options("d MMMM y"), "ar", &value), "٥ M03 ٢٠٢٤");
+
+        // Midnight and noon on a 12 hour clock.
+        let midnight = DateTime::new(0);
+        assert_eq!(format(&options("h a k K"), "en", &midnight), "12 AM 24 0");
+        let noon = DateTime::new(12 * 3_600_000);
+        assert_eq!(format(&options("h a"), "en-GB", &noon), "12 pm");
+
+        // Years before the Common Era.
+        let value = DateTime::new(-62_198_755_200_000);
+        assert_eq!(format(&options("y G"), "en", &value), "2 BC");
+    }
+
+    #[test]
+    fn time_zones_work() {
+        let zoned = |pattern: &str, locale: &str, timestamp: i64, zone: &TimeZone| {
+            let options = DateTimeFormatOptions::from_pattern(pattern).unwrap();
+            options
+                .format_in_time_zone(&locale.parse().unwrap(), &DateTime::new(timestamp), zone)
+                .unwrap()
+        };
+        let summer = 1_719_835_200_000;
+        let pattern = "HH:mm|z|zzzz|v|vvvv|VV|VVV|VVVV|O";
+
+        let zone = TimeZone::from_posix("America/Los_Angeles", "PST8PDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(
+            zoned(pattern, "en", TIMESTAMP, &zone),
+            "06:07|PST|Pacific Standard Time|PT|Pacific Time|America/Los_Angeles|Los Angeles|\
+             Los Angeles Time|GMT-8"
+        );
+        assert_eq!(
+            zoned(pattern, "en-GB", summer, &zone),
+            "05:00|GMT-7|Pacific Daylight Time|Los Angeles Time|Pacific Time|\
+             America/Los_Angeles|Los Angeles|Los Angeles Time|GMT-7"
+        );
+        assert_eq!(
+            zoned("zzzz VVVV", "es", summer, &zone),
+            "hora de verano del Pacífico hora de Los Ángeles"
+        );
+
+        let zone = TimeZone::from_posix("Europe/Paris", "CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(
+            zoned(pattern, "de", summer, &zone),
+            "14:00|MESZ|Mitteleuropäische Sommerzeit|MEZ|Mitteleuropäische Zeit|Europe/Paris|\
+             Paris|Paris (Ortszeit)|GMT+2"
+        );
+
+        let zone = TimeZone::from_posix("Asia/Tokyo", "JST-9").unwrap();
+        assert_eq!(
+            zoned("zzzz VVVV", "ja", TIMESTAMP, &zone),
+            "日本標準時 東京時間"
+        );
+
+        let zone = TimeZone::utc();
+        assert_eq!(
+            zoned(pattern, "en", TIMESTAMP, &zone),
+            "14:07|UTC|Coordinated Universal Time|GMT|GMT|Etc/UTC|GMT|GMT|GMT"
+        );
+
+        // Keyword styles and skeletons use the zone's names too.
+        let zone = TimeZone::from_posix("Europe/London", "GMT0BST,M3.5.0/1,M10.5.0").unwrap();
+        let options = DateTimeFormatOptions::Time(DateTimeStyle::Full);
+        let value = DateTime::new(summer);
+        assert_eq!(
+            options
+                .format_in_time_zone(&"en".parse().unwrap(), &value, &zone)
+                .unwrap(),
+            "1:00:00\u{202f}PM British Summer Time"
+        );
+        let options = DateTimeFormatOptions::from_skeleton("Hmv").unwrap();
+        assert_eq!(
+            options
+                .format_in_time_zone(&"fr".parse().unwrap(), &value, &zone)
+                .unwrap(),
+            "13:00 heure : Londres"
+        );
+
+        // Without a zone, names fall back to offsets.
+        let value = DateTime::new(TIMESTAMP).with_offset(3600);
+        assert_eq!(
+            format(
+                &DateTimeFormatOptions::from_pattern(pattern).unwrap(),
+                "en",
+                &value
+            ),
+            "15:07|GMT+1|GMT+01:00|GMT+1|GMT+01:00|GMT+01:00|GMT+01:00|GMT+01:00|GMT+1"
+        );
+    }
+
+    #[test]
+    fn errors_work() {
+        let e = DateTimeFormatOptions::from_skeleton("yMMMqq").unwrap_err();
+        assert_eq!(
+            e,
+            DateTimePatternError {
+                offset: 4,
+                field: "qq".to_string(),
+            }
+        );
+        assert!(DateTimeFormatOptions::from_skeleton("y-M").is_err());
+        let e = DateTimeFormatOptions::from_skeleton("").unwrap_err();
+        assert_eq!((e.offset, e.field.as_str()), (0, ""));
+        assert_eq!(e.to_string(), "empty date/time skeleton");
+
+        let e = DateTimeFormatOptions::from_pattern("y jj").unwrap_err();
+        assert_eq!((e.offset, e.field.as_str()), (2, "jj"));
+        let e = DateTimeFormatOptions::from_pattern("y 'at HH:mm").unwrap_err();
+        assert_eq!((e.offset, e.field.as_str()), (2, "'at HH:mm"));
+        assert_eq!(e.to_string(), "invalid date/time field `'at HH:mm`");
+
+        let options = DateTimeFormatOptions::Pattern("y 'at".to_string());
+        assert_eq!(format(&options, "en", &DateTime::new(0)), "");
+
+        // The local time of these is out of range.
+        let options = DateTimeFormatOptions::Date(DateTimeStyle::Short);
+        let en = "en".parse().unwrap();
+        let value = DateTime::new(i64::MAX).with_offset(3600);
+        assert_eq!(options.format(&en, &value), None);
+        let value = DateTime::new(i64::MIN + 1000);
+        let zone = TimeZone::from_posix("America/New_York", "EST5EDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(options.format_in_time_zone(&en, &value, &zone), None);
+        assert_eq!(
+            options.format(&en, &DateTime::new(i64::MAX).with_offset(-3600)),
+            Some("8/17/94".to_string())
+        );
+    }
+}
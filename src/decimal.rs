@@ -0,0 +1,577 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+use plural_operands;
+use {PluralOperands, RoundingMode};
+
+/// An arbitrary-precision decimal number.
+///
+/// Unlike a float, a `Decimal` keeps the digits it was written with,
+/// including trailing zeros in the fraction, which matter for display
+/// and for plural rules:
+///
+/// ```
+/// use message_format::Decimal;
+///
+/// let price: Decimal = "2.50".parse().unwrap();
+/// assert_eq!(price.to_string(), "2.50");
+///
+/// let total = &price + &"10".parse().unwrap();
+/// assert_eq!(total.to_string(), "12.50");
+///
+/// let big = Decimal::from(u128::MAX);
+/// assert_eq!(big.to_string(), "340282366920938463463374607431768211455");
+/// ```
+///
+/// Equality compares the digits, so `1.0` and `1` are not equal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decimal {
+    negative: bool,
+    /// The integer digits, without leading zeros unless the integer
+    /// part is `0`.
+    integer: String,
+    /// The fraction digits, including any trailing zeros.
+    fraction: String,
+}
+
+/// An error resulting from parsing a `Decimal` from a string that
+/// isn't a decimal number.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseDecimalError {
+    input: String,
+}
+
+impl Error for ParseDecimalError {
+    fn description(&self) -> &str {
+        "Invalid decimal number."
+    }
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "invalid decimal number `{}`", self.input)
+    }
+}
+
+impl Decimal {
+    fn new(negative: bool, integer: &str, fraction: &str) -> Self {
+        let integer = integer.trim_start_matches('0');
+        let integer = if integer.is_empty() { "0" } else { integer };
+        let is_zero = integer == "0" && fraction.chars().all(|c| c == '0');
+        Decimal {
+            negative: negative && !is_zero,
+            integer: integer.to_string(),
+            fraction: fraction.to_string(),
+        }
+    }
+
+    /// Convert a float to a `Decimal` using its shortest representation.
+    ///
+    /// This is `None` if `value` is infinite or NaN.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if value.is_finite() {
+            // `Display` gives the shortest representation that
+            // round-trips, without an exponent.
+            format!("{}", value).parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Whether this is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Whether this is zero, however many fraction digits it has.
+    pub fn is_zero(&self) -> bool {
+        self.integer == "0" && self.fraction.chars().all(|c| c == '0')
+    }
+
+    /// The digits before the decimal point, without leading zeros.
+    ///
+    /// ```
+    /// use message_format::Decimal;
+    ///
+    /// let value: Decimal = "-012.50".parse().unwrap();
+    /// assert_eq!(value.integer_digits(), "12");
+    /// assert_eq!(value.fraction_digits(), "50");
+    /// ```
+    pub fn integer_digits(&self) -> &str {
+        &self.integer
+    }
+
+    /// The digits after the decimal point, including trailing zeros.
+    pub fn fraction_digits(&self) -> &str {
+        &self.fraction
+    }
+
+    /// Round to at most `fraction_digits` digits after the decimal
+    /// point, rounding halves to even.
+    ///
+    /// A decimal that already has no more digits than that is returned
+    /// unchanged, keeping any trailing zeros.
+    ///
+    /// ```
+    /// use message_format::Decimal;
+    ///
+    /// let value: Decimal = "2.345".parse().unwrap();
+    /// assert_eq!(value.round(2).to_string(), "2.34");
+    /// assert_eq!(value.round(0).to_string(), "2");
+    /// ```
+    pub fn round(&self, fraction_digits: usize) -> Decimal {
+        self.round_with(fraction_digits, RoundingMode::HalfEven)
+    }
+
+    /// Round to at most `fraction_digits` digits after the decimal
+    /// point using a rounding mode.
+    ///
+    /// ```
+    /// use message_format::{Decimal, RoundingMode};
+    ///
+    /// let value: Decimal = "-2.345".parse().unwrap();
+    /// assert_eq!(value.round_with(2, RoundingMode::HalfUp).to_string(), "-2.35");
+    /// assert_eq!(value.round_with(2, RoundingMode::Ceiling).to_string(), "-2.34");
+    /// ```
+    pub fn round_with(&self, fraction_digits: usize, mode: RoundingMode) -> Decimal {
+        if self.fraction.len() <= fraction_digits {
+            return self.clone();
+        }
+        let (kept, dropped) = self.fraction.split_at(fraction_digits);
+        let truncated = Decimal::new(self.negative, &self.integer, kept);
+        if dropped.bytes().all(|b| b == b'0') {
+            return truncated;
+        }
+        let first = dropped.as_bytes()[0];
+        let above_half = first > b'5' || (first == b'5' && dropped[1..].bytes().any(|b| b != b'0'));
+        let at_least_half = first >= b'5';
+        let last_kept = self.integer.bytes().chain(kept.bytes()).last();
+        let odd = matches!(last_kept, Some(b) if (b - b'0') % 2 == 1);
+        let away_from_zero = match mode {
+            RoundingMode::Ceiling => !self.negative,
+            RoundingMode::Floor => self.negative,
+            RoundingMode::Down => false,
+            RoundingMode::Up => true,
+            RoundingMode::HalfEven => above_half || (at_least_half && odd),
+            RoundingMode::HalfDown => above_half,
+            RoundingMode::HalfUp => at_least_half,
+        };
+        if away_from_zero {
+            let unit = if kept.is_empty() {
+                Decimal::new(self.negative, "1", "")
+            } else {
+                Decimal::new(self.negative, "0", &format!("{:0>1$}", 1, kept.len()))
+            };
+            truncated.combine(&unit, false)
+        } else {
+            truncated
+        }
+    }
+
+    /// Round to a multiple of `increment`, which must be positive,
+    /// showing as many fraction digits as it has.
+    ///
+    /// ```
+    /// use message_format::{Decimal, RoundingMode};
+    ///
+    /// let value: Decimal = "1.23".parse().unwrap();
+    /// let increment = "0.05".parse().unwrap();
+    /// assert_eq!(value.round_to_increment(&increment, RoundingMode::HalfEven).to_string(), "1.25");
+    /// ```
+    pub fn round_to_increment(&self, increment: &Decimal, mode: RoundingMode) -> Decimal {
+        // Write the increment as `m * 10^-k`, and divide by `m` after
+        // shifting, so that only division by an integer is needed.
+        let k = increment.fraction.len();
+        let m = increment.shift(k as isize);
+        let divisor: u64 = match m.integer.parse() {
+            Ok(divisor) if divisor > 0 => divisor,
+            _ => return self.clone(),
+        };
+        let dividend = self.shift(k as isize);
+        let mut quotient = String::new();
+        let mut remainder: u128 = 0;
+        for b in dividend.integer.bytes().chain(dividend.fraction.bytes()) {
+            remainder = remainder * 10 + u128::from(b - b'0');
+            quotient.push(char::from(b'0' + (remainder / u128::from(divisor)) as u8));
+            remainder %= u128::from(divisor);
+        }
+        let (integer, fraction) = quotient.split_at(dividend.integer.len());
+        let mut fraction = fraction.to_string();
+        if remainder != 0 {
+            // One more digit of the quotient, then a non-zero digit
+            // standing in for the rest of it, is all that rounding
+            // needs to know.
+            remainder *= 10;
+            fraction.push(char::from(b'0' + (remainder / u128::from(divisor)) as u8));
+            if !remainder.is_multiple_of(u128::from(divisor)) {
+                fraction.push('1');
+            }
+        }
+        let multiple = Decimal::new(self.negative, integer, &fraction).round_with(0, mode);
+        (&multiple * &m).shift(-(k as isize))
+    }
+
+    /// Multiply by ten to the power of `exponent`, moving the decimal
+    /// point rather than doing any arithmetic.
+    ///
+    /// ```
+    /// use message_format::Decimal;
+    ///
+    /// let value: Decimal = "0.125".parse().unwrap();
+    /// assert_eq!(value.shift(2).to_string(), "12.5");
+    /// assert_eq!(value.shift(-2).to_string(), "0.00125");
+    /// ```
+    pub fn shift(&self, exponent: isize) -> Decimal {
+        let digits = format!("{}{}", self.integer, self.fraction);
+        let point = self.integer.len() as isize + exponent;
+        if point <= 0 {
+            let zeros = "0".repeat(-point as usize);
+            Decimal::new(self.negative, "0", &format!("{}{}", zeros, digits))
+        } else if point as usize >= digits.len() {
+            let zeros = "0".repeat(point as usize - digits.len());
+            Decimal::new(self.negative, &format!("{}{}", digits, zeros), "")
+        } else {
+            let (integer, fraction) = digits.split_at(point as usize);
+            Decimal::new(self.negative, integer, fraction)
+        }
+    }
+
+    /// The digits of the magnitude of this decimal with `scale` fraction
+    /// digits, which must be at least as many as it has.
+    fn scaled_digits(&self, scale: usize) -> Vec<u8> {
+        let mut digits: Vec<u8> = self
+            .integer
+            .bytes()
+            .chain(self.fraction.bytes())
+            .map(|b| b - b'0')
+            .collect();
+        digits.resize(self.integer.len() + scale, 0);
+        digits
+    }
+
+    /// Add `other` to this decimal, negating it first if `subtract`
+    /// is set. The result has as many fraction digits as the operand
+    /// with the most.
+    fn combine(&self, other: &Decimal, subtract: bool) -> Decimal {
+        let scale = self.fraction.len().max(other.fraction.len());
+        let a = self.scaled_digits(scale);
+        let b = other.scaled_digits(scale);
+        let width = a.len().max(b.len()) + 1;
+        let pad = |digits: Vec<u8>| {
+            let mut padded = vec![0; width - digits.len()];
+            padded.extend(digits);
+            padded
+        };
+        let (a, b) = (pad(a), pad(b));
+
+        let other_negative = other.negative != subtract;
+        let (negative, digits) = if self.negative == other_negative {
+            (self.negative, add_digits(&a, &b))
+        } else if a >= b {
+            (self.negative, subtract_digits(&a, &b))
+        } else {
+            (other_negative, subtract_digits(&b, &a))
+        };
+
+        let digits: String = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        Decimal::new(negative, integer, fraction)
+    }
+}
+
+/// Add two equal length slices of digits. The first digit of each
+/// must be zero so that the result can't overflow.
+fn add_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; a.len()];
+    let mut carry = 0;
+    for i in (0..a.len()).rev() {
+        let sum = a[i] + b[i] + carry;
+        result[i] = sum % 10;
+        carry = sum / 10;
+    }
+    result
+}
+
+/// Subtract two equal length slices of digits, where `a >= b`.
+fn subtract_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; a.len()];
+    let mut borrow = 0;
+    for i in (0..a.len()).rev() {
+        let (digit, next_borrow) = if a[i] >= b[i] + borrow {
+            (a[i] - b[i] - borrow, 0)
+        } else {
+            (a[i] + 10 - b[i] - borrow, 1)
+        };
+        result[i] = digit;
+        borrow = next_borrow;
+    }
+    result
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// Parse a decimal number like `-12.50`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (integer, fraction) = match unsigned.find('.') {
+            Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+            None => (unsigned, ""),
+        };
+        let all_digits = integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit());
+        if integer.is_empty() || !all_digits || (fraction.is_empty() && unsigned.ends_with('.')) {
+            return Err(ParseDecimalError {
+                input: text.to_string(),
+            });
+        }
+        Ok(Decimal::new(negative, integer, fraction))
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut text = String::new();
+        if self.negative {
+            text.push('-');
+        }
+        text.push_str(&self.integer);
+        if !self.fraction.is_empty() {
+            text.push('.');
+            text.push_str(&self.fraction);
+        }
+        f.pad(&text)
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Decimal {
+                fn from(value: $t) -> Self {
+                    // Integers always have a valid representation.
+                    value.to_string().parse().unwrap()
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Add<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: &Decimal) -> Decimal {
+        self.combine(other, false)
+    }
+}
+
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: Decimal) -> Decimal {
+        self.combine(&other, false)
+    }
+}
+
+impl Sub<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: &Decimal) -> Decimal {
+        self.combine(other, true)
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: Decimal) -> Decimal {
+        self.combine(&other, true)
+    }
+}
+
+impl Mul<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    /// Multiply two decimals. The result has as many fraction digits
+    /// as the operands have between them.
+    fn mul(self, other: &Decimal) -> Decimal {
+        let a = self.scaled_digits(self.fraction.len());
+        let b = other.scaled_digits(other.fraction.len());
+        let mut product = vec![0u32; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate().rev() {
+            for (j, &y) in b.iter().enumerate().rev() {
+                product[i + j + 1] += u32::from(x) * u32::from(y);
+            }
+        }
+        for i in (1..product.len()).rev() {
+            product[i - 1] += product[i] / 10;
+            product[i] %= 10;
+        }
+        let digits: String = product
+            .iter()
+            .map(|&d| char::from(b'0' + d as u8))
+            .collect();
+        let scale = self.fraction.len() + other.fraction.len();
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        Decimal::new(self.negative != other.negative, integer, fraction)
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, other: Decimal) -> Decimal {
+        &self * &other
+    }
+}
+
+impl<'a> From<&'a Decimal> for PluralOperands {
+    fn from(value: &'a Decimal) -> Self {
+        plural_operands::from_digits(value.integer_digits(), value.fraction_digits(), 0)
+    }
+}
+
+impl From<Decimal> for PluralOperands {
+    fn from(value: Decimal) -> Self {
+        PluralOperands::from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {PluralCategory, PluralRules};
+
+    fn decimal(text: &str) -> Decimal {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_works() {
+        assert_eq!(decimal("007.50").to_string(), "7.50");
+        assert_eq!(decimal("-0.0").to_string(), "0.0");
+        assert_eq!(decimal("+3").to_string(), "3");
+        assert!(decimal("-0.10").is_negative());
+        assert!(decimal("0.000").is_zero());
+        assert!("".parse::<Decimal>().is_err());
+        assert!("-".parse::<Decimal>().is_err());
+        assert!(".5".parse::<Decimal>().is_err());
+        assert!("5.".parse::<Decimal>().is_err());
+        assert!("1e3".parse::<Decimal>().is_err());
+        assert!("1.2.3".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(Decimal::from(i64::MIN).to_string(), "-9223372036854775808");
+        assert_eq!(Decimal::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Decimal::from_f64(0.1), Some(decimal("0.1")));
+        assert_eq!(Decimal::from_f64(-2.5e-7), Some(decimal("-0.00000025")));
+        assert_eq!(
+            Decimal::from_f64(1e21),
+            Some(decimal("1000000000000000000000"))
+        );
+        assert_eq!(Decimal::from_f64(f64::NAN), None);
+        assert_eq!(
+            PluralOperands::from(&decimal("-1.50")),
+            "1.50".parse().unwrap()
+        );
+
+        // Long fractions keep their digit counts.
+        let operands = PluralOperands::from(&Decimal::from_f64(1e-20).unwrap());
+        assert_eq!((operands.v, operands.w, operands.f), (20, 20, 0));
+        let rules = PluralRules::cardinal(&"en".parse().unwrap());
+        assert_eq!(
+            rules.select(decimal("1.00000000000000000000")),
+            PluralCategory::Other
+        );
+    }
+
+    #[test]
+    fn rounding_works() {
+        assert_eq!(decimal("2.5").round(0), decimal("2"));
+        assert_eq!(decimal("3.5").round(0), decimal("4"));
+        assert_eq!(decimal("2.51").round(0), decimal("3"));
+        assert_eq!(decimal("-2.675").round(2), decimal("-2.68"));
+        assert_eq!(decimal("9.995").round(2), decimal("10.00"));
+        assert_eq!(decimal("0.04").round(1), decimal("0.0"));
+        assert_eq!(decimal("1.50").round(3), decimal("1.50"));
+        assert_eq!(decimal("-0.4").round(0), decimal("0"));
+    }
+
+    #[test]
+    fn rounding_modes_work() {
+        use RoundingMode::*;
+
+        let round = |text: &str, mode| decimal(text).round_with(0, mode).to_string();
+        let modes = [Ceiling, Floor, Down, Up, HalfEven, HalfDown, HalfUp];
+        let expected = [
+            ("2.5", ["3", "2", "2", "3", "2", "2", "3"]),
+            ("-2.5", ["-2", "-3", "-2", "-3", "-2", "-2", "-3"]),
+            ("2.51", ["3", "2", "2", "3", "3", "3", "3"]),
+            ("-1.2", ["-1", "-2", "-1", "-2", "-1", "-1", "-1"]),
+            ("3.0", ["3", "3", "3", "3", "3", "3", "3"]),
+        ];
+        for &(text, ref results) in &expected {
+            for (&mode, &result) in modes.iter().zip(results.iter()) {
+                assert_eq!(round(text, mode), result, "{} {:?}", text, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn increments_work() {
+        let round = |text: &str, increment: &str| {
+            decimal(text)
+                .round_to_increment(&decimal(increment), RoundingMode::HalfEven)
+                .to_string()
+        };
+        assert_eq!(round("1.23", "0.05"), "1.25");
+        assert_eq!(round("1.225", "0.05"), "1.20");
+        assert_eq!(round("-1.275", "0.05"), "-1.30");
+        assert_eq!(round("7", "0.50"), "7.00");
+        assert_eq!(round("1234", "25"), "1225");
+        assert_eq!(round("1238", "25"), "1250");
+        assert_eq!(round("0.3333", "0.3"), "0.3");
+    }
+
+    #[test]
+    fn shifting_works() {
+        assert_eq!(decimal("1.5").shift(3), decimal("1500"));
+        assert_eq!(decimal("-12.5").shift(-3), decimal("-0.0125"));
+        assert_eq!(decimal("0.25").shift(2), decimal("25"));
+        assert_eq!(decimal("120").shift(0), decimal("120"));
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        assert_eq!(decimal("1.50") - decimal("1"), decimal("0.50"));
+        assert_eq!(decimal("1") - decimal("1.50"), decimal("-0.50"));
+        assert_eq!(decimal("-1.5") - decimal("1.25"), decimal("-2.75"));
+        assert_eq!(decimal("-1.5") + decimal("1.5"), decimal("0.0"));
+        assert_eq!(decimal("999.9") + decimal("0.1"), decimal("1000.0"));
+        assert_eq!(decimal("0.01") - decimal("-0.99"), decimal("1.00"));
+        assert_eq!(decimal("1.5") * decimal("-0.20"), decimal("-0.300"));
+        assert_eq!(decimal("0") * decimal("-2"), decimal("0"));
+        assert_eq!(decimal("99") * decimal("99"), decimal("9801"));
+        assert_eq!(
+            &Decimal::from(u128::MAX) + &Decimal::from(1),
+            decimal("340282366920938463463374607431768211456")
+        );
+    }
+}
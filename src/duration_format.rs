@@ -0,0 +1,361 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use language_tags::LanguageTag;
+use std::time::Duration;
+
+use cldr;
+use cldr::units::DIGITAL_PATTERNS;
+use {
+    Decimal, Grouping, IntegerWidth, ListFormatOptions, ListStyle, ListType, NumberFormatOptions,
+    NumberUnit, Precision, UnitWidth,
+};
+
+/// How a duration is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DurationStyle {
+    /// Full unit names, like `1 hour, 5 minutes, 7 seconds`.
+    Long,
+    /// Abbreviated unit names, like `1 hr, 5 min, 7 sec`.
+    #[default]
+    Short,
+    /// The shortest unit names, like `1h 5m 7s`.
+    Narrow,
+    /// Digits, like `1:05:07`.
+    Digital,
+}
+
+/// A unit of a duration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DurationUnit {
+    /// Weeks of 7 days.
+    Week,
+    /// Days of 24 hours.
+    Day,
+    /// Hours.
+    Hour,
+    /// Minutes.
+    Minute,
+    /// Seconds.
+    Second,
+    /// Milliseconds.
+    Millisecond,
+}
+
+impl DurationUnit {
+    /// All of the units, from the largest to the smallest.
+    const ALL: [DurationUnit; 6] = [
+        DurationUnit::Week,
+        DurationUnit::Day,
+        DurationUnit::Hour,
+        DurationUnit::Minute,
+        DurationUnit::Second,
+        DurationUnit::Millisecond,
+    ];
+
+    /// The name of the unit in the CLDR data.
+    fn key(self) -> &'static str {
+        match self {
+            DurationUnit::Week => "week",
+            DurationUnit::Day => "day",
+            DurationUnit::Hour => "hour",
+            DurationUnit::Minute => "minute",
+            DurationUnit::Second => "second",
+            DurationUnit::Millisecond => "millisecond",
+        }
+    }
+
+    /// The length of the unit in milliseconds.
+    fn milliseconds(self) -> u128 {
+        match self {
+            DurationUnit::Week => 604_800_000,
+            DurationUnit::Day => 86_400_000,
+            DurationUnit::Hour => 3_600_000,
+            DurationUnit::Minute => 60_000,
+            DurationUnit::Second => 1000,
+            DurationUnit::Millisecond => 1,
+        }
+    }
+}
+
+/// Options for formatting a duration, like `1 hr, 5 min` or `1:05:07`,
+/// using the [CLDR] unit names and list patterns for a locale.
+///
+/// The duration is split into the units from `largest_unit` down to
+/// `smallest_unit`, dropping anything smaller, and the units with an
+/// amount of zero are left out:
+///
+/// ```
+/// use message_format::{DurationFormatOptions, DurationStyle, DurationUnit};
+/// use std::time::Duration;
+///
+/// let en = "en".parse().unwrap();
+/// let value = Duration::from_secs(3907);
+///
+/// let options = DurationFormatOptions::new(DurationStyle::Short);
+/// assert_eq!(options.format(&en, &value), "1 hr, 5 min, 7 sec");
+///
+/// let options = DurationFormatOptions {
+///     smallest_unit: DurationUnit::Minute,
+///     ..DurationFormatOptions::new(DurationStyle::Long)
+/// };
+/// assert_eq!(options.format(&en, &value), "1 hour, 5 minutes");
+///
+/// let options = DurationFormatOptions::new(DurationStyle::Digital);
+/// assert_eq!(options.format(&en, &value), "1:05:07");
+/// ```
+///
+/// [CLDR]: http://cldr.unicode.org/
+#[derive(Clone, Debug, PartialEq)]
+pub struct DurationFormatOptions {
+    /// How the duration is written.
+    pub style: DurationStyle,
+    /// The largest unit shown, which holds any larger amounts, so that
+    /// 50 hours is `2 days, 2 hr` but `50 hr` with a largest unit of
+    /// hours.
+    pub largest_unit: DurationUnit,
+    /// The smallest unit shown.
+    pub smallest_unit: DurationUnit,
+}
+
+impl Default for DurationFormatOptions {
+    fn default() -> Self {
+        DurationFormatOptions::new(DurationStyle::default())
+    }
+}
+
+impl DurationFormatOptions {
+    /// Construct a `DurationFormatOptions` for a style, showing days
+    /// down to seconds.
+    pub fn new(style: DurationStyle) -> Self {
+        DurationFormatOptions {
+            style: style,
+            largest_unit: DurationUnit::Day,
+            smallest_unit: DurationUnit::Second,
+        }
+    }
+
+    /// Format `value` for the locale given by `language_tag`.
+    pub fn format(&self, language_tag: &LanguageTag, value: &Duration) -> String {
+        let (largest, smallest) =
+            if self.largest_unit.milliseconds() >= self.smallest_unit.milliseconds() {
+                (self.largest_unit, self.smallest_unit)
+            } else {
+                (self.smallest_unit, self.largest_unit)
+            };
+        let width = match self.style {
+            DurationStyle::Long => ListStyle::Long,
+            DurationStyle::Short => ListStyle::Short,
+            DurationStyle::Narrow => ListStyle::Narrow,
+            DurationStyle::Digital => return digital(language_tag, value, largest, smallest),
+        };
+
+        let mut remaining = value.as_millis();
+        let mut parts = vec![];
+        for &unit in DurationUnit::ALL.iter().filter(|unit| {
+            unit.milliseconds() <= largest.milliseconds()
+                && unit.milliseconds() >= smallest.milliseconds()
+        }) {
+            let amount = remaining / unit.milliseconds();
+            remaining %= unit.milliseconds();
+            if amount > 0 {
+                parts.push(amount_of(language_tag, unit, width, amount));
+            }
+        }
+        if parts.is_empty() {
+            parts.push(amount_of(language_tag, smallest, width, 0));
+        }
+        let list = ListFormatOptions {
+            list_type: ListType::Unit,
+            style: width,
+        };
+        list.format(language_tag, &parts)
+    }
+}
+
+/// Format an amount of a unit, like `5 min`.
+fn amount_of(
+    language_tag: &LanguageTag,
+    unit: DurationUnit,
+    width: ListStyle,
+    amount: u128,
+) -> String {
+    let options = NumberFormatOptions {
+        unit: NumberUnit::Measure(unit.key().to_string()),
+        unit_width: match width {
+            ListStyle::Long => UnitWidth::FullName,
+            ListStyle::Short => UnitWidth::Short,
+            ListStyle::Narrow => UnitWidth::Narrow,
+        },
+        ..NumberFormatOptions::default()
+    };
+    options.format(language_tag, &Decimal::from(amount))
+}
+
+/// Format a duration with digits, like `1:05:07`. Hours hold any
+/// larger amounts.
+fn digital(
+    language_tag: &LanguageTag,
+    value: &Duration,
+    largest: DurationUnit,
+    smallest: DurationUnit,
+) -> String {
+    let patterns = cldr::lookup(DIGITAL_PATTERNS, language_tag)
+        .expect("duration patterns for the root locale");
+    let hour = DurationUnit::Hour.milliseconds();
+    let minute = DurationUnit::Minute.milliseconds();
+    let pattern = if largest.milliseconds() < hour {
+        patterns[2]
+    } else if smallest.milliseconds() >= minute {
+        patterns[1]
+    } else {
+        patterns[0]
+    };
+
+    let milliseconds = value.as_millis();
+    let number = |value: Decimal, width: usize, fraction_digits: usize| {
+        let options = NumberFormatOptions {
+            precision: Precision::Fraction {
+                minimum: fraction_digits,
+                maximum: Some(fraction_digits),
+            },
+            grouping: Grouping::Off,
+            integer_width: IntegerWidth {
+                minimum: width,
+                maximum: None,
+            },
+            ..NumberFormatOptions::default()
+        };
+        options.format(language_tag, &value)
+    };
+    let mut output = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        let field = match c {
+            'h' => milliseconds / hour,
+            'm' if pattern.contains('h') => milliseconds / minute % 60,
+            'm' => milliseconds / minute,
+            's' => milliseconds % minute,
+            _ => {
+                output.extend((0..count).map(|_| c));
+                continue;
+            }
+        };
+        // Seconds show milliseconds as a fraction if they're wanted.
+        let (field, fraction_digits) = match c {
+            's' if smallest == DurationUnit::Millisecond => (Decimal::from(field).shift(-3), 3),
+            's' => (Decimal::from(field / 1000), 0),
+            _ => (Decimal::from(field), 0),
+        };
+        output.push_str(&number(field, count, fraction_digits));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(options: &DurationFormatOptions, locale: &str, seconds: f64) -> String {
+        options.format(
+            &locale.parse().unwrap(),
+            &Duration::from_millis((seconds * 1000.0) as u64),
+        )
+    }
+
+    #[test]
+    fn styles_work() {
+        let long = DurationFormatOptions::new(DurationStyle::Long);
+        let short = DurationFormatOptions::new(DurationStyle::Short);
+        let narrow = DurationFormatOptions::new(DurationStyle::Narrow);
+
+        assert_eq!(format(&long, "en", 3907.0), "1 hour, 5 minutes, 7 seconds");
+        assert_eq!(format(&short, "en", 3907.0), "1 hr, 5 min, 7 sec");
+        assert_eq!(format(&narrow, "en", 3907.0), "1h 5m 7s");
+        assert_eq!(format(&long, "en", 3600.0), "1 hour");
+        assert_eq!(format(&long, "en", 0.0), "0 seconds");
+        assert_eq!(format(&short, "en", 180_000.0), "2 days, 2 hr");
+
+        assert_eq!(format(&long, "de", 7320.0), "2 Stunden und 2 Minuten");
+        assert_eq!(format(&short, "de", 3907.0), "1 Std., 5 Min., 7 Sek.");
+        assert_eq!(format(&short, "fr", 3907.0), "1 h, 5 min, 7 s");
+        assert_eq!(format(&narrow, "ja", 3907.0), "1時間5分7秒");
+
+        // Russian has a form for `one`, `few`, `many` and `other`.
+        assert_eq!(format(&long, "ru", 3600.0 * 21.0), "21 час");
+        assert_eq!(format(&long, "ru", 3600.0 * 22.0), "22 часа");
+        assert_eq!(format(&long, "ru", 60.0 * 5.0), "5 минут");
+
+        // Locales without data use the root locale's.
+        assert_eq!(format(&short, "und", 3907.0), "1 h, 5 min, 7 s");
+    }
+
+    #[test]
+    fn units_work() {
+        let options = |largest, smallest| DurationFormatOptions {
+            largest_unit: largest,
+            smallest_unit: smallest,
+            ..DurationFormatOptions::new(DurationStyle::Short)
+        };
+        let hours = options(DurationUnit::Hour, DurationUnit::Minute);
+        assert_eq!(format(&hours, "en", 180_059.0), "50 hr");
+        assert_eq!(format(&hours, "en", 59.0), "0 min");
+
+        let weeks = options(DurationUnit::Week, DurationUnit::Day);
+        assert_eq!(format(&weeks, "en", 86_400.0 * 9.0), "1 wk, 2 days");
+
+        let seconds = options(DurationUnit::Second, DurationUnit::Millisecond);
+        assert_eq!(format(&seconds, "en", 1.25), "1 sec, 250 ms");
+        assert_eq!(format(&seconds, "en", 3907.0), "3,907 sec");
+
+        // The units may be given in either order.
+        let reversed = options(DurationUnit::Minute, DurationUnit::Hour);
+        assert_eq!(format(&reversed, "en", 3907.0), "1 hr, 5 min");
+    }
+
+    #[test]
+    fn digital_works() {
+        let options = |largest, smallest| DurationFormatOptions {
+            largest_unit: largest,
+            smallest_unit: smallest,
+            ..DurationFormatOptions::new(DurationStyle::Digital)
+        };
+        let digital = DurationFormatOptions::new(DurationStyle::Digital);
+        assert_eq!(format(&digital, "en", 3907.0), "1:05:07");
+        assert_eq!(format(&digital, "en", 0.0), "0:00:00");
+        assert_eq!(format(&digital, "en", 180_000.0), "50:00:00");
+        assert_eq!(
+            format(
+                &options(DurationUnit::Hour, DurationUnit::Minute),
+                "en",
+                3907.0
+            ),
+            "1:05"
+        );
+        assert_eq!(
+            format(
+                &options(DurationUnit::Minute, DurationUnit::Second),
+                "en",
+                3907.0
+            ),
+            "65:07"
+        );
+        assert_eq!(
+            format(
+                &options(DurationUnit::Minute, DurationUnit::Millisecond),
+                "de",
+                67.5
+            ),
+            "1:07,500"
+        );
+        assert_eq!(format(&digital, "ar-EG", 3907.0), "١:٠٥:٠٧");
+    }
+}
@@ -4,42 +4,218 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-#![allow(missing_docs)]
+use super::Annotation;
 
-#[derive(Debug)]
-pub enum Expression {
-    IdentifierExpression { name: String },
+/// An entry in a Fluent resource.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Entry {
+    /// A message, like `hello = Hello, { $name }!`.
+    Message {
+        /// The message's identifier.
+        id: Identifier,
+        /// The message's value, if it has one. A message without a
+        /// value has at least one attribute.
+        value: Option<Pattern>,
+        /// The message's attributes, like `.title = Hello`.
+        attributes: Vec<Attribute>,
+        /// The `#` comment immediately before the message.
+        comment: Option<Comment>,
+    },
+    /// A term, like `-brand-name = Firefox`, which may be used by
+    /// messages but not by the application.
+    Term {
+        /// The term's identifier, without the leading `-`.
+        id: Identifier,
+        /// The term's value.
+        value: Pattern,
+        /// The term's attributes, like `.gender = masculine`.
+        attributes: Vec<Attribute>,
+        /// The `#` comment immediately before the term.
+        comment: Option<Comment>,
+    },
+    /// A `#` comment that isn't attached to a message or term.
+    Comment(Comment),
+    /// A `##` comment, which describes the group of entries after it.
+    GroupComment(Comment),
+    /// A `###` comment, which describes the whole resource.
+    ResourceComment(Comment),
+    /// Text that couldn't be parsed as an entry, from the start of the
+    /// entry up to the start of the next one.
+    Junk {
+        /// The text.
+        content: String,
+        /// The errors found in the text.
+        annotations: Vec<Annotation>,
+    },
 }
 
-#[derive(Debug)]
-pub enum PatternElement {
-    TextElement { value: String },
-    Placeable { expressions: Vec<Expression> },
+/// A range of a resource, as byte offsets.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Span {
+    /// The offset of the start of the range.
+    pub start: usize,
+    /// The offset of the end of the range, which isn't included in it.
+    pub end: usize,
+}
+
+/// The identifier of a message, term, attribute, variable or function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Identifier {
+    /// The name, like `brand-name`.
+    pub name: String,
+}
+
+/// A comment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comment {
+    /// The text of the comment, without the leading `#` characters.
+    /// Lines of a comment spanning several lines are separated by
+    /// `\n`.
+    pub content: String,
 }
 
-#[derive(Debug)]
-pub enum Value {
-    Pattern {
-        source: String,
-        elements: Vec<PatternElement>,
+/// An attribute of a message or term, like `.title = Hello`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    /// The attribute's identifier.
+    pub id: Identifier,
+    /// The attribute's value.
+    pub value: Pattern,
+}
+
+/// Text with placeables, which is the value of a message, term,
+/// attribute or variant.
+///
+/// The indentation common to the lines of a pattern that spans
+/// several lines is removed, as is trailing whitespace.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern {
+    /// The text and placeables of the pattern.
+    pub elements: Vec<PatternElement>,
+}
+
+/// A part of a `Pattern`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternElement {
+    /// Plain text.
+    TextElement {
+        /// The text.
+        value: String,
+    },
+    /// An expression in braces, like `{ $name }`.
+    Placeable {
+        /// The expression.
+        expression: Expression,
     },
 }
 
-#[derive(Debug)]
-pub enum Entry {
-    Entity {
+/// An expression within a placeable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+    /// A string literal, like `"{"`.
+    StringLiteral {
+        /// The string, with escape sequences like `\u00A0` replaced by
+        /// the characters that they stand for.
+        value: String,
+    },
+    /// A number literal, like `-3.14`.
+    NumberLiteral {
+        /// The number as it was written.
+        value: String,
+    },
+    /// A reference to a message or one of its attributes, like
+    /// `menu-save` or `login-input.placeholder`.
+    MessageReference {
+        /// The message's identifier.
         id: Identifier,
-        comment: Option<Comment>,
-        value: Value,
+        /// The attribute's identifier, if an attribute is referenced.
+        attribute: Option<Identifier>,
+    },
+    /// A reference to a term or one of its attributes, like
+    /// `-brand-name` or `-brand-name(case: "genitive")`.
+    TermReference {
+        /// The term's identifier, without the leading `-`.
+        id: Identifier,
+        /// The attribute's identifier, if an attribute is referenced.
+        attribute: Option<Identifier>,
+        /// The arguments passed to the term, if there are any.
+        arguments: Option<CallArguments>,
+    },
+    /// A reference to a variable supplied by the application, like
+    /// `$count`.
+    VariableReference {
+        /// The variable's identifier, without the leading `$`.
+        id: Identifier,
+    },
+    /// A call to a function, like `NUMBER($ratio, style: "percent")`.
+    FunctionReference {
+        /// The function's identifier.
+        id: Identifier,
+        /// The arguments passed to the function.
+        arguments: CallArguments,
+    },
+    /// A choice between variants, depending on a selector:
+    ///
+    /// ```text
+    /// { $count ->
+    ///     [one] One file
+    ///    *[other] { $count } files
+    /// }
+    /// ```
+    SelectExpression {
+        /// The expression whose value chooses the variant.
+        selector: Box<Expression>,
+        /// The variants.
+        variants: Vec<Variant>,
+    },
+    /// A placeable within a placeable, like `{ { $name } }`.
+    Placeable {
+        /// The expression within the inner placeable.
+        expression: Box<Expression>,
     },
 }
 
-#[derive(Debug)]
-pub struct Identifier {
-    pub name: String,
+/// The arguments passed to a function or term.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallArguments {
+    /// The positional arguments, which come before the named ones.
+    pub positional: Vec<Expression>,
+    /// The named arguments, like `style: "percent"`.
+    pub named: Vec<NamedArgument>,
 }
 
-#[derive(Debug)]
-pub struct Comment {
-    pub content: String,
+/// A named argument, like `style: "percent"`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedArgument {
+    /// The argument's name.
+    pub name: Identifier,
+    /// The argument's value, which is a string or number literal.
+    pub value: Expression,
+}
+
+/// A variant of a `SelectExpression`, like `[one] One file`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Variant {
+    /// The key that is matched against the selector.
+    pub key: VariantKey,
+    /// The variant's value.
+    pub value: Pattern,
+    /// Whether this is the default variant, marked with `*`, which is
+    /// used when no other variant matches.
+    pub default: bool,
+}
+
+/// The key of a `Variant`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariantKey {
+    /// A name, like `one` or `masculine`.
+    Identifier {
+        /// The name.
+        name: String,
+    },
+    /// A number, like `0`.
+    NumberLiteral {
+        /// The number as it was written.
+        value: String,
+    },
 }
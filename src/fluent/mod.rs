@@ -4,10 +4,47 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-#![allow(missing_docs)]
+//! Fluent Support
+//!
+//! This module parses resources in the [Fluent] 1.0 syntax into a
+//! syntax tree of [`Entry`] values:
+//!
+//! ```text
+//! ### Strings for the downloads panel.
+//!
+//! -brand-name = Firefox
+//!
+//! # $count is the number of files being downloaded.
+//! downloading = { $count ->
+//!         [one] { -brand-name } is downloading a file
+//!        *[other] { -brand-name } is downloading { $count } files
+//!     }
+//!     .title = Downloads
+//! ```
+//!
+//! All of the syntax is supported: messages, terms, attributes,
+//! comments, placeables with references to variables, messages and
+//! terms, string and number literals, select expressions, function
+//! calls with named arguments, and patterns spanning several lines.
+//! An entry with a syntax error becomes an [`Entry::Junk`] without
+//! affecting the entries around it.
+//!
+//! ```
+//! use message_format::fluent::{self, Entry};
+//!
+//! let entries = fluent::parse("hello = Hello, { $name }!\n");
+//! match entries[0] {
+//!     Entry::Message { ref id, .. } => assert_eq!(id.name, "hello"),
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! [Fluent]: https://projectfluent.org/
+//! [`Entry`]: enum.Entry.html
+//! [`Entry::Junk`]: enum.Entry.html#variant.Junk
 
 mod ast;
 mod parse;
 
 pub use self::ast::*;
-pub use self::parse::parse;
+pub use self::parse::{parse, Annotation, ParseErrorKind};
@@ -4,229 +4,1180 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-#![allow(missing_docs)]
-
+use std::cmp;
 use std::error::Error;
 use std::fmt;
-use std::str;
+
 use super::ast::*;
 
-pub fn parse(source: &str) -> Result<Vec<Entry>, ParseError> {
+/// Parse a Fluent resource into its entries.
+///
+/// An entry that can't be parsed doesn't stop the rest of the
+/// resource from being parsed. Instead, the text from its start up to
+/// the start of the next entry becomes an [`Entry::Junk`], which
+/// records what went wrong.
+///
+/// ```
+/// use message_format::fluent::{self, Entry};
+///
+/// let entries = fluent::parse("a = { $name\nb = Hello\n");
+/// match entries[0] {
+///     Entry::Junk {
+///         ref content,
+///         ref annotations,
+///     } => {
+///         assert_eq!(content, "a = { $name\n");
+///         assert_eq!(annotations[0].code(), "E0003");
+///         assert_eq!(annotations[0].to_string(), "2:1: expected `}`\nb = Hello\n^");
+///     }
+///     _ => unreachable!(),
+/// }
+/// match entries[1] {
+///     Entry::Message { ref id, .. } => assert_eq!(id.name, "b"),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// [`Entry::Junk`]: enum.Entry.html#variant.Junk
+pub fn parse(source: &str) -> Vec<Entry> {
     let mut p = Parser::new(source);
     p.parse()
 }
 
-#[derive(Debug)]
-pub struct ParseError {
-    pub error_message: String,
+/// An error found while parsing an entry of a resource, which is
+/// recorded in the `Junk` entry made from it.
+///
+/// The `Display` implementation renders the offending line of the
+/// resource with the error location marked:
+///
+/// ```text
+/// 1:12: unknown escape sequence `\n`
+/// hello = { "\n" }
+///            ^^
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Annotation {
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+    /// Where the error was found in the resource. For most errors,
+    /// this is empty, marking the position at which it was detected.
+    pub span: Span,
+    /// The line on which the error was found, starting from `1`.
+    pub line: usize,
+    /// The column at which the error was found, counted in characters
+    /// and starting from `1`.
+    pub column: usize,
+    /// The line of the resource containing the error.
+    source_line: String,
+}
+
+/// The kinds of errors that can be found by `parse`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseErrorKind {
+    /// A line at the start of an entry doesn't start a message, term
+    /// or comment.
+    ExpectedEntry,
+    /// Some character, like `=`, was expected but not found. A `\n`
+    /// stands for the end of a line.
+    ExpectedToken(char),
+    /// A character from a range, like `0-9`, was expected but not
+    /// found.
+    ExpectedCharRange(String),
+    /// A message has neither a value nor attributes.
+    MissingMessageValue(String),
+    /// A term has no value.
+    MissingTermValue(String),
+    /// An attribute or variant has no value.
+    MissingValue,
+    /// A function was called by a name that isn't upper-case.
+    InvalidFunctionName(String),
+    /// The name of a named argument isn't a plain identifier.
+    InvalidArgumentName,
+    /// A positional argument was passed after a named argument.
+    PositionalArgumentAfterNamed,
+    /// The same named argument was passed more than once.
+    DuplicateNamedArgument(String),
+    /// A named argument's value isn't a string or number literal.
+    ExpectedLiteral,
+    /// A select expression has no variants.
+    MissingVariants,
+    /// None of the variants of a select expression is marked as the
+    /// default with a `*`.
+    MissingDefaultVariant,
+    /// More than one variant of a select expression is marked as the
+    /// default with a `*`.
+    DuplicateDefaultVariant,
+    /// A message reference was used as a selector.
+    MessageReferenceSelector,
+    /// An attribute of a message was used as a selector.
+    MessageAttributeSelector,
+    /// A term reference without an attribute was used as a selector.
+    TermReferenceSelector,
+    /// A placeable was used as a selector.
+    PlaceableSelector,
+    /// An attribute of a term was used outside of a selector.
+    TermAttributePlaceable,
+    /// A placeable doesn't start with an expression.
+    ExpectedInlineExpression,
+    /// A `}` was found in text, outside of a placeable.
+    UnbalancedClosingBrace,
+    /// A string literal wasn't closed before the end of the line.
+    UnterminatedString,
+    /// A string literal has an escape sequence other than `\\`, `\"`,
+    /// `\uXXXX` and `\UXXXXXX`.
+    UnknownEscape(String),
+    /// A `\u` or `\U` escape sequence doesn't have enough hex digits.
+    InvalidUnicodeEscape(String),
+    /// Placeables or call arguments were nested more than 100 deep.
+    TooDeeplyNested,
 }
 
-impl ParseError {
-    pub fn new(error_message: &str) -> Self {
-        ParseError {
-            error_message: String::from(error_message),
+impl Annotation {
+    fn new(source: &str, span: Span, kind: ParseErrorKind) -> Self {
+        let offset = span.start;
+        let (line, column) = line_column(source, offset);
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        Annotation {
+            kind: kind,
+            span: span,
+            line: line,
+            column: column,
+            source_line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
         }
     }
+
+    /// The error's code in the Fluent specification, like `E0003`.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
 }
 
-impl Error for ParseError {
+impl ParseErrorKind {
+    /// The error's code in the Fluent specification, like `E0003`.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            ParseErrorKind::ExpectedEntry => "E0002",
+            ParseErrorKind::ExpectedToken(_) => "E0003",
+            ParseErrorKind::ExpectedCharRange(_) => "E0004",
+            ParseErrorKind::MissingMessageValue(_) => "E0005",
+            ParseErrorKind::MissingTermValue(_) => "E0006",
+            ParseErrorKind::InvalidFunctionName(_) => "E0008",
+            ParseErrorKind::InvalidArgumentName => "E0009",
+            ParseErrorKind::MissingDefaultVariant => "E0010",
+            ParseErrorKind::MissingVariants => "E0011",
+            ParseErrorKind::MissingValue => "E0012",
+            ParseErrorKind::ExpectedLiteral => "E0014",
+            ParseErrorKind::DuplicateDefaultVariant => "E0015",
+            ParseErrorKind::MessageReferenceSelector => "E0016",
+            ParseErrorKind::TermReferenceSelector => "E0017",
+            ParseErrorKind::MessageAttributeSelector => "E0018",
+            ParseErrorKind::TermAttributePlaceable => "E0019",
+            ParseErrorKind::UnterminatedString => "E0020",
+            ParseErrorKind::PositionalArgumentAfterNamed => "E0021",
+            ParseErrorKind::DuplicateNamedArgument(_) => "E0022",
+            ParseErrorKind::UnknownEscape(_) => "E0025",
+            ParseErrorKind::InvalidUnicodeEscape(_) => "E0026",
+            ParseErrorKind::UnbalancedClosingBrace => "E0027",
+            ParseErrorKind::ExpectedInlineExpression => "E0028",
+            ParseErrorKind::PlaceableSelector => "E0029",
+            // The specification doesn't limit nesting, so this is
+            // the next unused code.
+            ParseErrorKind::TooDeeplyNested => "E0030",
+        }
+    }
+}
+
+impl Error for Annotation {
     fn description(&self) -> &str {
-        &self.error_message
+        match self.kind {
+            ParseErrorKind::ExpectedEntry
+            | ParseErrorKind::ExpectedToken(_)
+            | ParseErrorKind::ExpectedCharRange(_)
+            | ParseErrorKind::ExpectedLiteral
+            | ParseErrorKind::ExpectedInlineExpression => "Unexpected input.",
+            ParseErrorKind::MissingMessageValue(_)
+            | ParseErrorKind::MissingTermValue(_)
+            | ParseErrorKind::MissingValue => "Missing value.",
+            ParseErrorKind::InvalidFunctionName(_) => "Invalid function name.",
+            ParseErrorKind::InvalidArgumentName
+            | ParseErrorKind::PositionalArgumentAfterNamed
+            | ParseErrorKind::DuplicateNamedArgument(_) => "Invalid arguments.",
+            ParseErrorKind::MissingVariants
+            | ParseErrorKind::MissingDefaultVariant
+            | ParseErrorKind::DuplicateDefaultVariant => "Invalid variants.",
+            ParseErrorKind::MessageReferenceSelector
+            | ParseErrorKind::MessageAttributeSelector
+            | ParseErrorKind::TermReferenceSelector
+            | ParseErrorKind::PlaceableSelector => "Invalid selector.",
+            ParseErrorKind::TermAttributePlaceable => "Invalid placeable.",
+            ParseErrorKind::UnbalancedClosingBrace => "Unbalanced closing brace.",
+            ParseErrorKind::UnterminatedString => "Unterminated string.",
+            ParseErrorKind::UnknownEscape(_) | ParseErrorKind::InvalidUnicodeEscape(_) => {
+                "Invalid escape sequence."
+            }
+            ParseErrorKind::TooDeeplyNested => "Nested too deeply.",
+        }
     }
 }
 
-impl fmt::Display for ParseError {
+impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        self.description().fmt(f)
+        try!(writeln!(f, "{}:{}: {}", self.line, self.column, self.kind));
+        try!(writeln!(f, "{}", self.source_line));
+        // Keep tabs so that the caret lines up with the source line.
+        let indent: String = self
+            .source_line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = self
+            .source_line
+            .chars()
+            .skip(self.column - 1)
+            .scan(self.span.start, |offset, c| {
+                *offset += c.len_utf8();
+                Some(*offset)
+            })
+            .take_while(|&offset| offset <= self.span.end)
+            .count();
+        write!(f, "{}{}", indent, "^".repeat(cmp::max(width, 1)))
     }
 }
 
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            ParseErrorKind::ExpectedEntry => {
+                write!(
+                    f,
+                    "expected a message, term or comment at the start of the line"
+                )
+            }
+            ParseErrorKind::ExpectedToken('\n') => write!(f, "expected the end of the line"),
+            ParseErrorKind::ExpectedToken(c) => write!(f, "expected `{}`", c),
+            ParseErrorKind::ExpectedCharRange(ref range) => {
+                write!(f, "expected a character from `{}`", range)
+            }
+            ParseErrorKind::MissingMessageValue(ref id) => {
+                write!(f, "expected message `{}` to have a value or attributes", id)
+            }
+            ParseErrorKind::MissingTermValue(ref id) => {
+                write!(f, "expected term `-{}` to have a value", id)
+            }
+            ParseErrorKind::MissingValue => write!(f, "expected a value"),
+            ParseErrorKind::InvalidFunctionName(ref id) => write!(
+                f,
+                "`{}` can't be called; functions have upper-case names like `NUMBER`",
+                id
+            ),
+            ParseErrorKind::InvalidArgumentName => {
+                write!(f, "the name of an argument has to be a plain identifier")
+            }
+            ParseErrorKind::PositionalArgumentAfterNamed => {
+                write!(f, "positional arguments must come before named arguments")
+            }
+            ParseErrorKind::DuplicateNamedArgument(ref name) => {
+                write!(f, "named argument `{}` is passed more than once", name)
+            }
+            ParseErrorKind::ExpectedLiteral => write!(f, "expected a string or number literal"),
+            ParseErrorKind::MissingVariants => {
+                write!(f, "expected at least one variant after `->`")
+            }
+            ParseErrorKind::MissingDefaultVariant => write!(
+                f,
+                "expected one of the variants to be marked as the default with `*`"
+            ),
+            ParseErrorKind::DuplicateDefaultVariant => {
+                write!(f, "only one variant can be marked as the default with `*`")
+            }
+            ParseErrorKind::MessageReferenceSelector => {
+                write!(f, "messages can't be used as selectors")
+            }
+            ParseErrorKind::MessageAttributeSelector => {
+                write!(f, "attributes of messages can't be used as selectors")
+            }
+            ParseErrorKind::TermReferenceSelector => {
+                write!(
+                    f,
+                    "terms can't be used as selectors, but their attributes can"
+                )
+            }
+            ParseErrorKind::PlaceableSelector => write!(f, "placeables can't be used as selectors"),
+            ParseErrorKind::TermAttributePlaceable => {
+                write!(f, "attributes of terms can only be used as selectors")
+            }
+            ParseErrorKind::ExpectedInlineExpression => write!(
+                f,
+                "expected a variable, message or term reference, a string or number \
+                 literal, a function call or a placeable"
+            ),
+            ParseErrorKind::UnbalancedClosingBrace => write!(
+                f,
+                "unbalanced `}}` in text; use `{{ \"}}\" }}` for a literal `}}`"
+            ),
+            ParseErrorKind::UnterminatedString => {
+                write!(
+                    f,
+                    "expected `\"` to close the string before the end of the line"
+                )
+            }
+            ParseErrorKind::UnknownEscape(ref escape) => {
+                write!(f, "unknown escape sequence `{}`", escape)
+            }
+            ParseErrorKind::InvalidUnicodeEscape(ref escape) => write!(
+                f,
+                "invalid Unicode escape sequence `{}`; expected `\\uXXXX` or `\\UXXXXXX`",
+                escape
+            ),
+            ParseErrorKind::TooDeeplyNested => write!(
+                f,
+                "placeables and call arguments can't be nested more than {} deep",
+                MAX_DEPTH
+            ),
+        }
+    }
+}
+
+/// Find the 1-based line and column of a byte offset.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// Characters that may start an identifier.
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+/// Characters that may be used within an identifier.
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// A piece of a pattern, before the common indentation is removed.
+enum Piece {
+    /// Text within a line.
+    Text(String),
+    /// The line breaks and indentation before a line.
+    Indent(String),
+    /// A placeable.
+    Placeable(Expression),
+}
+
+/// Remove the common indentation from the pieces of a pattern, joining
+/// adjacent text and dropping trailing whitespace.
+fn dedent(pieces: Vec<Piece>, common_indent: usize) -> Vec<PatternElement> {
+    let mut elements = vec![];
+    for piece in pieces {
+        let text = match piece {
+            Piece::Text(text) => text,
+            Piece::Indent(indent) => indent[..indent.len() - common_indent].to_string(),
+            Piece::Placeable(expression) => {
+                elements.push(PatternElement::Placeable {
+                    expression: expression,
+                });
+                continue;
+            }
+        };
+        if text.is_empty() {
+            continue;
+        }
+        if let Some(&mut PatternElement::TextElement { ref mut value }) = elements.last_mut() {
+            value.push_str(&text);
+            continue;
+        }
+        elements.push(PatternElement::TextElement { value: text });
+    }
+    let trailing_blank = match elements.last_mut() {
+        Some(&mut PatternElement::TextElement { ref mut value }) => {
+            let len = value.trim_end_matches(&[' ', '\n', '\r'][..]).len();
+            value.truncate(len);
+            value.is_empty()
+        }
+        _ => false,
+    };
+    if trailing_blank {
+        elements.pop();
+    }
+    elements
+}
+
+/// How deeply placeables and call arguments may be nested before an
+/// entry becomes junk, so that a resource can't overflow the stack.
+const MAX_DEPTH: usize = 100;
+
 struct Parser<'a> {
-    source: str::Chars<'a>,
-    ch: Option<char>,
-    pos: u16,
+    source: &'a str,
+    pos: usize,
+    /// How many placeables and call arguments enclose the current
+    /// position.
+    depth: usize,
 }
 
 impl<'a> Parser<'a> {
     fn new(source: &'a str) -> Parser<'a> {
         Parser {
-            source: source.chars(),
-            ch: None,
+            source: source,
             pos: 0,
+            depth: 0,
         }
     }
 
-    fn bump(&mut self) {
-        self.ch = self.source.next();
+    fn error(&self, kind: ParseErrorKind) -> Annotation {
+        self.error_at(self.pos, self.pos, kind)
+    }
 
-        self.pos += 1;
+    fn error_at(&self, start: usize, end: usize, kind: ParseErrorKind) -> Annotation {
+        let span = Span {
+            start: start,
+            end: end,
+        };
+        Annotation::new(self.source, span, kind)
     }
 
-    fn ch_is(&self, ch: char) -> bool {
-        self.ch == Some(ch)
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
     }
 
-    fn get_ws(&mut self) {
-        while self.ch_is(' ') || self.ch_is('\n') || self.ch_is('\t') || self.ch_is('\r') {
-            self.bump();
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.rest().chars().nth(1)
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
         }
     }
 
-    fn get_line_ws(&mut self) {
-        while self.ch_is(' ') || self.ch_is('\t') {
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
             self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Annotation> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::ExpectedToken(c)))
+        }
+    }
+
+    /// The length of the line break at the current position, or `0`
+    /// if there isn't one.
+    fn line_end_len(&self) -> usize {
+        if self.rest().starts_with('\n') {
+            1
+        } else if self.rest().starts_with("\r\n") {
+            2
+        } else {
+            0
         }
     }
 
-    fn parse(&mut self) -> Result<Vec<Entry>, ParseError> {
-        let mut entries: Vec<Entry> = Vec::new();
+    fn at_line_end(&self) -> bool {
+        self.line_end_len() > 0
+    }
 
-        self.get_ws();
+    fn skip_line_end(&mut self) -> bool {
+        let len = self.line_end_len();
+        self.pos += len;
+        len > 0
+    }
 
-        self.bump();
+    fn expect_line_end(&mut self) -> Result<(), Annotation> {
+        if self.peek().is_none() || self.skip_line_end() {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::ExpectedToken('\n')))
+        }
+    }
 
+    /// Skip spaces, returning how many there were.
+    fn skip_blank_inline(&mut self) -> usize {
+        let start = self.pos;
+        while self.eat(' ') {}
+        self.pos - start
+    }
+
+    /// Skip spaces and line breaks.
+    fn skip_blank(&mut self) {
+        self.skip_blank_inline();
+        while self.skip_line_end() {
+            self.skip_blank_inline();
+        }
+    }
+
+    /// Skip lines that are empty or only have spaces, returning how
+    /// many line breaks there were.
+    fn skip_blank_block(&mut self) -> usize {
+        let mut count = 0;
         loop {
-            if self.ch == None {
-                break;
+            let line_start = self.pos;
+            self.skip_blank_inline();
+            if self.skip_line_end() {
+                count += 1;
+                continue;
             }
-
-            let comment = None;
-            match self.get_entry(comment) {
-                Ok(entry) => entries.push(entry),
-                Err(err) => return Err(err),
+            if self.peek().is_some() {
+                self.pos = line_start;
             }
-            self.get_ws();
+            return count;
         }
-        Ok(entries)
     }
 
-    fn get_entry(&mut self, comment: Option<Comment>) -> Result<Entry, ParseError> {
-        self.get_entity(comment)
+    /// Whether the line at the current position continues a pattern,
+    /// which it does if it is indented and doesn't start with a
+    /// character that has a special meaning there, or if it starts
+    /// with a placeable.
+    fn is_value_continuation(&self) -> bool {
+        let rest = self.rest();
+        let indented = rest.trim_start_matches(' ');
+        match indented.chars().next() {
+            Some('{') => true,
+            _ if indented.len() == rest.len() => false,
+            Some('}') | Some('.') | Some('[') | Some('*') | None => false,
+            Some(_) => true,
+        }
     }
 
-    fn get_entity(&mut self, comment: Option<Comment>) -> Result<Entry, ParseError> {
-        let id = try!(self.get_identifier());
-        self.get_line_ws();
+    fn parse(&mut self) -> Vec<Entry> {
+        let mut entries = vec![];
+        let mut last_comment = None;
+
+        self.skip_blank_block();
+        while self.peek().is_some() {
+            let mut entry = self.get_entry_or_junk();
+            let blank_lines = self.skip_blank_block();
 
-        if !self.ch_is('=') {
-            return Err(ParseError::new("Expected '='"));
+            // A comment belongs to a message or term that follows it
+            // without a blank line in between.
+            if let Entry::Comment(ref comment) = entry {
+                if blank_lines == 0 && self.peek().is_some() {
+                    last_comment = Some(comment.clone());
+                    continue;
+                }
+            }
+            if let Some(comment) = last_comment.take() {
+                match entry {
+                    Entry::Message {
+                        comment: ref mut entry_comment,
+                        ..
+                    }
+                    | Entry::Term {
+                        comment: ref mut entry_comment,
+                        ..
+                    } => *entry_comment = Some(comment),
+                    _ => entries.push(Entry::Comment(comment)),
+                }
+            }
+            entries.push(entry);
         }
-        self.bump();
+        entries
+    }
 
-        self.get_line_ws();
+    /// Parse an entry, or if it has an error, skip to the start of the
+    /// next entry and return the text skipped as `Junk`.
+    fn get_entry_or_junk(&mut self) -> Entry {
+        let start = self.pos;
+        // An error may have left the depth of the last entry raised.
+        self.depth = 0;
+        let mut annotation = match self.get_entry() {
+            Ok(entry) => return entry,
+            Err(annotation) => annotation,
+        };
+        self.skip_to_next_entry_start(start);
+        // The error should be within the junk.
+        if annotation.span.start > self.pos {
+            annotation = Annotation::new(
+                self.source,
+                Span {
+                    start: self.pos,
+                    end: self.pos,
+                },
+                annotation.kind,
+            );
+        }
+        Entry::Junk {
+            content: self.source[start..self.pos].to_string(),
+            annotations: vec![annotation],
+        }
+    }
 
-        match self.get_pattern() {
-            Ok(value) => Ok(Entry::Entity {
-                id: id,
-                comment: comment,
-                value: value,
-            }),
-            Err(err) => Err(err),
+    /// Skip to the next line that starts like an entry, beginning
+    /// with the line that the parser stopped on.
+    fn skip_to_next_entry_start(&mut self, junk_start: usize) {
+        self.pos = match self.source[..self.pos].rfind('\n') {
+            Some(line_end) if line_end > junk_start => line_end,
+            _ => junk_start,
+        };
+        while let Some(line_end) = self.rest().find('\n') {
+            self.pos += line_end + 1;
+            match self.peek() {
+                Some(c) if is_identifier_start(c) || c == '-' || c == '#' => return,
+                _ => {}
+            }
         }
+        self.pos = self.source.len();
     }
 
-    fn get_identifier(&mut self) -> Result<Identifier, ParseError> {
-        let mut name = String::new();
+    fn get_entry(&mut self) -> Result<Entry, Annotation> {
+        let entry = match self.peek() {
+            Some('#') => try!(self.get_comment()),
+            Some('-') => try!(self.get_term()),
+            Some(c) if is_identifier_start(c) => try!(self.get_message()),
+            _ => return Err(self.error(ParseErrorKind::ExpectedEntry)),
+        };
+        try!(self.expect_line_end());
+        Ok(entry)
+    }
 
-        let ch = match self.ch {
-            Some(c) => c,
-            None => return Err(ParseError::new("Unexpected end of input.")),
+    fn get_comment(&mut self) -> Result<Entry, Annotation> {
+        let level = self
+            .rest()
+            .chars()
+            .take(3)
+            .take_while(|&c| c == '#')
+            .count();
+        let mut lines = vec![];
+        loop {
+            self.pos += level;
+            if self.peek().is_some() && !self.at_line_end() {
+                try!(self.expect(' '));
+                lines.push(self.get_rest_of_line());
+            } else {
+                lines.push("");
+            }
+            if !self.is_next_line_comment(level) {
+                break;
+            }
+            self.skip_line_end();
+        }
+        let comment = Comment {
+            content: lines.join("\n"),
         };
+        Ok(match level {
+            1 => Entry::Comment(comment),
+            2 => Entry::GroupComment(comment),
+            _ => Entry::ResourceComment(comment),
+        })
+    }
 
-        match ch {
-            'a'...'z' | 'A'...'Z' | '_' => name.push(ch),
-            _ => return Ok(Identifier { name: name }),
+    /// Whether the next line continues a comment of the given level.
+    fn is_next_line_comment(&self, level: usize) -> bool {
+        let len = self.line_end_len();
+        if len == 0 {
+            return false;
         }
-        self.bump();
+        let next = &self.rest()[len..];
+        let hashes = next.chars().take_while(|&c| c == '#').count();
+        let after = &next[hashes..];
+        hashes == level
+            && (after.starts_with(' ') || after.starts_with('\n') || after.starts_with("\r\n"))
+    }
 
-        while let Some(ch) = self.ch {
-            match ch {
-                'a'...'z' | 'A'...'Z' | '0'...'9' | '_' | '-' => name.push(ch),
-                _ => break,
-            }
+    fn get_rest_of_line(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some() && !self.at_line_end() {
             self.bump();
         }
+        &self.source[start..self.pos]
+    }
+
+    fn get_message(&mut self) -> Result<Entry, Annotation> {
+        let id = try!(self.get_identifier());
+        self.skip_blank_inline();
+        try!(self.expect('='));
+        let value = try!(self.maybe_get_pattern());
+        let attributes = try!(self.get_attributes());
+        if value.is_none() && attributes.is_empty() {
+            return Err(self.error(ParseErrorKind::MissingMessageValue(id.name)));
+        }
+        Ok(Entry::Message {
+            id: id,
+            value: value,
+            attributes: attributes,
+            comment: None,
+        })
+    }
+
+    fn get_term(&mut self) -> Result<Entry, Annotation> {
+        try!(self.expect('-'));
+        let id = try!(self.get_identifier());
+        self.skip_blank_inline();
+        try!(self.expect('='));
+        let value = match try!(self.maybe_get_pattern()) {
+            Some(value) => value,
+            None => return Err(self.error(ParseErrorKind::MissingTermValue(id.name))),
+        };
+        let attributes = try!(self.get_attributes());
+        Ok(Entry::Term {
+            id: id,
+            value: value,
+            attributes: attributes,
+            comment: None,
+        })
+    }
 
-        Ok(Identifier { name: name })
+    fn get_attributes(&mut self) -> Result<Vec<Attribute>, Annotation> {
+        let mut attributes = vec![];
+        loop {
+            let start = self.pos;
+            self.skip_blank();
+            if !self.eat('.') {
+                self.pos = start;
+                return Ok(attributes);
+            }
+            let id = try!(self.get_identifier());
+            self.skip_blank_inline();
+            try!(self.expect('='));
+            match try!(self.maybe_get_pattern()) {
+                Some(value) => attributes.push(Attribute {
+                    id: id,
+                    value: value,
+                }),
+                None => return Err(self.error(ParseErrorKind::MissingValue)),
+            }
+        }
     }
 
-    fn get_pattern(&mut self) -> Result<Value, ParseError> {
-        let mut buffer = String::new();
-        let mut source = String::new();
-        let mut content = vec![];
-        let mut quote_delimited: bool = false;
-        let mut first_line = true;
+    fn get_identifier(&mut self) -> Result<Identifier, Annotation> {
+        let start = self.pos;
+        match self.peek() {
+            Some(c) if is_identifier_start(c) => self.bump(),
+            _ => return Err(self.error(ParseErrorKind::ExpectedCharRange("a-zA-Z".to_string()))),
+        }
+        while self.peek().is_some_and(is_identifier_char) {
+            self.bump();
+        }
+        Ok(Identifier {
+            name: self.source[start..self.pos].to_string(),
+        })
+    }
 
-        if self.ch_is('"') {
-            quote_delimited = true;
+    /// Parse a pattern if one starts on this line or is on the lines
+    /// after it.
+    fn maybe_get_pattern(&mut self) -> Result<Option<Pattern>, Annotation> {
+        self.skip_blank_inline();
+        if self.peek().is_some() && !self.at_line_end() {
+            return self.get_pattern(false).map(Some);
         }
+        let start = self.pos;
+        self.skip_blank_block();
+        if self.is_value_continuation() {
+            return self.get_pattern(true).map(Some);
+        }
+        self.pos = start;
+        Ok(None)
+    }
 
+    fn get_pattern(&mut self, is_block: bool) -> Result<Pattern, Annotation> {
+        let mut pieces = vec![];
+        // The text on the line of the identifier isn't indented, so it
+        // doesn't count toward the common indentation.
+        let mut common_indent = if is_block {
+            let indent = self.skip_blank_inline();
+            pieces.push(Piece::Indent(" ".repeat(indent)));
+            indent
+        } else {
+            usize::MAX
+        };
         loop {
-            match self.ch {
-                Some(c) if c == '\n' => {
-                    if quote_delimited {
-                        return Err(ParseError::new("Unclosed string"));
-                    }
-                    self.bump();
-                    self.get_line_ws();
-
-                    if !self.ch_is('|') {
+            match self.peek() {
+                None => break,
+                Some('{') => pieces.push(Piece::Placeable(try!(self.get_placeable()))),
+                Some('}') => return Err(self.error(ParseErrorKind::UnbalancedClosingBrace)),
+                Some(_) if self.at_line_end() => {
+                    let start = self.pos;
+                    let line_ends = self.skip_blank_block();
+                    if !self.is_value_continuation() {
+                        self.pos = start;
                         break;
                     }
-                    if first_line && !buffer.is_empty() {
-                        return Err(ParseError::new(
-                            "Multiline string should have the ID line \
-                             empty",
+                    let indent = self.skip_blank_inline();
+                    common_indent = cmp::min(common_indent, indent);
+                    pieces.push(Piece::Indent("\n".repeat(line_ends) + &" ".repeat(indent)));
+                }
+                Some(_) => pieces.push(Piece::Text(self.get_text())),
+            }
+        }
+        Ok(Pattern {
+            elements: dedent(pieces, common_indent),
+        })
+    }
+
+    fn get_text(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == '{' || c == '}' || self.at_line_end() {
+                break;
+            }
+            self.bump();
+        }
+        self.source[start..self.pos].to_string()
+    }
+
+    /// Count a placeable or call arguments opened by the last
+    /// character, failing if they are nested too deeply.
+    fn nest(&mut self) -> Result<(), Annotation> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error_at(self.pos - 1, self.pos, ParseErrorKind::TooDeeplyNested));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn get_placeable(&mut self) -> Result<Expression, Annotation> {
+        try!(self.expect('{'));
+        try!(self.nest());
+        self.skip_blank();
+        let expression = try!(self.get_expression());
+        self.depth -= 1;
+        try!(self.expect('}'));
+        Ok(expression)
+    }
+
+    fn get_expression(&mut self) -> Result<Expression, Annotation> {
+        let start = self.pos;
+        let selector = try!(self.get_inline_expression());
+        let end = self.pos;
+        self.skip_blank();
+
+        if !self.rest().starts_with("->") {
+            if let Expression::TermReference {
+                attribute: Some(_), ..
+            } = selector
+            {
+                return Err(self.error_at(start, end, ParseErrorKind::TermAttributePlaceable));
+            }
+            return Ok(selector);
+        }
+
+        let invalid = match selector {
+            Expression::MessageReference {
+                attribute: None, ..
+            } => Some(ParseErrorKind::MessageReferenceSelector),
+            Expression::MessageReference { .. } => Some(ParseErrorKind::MessageAttributeSelector),
+            Expression::TermReference {
+                attribute: None, ..
+            } => Some(ParseErrorKind::TermReferenceSelector),
+            Expression::Placeable { .. } => Some(ParseErrorKind::PlaceableSelector),
+            _ => None,
+        };
+        if let Some(kind) = invalid {
+            return Err(self.error_at(start, end, kind));
+        }
+        self.pos += 2;
+        self.skip_blank_inline();
+        try!(self.expect_line_end());
+        let variants = try!(self.get_variants());
+        Ok(Expression::SelectExpression {
+            selector: Box::new(selector),
+            variants: variants,
+        })
+    }
+
+    fn get_inline_expression(&mut self) -> Result<Expression, Annotation> {
+        match self.peek() {
+            Some('{') => {
+                let expression = try!(self.get_placeable());
+                Ok(Expression::Placeable {
+                    expression: Box::new(expression),
+                })
+            }
+            Some('"') => self.get_string(),
+            Some(c) if self.is_number_start(c) => self.get_number(),
+            Some('$') => {
+                self.bump();
+                let id = try!(self.get_identifier());
+                Ok(Expression::VariableReference { id: id })
+            }
+            Some('-') => {
+                self.bump();
+                let id = try!(self.get_identifier());
+                let attribute = try!(self.get_attribute_accessor());
+                let arguments = if self.is_call_start() {
+                    Some(try!(self.get_call_arguments()))
+                } else {
+                    None
+                };
+                Ok(Expression::TermReference {
+                    id: id,
+                    attribute: attribute,
+                    arguments: arguments,
+                })
+            }
+            Some(c) if is_identifier_start(c) => {
+                let start = self.pos;
+                let id = try!(self.get_identifier());
+                let end = self.pos;
+                if self.is_call_start() {
+                    let mut chars = id.name.chars();
+                    let upper_case = chars.next().is_some_and(|c| c.is_ascii_uppercase())
+                        && chars.all(|c| {
+                            c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_' || c == '-'
+                        });
+                    if !upper_case {
+                        return Err(self.error_at(
+                            start,
+                            end,
+                            ParseErrorKind::InvalidFunctionName(id.name),
                         ));
                     }
-                    first_line = false;
-                    self.bump();
-                    if self.ch_is(' ') {
-                        self.bump();
-                    }
-                    if !buffer.is_empty() {
-                        buffer.push('\n');
+                    let arguments = try!(self.get_call_arguments());
+                    return Ok(Expression::FunctionReference {
+                        id: id,
+                        arguments: arguments,
+                    });
+                }
+                let attribute = try!(self.get_attribute_accessor());
+                Ok(Expression::MessageReference {
+                    id: id,
+                    attribute: attribute,
+                })
+            }
+            _ => Err(self.error(ParseErrorKind::ExpectedInlineExpression)),
+        }
+    }
+
+    fn is_number_start(&self, c: char) -> bool {
+        c.is_ascii_digit() || (c == '-' && self.peek_second().is_some_and(|c| c.is_ascii_digit()))
+    }
+
+    /// Whether call arguments follow, skipping any blank before them
+    /// if they do.
+    fn is_call_start(&mut self) -> bool {
+        let start = self.pos;
+        self.skip_blank();
+        if self.peek() == Some('(') {
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    fn get_attribute_accessor(&mut self) -> Result<Option<Identifier>, Annotation> {
+        if self.eat('.') {
+            self.get_identifier().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn get_call_arguments(&mut self) -> Result<CallArguments, Annotation> {
+        let mut positional = vec![];
+        let mut named: Vec<NamedArgument> = vec![];
+        try!(self.expect('('));
+        try!(self.nest());
+        self.skip_blank();
+        while self.peek() != Some(')') {
+            let start = self.pos;
+            let argument = try!(self.get_inline_expression());
+            let end = self.pos;
+            self.skip_blank();
+            if self.eat(':') {
+                let name = match argument {
+                    Expression::MessageReference {
+                        id,
+                        attribute: None,
+                    } => id,
+                    _ => {
+                        return Err(self.error_at(start, end, ParseErrorKind::InvalidArgumentName))
                     }
-                    continue;
+                };
+                if named.iter().any(|argument| argument.name == name) {
+                    return Err(self.error_at(
+                        start,
+                        end,
+                        ParseErrorKind::DuplicateNamedArgument(name.name),
+                    ));
                 }
-                Some(c) if c == '"' => {
+                self.skip_blank();
+                let value = try!(self.get_literal());
+                named.push(NamedArgument {
+                    name: name,
+                    value: value,
+                });
+            } else if !named.is_empty() {
+                return Err(self.error_at(
+                    start,
+                    end,
+                    ParseErrorKind::PositionalArgumentAfterNamed,
+                ));
+            } else {
+                positional.push(argument);
+            }
+            self.skip_blank();
+            if !self.eat(',') {
+                break;
+            }
+            self.skip_blank();
+        }
+        self.depth -= 1;
+        try!(self.expect(')'));
+        Ok(CallArguments {
+            positional: positional,
+            named: named,
+        })
+    }
+
+    fn get_literal(&mut self) -> Result<Expression, Annotation> {
+        match self.peek() {
+            Some('"') => self.get_string(),
+            Some(c) if self.is_number_start(c) => self.get_number(),
+            _ => Err(self.error(ParseErrorKind::ExpectedLiteral)),
+        }
+    }
+
+    fn get_number(&mut self) -> Result<Expression, Annotation> {
+        Ok(Expression::NumberLiteral {
+            value: try!(self.get_number_text()),
+        })
+    }
+
+    fn get_number_text(&mut self) -> Result<String, Annotation> {
+        let start = self.pos;
+        self.eat('-');
+        try!(self.get_digits());
+        if self.eat('.') {
+            try!(self.get_digits());
+        }
+        Ok(self.source[start..self.pos].to_string())
+    }
+
+    fn get_digits(&mut self) -> Result<(), Annotation> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        if self.pos == start {
+            Err(self.error(ParseErrorKind::ExpectedCharRange("0-9".to_string())))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn get_string(&mut self) -> Result<Expression, Annotation> {
+        try!(self.expect('"'));
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
                     self.bump();
-                    quote_delimited = false;
-                    break;
+                    return Ok(Expression::StringLiteral { value: value });
                 }
-                Some(c) => source.push(c),
-                None => break,
+                Some('\\') => value.push(try!(self.get_escape())),
+                Some(c) if !self.at_line_end() => {
+                    value.push(c);
+                    self.bump();
+                }
+                _ => return Err(self.error(ParseErrorKind::UnterminatedString)),
             }
-            match self.ch {
-                Some(c) => buffer.push(c),
-                None => continue,
-            };
-            self.bump();
         }
+    }
 
-        if quote_delimited {
-            return Err(ParseError::new("Unclosed string"));
+    fn get_escape(&mut self) -> Result<char, Annotation> {
+        let start = self.pos;
+        try!(self.expect('\\'));
+        let digits = match self.peek() {
+            Some(c @ '\\') | Some(c @ '"') => {
+                self.bump();
+                return Ok(c);
+            }
+            Some('u') => 4,
+            Some('U') => 6,
+            Some(_) if !self.at_line_end() => {
+                self.bump();
+                let escape = self.source[start..self.pos].to_string();
+                let kind = ParseErrorKind::UnknownEscape(escape);
+                return Err(self.error_at(start, self.pos, kind));
+            }
+            _ => return Err(self.error(ParseErrorKind::UnterminatedString)),
+        };
+        self.bump();
+        let hex_start = self.pos;
+        for _ in 0..digits {
+            match self.peek() {
+                Some(c) if c.is_ascii_hexdigit() => self.bump(),
+                _ => {
+                    let escape = self.source[start..self.pos].to_string();
+                    let kind = ParseErrorKind::InvalidUnicodeEscape(escape);
+                    return Err(self.error_at(start, self.pos, kind));
+                }
+            }
         }
+        let code = u32::from_str_radix(&self.source[hex_start..self.pos], 16).unwrap_or(0xfffd);
+        // Surrogates and values past the last code point can't be
+        // represented, so they are replaced.
+        Ok(::std::char::from_u32(code).unwrap_or('\u{fffd}'))
+    }
 
-        if !buffer.is_empty() {
-            // source.append(buffer);
-            content.push(PatternElement::TextElement {
-                value: source.clone(),
+    fn get_variants(&mut self) -> Result<Vec<Variant>, Annotation> {
+        let mut variants = vec![];
+        let mut has_default = false;
+        self.skip_blank();
+        while self.rest().starts_with('[') || self.rest().starts_with("*[") {
+            let start = self.pos;
+            let default = self.eat('*');
+            if default && has_default {
+                return Err(self.error_at(
+                    start,
+                    self.pos,
+                    ParseErrorKind::DuplicateDefaultVariant,
+                ));
+            }
+            try!(self.expect('['));
+            self.skip_blank();
+            let key = try!(self.get_variant_key());
+            self.skip_blank();
+            try!(self.expect(']'));
+            let value = match try!(self.maybe_get_pattern()) {
+                Some(value) => value,
+                None => return Err(self.error(ParseErrorKind::MissingValue)),
+            };
+            has_default |= default;
+            variants.push(Variant {
+                key: key,
+                value: value,
+                default: default,
             });
+            try!(self.expect_line_end());
+            self.skip_blank();
         }
-
-        if content.is_empty() {
-            // return Value::Pattern(source: source, elements: content);
+        if variants.is_empty() {
+            return Err(self.error(ParseErrorKind::MissingVariants));
         }
+        if !has_default {
+            return Err(self.error(ParseErrorKind::MissingDefaultVariant));
+        }
+        Ok(variants)
+    }
 
-        content.push(PatternElement::TextElement {
-            value: source.clone(),
-        });
-
-        Ok(Value::Pattern {
-            source: source,
-            elements: content,
-        })
+    fn get_variant_key(&mut self) -> Result<VariantKey, Annotation> {
+        match self.peek() {
+            Some(c) if c.is_ascii_digit() || c == '-' => Ok(VariantKey::NumberLiteral {
+                value: try!(self.get_number_text()),
+            }),
+            _ => Ok(VariantKey::Identifier {
+                name: try!(self.get_identifier()).name,
+            }),
+        }
     }
 }
 
@@ -234,20 +1185,76 @@ impl<'a> Parser<'a> {
 mod tests {
     use super::*;
 
+    /// The annotations of all of the junk in `source`.
+    fn annotations(source: &str) -> Vec<Annotation> {
+        parse(source)
+            .into_iter()
+            .flat_map(|entry| match entry {
+                Entry::Junk { annotations, .. } => annotations,
+                _ => vec![],
+            })
+            .collect()
+    }
+
     fn expected_parse(name: &str, source: &str) {
-        match parse(source) {
-            Err(e) => panic!("Parse failed: {}: {}", name, e),
-            _ => {}
+        if let Some(e) = annotations(source).first() {
+            panic!("Parse failed: {}: {}", name, e);
         }
     }
 
     fn expected_failure(name: &str, source: &str) {
+        if annotations(source).is_empty() {
+            panic!("Parse unexpectedly worked: {}", name);
+        }
+    }
+
+    fn id(name: &str) -> Identifier {
+        Identifier {
+            name: name.to_string(),
+        }
+    }
+
+    fn text(value: &str) -> PatternElement {
+        PatternElement::TextElement {
+            value: value.to_string(),
+        }
+    }
+
+    fn placeable(expression: Expression) -> PatternElement {
+        PatternElement::Placeable {
+            expression: expression,
+        }
+    }
+
+    fn variable(name: &str) -> Expression {
+        Expression::VariableReference { id: id(name) }
+    }
+
+    fn string(value: &str) -> Expression {
+        Expression::StringLiteral {
+            value: value.to_string(),
+        }
+    }
+
+    /// The elements of the value of the only message in `source`.
+    fn value(source: &str) -> Vec<PatternElement> {
         match parse(source) {
-            Ok(_) => panic!("Parse unexpectedly worked: {}", name),
-            _ => {}
+            ref entries if entries.len() == 1 => match entries[0] {
+                Entry::Message {
+                    value: Some(ref value),
+                    ..
+                } => value.elements.clone(),
+                ref entry => panic!("Not a message with a value: {:?}", entry),
+            },
+            entries => panic!("Not a single entry: {:?}", entries),
         }
     }
 
+    fn error(source: &str) -> (ParseErrorKind, usize, usize) {
+        let e = annotations(source).remove(0);
+        (e.kind, e.line, e.column)
+    }
+
     #[test]
     fn it_works() {
         expected_parse("simple", "a = b");
@@ -256,11 +1263,468 @@ mod tests {
         expected_parse(
             "multiline",
             "multi =\n\
-             | abc\n\
+             \x20   abc\n\
              ",
         );
+        expected_parse("comment", "#\n# comment\n#");
+        expected_parse("comment", "# comment");
+        expected_parse("comment", "#  comment");
         expected_failure("comment", "#comment");
-        expected_failure("comment", "# comment");
-        expected_failure("comment", "#  comment");
+        expected_failure("old multiline", "multi =\n| abc\n");
+        expected_failure("indented entry", " a = b");
+    }
+
+    #[test]
+    fn entries_work() {
+        let source = "\
+### Resource comment
+
+## Group comment
+
+# Standalone comment
+
+# Attached
+# comment
+hello = Hello
+    .title = Hi
+
+-brand = Firefox
+    .gender = masculine
+login =
+    .placeholder = Email
+";
+        let entries = parse(source);
+        assert_eq!(
+            entries,
+            vec![
+                Entry::ResourceComment(Comment {
+                    content: "Resource comment".to_string(),
+                }),
+                Entry::GroupComment(Comment {
+                    content: "Group comment".to_string(),
+                }),
+                Entry::Comment(Comment {
+                    content: "Standalone comment".to_string(),
+                }),
+                Entry::Message {
+                    id: id("hello"),
+                    value: Some(Pattern {
+                        elements: vec![text("Hello")],
+                    }),
+                    attributes: vec![Attribute {
+                        id: id("title"),
+                        value: Pattern {
+                            elements: vec![text("Hi")],
+                        },
+                    }],
+                    comment: Some(Comment {
+                        content: "Attached\ncomment".to_string(),
+                    }),
+                },
+                Entry::Term {
+                    id: id("brand"),
+                    value: Pattern {
+                        elements: vec![text("Firefox")],
+                    },
+                    attributes: vec![Attribute {
+                        id: id("gender"),
+                        value: Pattern {
+                            elements: vec![text("masculine")],
+                        },
+                    }],
+                    comment: None,
+                },
+                Entry::Message {
+                    id: id("login"),
+                    value: None,
+                    attributes: vec![Attribute {
+                        id: id("placeholder"),
+                        value: Pattern {
+                            elements: vec![text("Email")],
+                        },
+                    }],
+                    comment: None,
+                },
+            ]
+        );
+
+        // Line breaks may be `\r\n`.
+        let entries = parse("# A\r\n# B\r\na = b\r\n");
+        assert_eq!(
+            entries,
+            vec![Entry::Message {
+                id: id("a"),
+                value: Some(Pattern {
+                    elements: vec![text("b")],
+                }),
+                attributes: vec![],
+                comment: Some(Comment {
+                    content: "A\nB".to_string(),
+                }),
+            }]
+        );
+    }
+
+    #[test]
+    fn multiline_patterns_work() {
+        assert_eq!(value("a = b\n    c\n"), vec![text("b\nc")]);
+        assert_eq!(
+            value("a =\n    b\n      c\n\n\n    d\n\n"),
+            vec![text("b\n  c\n\n\nd")]
+        );
+        assert_eq!(value("a =\n      b\n    c\n"), vec![text("  b\nc")]);
+        assert_eq!(
+            value("a =\n    { $x }\n  b\n"),
+            vec![text("  "), placeable(variable("x")), text("\nb")]
+        );
+        // Lines starting with a placeable continue the pattern even if
+        // they aren't indented.
+        assert_eq!(
+            value("a = b\n{ $x }\n"),
+            vec![text("b\n"), placeable(variable("x"))]
+        );
+        // Trailing spaces are removed, but tabs are text.
+        assert_eq!(value("a = b   \n"), vec![text("b")]);
+        assert_eq!(value("a = \tb\t\n"), vec![text("\tb\t")]);
+    }
+
+    #[test]
+    fn expressions_work() {
+        assert_eq!(
+            value("a = { \"\\\"\\\\\\u00A0\\U01F602\" }{ -3.14 }"),
+            vec![
+                placeable(string("\"\\\u{a0}\u{1f602}")),
+                placeable(Expression::NumberLiteral {
+                    value: "-3.14".to_string(),
+                }),
+            ]
+        );
+        assert_eq!(
+            value("a = { b }{ b.c }{ -d }{ -d.e ->\n *[f] g\n}"),
+            vec![
+                placeable(Expression::MessageReference {
+                    id: id("b"),
+                    attribute: None,
+                }),
+                placeable(Expression::MessageReference {
+                    id: id("b"),
+                    attribute: Some(id("c")),
+                }),
+                placeable(Expression::TermReference {
+                    id: id("d"),
+                    attribute: None,
+                    arguments: None,
+                }),
+                placeable(Expression::SelectExpression {
+                    selector: Box::new(Expression::TermReference {
+                        id: id("d"),
+                        attribute: Some(id("e")),
+                        arguments: None,
+                    }),
+                    variants: vec![Variant {
+                        key: VariantKey::Identifier {
+                            name: "f".to_string(),
+                        },
+                        value: Pattern {
+                            elements: vec![text("g")],
+                        },
+                        default: true,
+                    }],
+                }),
+            ]
+        );
+        assert_eq!(
+            value("a = {{ $b }}"),
+            vec![placeable(Expression::Placeable {
+                expression: Box::new(variable("b")),
+            })]
+        );
+        assert_eq!(
+            value("a = { NUMBER($n, minimumFractionDigits: 2) } { -b(case: \"gen\") }"),
+            vec![
+                placeable(Expression::FunctionReference {
+                    id: id("NUMBER"),
+                    arguments: CallArguments {
+                        positional: vec![variable("n")],
+                        named: vec![NamedArgument {
+                            name: id("minimumFractionDigits"),
+                            value: Expression::NumberLiteral {
+                                value: "2".to_string(),
+                            },
+                        }],
+                    },
+                }),
+                text(" "),
+                placeable(Expression::TermReference {
+                    id: id("b"),
+                    attribute: None,
+                    arguments: Some(CallArguments {
+                        positional: vec![],
+                        named: vec![NamedArgument {
+                            name: id("case"),
+                            value: string("gen"),
+                        }],
+                    }),
+                }),
+            ]
+        );
+        assert_eq!(
+            value("a = { FN(\n  1,\n  \"x\"\n) }"),
+            vec![placeable(Expression::FunctionReference {
+                id: id("FN"),
+                arguments: CallArguments {
+                    positional: vec![
+                        Expression::NumberLiteral {
+                            value: "1".to_string(),
+                        },
+                        string("x"),
+                    ],
+                    named: vec![],
+                },
+            })]
+        );
+    }
+
+    #[test]
+    fn select_expressions_work() {
+        let source = "\
+emails = { $count ->
+        [0] No new emails
+        [one] { $count } new
+            email
+       *[other] { $count } new emails
+    }.
+";
+        let number = |value: &str| VariantKey::NumberLiteral {
+            value: value.to_string(),
+        };
+        assert_eq!(
+            value(source),
+            vec![
+                placeable(Expression::SelectExpression {
+                    selector: Box::new(variable("count")),
+                    variants: vec![
+                        Variant {
+                            key: number("0"),
+                            value: Pattern {
+                                elements: vec![text("No new emails")],
+                            },
+                            default: false,
+                        },
+                        Variant {
+                            key: VariantKey::Identifier {
+                                name: "one".to_string(),
+                            },
+                            value: Pattern {
+                                elements: vec![placeable(variable("count")), text(" new\nemail"),],
+                            },
+                            default: false,
+                        },
+                        Variant {
+                            key: VariantKey::Identifier {
+                                name: "other".to_string(),
+                            },
+                            value: Pattern {
+                                elements: vec![placeable(variable("count")), text(" new emails")],
+                            },
+                            default: true,
+                        },
+                    ],
+                }),
+                text("."),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_work() {
+        use super::ParseErrorKind::*;
+
+        assert_eq!(error("a = b\n!"), (ExpectedEntry, 2, 1));
+        assert_eq!(error("a b"), (ExpectedToken('='), 1, 3));
+        assert_eq!(error("####"), (ExpectedToken(' '), 1, 4));
+        assert_eq!(error("a =\n"), (MissingMessageValue("a".to_string()), 1, 4));
+        assert_eq!(error("-a = "), (MissingTermValue("a".to_string()), 1, 6));
+        assert_eq!(error("a =\n    .b =\n"), (MissingValue, 2, 9));
+        assert_eq!(error("a = { 1x }"), (ExpectedToken('}'), 1, 8));
+        assert_eq!(
+            error("a = { $ }"),
+            (ExpectedCharRange("a-zA-Z".to_string()), 1, 8)
+        );
+        assert_eq!(
+            error("a = { -1. }"),
+            (ExpectedCharRange("0-9".to_string()), 1, 10)
+        );
+        assert_eq!(error("a = { } }"), (ExpectedInlineExpression, 1, 7));
+        assert_eq!(error("a = b }"), (UnbalancedClosingBrace, 1, 7));
+        assert_eq!(
+            error("a = { fn() }"),
+            (InvalidFunctionName("fn".to_string()), 1, 7)
+        );
+        assert_eq!(error("a = { F($b: 1) }"), (InvalidArgumentName, 1, 9));
+        assert_eq!(
+            error("a = { F(b: 1, 2) }"),
+            (PositionalArgumentAfterNamed, 1, 15)
+        );
+        assert_eq!(
+            error("a = { F(b: 1, b: 2) }"),
+            (DuplicateNamedArgument("b".to_string()), 1, 15)
+        );
+        assert_eq!(error("a = { F(b: $c) }"), (ExpectedLiteral, 1, 12));
+        assert_eq!(error("a = { $b ->\n}"), (MissingVariants, 2, 1));
+        assert_eq!(
+            error("a = { $b ->\n [c] d\n}"),
+            (MissingDefaultVariant, 3, 1)
+        );
+        assert_eq!(
+            error("a = { $b ->\n *[c] d\n *[e] f\n}"),
+            (DuplicateDefaultVariant, 3, 2)
+        );
+        assert_eq!(error("a = { $b ->\n *[c]\n}"), (MissingValue, 2, 6));
+        assert_eq!(
+            error("a = { b ->\n *[c] d\n}"),
+            (MessageReferenceSelector, 1, 7)
+        );
+        assert_eq!(
+            error("a = { b.c ->\n *[c] d\n}"),
+            (MessageAttributeSelector, 1, 7)
+        );
+        assert_eq!(
+            error("a = { -b ->\n *[c] d\n}"),
+            (TermReferenceSelector, 1, 7)
+        );
+        assert_eq!(
+            error("a = { {$b} ->\n *[c] d\n}"),
+            (PlaceableSelector, 1, 7)
+        );
+        assert_eq!(error("a = { -b.c }"), (TermAttributePlaceable, 1, 7));
+        assert_eq!(error("a = { \"b }"), (UnterminatedString, 1, 11));
+        assert_eq!(error("a = { \"b\n\" }"), (UnterminatedString, 1, 9));
+        assert_eq!(
+            error("a = { \"\\n\" }"),
+            (UnknownEscape("\\n".to_string()), 1, 8)
+        );
+        assert_eq!(
+            error("a = { \"\\u12\" }"),
+            (InvalidUnicodeEscape("\\u12".to_string()), 1, 8)
+        );
+        let nested = |open: &str, close: &str, depth: usize| {
+            format!("a = {}$b{}", open.repeat(depth), close.repeat(depth))
+        };
+        assert_eq!(error(&nested("{", "}", 101)), (TooDeeplyNested, 1, 105));
+        assert_eq!(error(&nested("{ F(", ") }", 51)), (TooDeeplyNested, 1, 205));
+        assert_eq!(error(&nested("{", "}", 100_000)), (TooDeeplyNested, 1, 105));
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested =
+            |depth: usize| format!("a = {}$b{}\nc = C\n", "{".repeat(depth), "}".repeat(depth));
+        match parse(&nested(100))[0] {
+            Entry::Message { ref id, .. } => assert_eq!(id.name, "a"),
+            _ => unreachable!(),
+        }
+
+        let source = nested(100_000);
+        let entries = parse(&source);
+        assert_eq!(entries.len(), 2);
+        match entries[0] {
+            Entry::Junk {
+                ref content,
+                ref annotations,
+            } => {
+                assert_eq!(content.len(), source.len() - "c = C\n".len());
+                assert_eq!(annotations[0].code(), "E0030");
+            }
+            _ => unreachable!(),
+        }
+        match entries[1] {
+            Entry::Message { ref id, .. } => assert_eq!(id.name, "c"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn junk_works() {
+        let source = "\
+# Attached to junk
+a = { $b
+c = C
+    d = { -e.f }
+  g
+
+# Comment
+-h = H {
+i = I
+";
+        let junk = |content: &str, annotation: Annotation| Entry::Junk {
+            content: content.to_string(),
+            annotations: vec![annotation],
+        };
+        let message = |name: &str, value: &str| Entry::Message {
+            id: id(name),
+            value: Some(Pattern {
+                elements: vec![text(value)],
+            }),
+            attributes: vec![],
+            comment: None,
+        };
+        let annotation = |start: usize, end: usize, kind: ParseErrorKind| {
+            let span = Span {
+                start: start,
+                end: end,
+            };
+            Annotation::new(source, span, kind)
+        };
+        assert_eq!(
+            parse(source),
+            vec![
+                Entry::Comment(Comment {
+                    content: "Attached to junk".to_string(),
+                }),
+                junk(
+                    "a = { $b\n",
+                    annotation(28, 28, ParseErrorKind::ExpectedToken('}')),
+                ),
+                junk(
+                    "c = C\n    d = { -e.f }\n  g\n\n",
+                    annotation(44, 48, ParseErrorKind::TermAttributePlaceable),
+                ),
+                Entry::Comment(Comment {
+                    content: "Comment".to_string(),
+                }),
+                junk(
+                    "-h = H {\n",
+                    annotation(75, 75, ParseErrorKind::ExpectedToken('}')),
+                ),
+                message("i", "I"),
+            ]
+        );
+
+        let e = annotations("a = { \"\\n\" }").remove(0);
+        assert_eq!(e.code(), "E0025");
+        assert_eq!(e.span, Span { start: 7, end: 9 });
+        assert_eq!(
+            e.to_string(),
+            "1:8: unknown escape sequence `\\n`\na = { \"\\n\" }\n       ^^"
+        );
+
+        // An error on a line that starts like an entry is moved to the
+        // end of the junk before that line.
+        let source = "a = { $b ->\n *[c] d\n-x\n";
+        let entries = parse(source);
+        assert_eq!(entries.len(), 2);
+        let e = annotations(source).remove(0);
+        assert_eq!(e.kind, ParseErrorKind::ExpectedToken('}'));
+        assert_eq!(e.span, Span { start: 20, end: 20 });
+    }
+
+    #[test]
+    fn error_display_works() {
+        let e = annotations("a = b\nhello = { $name").remove(0);
+        assert_eq!(
+            e.to_string(),
+            "2:16: expected `}`\nhello = { $name\n               ^"
+        );
     }
 }
@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use {Args, Context, FormatError, MessagePart};
+
+/// An argument along with its text in the message source, like
+/// `{d, date, short}`.
+#[derive(Debug)]
+pub struct Argument {
+    /// The part that formats the argument.
+    part: Box<MessagePart>,
+    /// The text of the argument in the message source.
+    source: String,
+}
+
+impl Argument {
+    /// Construct an `Argument`.
+    pub fn new(part: Box<MessagePart>, source: &str) -> Self {
+        Argument {
+            part: part,
+            source: source.to_string(),
+        }
+    }
+}
+
+impl MessagePart for Argument {
+    fn apply_format<'f>(
+        &self,
+        ctx: &Context,
+        stream: &mut fmt::Write,
+        args: Option<&Args<'f>>,
+    ) -> Result<(), FormatError> {
+        self.part.apply_format(ctx, stream, args)
+    }
+
+    fn source(&self) -> Option<&str> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Argument;
+    use icu::ast::SimpleFormat;
+    use {Context, Message, MissingArgumentPolicy};
+
+    #[test]
+    fn it_works() {
+        let ctx = Context::default();
+
+        let fmt = Argument::new(Box::new(SimpleFormat::new("name")), "{ name }");
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, name => "John");
+        assert_eq!("John", output);
+
+        let ctx = ctx.with_missing_argument_policy(MissingArgumentPolicy::Source);
+        assert_eq!(ctx.format(&msg, None), "{ name }");
+    }
+}
@@ -0,0 +1,167 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use {Args, Context, DateTime, DateTimeFormatOptions, FormatError, MessagePart, Value};
+
+/// Format a date, a time or both using the conventions of the
+/// context's locale.
+///
+/// The value should be a `Value::DateTime`, but an integer is also
+/// accepted as a number of milliseconds since the Unix epoch, as in
+/// ICU.
+///
+/// A value with its own offset from UTC is shown at that offset.
+/// Otherwise, it is shown in the context's time zone, if there is one,
+/// and in UTC if not. A value whose local time is out of range is a
+/// `FormatError::ValueOutOfRange`.
+#[derive(Debug)]
+pub struct DateTimeFormat {
+    /// The name of the variable whose value should be formatted.
+    variable_name: String,
+    /// How the value should be formatted.
+    options: DateTimeFormatOptions,
+}
+
+impl DateTimeFormat {
+    /// Construct a `DateTimeFormat`.
+    pub fn new(variable_name: &str, options: DateTimeFormatOptions) -> Self {
+        DateTimeFormat {
+            variable_name: variable_name.to_string(),
+            options: options,
+        }
+    }
+}
+
+impl MessagePart for DateTimeFormat {
+    fn apply_format<'f>(
+        &self,
+        ctx: &Context,
+        stream: &mut fmt::Write,
+        args: Option<&Args<'f>>,
+    ) -> Result<(), FormatError> {
+        let arg = args.and_then(|args| args.get(&self.variable_name));
+        let value = match arg.map(|arg| arg.value()) {
+            Some(&Value::DateTime(value)) => value,
+            Some(&Value::Number(timestamp)) => DateTime::new(timestamp),
+            Some(value) => {
+                return Err(FormatError::TypeMismatch {
+                    name: self.variable_name.clone(),
+                    expected: "a date and time or a timestamp".to_string(),
+                    actual: value.type_name().to_string(),
+                })
+            }
+            None => {
+                return Err(FormatError::MissingArgument {
+                    name: self.variable_name.clone(),
+                })
+            }
+        };
+        let output = match ctx.time_zone {
+            Some(ref time_zone) if value.offset.is_none() => {
+                self.options
+                    .format_in_time_zone(&ctx.language_tag, &value, time_zone)
+            }
+            _ => self.options.format(&ctx.language_tag, &value),
+        };
+        let output = try!(output.ok_or_else(|| FormatError::ValueOutOfRange {
+            name: self.variable_name.clone(),
+        }));
+        try!(write!(stream, "{}", output));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateTimeFormat;
+    use {
+        arg, Context, DateTime, DateTimeFormatOptions, DateTimeStyle, FormatError, Message,
+        TimeZone,
+    };
+
+    #[test]
+    fn it_works() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let fmt = DateTimeFormat::new("d", DateTimeFormatOptions::default());
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, d => DateTime::new(1_709_647_629_000));
+        assert_eq!("Mar 5, 2024", output);
+
+        let output = format_message!(ctx, &msg, d => 1_709_647_629_000_i64);
+        assert_eq!("Mar 5, 2024", output);
+
+        let output = format_message!(ctx, &msg, d => "2024-03-05");
+        assert_eq!("", output);
+    }
+
+    #[test]
+    fn offsets_work() {
+        let ctx = Context::new("en-GB".parse().unwrap(), None);
+
+        let options = DateTimeFormatOptions::DateTime(DateTimeStyle::Medium, DateTimeStyle::Long);
+        let fmt = DateTimeFormat::new("d", options);
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let d = DateTime::new(1_709_647_629_000).with_offset(-8 * 3600);
+        let output = format_message!(ctx, &msg, d);
+        assert_eq!("5 Mar 2024, 06:07:09 GMT-8", output);
+    }
+
+    #[test]
+    fn time_zones_work() {
+        let zone = TimeZone::from_posix("Europe/London", "GMT0BST,M3.5.0/1,M10.5.0").unwrap();
+        let ctx = Context::new("en-GB".parse().unwrap(), None).with_time_zone(zone);
+
+        let options = DateTimeFormatOptions::DateTime(DateTimeStyle::Medium, DateTimeStyle::Long);
+        let fmt = DateTimeFormat::new("d", options);
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, d => DateTime::new(1_709_647_629_000));
+        assert_eq!("5 Mar 2024, 14:07:09 GMT", output);
+
+        let output = format_message!(ctx, &msg, d => DateTime::new(1_719_835_200_000));
+        assert_eq!("1 Jul 2024, 13:00:00 BST", output);
+
+        // The value's own offset takes precedence over the time zone.
+        let d = DateTime::new(1_719_835_200_000).with_offset(2 * 3600);
+        let output = format_message!(ctx, &msg, d);
+        assert_eq!("1 Jul 2024, 14:00:00 GMT+2", output);
+    }
+
+    #[test]
+    fn out_of_range_works() {
+        let ctx = Context::default();
+
+        let fmt = DateTimeFormat::new("d", DateTimeFormatOptions::default());
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let d = DateTime::new(i64::MAX).with_offset(3600);
+        assert_eq!(
+            ctx.try_format(&msg, Some(&arg("d", d))),
+            Err(FormatError::ValueOutOfRange {
+                name: "d".to_string(),
+            })
+        );
+
+        // Near the limits, but in range.
+        let output = format_message!(ctx, &msg, d => i64::MAX);
+        assert_eq!("Aug 17, 292278994", output);
+        let output = format_message!(ctx, &msg, d => i64::MIN);
+        assert_eq!("May 16, 292275056", output);
+
+        // A time zone can take the value out of range too.
+        let zone = TimeZone::from_posix("Asia/Tokyo", "JST-9").unwrap();
+        let ctx = ctx.with_time_zone(zone);
+        assert!(ctx
+            .try_format(&msg, Some(&arg("d", DateTime::new(i64::MAX))))
+            .is_err());
+    }
+}
@@ -0,0 +1,98 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::time::Duration;
+
+use {Args, Context, DurationFormatOptions, FormatError, MessagePart, Value};
+
+/// Format a length of time, like `1 hr, 5 min` or `1:05:07`, using the
+/// conventions of the context's locale.
+///
+/// The value should be a `Value::Duration`, or a number of seconds
+/// that isn't negative.
+#[derive(Debug)]
+pub struct DurationFormat {
+    /// The name of the variable whose value should be formatted.
+    variable_name: String,
+    /// How the value should be formatted.
+    options: DurationFormatOptions,
+}
+
+impl DurationFormat {
+    /// Construct a `DurationFormat`.
+    pub fn new(variable_name: &str, options: DurationFormatOptions) -> Self {
+        DurationFormat {
+            variable_name: variable_name.to_string(),
+            options: options,
+        }
+    }
+}
+
+/// The value as a duration, taking numbers as seconds.
+fn duration(value: &Value) -> Option<Duration> {
+    match *value {
+        Value::Duration(duration) => Some(duration),
+        ref value => value
+            .to_decimal()
+            .filter(|seconds| !seconds.is_negative())
+            .and_then(|seconds| seconds.shift(3).round(0).integer_digits().parse().ok())
+            .map(Duration::from_millis),
+    }
+}
+
+impl MessagePart for DurationFormat {
+    fn apply_format<'f>(
+        &self,
+        ctx: &Context,
+        stream: &mut fmt::Write,
+        args: Option<&Args<'f>>,
+    ) -> Result<(), FormatError> {
+        let arg = try!(args
+            .and_then(|args| args.get(&self.variable_name))
+            .ok_or_else(|| FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            }));
+        let value = try!(
+            duration(arg.value()).ok_or_else(|| FormatError::TypeMismatch {
+                name: self.variable_name.clone(),
+                expected: "a duration or a number of seconds that isn't negative".to_string(),
+                actual: arg.value().type_name().to_string(),
+            })
+        );
+        try!(write!(
+            stream,
+            "{}",
+            self.options.format(&ctx.language_tag, &value)
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DurationFormat;
+    use std::time::Duration;
+    use {Context, DurationFormatOptions, DurationStyle, Message};
+
+    #[test]
+    fn it_works() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let fmt = DurationFormat::new("elapsed", DurationFormatOptions::new(DurationStyle::Narrow));
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, elapsed => Duration::from_secs(125));
+        assert_eq!("2m 5s", output);
+
+        let output = format_message!(ctx, &msg, elapsed => 3907);
+        assert_eq!("1h 5m 7s", output);
+
+        let output = format_message!(ctx, &msg, elapsed => 2.5);
+        assert_eq!("2s", output);
+    }
+}
@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use {Args, Context, FormatError, ListFormatOptions, MessagePart, Value};
+
+/// Join the items of a list using the conventions of the context's
+/// locale.
+///
+/// The value should be a `Value::List`, whose items are formatted as
+/// with a simple argument. Any other value is treated as a list of
+/// one item.
+#[derive(Debug)]
+pub struct ListFormat {
+    /// The name of the variable whose value should be formatted.
+    variable_name: String,
+    /// How the list should be formatted.
+    options: ListFormatOptions,
+}
+
+impl ListFormat {
+    /// Construct a `ListFormat`.
+    pub fn new(variable_name: &str, options: ListFormatOptions) -> Self {
+        ListFormat {
+            variable_name: variable_name.to_string(),
+            options: options,
+        }
+    }
+}
+
+impl MessagePart for ListFormat {
+    fn apply_format<'f>(
+        &self,
+        ctx: &Context,
+        stream: &mut fmt::Write,
+        args: Option<&Args<'f>>,
+    ) -> Result<(), FormatError> {
+        let arg = try!(args
+            .and_then(|args| args.get(&self.variable_name))
+            .ok_or_else(|| FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            }));
+        let items: Vec<String> = match *arg.value() {
+            Value::List(ref values) => values.iter().map(Value::to_string).collect(),
+            ref value => vec![value.to_string()],
+        };
+        try!(write!(
+            stream,
+            "{}",
+            self.options.format(&ctx.language_tag, &items)
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListFormat;
+    use {Context, ListFormatOptions, ListType, Message};
+
+    #[test]
+    fn it_works() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let fmt = ListFormat::new("l", ListFormatOptions::new(ListType::Disjunction));
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, l => vec!["red", "green", "blue"]);
+        assert_eq!("red, green, or blue", output);
+
+        let output = format_message!(ctx, &msg, l => &[1, 2][..]);
+        assert_eq!("1 or 2", output);
+
+        let output = format_message!(ctx, &msg, l => "red");
+        assert_eq!("red", output);
+
+        let output = format_message!(ctx, &msg, l => Vec::<&str>::new());
+        assert_eq!("", output);
+    }
+}
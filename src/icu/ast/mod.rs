@@ -11,14 +11,28 @@
 //!
 //! [`MessagePart`]: ../../trait.MessagePart.html
 
+mod argument;
+mod date_time_format;
+mod duration_format;
+mod list_format;
+mod number_format;
 mod placeholder_format;
 mod plain_text;
 mod plural_format;
+mod relative_time_format;
+mod rule_based_number_format;
 mod select_format;
 mod simple_format;
 
+pub use self::argument::Argument;
+pub use self::date_time_format::DateTimeFormat;
+pub use self::duration_format::DurationFormat;
+pub use self::list_format::ListFormat;
+pub use self::number_format::NumberFormat;
 pub use self::placeholder_format::PlaceholderFormat;
 pub use self::plain_text::PlainText;
 pub use self::plural_format::PluralFormat;
+pub use self::relative_time_format::RelativeTimeFormat;
+pub use self::rule_based_number_format::RuleBasedNumberFormat;
 pub use self::select_format::SelectFormat;
 pub use self::simple_format::SimpleFormat;
@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use {Args, Context, FormatError, MessagePart, NumberFormatOptions, NumberUnit};
+
+/// Format a numeric value using the conventions of the context's
+/// locale.
+///
+/// A `Value::Currency` is formatted as an amount of its own currency,
+/// and a `Value::Measure` as an amount of its own unit, unless the
+/// options are for another kind of unit.
+#[derive(Debug)]
+pub struct NumberFormat {
+    /// The name of the variable whose value should be formatted.
+    variable_name: String,
+    /// How the number should be formatted.
+    options: NumberFormatOptions,
+}
+
+impl NumberFormat {
+    /// Construct a `NumberFormat`.
+    pub fn new(variable_name: &str, options: NumberFormatOptions) -> Self {
+        NumberFormat {
+            variable_name: variable_name.to_string(),
+            options: options,
+        }
+    }
+}
+
+impl MessagePart for NumberFormat {
+    fn apply_format<'f>(
+        &self,
+        ctx: &Context,
+        stream: &mut fmt::Write,
+        args: Option<&Args<'f>>,
+    ) -> Result<(), FormatError> {
+        let arg = try!(args
+            .and_then(|args| args.get(&self.variable_name))
+            .ok_or_else(|| FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            }));
+        let value = try!(arg
+            .value()
+            .to_decimal()
+            .ok_or_else(|| FormatError::TypeMismatch {
+                name: self.variable_name.clone(),
+                expected: "a number".to_string(),
+                actual: arg.value().type_name().to_string(),
+            }));
+        let code = arg.value().currency_code();
+        let measure = arg.value().measure_unit();
+        let unit = match (code, measure, &self.options.unit) {
+            (Some(code), _, &NumberUnit::None) | (Some(code), _, &NumberUnit::Currency(_)) => {
+                Some(NumberUnit::Currency(Some(code.to_string())))
+            }
+            (_, Some(unit), &NumberUnit::None) | (_, Some(unit), &NumberUnit::Measure(_)) => {
+                Some(NumberUnit::Measure(unit.to_string()))
+            }
+            _ => None,
+        };
+        let formatted = match unit {
+            Some(unit) => NumberFormatOptions {
+                unit: unit,
+                ..self.options.clone()
+            }
+            .format(&ctx.language_tag, &value),
+            None => self.options.format(&ctx.language_tag, &value),
+        };
+        try!(write!(stream, "{}", formatted));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumberFormat;
+    use {Context, Decimal, Message, NumberFormatOptions, NumberStyle, Value};
+
+    #[test]
+    fn it_works() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let fmt = NumberFormat::new("n", NumberFormatOptions::default());
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, n => 1234567);
+        assert_eq!("1,234,567", output);
+
+        let output = format_message!(ctx, &msg, n => -1234.5);
+        assert_eq!("-1,234.5", output);
+
+        let output = format_message!(ctx, &msg, n => "1234");
+        assert_eq!("", output);
+    }
+
+    #[test]
+    fn styles_work() {
+        let ctx = Context::new("de".parse().unwrap(), None);
+
+        let fmt = NumberFormat::new("n", NumberFormatOptions::new(NumberStyle::Currency));
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, n => 1234.5);
+        assert_eq!("1.234,50\u{a0}€", output);
+    }
+
+    #[test]
+    fn currency_values_work() {
+        let ctx = Context::default();
+        let yen = Value::Currency {
+            amount: "1234.5".parse().unwrap(),
+            code: "JPY",
+        };
+        let dinars = Value::Currency {
+            amount: "-3".parse().unwrap(),
+            code: "BHD",
+        };
+
+        let fmt = NumberFormat::new("n", NumberFormatOptions::new(NumberStyle::Currency));
+        let msg = Message::new(vec![Box::new(fmt)]);
+        let output = format_message!(ctx, &msg, n => yen);
+        assert_eq!("¥1,234", output);
+
+        let fmt = NumberFormat::new("n", NumberFormatOptions::default());
+        let msg = Message::new(vec![Box::new(fmt)]);
+        let output = format_message!(ctx, &msg, n => dinars);
+        assert_eq!("-BHD\u{a0}3.000", output);
+
+        let options = NumberFormatOptions::from_skeleton("currency/EUR unit-width-full-name");
+        let fmt = NumberFormat::new("n", options.unwrap());
+        let msg = Message::new(vec![Box::new(fmt)]);
+        let output = format_message!(ctx, &msg, n => Value::Currency {
+            amount: Decimal::from(3),
+            code: "USD",
+        });
+        assert_eq!("3.00 US dollars", output);
+    }
+
+    #[test]
+    fn measure_values_work() {
+        let ctx = Context::default();
+        let speed = |amount: u32| Value::Measure {
+            amount: Decimal::from(amount),
+            unit: "mile-per-hour",
+        };
+
+        let fmt = NumberFormat::new("n", NumberFormatOptions::default());
+        let msg = Message::new(vec![Box::new(fmt)]);
+        let output = format_message!(ctx, &msg, n => speed(30));
+        assert_eq!("30 mph", output);
+
+        let options = NumberFormatOptions::from_skeleton("unit/kilometer unit-width-full-name");
+        let fmt = NumberFormat::new("n", options.unwrap());
+        let msg = Message::new(vec![Box::new(fmt)]);
+        let output = format_message!(ctx, &msg, n => speed(1));
+        assert_eq!("1 mile per hour", output);
+
+        let output = format_message!(ctx, &msg, n => 5);
+        assert_eq!("5 kilometers", output);
+
+        let fmt = NumberFormat::new("n", NumberFormatOptions::new(NumberStyle::Percent));
+        let msg = Message::new(vec![Box::new(fmt)]);
+        let output = format_message!(ctx, &msg, n => speed(1));
+        assert_eq!("100%", output);
+    }
+}
@@ -6,9 +6,13 @@
 
 use std::fmt;
 
-use {Args, Context, MessagePart};
+use {Args, Context, FormatError, MessagePart, NumberFormatOptions, Precision};
 
 /// A placeholder for a value. Used by `PluralFormat`.
+///
+/// The value is formatted like a `number` argument, using the
+/// conventions of the context's locale, but with all of its digits,
+/// so that `1.0` is still shown as `1.0` when it selects `other`.
 #[derive(Debug, Default)]
 pub struct PlaceholderFormat {}
 
@@ -25,12 +29,22 @@ impl MessagePart for PlaceholderFormat {
         ctx: &Context,
         stream: &mut fmt::Write,
         _args: Option<&Args>,
-    ) -> fmt::Result {
-        if let Some(value) = ctx.placeholder_value {
-            try!(write!(stream, "{}", value));
+    ) -> Result<(), FormatError> {
+        if let Some(ref value) = ctx.placeholder_value {
+            let options = NumberFormatOptions {
+                precision: Precision::Fraction {
+                    minimum: value.fraction_digits().len(),
+                    maximum: None,
+                },
+                ..NumberFormatOptions::default()
+            };
+            let formatted = options.format(&ctx.language_tag, value);
+            try!(write!(stream, "{}", formatted));
             Ok(())
         } else {
-            Err(fmt::Error {})
+            Err(FormatError::MissingArgument {
+                name: "#".to_string(),
+            })
         }
     }
 }
@@ -38,12 +52,12 @@ impl MessagePart for PlaceholderFormat {
 #[cfg(test)]
 mod tests {
     use super::PlaceholderFormat;
-    use {Context, Message};
+    use {Context, Decimal, Message};
 
     #[test]
     fn it_works() {
         let ctx = Context {
-            placeholder_value: Some(3),
+            placeholder_value: Some(Decimal::from(3)),
             ..Context::default()
         };
 
@@ -52,4 +66,30 @@ mod tests {
         let output = format_message!(ctx, &msg);
         assert_eq!("3", output);
     }
+
+    #[test]
+    fn decimals_work() {
+        let ctx = Context {
+            placeholder_value: Some("-2.50".parse().unwrap()),
+            ..Context::default()
+        };
+
+        let msg = Message::new(vec![Box::new(PlaceholderFormat::new())]);
+
+        let output = format_message!(ctx, &msg);
+        assert_eq!("-2.50", output);
+    }
+
+    #[test]
+    fn locales_work() {
+        let msg = Message::new(vec![Box::new(PlaceholderFormat::new())]);
+        let format = |language: &str, value: &str| {
+            let ctx = Context::new(language.parse().unwrap(), Some(value.parse().unwrap()));
+            format_message!(ctx, &msg)
+        };
+
+        assert_eq!(format("en", "1234"), "1,234");
+        assert_eq!(format("de", "1234.5"), "1.234,5");
+        assert_eq!(format("ar", "1234"), "١٬٢٣٤");
+    }
 }
@@ -6,7 +6,7 @@
 
 use std::fmt;
 
-use {Args, Context, MessagePart};
+use {Args, Context, FormatError, MessagePart};
 
 /// A string that should be output. Used for the text in between
 /// formats.
@@ -31,7 +31,7 @@ impl MessagePart for PlainText {
         _ctx: &Context,
         stream: &mut fmt::Write,
         _args: Option<&Args>,
-    ) -> fmt::Result {
+    ) -> Result<(), FormatError> {
         try!(stream.write_str(self.text.as_str()));
         Ok(())
     }
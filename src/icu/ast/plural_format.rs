@@ -6,8 +6,10 @@
 
 use std::fmt;
 
-use english_cardinal_classifier;
-use {Args, Context, Message, MessagePart, PluralCategory, Value};
+use {
+    Args, Context, Decimal, FormatError, Message, MessagePart, PluralCategory, PluralRules,
+    PluralType,
+};
 
 #[derive(Debug)]
 struct PluralMapping {
@@ -16,11 +18,15 @@ struct PluralMapping {
 }
 
 /// Format a value taking pluralization rules into account.
+///
+/// Unless plural rules have been set, the CLDR plural rules for the
+/// `language_tag` of the `Context` are used.
 #[derive(Debug)]
 pub struct PluralFormat {
     /// The name of the variable whose value should be formatted.
     variable_name: String,
-    classifier: fn(i64) -> PluralCategory,
+    plural_type: PluralType,
+    rules: Option<PluralRules>,
     literals: Vec<PluralMapping>,
     offset: i64,
     zero: Option<Message>,
@@ -32,11 +38,22 @@ pub struct PluralFormat {
 }
 
 impl PluralFormat {
-    /// Construct a `PluralFormat`.
+    /// Construct a `PluralFormat` using cardinal plural rules.
     pub fn new(variable_name: &str, other: Message) -> Self {
+        PluralFormat::new_with_type(variable_name, PluralType::Cardinal, other)
+    }
+
+    /// Construct a `PluralFormat` using ordinal plural rules, as used
+    /// by ICU's `selectordinal`.
+    pub fn new_ordinal(variable_name: &str, other: Message) -> Self {
+        PluralFormat::new_with_type(variable_name, PluralType::Ordinal, other)
+    }
+
+    fn new_with_type(variable_name: &str, plural_type: PluralType, other: Message) -> Self {
         PluralFormat {
             variable_name: variable_name.to_string(),
-            classifier: english_cardinal_classifier,
+            plural_type: plural_type,
+            rules: None,
             literals: vec![],
             offset: 0,
             zero: None,
@@ -48,6 +65,19 @@ impl PluralFormat {
         }
     }
 
+    /// The kind of plural rules used by this format.
+    pub fn plural_type(&self) -> PluralType {
+        self.plural_type
+    }
+
+    /// Set the plural rules used to determine the `PluralCategory` of a
+    /// value, rather than using the rules for the language of the `Context`.
+    ///
+    /// These should match the `plural_type` of this format.
+    pub fn rules(&mut self, rules: PluralRules) {
+        self.rules = Some(rules);
+    }
+
     /// Set the `message` to be used for a literal value.
     pub fn literal(&mut self, literal: i64, message: Message) {
         self.literals.push(PluralMapping {
@@ -57,6 +87,11 @@ impl PluralFormat {
     }
 
     /// Apply an `offset`.
+    ///
+    /// The offset is subtracted from the value before determining the
+    /// plural category and before it is used by a `PlaceholderFormat`.
+    /// Literal values are matched against the value before the offset
+    /// is applied.
     pub fn offset(&mut self, offset: i64) {
         self.offset = offset;
     }
@@ -86,16 +121,21 @@ impl PluralFormat {
         self.many = Some(message);
     }
 
-    /// Given a value adjusted by the `offset`, determine which `Message` to use.
-    fn lookup_message(&self, offset_value: i64) -> &Message {
+    /// Given a value and that value adjusted by the `offset`, determine
+    /// which `Message` to use.
+    fn lookup_message(&self, ctx: &Context, value: &Decimal, offset_value: &Decimal) -> &Message {
         if let Some(literal_message) = self.literals
             .iter()
-            .find(|mapping| mapping.value == offset_value)
+            .find(|mapping| (value - &Decimal::from(mapping.value)).is_zero())
             .map(|mapping| &mapping.message)
         {
             literal_message
         } else {
-            let category = (self.classifier)(offset_value);
+            let category = match self.rules {
+                Some(ref rules) => rules.select(offset_value),
+                None => PluralRules::for_language(&ctx.language_tag, self.plural_type)
+                    .select(offset_value),
+            };
             match category {
                 PluralCategory::Zero => self.zero.as_ref().unwrap_or(&self.other),
                 PluralCategory::One => self.one.as_ref().unwrap_or(&self.other),
@@ -114,28 +154,36 @@ impl MessagePart for PluralFormat {
         ctx: &Context,
         stream: &mut fmt::Write,
         args: Option<&Args<'f>>,
-    ) -> fmt::Result {
-        let arg = args.and_then(|args| args.get(&self.variable_name));
-        if let Some(&Value::Number(value)) = arg.map(|a| a.value()) {
-            let offset_value = value - self.offset;
-            let message = self.lookup_message(offset_value);
-            let ctx = Context {
-                placeholder_value: Some(offset_value),
-                ..ctx.clone()
-            };
-            try!(message.write_message(&ctx, stream, args));
-            Ok(())
-        } else {
-            Err(fmt::Error {})
-        }
+    ) -> Result<(), FormatError> {
+        let arg = try!(args
+            .and_then(|args| args.get(&self.variable_name))
+            .ok_or_else(|| FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            }));
+        let value = try!(arg
+            .value()
+            .to_decimal()
+            .ok_or_else(|| FormatError::TypeMismatch {
+                name: self.variable_name.clone(),
+                expected: "a number".to_string(),
+                actual: arg.value().type_name().to_string(),
+            }));
+        let offset_value = &value - &Decimal::from(self.offset);
+        let message = self.lookup_message(ctx, &value, &offset_value);
+        let ctx = Context {
+            placeholder_value: Some(offset_value),
+            ..ctx.clone()
+        };
+        message.write_message(&ctx, stream, args)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use icu::ast::{PlaceholderFormat, PlainText};
     use icu::parse;
     use super::PluralFormat;
-    use {Context, Message};
+    use {Context, Decimal, Message, PluralRules};
 
     #[test]
     fn it_works() {
@@ -179,4 +227,138 @@ mod tests {
         let output = format_message!(ctx, &msg, count => 0);
         assert_eq!("Other", output);
     }
+
+    #[test]
+    fn offset_works() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let mut fmt = PluralFormat::new("count", parse("Other").unwrap());
+        fmt.one(parse("One").unwrap());
+        fmt.literal(1, parse("Literal").unwrap());
+        fmt.offset(1);
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, count => 1);
+        assert_eq!("Literal", output);
+
+        let output = format_message!(ctx, &msg, count => 2);
+        assert_eq!("One", output);
+
+        let output = format_message!(ctx, &msg, count => 3);
+        assert_eq!("Other", output);
+    }
+
+    #[test]
+    fn decimals_work() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let placeholder = |text| {
+            Message::new(vec![
+                Box::new(PlaceholderFormat::new()),
+                Box::new(PlainText::new(text)),
+            ])
+        };
+        let mut fmt = PluralFormat::new("count", placeholder(" items"));
+        fmt.one(placeholder(" item"));
+        fmt.literal(2, parse("Two").unwrap());
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, count => "1.0".parse::<Decimal>().unwrap());
+        assert_eq!("1.0 items", output);
+
+        let output = format_message!(ctx, &msg, count => 1.5);
+        assert_eq!("1.5 items", output);
+
+        let output = format_message!(ctx, &msg, count => 1u64);
+        assert_eq!("1 item", output);
+
+        let output = format_message!(ctx, &msg, count => 2.0);
+        assert_eq!("Two", output);
+
+        let output = format_message!(ctx, &msg, count => u128::MAX);
+        assert_eq!(
+            "340,282,366,920,938,463,463,374,607,431,768,211,455 items",
+            output
+        );
+
+        let output = format_message!(ctx, &msg, count => "1");
+        assert_eq!("", output);
+    }
+
+    #[test]
+    fn ordinals_work() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let mut fmt = PluralFormat::new_ordinal("place", parse("th").unwrap());
+        fmt.one(parse("st").unwrap());
+        fmt.two(parse("nd").unwrap());
+        fmt.few(parse("rd").unwrap());
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, place => 1);
+        assert_eq!("st", output);
+
+        let output = format_message!(ctx, &msg, place => 2);
+        assert_eq!("nd", output);
+
+        let output = format_message!(ctx, &msg, place => 3);
+        assert_eq!("rd", output);
+
+        let output = format_message!(ctx, &msg, place => 11);
+        assert_eq!("th", output);
+    }
+
+    #[test]
+    fn rules_work() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let mut fmt = PluralFormat::new_ordinal("place", parse(":e").unwrap());
+        fmt.one(parse(":a").unwrap());
+        fmt.rules(PluralRules::parse("one: n % 10 = 1,2 and n % 100 != 11,12").unwrap());
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, place => 2);
+        assert_eq!(":a", output);
+
+        let output = format_message!(ctx, &msg, place => 3);
+        assert_eq!(":e", output);
+    }
+
+    #[test]
+    fn language_rules_work() {
+        let ctx = Context::new("ar".parse().unwrap(), None);
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let mut fmt = PluralFormat::new("count", parse("Other").unwrap());
+        fmt.zero(parse("Zero").unwrap());
+        fmt.one(parse("One").unwrap());
+        fmt.two(parse("Two").unwrap());
+        fmt.few(parse("Few").unwrap());
+        fmt.many(parse("Many").unwrap());
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, count => 0);
+        assert_eq!("Zero", output);
+
+        let output = format_message!(ctx, &msg, count => 2);
+        assert_eq!("Two", output);
+
+        let output = format_message!(ctx, &msg, count => 105);
+        assert_eq!("Few", output);
+
+        let output = format_message!(ctx, &msg, count => 111);
+        assert_eq!("Many", output);
+
+        let output = format_message!(ctx, &msg, count => 100);
+        assert_eq!("Other", output);
+
+        // Unknown languages use the root rules.
+        let ctx = Context::new("x-unknown".parse().unwrap(), None);
+        let output = format_message!(ctx, &msg, count => 1);
+        assert_eq!("Other", output);
+    }
 }
@@ -0,0 +1,89 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use {Args, Context, FormatError, MessagePart, RelativeTimeFormatOptions};
+
+/// Format a numeric value as a time relative to now, like `3 days ago`
+/// or `in 2 hours`, using the conventions of the context's locale.
+#[derive(Debug)]
+pub struct RelativeTimeFormat {
+    /// The name of the variable whose value should be formatted.
+    variable_name: String,
+    /// How the value should be formatted.
+    options: RelativeTimeFormatOptions,
+}
+
+impl RelativeTimeFormat {
+    /// Construct a `RelativeTimeFormat`.
+    pub fn new(variable_name: &str, options: RelativeTimeFormatOptions) -> Self {
+        RelativeTimeFormat {
+            variable_name: variable_name.to_string(),
+            options: options,
+        }
+    }
+}
+
+impl MessagePart for RelativeTimeFormat {
+    fn apply_format<'f>(
+        &self,
+        ctx: &Context,
+        stream: &mut fmt::Write,
+        args: Option<&Args<'f>>,
+    ) -> Result<(), FormatError> {
+        let arg = try!(args
+            .and_then(|args| args.get(&self.variable_name))
+            .ok_or_else(|| FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            }));
+        let value = try!(arg
+            .value()
+            .to_decimal()
+            .ok_or_else(|| FormatError::TypeMismatch {
+                name: self.variable_name.clone(),
+                expected: "a number".to_string(),
+                actual: arg.value().type_name().to_string(),
+            }));
+        try!(write!(
+            stream,
+            "{}",
+            self.options.format(&ctx.language_tag, &value)
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeTimeFormat;
+    use {Context, Message, RelativeTimeFormatOptions, RelativeTimeNumeric, RelativeTimeUnit};
+
+    #[test]
+    fn it_works() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let options = RelativeTimeFormatOptions {
+            numeric: RelativeTimeNumeric::Auto,
+            ..RelativeTimeFormatOptions::new(RelativeTimeUnit::Day)
+        };
+        let fmt = RelativeTimeFormat::new("delta", options);
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, delta => -1);
+        assert_eq!("yesterday", output);
+
+        let output = format_message!(ctx, &msg, delta => -3);
+        assert_eq!("3 days ago", output);
+
+        let output = format_message!(ctx, &msg, delta => 2.5);
+        assert_eq!("in 2.5 days", output);
+
+        let output = format_message!(ctx, &msg, delta => "soon");
+        assert_eq!("", output);
+    }
+}
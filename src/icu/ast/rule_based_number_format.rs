@@ -0,0 +1,109 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use {
+    Args, Context, Decimal, FormatError, MessagePart, RuleBasedNumberFormatOptions,
+    RuleBasedNumberType, Value,
+};
+
+/// Format a number using the context's locale's rule-based number
+/// format rules, like `forty-two`, `42nd` or `1:05:07`.
+///
+/// The value should be a number. For duration rules, it may also be a
+/// `Value::Duration`, which is formatted as a number of seconds.
+#[derive(Debug)]
+pub struct RuleBasedNumberFormat {
+    /// The name of the variable whose value should be formatted.
+    variable_name: String,
+    /// How the value should be formatted.
+    options: RuleBasedNumberFormatOptions,
+}
+
+impl RuleBasedNumberFormat {
+    /// Construct a `RuleBasedNumberFormat`.
+    pub fn new(variable_name: &str, options: RuleBasedNumberFormatOptions) -> Self {
+        RuleBasedNumberFormat {
+            variable_name: variable_name.to_string(),
+            options: options,
+        }
+    }
+
+    /// The value as a number, taking durations as seconds.
+    fn number(&self, value: &Value) -> Option<Decimal> {
+        match *value {
+            Value::Duration(duration)
+                if self.options.rule_type == RuleBasedNumberType::Duration =>
+            {
+                Some(Decimal::from(duration.as_millis()).shift(-3))
+            }
+            ref value => value.to_decimal(),
+        }
+    }
+}
+
+impl MessagePart for RuleBasedNumberFormat {
+    fn apply_format<'f>(
+        &self,
+        ctx: &Context,
+        stream: &mut fmt::Write,
+        args: Option<&Args<'f>>,
+    ) -> Result<(), FormatError> {
+        let arg = try!(args
+            .and_then(|args| args.get(&self.variable_name))
+            .ok_or_else(|| FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            }));
+        let expected = match self.options.rule_type {
+            RuleBasedNumberType::Duration => "a number or a duration",
+            _ => "a number",
+        };
+        let value = try!(self
+            .number(arg.value())
+            .ok_or_else(|| FormatError::TypeMismatch {
+                name: self.variable_name.clone(),
+                expected: expected.to_string(),
+                actual: arg.value().type_name().to_string(),
+            }));
+        try!(write!(
+            stream,
+            "{}",
+            self.options.format(&ctx.language_tag, &value)
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuleBasedNumberFormat;
+    use std::time::Duration;
+    use {Context, Message, RuleBasedNumberFormatOptions, RuleBasedNumberType};
+
+    #[test]
+    fn it_works() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let options = RuleBasedNumberFormatOptions::new(RuleBasedNumberType::Spellout);
+        let fmt = RuleBasedNumberFormat::new("count", options);
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, count => 42);
+        assert_eq!("forty-two", output);
+
+        let output = format_message!(ctx, &msg, count => 2.5);
+        assert_eq!("two point five", output);
+
+        let options = RuleBasedNumberFormatOptions::new(RuleBasedNumberType::Duration);
+        let fmt = RuleBasedNumberFormat::new("elapsed", options);
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, elapsed => Duration::from_millis(3907500));
+        assert_eq!("1:05:07", output);
+    }
+}
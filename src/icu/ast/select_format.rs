@@ -6,7 +6,7 @@
 
 use std::fmt;
 
-use {Args, Context, Message, MessagePart, Value};
+use {Args, Context, FormatError, Message, MessagePart, Value};
 
 #[derive(Debug)]
 struct SelectMapping {
@@ -59,14 +59,22 @@ impl MessagePart for SelectFormat {
         ctx: &Context,
         stream: &mut fmt::Write,
         args: Option<&Args<'f>>,
-    ) -> fmt::Result {
+    ) -> Result<(), FormatError> {
         let arg = args.and_then(|args| args.get(&self.variable_name));
-        if let Some(&Value::Str(value)) = arg.map(|a| a.value()) {
-            let message = self.lookup_message(value);
-            try!(message.write_message(ctx, stream, args));
-            Ok(())
-        } else {
-            Err(fmt::Error {})
+        match arg.map(|a| a.value()) {
+            Some(&Value::Str(value)) => {
+                let message = self.lookup_message(value);
+                try!(message.write_message(ctx, stream, args));
+                Ok(())
+            }
+            Some(value) => Err(FormatError::TypeMismatch {
+                name: self.variable_name.clone(),
+                expected: "a string".to_string(),
+                actual: value.type_name().to_string(),
+            }),
+            None => Err(FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            }),
         }
     }
 }
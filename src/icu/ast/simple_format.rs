@@ -6,7 +6,7 @@
 
 use std::fmt;
 
-use {Args, Context, MessagePart};
+use {Args, Context, FormatError, MessagePart};
 
 /// A simple message consisting of a value to be formatted.
 #[derive(Debug)]
@@ -30,12 +30,14 @@ impl MessagePart for SimpleFormat {
         _ctx: &Context,
         stream: &mut fmt::Write,
         args: Option<&Args<'f>>,
-    ) -> fmt::Result {
+    ) -> Result<(), FormatError> {
         if let Some(arg) = args.and_then(|args| args.get(&self.variable_name)) {
             try!(write!(stream, "{}", arg.value()));
             Ok(())
         } else {
-            Err(fmt::Error {})
+            Err(FormatError::MissingArgument {
+                name: self.variable_name.clone(),
+            })
         }
     }
 }
@@ -43,7 +45,7 @@ impl MessagePart for SimpleFormat {
 #[cfg(test)]
 mod tests {
     use super::SimpleFormat;
-    use {Context, Message};
+    use {Context, Decimal, Message};
 
     #[test]
     fn it_works() {
@@ -56,4 +58,25 @@ mod tests {
         let output = format_message!(ctx, &msg, name => "John");
         assert_eq!("John", output);
     }
+
+    #[test]
+    fn numbers_work() {
+        let ctx = Context::default();
+
+        // Manually construct a message in an ugly way so that we aren't testing parsing.
+        let fmt = SimpleFormat::new("n");
+        let msg = Message::new(vec![Box::new(fmt)]);
+
+        let output = format_message!(ctx, &msg, n => 2.5);
+        assert_eq!("2.5", output);
+
+        let output = format_message!(ctx, &msg, n => "2.50".parse::<Decimal>().unwrap());
+        assert_eq!("2.50", output);
+
+        let output = format_message!(ctx, &msg, n => u64::MAX);
+        assert_eq!("18446744073709551615", output);
+
+        let output = format_message!(ctx, &msg, n => i128::MIN);
+        assert_eq!("-170141183460469231731687303715884105728", output);
+    }
 }
@@ -35,19 +35,265 @@
 //! "Connecting to {application} on {host}..."
 //! ```
 //!
+//! ## Number Messages
+//!
+//! A `number` message formats a numeric value using the digits,
+//! separators and grouping of the context's locale, so that `1234.5`
+//! is `1,234.5` in English, `1.234,5` in German and `١٬٢٣٤٫٥` in
+//! Arabic:
+//!
+//! ```text
+//! "{count, number} files"
+//! ```
+//!
+//! A style may follow the argument type:
+//!
+//! * `integer` rounds the value to an integer.
+//! * `percent` formats the value as a percentage, so `0.25` is `25%`.
+//! * `currency` formats the value as an amount of the currency used
+//!   in the locale's region, like `$1,234.50`.
+//!
+//! ```text
+//! "{done, number, percent} complete"
+//! ```
+//!
+//! A [`Value::Currency`] is formatted as an amount of its own currency,
+//! with that currency's symbol and number of fraction digits, so
+//! `1234.5` yen is `¥1,235` and `3` Bahraini dinars `BHD 3.000`.
+//!
+//! Instead of a style, a [number skeleton] starting with `::` gives
+//! more control over the unit, precision, rounding, sign, grouping and
+//! notation, as in ICU4J, ICU4C and FormatJS:
+//!
+//! ```text
+//! "Total: {price, number, ::currency/EUR precision-currency-cash}"
+//! "{ratio, number, ::percent .00 group-off}"
+//! "{visits, number, ::compact-short} visits"
+//! "{total, number, ::currency/USD unit-width-full-name}"
+//! ```
+//!
+//! Compact notation uses the locale's abbreviations or words for large
+//! numbers, so `::compact-long` writes `3400000` as `3.4 million` in
+//! English and `3,4 Millionen` in German. Scientific notation is also
+//! available, as `::scientific` or `::engineering`.
+//!
+//! A `unit/` stem formats the value as an amount of a measurement
+//! unit, named in the plural form for the amount, so `5` is `5 km` or,
+//! with `unit-width-full-name`, `5 kilometers`. Compound units like
+//! `kilometer-per-hour` are also supported:
+//!
+//! ```text
+//! "You ran {distance, number, ::unit/kilometer unit-width-full-name}"
+//! "Top speed: {speed, number, ::unit/kilometer-per-hour .0}"
+//! ```
+//!
+//! A [`Value::Measure`] is formatted as an amount of its own unit.
+//!
+//! See [`NumberFormatOptions::from_skeleton`] for the supported stems.
+//!
+//! [number skeleton]: https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html
+//! [`NumberFormatOptions::from_skeleton`]: ../struct.NumberFormatOptions.html#method.from_skeleton
+//!
+//! ## Date and Time Messages
+//!
+//! `date` and `time` messages format a [`DateTime`] using the month
+//! and weekday names and the patterns of the context's locale, in the
+//! Gregorian calendar. The `short`, `medium` (the default), `long` and
+//! `full` styles give more or less detail:
+//!
+//! ```text
+//! "Posted on {when, date, long} at {when, time, short}"
+//! ```
+//!
+//! Instead of a style, a [date/time skeleton] starting with `::` lists
+//! the fields to show, which are then ordered and punctuated as usual
+//! in the locale, so `::yMMMd` is `Mar 5, 2024` in English and
+//! `5. März 2024` in German. Any other style is used as a pattern:
+//!
+//! ```text
+//! "Updated {when, date, ::yMMMdjm}"
+//! "Logged at {when, time, HH:mm:ss.SSS}"
+//! ```
+//!
+//! A `DateTime` is shown at its own offset from UTC, if it has one.
+//! Otherwise, it is shown in the context's [`TimeZone`], if there is
+//! one, and in UTC if not. An integer value is also accepted, as a
+//! number of milliseconds since the Unix epoch.
+//!
+//! With a time zone, zone fields are shown by name, so `zzzz` is
+//! `Pacific Daylight Time`, `z` is `PDT` and `VVVV` is `Los Angeles
+//! Time` in English, while `O` is always an offset like `GMT-7`:
+//!
+//! ```text
+//! "The meeting starts at {when, time, ::jmmz}"
+//! ```
+//!
+//! See [`DateTimeFormatOptions::from_skeleton`] for the supported
+//! fields.
+//!
+//! [date/time skeleton]: https://unicode-org.github.io/icu/userguide/format_parse/datetime/#datetimepatterngenerator
+//! [`DateTime`]: ../struct.DateTime.html
+//! [`TimeZone`]: ../struct.TimeZone.html
+//! [`DateTimeFormatOptions::from_skeleton`]: ../enum.DateTimeFormatOptions.html#method.from_skeleton
+//!
+//! ## Relative Time Messages
+//!
+//! A `relativetime` message formats a number of units before or after
+//! now, like `3 days ago` or `in 2 hours`. Negative values are in the
+//! past. The unit, one of `year`, `quarter`, `month`, `week`, `day`,
+//! `hour`, `minute` or `second`, is required:
+//!
+//! ```text
+//! "Edited {delta, relativetime, day}"
+//! ```
+//!
+//! It may be followed by `long` (the default), `short` or `narrow`
+//! names, and by `auto` to use names like `yesterday` or `next week`
+//! where the locale has them instead of the default `numeric`:
+//!
+//! ```text
+//! "Due {delta, relativetime, day auto}"
+//! "{delta, relativetime, hour short}"
+//! ```
+//!
+//! The unit's name agrees with the number, using the locale's plural
+//! rules, so Russian has `через 2 дня` but `через 5 дней`.
+//!
+//! ## List Messages
+//!
+//! A `list` message joins the items of a [`Value::List`] with the
+//! words and punctuation of the context's locale, like `Ana, Ben, and
+//! Chloé` in English and `Ana, Ben und Chloé` in German:
+//!
+//! ```text
+//! "Shared with {names, list}"
+//! "Pick {options, list, disjunction}"
+//! ```
+//!
+//! The style may give the type of list, which is `conjunction` ("and",
+//! the default), `disjunction` ("or") or `unit` (for measurements like
+//! `5 ft, 2 in`), and the width, which is `long` (the default), `short`
+//! or `narrow`, as in `{names, list, conjunction short}`.
+//!
+//! ## Duration Messages
+//!
+//! A `duration` message formats a [`Value::Duration`], or a number of
+//! seconds, with the locale's names for its units, like `1 hr, 5 min,
+//! 7 sec` in English and `1 Std., 5 Min., 7 Sek.` in German:
+//!
+//! ```text
+//! "Finished in {elapsed, duration}"
+//! "Finished in {elapsed, duration, long smallest/minute}"
+//! ```
+//!
+//! The style may be `long`, `short` (the default), `narrow` or
+//! `digital`, which is like `1:05:07`. It may be followed by
+//! `largest/` and `smallest/` with a unit, which is one of `week`,
+//! `day`, `hour`, `minute`, `second` or `millisecond`, to choose the
+//! units shown. By default, these are days down to seconds. Units with
+//! nothing in them are left out, and each unit's name agrees with its
+//! amount, so Russian has `22 часа` but `25 часов`.
+//!
+//! ## Spellout and Ordinal Messages
+//!
+//! A `spellout` message writes a number in words, like `forty-two`,
+//! and an `ordinal` message writes it with digits and the locale's
+//! ordinal suffix, like `42nd`. Either may name one of the locale's
+//! rule sets, which are adapted from ICU's rule-based number format
+//! rules:
+//!
+//! ```text
+//! "{count, spellout}"
+//! "The {place, spellout, %spellout-ordinal} runner"
+//! "{count, spellout, %spellout-cardinal-feminine} personas"
+//! "{place, ordinal, %digits-ordinal-feminine}"
+//! ```
+//!
+//! A `duration` message may also name a rule set, like `%with-words`
+//! or `%duration`, to format a number of seconds with ICU's duration
+//! rules instead of the CLDR unit names. A rule set that the locale
+//! doesn't have falls back to the locale's default one, and a name
+//! that no locale's rules define is an error.
+//!
 //! ## Pluralized Messages
 //!
-//! Parsing of `plural` messages is not yet supported.
+//! A `plural` message selects a sub-message based on the plural category
+//! of a numeric value:
+//!
+//! ```text
+//! "{count, plural, =0 {No items} one {One item} other {Many items}}"
+//! ```
+//!
+//! Each arm is either a plural category keyword (`zero`, `one`, `two`,
+//! `few`, `many` or `other`) or an explicit value like `=0`. Explicit
+//! values are checked before the plural category. The `other` arm is
+//! required. Sub-messages may contain further formats, including other
+//! `plural` messages.
+//!
+//! Within a sub-message of a `plural`, `#` is replaced by the value being
+//! formatted. Elsewhere, including within a `select` nested inside a
+//! `plural`, `#` is plain text.
+//!
+//! An `offset` may be given before the arms. It is subtracted from the
+//! value before choosing the plural category and before formatting `#`,
+//! but explicit values are matched against the original value:
+//!
+//! ```text
+//! "{guests, plural, offset:1
+//!     =0 {Nobody is coming.}
+//!     =1 {{host} is coming.}
+//!     one {{host} and # other person are coming.}
+//!     other {{host} and # other people are coming.}}"
+//! ```
+//!
+//! ## Ordinal Messages
+//!
+//! A `selectordinal` message is like a `plural` message, but uses the
+//! ordinal plural rules, which are used for ranking rather than counting:
+//!
+//! ```text
+//! "You finished {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}!"
+//! ```
 //!
 //! ## Select Messages
 //!
-//! Parsing of `select` messages is not yet supported.
+//! A `select` message chooses a sub-message by matching a string value
+//! against a set of keywords:
+//!
+//! ```text
+//! "{gender, select, male {He} female {She} other {They}} liked this."
+//! ```
+//!
+//! The `other` arm is required and is used when no other keyword
+//! matches. As with `plural` messages, sub-messages may contain further
+//! formats.
+//!
+//! ## Quoting
+//!
+//! Apostrophes are used to include literal `{`, `}` or `#` characters,
+//! following the ICU default (`DOUBLE_OPTIONAL`) apostrophe mode:
+//!
+//! * `''` is always a single apostrophe.
+//! * An apostrophe immediately before a `{` or `}` (or a `#` within a
+//!   `plural` sub-message) starts quoted text, which continues up to the
+//!   next single apostrophe.
+//! * Any other apostrophe is a literal apostrophe, so `"Don't"` needs
+//!   no special treatment.
+//!
+//! ```text
+//! "Don't use '{braces}' here."  =>  Don't use {braces} here.
+//! "It''s '{'{name}'}'"          =>  It's {John}
+//! ```
 //!
 //! [`icu::parse`]: fn.parse.html
 //! [`Message`]: ../struct.Message.html
+//! [`Value::Currency`]: ../enum.Value.html#variant.Currency
+//! [`Value::Duration`]: ../enum.Value.html#variant.Duration
+//! [`Value::Measure`]: ../enum.Value.html#variant.Measure
+//! [`Value::List`]: ../enum.Value.html#variant.List
 //! [ICU-style message formatting]: http://userguide.icu-project.org/formatparse/messages
 
 pub mod ast;
 mod parse;
 
-pub use self::parse::parse;
+pub use self::parse::{parse, ParseError, ParseErrorKind};
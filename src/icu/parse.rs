@@ -6,108 +6,1008 @@
 
 use std::error::Error;
 use std::fmt;
-use std::str;
-
-use nom::{multispace, IResult};
 
 use super::ast;
-use {Message, MessagePart};
+use {
+    DateTimeFormatOptions, DateTimeStyle, DurationFormatOptions, DurationStyle, DurationUnit,
+    ListFormatOptions, ListStyle, ListType, Message,
+    MessagePart, NumberFormatOptions, NumberStyle, PluralCategory, PluralType,
+    RelativeTimeFormatOptions, RelativeTimeNumeric, RelativeTimeStyle, RelativeTimeUnit,
+    RuleBasedNumberFormatOptions, RuleBasedNumberType,
+};
 
 /// An error resulting from `parse`.
-#[derive(Clone, Debug)]
-pub enum ParseError {
-    /// The message could not be parsed.
-    NotImplemented,
+///
+/// This records where in the message the error was detected along
+/// with what was found there. The `Display` implementation renders
+/// the offending line of the message with the error location marked:
+///
+/// ```text
+/// 1:32: expected `}` to close argument `count` opened at 1:1, found end of input
+/// {count, plural, other {# items}
+///                                ^
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+    /// The byte offset into the message at which the error was detected.
+    pub offset: usize,
+    /// The line on which the error was detected, starting from `1`.
+    pub line: usize,
+    /// The column at which the error was detected, counted in
+    /// characters and starting from `1`.
+    pub column: usize,
+    /// The token found at the error location, or `None` if the end of
+    /// the message was reached.
+    pub found: Option<String>,
+    /// The line of the message containing the error.
+    source_line: String,
+}
+
+/// The kinds of errors that can be reported by `parse`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseErrorKind {
+    /// Something other than what was expected was found. This holds
+    /// a description of what was expected.
+    Expected(String),
+    /// An argument was opened with a `{` but not closed.
+    UnclosedArgument {
+        /// The name of the argument.
+        name: String,
+        /// The line on which the argument was opened.
+        line: usize,
+        /// The column at which the argument was opened.
+        column: usize,
+    },
+    /// The sub-message for an arm of a `plural` or `select` was opened
+    /// with a `{` but not closed.
+    UnclosedSubMessage {
+        /// The selector for the arm.
+        selector: String,
+        /// The line on which the sub-message was opened.
+        line: usize,
+        /// The column at which the sub-message was opened.
+        column: usize,
+    },
+    /// The argument type, like `plural` in `{count, plural, ...}`, is
+    /// not supported.
+    UnknownArgumentType(String),
+    /// The style of a `number` argument, like `percent` in
+    /// `{n, number, percent}`, is not supported.
+    UnknownNumberStyle(String),
+    /// A number skeleton, like `::percent .00` in
+    /// `{n, number, ::percent .00}`, has a token that is not valid or
+    /// not supported.
+    InvalidNumberSkeleton(String),
+    /// A date/time skeleton, like `::yMMMd` in `{d, date, ::yMMMd}`,
+    /// has a field that is not valid or not supported.
+    InvalidDateTimeSkeleton(String),
+    /// A date/time pattern, like `y-MM-dd` in `{d, date, y-MM-dd}`,
+    /// has a field that is not valid or not supported, or an unclosed
+    /// apostrophe.
+    InvalidDateTimePattern(String),
+    /// The style of a `relativetime` argument, like `days` in
+    /// `{delta, relativetime, days}`, is not a unit or not supported.
+    UnknownRelativeTimeStyle(String),
+    /// The style of a `list` argument, like `and` in
+    /// `{names, list, and}`, is not supported.
+    UnknownListStyle(String),
+    /// The style of a `duration` argument, like `hours` in
+    /// `{elapsed, duration, hours}`, is not supported.
+    UnknownDurationStyle(String),
+    /// The rule set of a `spellout`, `ordinal` or `duration` argument,
+    /// like `%spellout-ordinal` in `{n, spellout, %spellout-ordinal}`,
+    /// is not defined by any locale's rules of that type.
+    UnknownRuleSet(String),
+    /// An arm of a `plural` used a keyword that is not a plural category.
+    UnknownPluralKeyword(String),
+    /// The same selector was used for more than one arm.
+    DuplicateSelector(String),
+    /// A `plural` or `select` did not have an `other` arm.
+    MissingOther {
+        /// The name of the argument.
+        name: String,
+    },
+    /// Sub-messages were nested more than 100 deep.
+    TooDeeplyNested,
+}
+
+impl ParseError {
+    fn new(source: &str, offset: usize, kind: ParseErrorKind) -> Self {
+        let (line, column) = line_column(source, offset);
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        ParseError {
+            kind: kind,
+            offset: offset,
+            line: line,
+            column: column,
+            found: token_at(&source[offset..]).map(str::to_string),
+            source_line: source[line_start..line_end].to_string(),
+        }
+    }
 }
 
 impl Error for ParseError {
     fn description(&self) -> &str {
-        match *self {
-            ParseError::NotImplemented => "Not implemented.",
+        match self.kind {
+            ParseErrorKind::Expected(_) => "Unexpected input.",
+            ParseErrorKind::UnclosedArgument { .. } => "Unclosed argument.",
+            ParseErrorKind::UnclosedSubMessage { .. } => "Unclosed sub-message.",
+            ParseErrorKind::UnknownArgumentType(_) => "Unknown argument type.",
+            ParseErrorKind::UnknownNumberStyle(_) => "Unknown number style.",
+            ParseErrorKind::InvalidNumberSkeleton(_) => "Invalid number skeleton.",
+            ParseErrorKind::InvalidDateTimeSkeleton(_) => "Invalid date/time skeleton.",
+            ParseErrorKind::InvalidDateTimePattern(_) => "Invalid date/time pattern.",
+            ParseErrorKind::UnknownRelativeTimeStyle(_) => "Unknown relative time style.",
+            ParseErrorKind::UnknownListStyle(_) => "Unknown list style.",
+            ParseErrorKind::UnknownDurationStyle(_) => "Unknown duration style.",
+            ParseErrorKind::UnknownRuleSet(_) => "Unknown rule set.",
+            ParseErrorKind::UnknownPluralKeyword(_) => "Unknown plural keyword.",
+            ParseErrorKind::DuplicateSelector(_) => "Duplicate selector.",
+            ParseErrorKind::MissingOther { .. } => "Missing `other` arm.",
+            ParseErrorKind::TooDeeplyNested => "Sub-messages nested too deeply.",
         }
     }
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        self.description().fmt(f)
+        try!(write!(f, "{}:{}: {}", self.line, self.column, self.kind));
+        match self.kind {
+            ParseErrorKind::Expected(_)
+            | ParseErrorKind::UnclosedArgument { .. }
+            | ParseErrorKind::UnclosedSubMessage { .. } => match self.found {
+                Some(ref found) => try!(write!(f, ", found `{}`", found)),
+                None => try!(write!(f, ", found end of input")),
+            },
+            _ => {}
+        }
+        try!(writeln!(f));
+        try!(writeln!(f, "{}", self.source_line));
+        // Keep tabs so that the caret lines up with the source line.
+        let indent: String = self
+            .source_line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = self.found.as_ref().map_or(1, |found| found.chars().count());
+        write!(f, "{}{}", indent, "^".repeat(width))
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            ParseErrorKind::Expected(ref expected) => write!(f, "expected {}", expected),
+            ParseErrorKind::UnclosedArgument {
+                ref name,
+                line,
+                column,
+            } => write!(
+                f,
+                "expected `}}` to close argument `{}` opened at {}:{}",
+                name, line, column
+            ),
+            ParseErrorKind::UnclosedSubMessage {
+                ref selector,
+                line,
+                column,
+            } => write!(
+                f,
+                "expected `}}` to close `{}` sub-message opened at {}:{}",
+                selector, line, column
+            ),
+            ParseErrorKind::UnknownArgumentType(ref name) => {
+                write!(f, "unknown argument type `{}`", name)
+            }
+            ParseErrorKind::UnknownNumberStyle(ref style) => write!(
+                f,
+                "unknown number style `{}`; expected `integer`, `percent`, `currency` \
+                 or a `::` skeleton",
+                style
+            ),
+            ParseErrorKind::InvalidNumberSkeleton(ref token) => {
+                write!(f, "invalid number skeleton token `{}`", token)
+            }
+            ParseErrorKind::InvalidDateTimeSkeleton(ref field) => {
+                write!(f, "invalid date/time skeleton field `{}`", field)
+            }
+            ParseErrorKind::InvalidDateTimePattern(ref field) => {
+                write!(f, "invalid date/time pattern field `{}`", field)
+            }
+            ParseErrorKind::UnknownRelativeTimeStyle(ref style) => write!(
+                f,
+                "unknown relative time style `{}`; expected a unit like `day`, \
+                 followed by `long`, `short`, `narrow`, `numeric` or `auto`",
+                style
+            ),
+            ParseErrorKind::UnknownListStyle(ref style) => write!(
+                f,
+                "unknown list style `{}`; expected `conjunction`, `disjunction`, `unit`, \
+                 `long`, `short` or `narrow`",
+                style
+            ),
+            ParseErrorKind::UnknownDurationStyle(ref style) => write!(
+                f,
+                "unknown duration style `{}`; expected `long`, `short`, `narrow`, `digital`, \
+                 or `largest/` or `smallest/` followed by a unit like `hour`",
+                style
+            ),
+            ParseErrorKind::UnknownRuleSet(ref rule_set) => write!(
+                f,
+                "unknown rule set `{}`; expected a rule set like `%spellout-ordinal`",
+                rule_set
+            ),
+            ParseErrorKind::UnknownPluralKeyword(ref keyword) => write!(
+                f,
+                "`{}` is not a plural category; expected `zero`, `one`, `two`, \
+                 `few`, `many`, `other` or `=N`",
+                keyword
+            ),
+            ParseErrorKind::DuplicateSelector(ref selector) => {
+                write!(f, "duplicate selector `{}`", selector)
+            }
+            ParseErrorKind::MissingOther { ref name } => {
+                write!(f, "expected an `other` arm for argument `{}`", name)
+            }
+            ParseErrorKind::TooDeeplyNested => {
+                write!(f, "sub-messages nested more than {} deep", MAX_DEPTH)
+            }
+        }
     }
 }
 
-/// Given a name, create a `SimpleFormat`.
-fn mk_simple(name: &str) -> Box<MessagePart> {
-    Box::new(ast::SimpleFormat::new(name))
+/// Find the 1-based line and column of a byte offset.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
 }
 
-/// This grabs the variable name from a format, which is
-/// the first thing after the '{' and extends to the first
-/// ',' or '}'.
-///
-/// '{name}' has a variable name of 'name'.
-named!(variable_name <&str, &str>, is_not_s!(",}"));
-
-/// A simple format has only a name, delimited by braces.
-named!(simple_format <&str, Box<MessagePart> >,
-    map!(
-        delimited!(
-            tag_s!("{"),
-            variable_name,
-            tag_s!("}")),
-        mk_simple));
-
-named!(plural_format <&str, Box<MessagePart> >,
-    delimited!(
-        tag_s!("{"),
-        do_parse!(
-            name: variable_name >>
-            tag_s!(",") >> opt!(multispace) >>
-            tag_s!("plural") >> opt!(multispace) >>
-            (Box::new(ast::SimpleFormat::new(name)))),
-        tag_s!("}")));
-
-named!(select_format <&str, Box<MessagePart> >,
-    delimited!(
-        tag_s!("{"),
-        do_parse!(
-            name: variable_name >>
-            tag_s!(",") >> opt!(multispace) >>
-            tag_s!("select") >> opt!(multispace) >>
-            (Box::new(ast::SimpleFormat::new(name)))),
-        tag_s!("}")));
-
-/// Plain text extends up through to the start of the next format
-/// block.
-named!(plain_text <&str, Box<MessagePart> >,
-    map!(is_not_s!("{"), |text| Box::new(ast::PlainText::new(text))));
-
-/// Message parts must be 1 of the various part types. And there must
-/// be at least one of them for now.
-named!(message_parts <&str, Vec<Box<MessagePart> > >,
-    many1!(
-        alt!(call!(simple_format) |
-             call!(plural_format) |
-             call!(select_format) |
-             call!(plain_text))));
-
-/// Given a set of `MessagePart`s, create a `Message`.
-named!(pub message_parser <&str, Message>,
-    map!(message_parts, Message::new));
+/// The token at the start of `input`: a run of name characters or
+/// a single other character.
+fn token_at(input: &str) -> Option<&str> {
+    let mut chars = input.char_indices();
+    match chars.next() {
+        None => None,
+        Some((_, c)) if is_name_char(c) => {
+            let end = input.find(|c| !is_name_char(c)).unwrap_or(input.len());
+            Some(&input[..end])
+        }
+        Some((_, c)) => Some(&input[..c.len_utf8()]),
+    }
+}
+
+/// Characters that may be used within a variable name.
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Characters that may be used within a select keyword.
+fn is_keyword_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// The selector for an arm of a `plural` format.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PluralSelector {
+    /// An explicit value, like `=0`.
+    Literal(i64),
+    /// One of the plural category keywords, like `one` or `other`.
+    Category(PluralCategory),
+}
+
+/// Map a plural category keyword to a `PluralSelector`.
+fn plural_keyword(keyword: &str) -> Option<PluralSelector> {
+    let category = match keyword {
+        "zero" => PluralCategory::Zero,
+        "one" => PluralCategory::One,
+        "two" => PluralCategory::Two,
+        "few" => PluralCategory::Few,
+        "many" => PluralCategory::Many,
+        "other" => PluralCategory::Other,
+        _ => return None,
+    };
+    Some(PluralSelector::Category(category))
+}
+
+/// Where a (sub-)message appears. This determines which characters
+/// end plain text and which may be quoted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Nesting {
+    /// The top level message. A `}` here is plain text.
+    TopLevel,
+    /// A sub-message of a `select`.
+    Select,
+    /// A sub-message of a `plural`, where `#` is a placeholder.
+    Plural,
+}
+
+/// How deeply sub-messages may be nested. This keeps parsing, and
+/// formatting the parsed message, from overflowing the stack.
+const MAX_DEPTH: usize = 100;
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+    /// How many sub-messages enclose the current position.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Parser<'a> {
+        Parser {
+            source: source,
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn ch_is(&self, ch: char) -> bool {
+        self.peek() == Some(ch)
+    }
+
+    fn get_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.source, self.pos, kind)
+    }
+
+    fn error_at(&self, offset: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.source, offset, kind)
+    }
+
+    fn expected(&self, expected: &str) -> ParseError {
+        self.error(ParseErrorKind::Expected(expected.to_string()))
+    }
+
+    fn expect(&mut self, ch: char, expected: &str) -> Result<(), ParseError> {
+        if self.ch_is(ch) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.expected(expected))
+        }
+    }
+
+    /// Take the longest run of characters matching `pred`.
+    fn take_while<P: Fn(char) -> bool>(&mut self, pred: P) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.bump();
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn parse(&mut self) -> Result<Message, ParseError> {
+        let message = try!(self.get_message(Nesting::TopLevel));
+        debug_assert!(self.peek().is_none());
+        Ok(message)
+    }
+
+    /// Parse message parts up to the end of the input or, within
+    /// a sub-message, an unquoted `}`.
+    fn get_message(&mut self, nesting: Nesting) -> Result<Message, ParseError> {
+        let mut parts: Vec<Box<MessagePart>> = vec![];
+        loop {
+            match self.peek() {
+                None => break,
+                Some('}') if nesting != Nesting::TopLevel => break,
+                Some('{') => parts.push(try!(self.get_argument())),
+                Some('#') if nesting == Nesting::Plural => {
+                    self.bump();
+                    parts.push(Box::new(ast::PlaceholderFormat::new()));
+                }
+                Some(_) => {
+                    let text = self.get_text(nesting);
+                    parts.push(Box::new(ast::PlainText::new(&text)));
+                }
+            }
+        }
+        Ok(Message::new(parts))
+    }
+
+    /// Scan literal text up to the next unquoted format, applying ICU's
+    /// apostrophe quoting rules.
+    ///
+    /// This matches ICU's default `DOUBLE_OPTIONAL` apostrophe mode:
+    ///
+    /// * `''` is always a single apostrophe, whether or not it appears
+    ///   within quoted text.
+    /// * An apostrophe that is immediately followed by a `{` or `}`, or
+    ///   a `#` within a plural sub-message, starts quoted text. The quoted
+    ///   text extends to the next single apostrophe, or the end of the
+    ///   input if there is none.
+    /// * Any other apostrophe is a literal apostrophe.
+    fn get_text(&mut self, nesting: Nesting) -> String {
+        let quotable = if nesting == Nesting::Plural {
+            "{}#"
+        } else {
+            "{}"
+        };
+        let stops = match nesting {
+            Nesting::TopLevel => "{",
+            Nesting::Select => "{}",
+            Nesting::Plural => "{}#",
+        };
+        let mut text = String::new();
+        let mut quoted = false;
+        while let Some(c) = self.peek() {
+            if c == '\'' {
+                self.bump();
+                let next = self.peek();
+                if next == Some('\'') {
+                    text.push('\'');
+                    self.bump();
+                } else if quoted {
+                    quoted = false;
+                } else if next.is_some_and(|next| quotable.contains(next)) {
+                    quoted = true;
+                } else {
+                    text.push('\'');
+                }
+            } else if !quoted && stops.contains(c) {
+                break;
+            } else {
+                text.push(c);
+                self.bump();
+            }
+        }
+        text
+    }
+
+    /// Parse an argument, starting at its opening `{`.
+    fn get_argument(&mut self) -> Result<Box<MessagePart>, ParseError> {
+        let start = self.pos;
+        self.bump();
+        self.get_ws();
+        let name = self.take_while(is_name_char);
+        if name.is_empty() {
+            return Err(self.expected("an argument name"));
+        }
+        self.get_ws();
+
+        let (line, column) = line_column(self.source, start);
+        let unclosed = ParseErrorKind::UnclosedArgument {
+            name: name.to_string(),
+            line: line,
+            column: column,
+        };
+
+        match self.peek() {
+            Some('}') => {
+                self.bump();
+                let part = Box::new(ast::SimpleFormat::new(name));
+                let source = &self.source[start..self.pos];
+                return Ok(Box::new(ast::Argument::new(part, source)));
+            }
+            Some(',') => self.bump(),
+            Some(_) => return Err(self.expected("`,` or `}`")),
+            None => return Err(self.error(unclosed)),
+        }
+        self.get_ws();
+
+        let type_start = self.pos;
+        let arg_type = self.take_while(is_name_char);
+        if arg_type.is_empty() {
+            return Err(self.expected("an argument type"));
+        }
+        let part = match arg_type {
+            "plural" => try!(self.get_plural(name, PluralType::Cardinal, &unclosed)),
+            "selectordinal" => try!(self.get_plural(name, PluralType::Ordinal, &unclosed)),
+            "select" => try!(self.get_select(name, &unclosed)),
+            "number" => try!(self.get_number(name)),
+            "date" => try!(self.get_date_time(name, DateTimeFormatOptions::Date)),
+            "time" => try!(self.get_date_time(name, DateTimeFormatOptions::Time)),
+            "relativetime" => try!(self.get_relative_time(name)),
+            "list" => try!(self.get_list(name)),
+            "duration" => try!(self.get_duration(name)),
+            "spellout" => try!(self.get_rule_based(name, RuleBasedNumberType::Spellout)),
+            "ordinal" => try!(self.get_rule_based(name, RuleBasedNumberType::Ordinal)),
+            _ => {
+                return Err(self.error_at(
+                    type_start,
+                    ParseErrorKind::UnknownArgumentType(arg_type.to_string()),
+                ))
+            }
+        };
+
+        if self.ch_is('}') {
+            self.bump();
+            let source = &self.source[start..self.pos];
+            Ok(Box::new(ast::Argument::new(part, source)))
+        } else {
+            Err(self.error(unclosed))
+        }
+    }
+
+    /// Parse the optional style or `::` skeleton of a `number`
+    /// argument, following the argument type.
+    fn get_number(&mut self, name: &str) -> Result<Box<MessagePart>, ParseError> {
+        self.get_ws();
+        let mut options = NumberFormatOptions::default();
+        if self.ch_is(',') {
+            try!(self.get_style_separator());
+            if self.rest().starts_with("::") {
+                self.pos += 2;
+                let skeleton_start = self.pos;
+                let skeleton = self.take_while(|c| c != '}');
+                if skeleton.trim().is_empty() {
+                    return Err(self.expected("a number skeleton"));
+                }
+                options = try!(NumberFormatOptions::from_skeleton(skeleton).map_err(|e| {
+                    self.error_at(
+                        skeleton_start + e.offset,
+                        ParseErrorKind::InvalidNumberSkeleton(e.token),
+                    )
+                }));
+                return Ok(Box::new(ast::NumberFormat::new(name, options)));
+            }
+            let style_start = self.pos;
+            let style = self.take_while(is_name_char);
+            options = NumberFormatOptions::new(match style {
+                "integer" => NumberStyle::Integer,
+                "percent" => NumberStyle::Percent,
+                "currency" => NumberStyle::Currency,
+                "" => return Err(self.expected("a number style")),
+                _ => {
+                    return Err(self.error_at(
+                        style_start,
+                        ParseErrorKind::UnknownNumberStyle(style.to_string()),
+                    ))
+                }
+            });
+            self.get_ws();
+        }
+        Ok(Box::new(ast::NumberFormat::new(name, options)))
+    }
+
+    /// Parse the optional style, `::` skeleton or pattern of a `date`
+    /// or `time` argument, following the argument type. `keyword`
+    /// makes the options for a keyword style.
+    fn get_date_time(
+        &mut self,
+        name: &str,
+        keyword: fn(DateTimeStyle) -> DateTimeFormatOptions,
+    ) -> Result<Box<MessagePart>, ParseError> {
+        self.get_ws();
+        if !self.ch_is(',') {
+            let options = keyword(DateTimeStyle::Medium);
+            return Ok(Box::new(ast::DateTimeFormat::new(name, options)));
+        }
+        try!(self.get_style_separator());
+        let style_start = self.pos;
+        let style = self.take_while(|c| c != '}').trim_end();
+        let options = match style {
+            "short" => keyword(DateTimeStyle::Short),
+            "medium" => keyword(DateTimeStyle::Medium),
+            "long" => keyword(DateTimeStyle::Long),
+            "full" => keyword(DateTimeStyle::Full),
+            "" => return Err(self.expected("a date/time style")),
+            "::" => return Err(self.expected("a date/time skeleton")),
+            _ => match style.strip_prefix("::") {
+                Some(skeleton) => {
+                    try!(DateTimeFormatOptions::from_skeleton(skeleton).map_err(|e| {
+                        self.error_at(
+                            style_start + 2 + e.offset,
+                            ParseErrorKind::InvalidDateTimeSkeleton(e.field),
+                        )
+                    }))
+                }
+                None => try!(DateTimeFormatOptions::from_pattern(style).map_err(|e| {
+                    self.error_at(
+                        style_start + e.offset,
+                        ParseErrorKind::InvalidDateTimePattern(e.field),
+                    )
+                })),
+            },
+        };
+        Ok(Box::new(ast::DateTimeFormat::new(name, options)))
+    }
+
+    /// Parse the unit and options of a `relativetime` argument, like
+    /// `day short auto`, following the argument type.
+    fn get_relative_time(&mut self, name: &str) -> Result<Box<MessagePart>, ParseError> {
+        try!(self.get_style_separator());
+        let unit_start = self.pos;
+        let unit = self.take_while(is_name_char);
+        let mut options = RelativeTimeFormatOptions::new(match unit {
+            "year" => RelativeTimeUnit::Year,
+            "quarter" => RelativeTimeUnit::Quarter,
+            "month" => RelativeTimeUnit::Month,
+            "week" => RelativeTimeUnit::Week,
+            "day" => RelativeTimeUnit::Day,
+            "hour" => RelativeTimeUnit::Hour,
+            "minute" => RelativeTimeUnit::Minute,
+            "second" => RelativeTimeUnit::Second,
+            "" => return Err(self.expected("a relative time unit")),
+            _ => {
+                return Err(self.error_at(
+                    unit_start,
+                    ParseErrorKind::UnknownRelativeTimeStyle(unit.to_string()),
+                ))
+            }
+        });
+        self.get_ws();
+        while !self.ch_is('}') && self.peek().is_some() {
+            let option_start = self.pos;
+            let option = self.take_while(|c| c != '}' && !c.is_whitespace());
+            match option {
+                "long" => options.style = RelativeTimeStyle::Long,
+                "short" => options.style = RelativeTimeStyle::Short,
+                "narrow" => options.style = RelativeTimeStyle::Narrow,
+                "numeric" => options.numeric = RelativeTimeNumeric::Always,
+                "auto" => options.numeric = RelativeTimeNumeric::Auto,
+                _ => {
+                    return Err(self.error_at(
+                        option_start,
+                        ParseErrorKind::UnknownRelativeTimeStyle(option.to_string()),
+                    ))
+                }
+            }
+            self.get_ws();
+        }
+        Ok(Box::new(ast::RelativeTimeFormat::new(name, options)))
+    }
+
+    /// Parse the optional type and width of a `list` argument, like
+    /// `disjunction short`, following the argument type.
+    fn get_list(&mut self, name: &str) -> Result<Box<MessagePart>, ParseError> {
+        self.get_ws();
+        let mut options = ListFormatOptions::default();
+        if !self.ch_is(',') {
+            return Ok(Box::new(ast::ListFormat::new(name, options)));
+        }
+        try!(self.get_style_separator());
+        if self.ch_is('}') {
+            return Err(self.expected("a list style"));
+        }
+        while !self.ch_is('}') && self.peek().is_some() {
+            let style_start = self.pos;
+            let style = self.take_while(|c| c != '}' && !c.is_whitespace());
+            match style {
+                "conjunction" => options.list_type = ListType::Conjunction,
+                "disjunction" => options.list_type = ListType::Disjunction,
+                "unit" => options.list_type = ListType::Unit,
+                "long" => options.style = ListStyle::Long,
+                "short" => options.style = ListStyle::Short,
+                "narrow" => options.style = ListStyle::Narrow,
+                _ => {
+                    return Err(self.error_at(
+                        style_start,
+                        ParseErrorKind::UnknownListStyle(style.to_string()),
+                    ))
+                }
+            }
+            self.get_ws();
+        }
+        Ok(Box::new(ast::ListFormat::new(name, options)))
+    }
+
+    /// Parse the optional style and units of a `duration` argument,
+    /// like `digital smallest/minute`, or its rule set, like
+    /// `%with-words`, following the argument type.
+    fn get_duration(&mut self, name: &str) -> Result<Box<MessagePart>, ParseError> {
+        self.get_ws();
+        let mut options = DurationFormatOptions::default();
+        if !self.ch_is(',') {
+            return Ok(Box::new(ast::DurationFormat::new(name, options)));
+        }
+        try!(self.get_style_separator());
+        if self.ch_is('%') {
+            return self.get_rule_set(name, RuleBasedNumberType::Duration);
+        }
+        if self.ch_is('}') {
+            return Err(self.expected("a duration style"));
+        }
+        while !self.ch_is('}') && self.peek().is_some() {
+            let style_start = self.pos;
+            let style = self.take_while(|c| c != '}' && !c.is_whitespace());
+            let unit = |unit| match unit {
+                "week" => Some(DurationUnit::Week),
+                "day" => Some(DurationUnit::Day),
+                "hour" => Some(DurationUnit::Hour),
+                "minute" => Some(DurationUnit::Minute),
+                "second" => Some(DurationUnit::Second),
+                "millisecond" => Some(DurationUnit::Millisecond),
+                _ => None,
+            };
+            let mut parts = style.splitn(2, '/');
+            match (parts.next(), parts.next().and_then(unit)) {
+                (Some("long"), None) => options.style = DurationStyle::Long,
+                (Some("short"), None) => options.style = DurationStyle::Short,
+                (Some("narrow"), None) => options.style = DurationStyle::Narrow,
+                (Some("digital"), None) => options.style = DurationStyle::Digital,
+                (Some("largest"), Some(unit)) => options.largest_unit = unit,
+                (Some("smallest"), Some(unit)) => options.smallest_unit = unit,
+                _ => {
+                    return Err(self.error_at(
+                        style_start,
+                        ParseErrorKind::UnknownDurationStyle(style.to_string()),
+                    ))
+                }
+            }
+            self.get_ws();
+        }
+        Ok(Box::new(ast::DurationFormat::new(name, options)))
+    }
+
+    /// Parse the optional rule set of a `spellout` or `ordinal`
+    /// argument, like `%spellout-ordinal`, following the argument type.
+    fn get_rule_based(
+        &mut self,
+        name: &str,
+        rule_type: RuleBasedNumberType,
+    ) -> Result<Box<MessagePart>, ParseError> {
+        self.get_ws();
+        if !self.ch_is(',') {
+            let options = RuleBasedNumberFormatOptions::new(rule_type);
+            return Ok(Box::new(ast::RuleBasedNumberFormat::new(name, options)));
+        }
+        try!(self.get_style_separator());
+        self.get_rule_set(name, rule_type)
+    }
+
+    /// Parse the name of a rule set, like `%spellout-ordinal`, which
+    /// must be defined by some locale's rules of the given type.
+    fn get_rule_set(
+        &mut self,
+        name: &str,
+        rule_type: RuleBasedNumberType,
+    ) -> Result<Box<MessagePart>, ParseError> {
+        let rule_set_start = self.pos;
+        let rule_set = self.take_while(|c| c != '}' && !c.is_whitespace());
+        if rule_set.is_empty() {
+            return Err(self.expected("a rule set"));
+        }
+        let known = rule_type.rule_set_names();
+        if !known.iter().any(|known| known == rule_set) {
+            return Err(self.error_at(
+                rule_set_start,
+                ParseErrorKind::UnknownRuleSet(rule_set.to_string()),
+            ));
+        }
+        let options = RuleBasedNumberFormatOptions {
+            rule_type: rule_type,
+            rule_set: Some(rule_set.to_string()),
+        };
+        self.get_ws();
+        Ok(Box::new(ast::RuleBasedNumberFormat::new(name, options)))
+    }
+
+    /// Parse the `,` that separates the argument type from its arms.
+    fn get_style_separator(&mut self) -> Result<(), ParseError> {
+        self.get_ws();
+        try!(self.expect(',', "`,`"));
+        self.get_ws();
+        Ok(())
+    }
+
+    /// Parse a sub-message, starting at its opening `{`.
+    fn get_sub_message(&mut self, selector: &str, nesting: Nesting) -> Result<Message, ParseError> {
+        let start = self.pos;
+        try!(self.expect(
+            '{',
+            &format!("`{{` to start the `{}` sub-message", selector),
+        ));
+        if self.depth == MAX_DEPTH {
+            return Err(self.error_at(start, ParseErrorKind::TooDeeplyNested));
+        }
+        self.depth += 1;
+        let message = try!(self.get_message(nesting));
+        self.depth -= 1;
+        if self.ch_is('}') {
+            self.bump();
+            Ok(message)
+        } else {
+            let (line, column) = line_column(self.source, start);
+            Err(self.error(ParseErrorKind::UnclosedSubMessage {
+                selector: selector.to_string(),
+                line: line,
+                column: column,
+            }))
+        }
+    }
+
+    /// Parse the body of a `plural` or `selectordinal` argument,
+    /// following the argument type.
+    fn get_plural(
+        &mut self,
+        name: &str,
+        plural_type: PluralType,
+        unclosed: &ParseErrorKind,
+    ) -> Result<Box<MessagePart>, ParseError> {
+        try!(self.get_style_separator());
+
+        let mut offset = None;
+        if self.rest().starts_with("offset:") {
+            self.pos += "offset:".len();
+            self.get_ws();
+            offset = Some(try!(self.get_integer()));
+            self.get_ws();
+        }
+
+        let mut other = None;
+        let mut arms = vec![];
+        while !self.ch_is('}') && self.peek().is_some() {
+            let selector_start = self.pos;
+            let (selector, selector_text) = if self.ch_is('=') {
+                self.bump();
+                let value = try!(self.get_integer());
+                (PluralSelector::Literal(value), format!("={}", value))
+            } else {
+                let keyword = self.take_while(is_keyword_char);
+                if keyword.is_empty() {
+                    return Err(self.expected("a plural selector or `}`"));
+                }
+                match plural_keyword(keyword) {
+                    Some(selector) => (selector, keyword.to_string()),
+                    None => {
+                        return Err(self.error_at(
+                            selector_start,
+                            ParseErrorKind::UnknownPluralKeyword(keyword.to_string()),
+                        ))
+                    }
+                }
+            };
+            if arms.iter().any(|&(s, _)| s == selector)
+                || (selector == PluralSelector::Category(PluralCategory::Other) && other.is_some())
+            {
+                return Err(self.error_at(
+                    selector_start,
+                    ParseErrorKind::DuplicateSelector(selector_text),
+                ));
+            }
+            self.get_ws();
+            let message = try!(self.get_sub_message(&selector_text, Nesting::Plural));
+            self.get_ws();
+            if selector == PluralSelector::Category(PluralCategory::Other) {
+                other = Some(message);
+            } else {
+                arms.push((selector, message));
+            }
+        }
+
+        if self.peek().is_none() {
+            return Err(self.error(unclosed.clone()));
+        }
+        let other = match other {
+            Some(other) => other,
+            None => {
+                return Err(self.error(ParseErrorKind::MissingOther {
+                    name: name.to_string(),
+                }))
+            }
+        };
+        let mut fmt = match plural_type {
+            PluralType::Cardinal => ast::PluralFormat::new(name, other),
+            PluralType::Ordinal => ast::PluralFormat::new_ordinal(name, other),
+        };
+        if let Some(offset) = offset {
+            fmt.offset(offset);
+        }
+        for (selector, message) in arms {
+            match selector {
+                PluralSelector::Literal(value) => fmt.literal(value, message),
+                PluralSelector::Category(PluralCategory::Zero) => fmt.zero(message),
+                PluralSelector::Category(PluralCategory::One) => fmt.one(message),
+                PluralSelector::Category(PluralCategory::Two) => fmt.two(message),
+                PluralSelector::Category(PluralCategory::Few) => fmt.few(message),
+                PluralSelector::Category(PluralCategory::Many) => fmt.many(message),
+                PluralSelector::Category(PluralCategory::Other) => unreachable!(),
+            }
+        }
+        Ok(Box::new(fmt))
+    }
+
+    /// Parse the body of a `select` argument, following the `select`
+    /// keyword.
+    fn get_select(
+        &mut self,
+        name: &str,
+        unclosed: &ParseErrorKind,
+    ) -> Result<Box<MessagePart>, ParseError> {
+        try!(self.get_style_separator());
+
+        let mut other = None;
+        let mut arms: Vec<(&str, Message)> = vec![];
+        while !self.ch_is('}') && self.peek().is_some() {
+            let selector_start = self.pos;
+            let selector = self.take_while(is_keyword_char);
+            if selector.is_empty() {
+                return Err(self.expected("a select keyword or `}`"));
+            }
+            if arms.iter().any(|&(s, _)| s == selector) || (selector == "other" && other.is_some())
+            {
+                return Err(self.error_at(
+                    selector_start,
+                    ParseErrorKind::DuplicateSelector(selector.to_string()),
+                ));
+            }
+            self.get_ws();
+            let message = try!(self.get_sub_message(selector, Nesting::Select));
+            self.get_ws();
+            if selector == "other" {
+                other = Some(message);
+            } else {
+                arms.push((selector, message));
+            }
+        }
+
+        if self.peek().is_none() {
+            return Err(self.error(unclosed.clone()));
+        }
+        let other = match other {
+            Some(other) => other,
+            None => {
+                return Err(self.error(ParseErrorKind::MissingOther {
+                    name: name.to_string(),
+                }))
+            }
+        };
+        let mut fmt = ast::SelectFormat::new(name, other);
+        for (selector, message) in arms {
+            fmt.map(selector, message);
+        }
+        Ok(Box::new(fmt))
+    }
+
+    /// Parse an integer, possibly negative.
+    fn get_integer(&mut self) -> Result<i64, ParseError> {
+        let start = self.pos;
+        if self.ch_is('-') {
+            self.bump();
+        }
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            self.pos = start;
+            return Err(self.expected("an integer"));
+        }
+        self.source[start..self.pos].parse().map_err(|_| {
+            self.error_at(
+                start,
+                ParseErrorKind::Expected("a smaller integer".to_string()),
+            )
+        })
+    }
+}
 
 /// Parse some text and hopefully return a [`Message`].
 ///
 /// [`Message`]: ../struct.Message.html
 pub fn parse(message: &str) -> Result<Message, ParseError> {
-    match message_parser(message) {
-        IResult::Error(_) | IResult::Incomplete(_) => Err(ParseError::NotImplemented),
-        IResult::Done(_, m) => Ok(m),
-    }
+    Parser::new(message).parse()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use {arg, Context};
-    use nom::IResult;
+    use std::time::Duration;
+    use {arg, Context, DateTime};
 
     #[test]
     fn it_works() {
@@ -125,34 +1025,663 @@ mod tests {
 
     #[test]
     fn incomplete_fails() {
-        match message_parser("{name") {
-            IResult::Incomplete(_) => {}
-            IResult::Error(e) => panic!("Expected incomplete failure: Got {}", e),
-            IResult::Done(_, _) => panic!("Expected incomplete failure, but succeeded."),
+        match parse("{name") {
+            Err(ParseError {
+                kind: ParseErrorKind::UnclosedArgument { .. },
+                ..
+            }) => {}
+            Err(e) => panic!("Expected incomplete failure: Got {}", e),
+            Ok(_) => panic!("Expected incomplete failure, but succeeded."),
         }
     }
 
     #[test]
     fn all_text_works() {
-        match message_parser("Hello, world!") {
-            IResult::Done(_, _) => {}
-            _ => panic!("Expected successful parse."),
+        match parse("Hello, world!") {
+            Ok(_) => {}
+            Err(e) => panic!("Expected successful parse: Got {}", e),
         }
     }
 
     #[test]
     fn plural_format_works() {
-        match message_parser("{count,plural}") {
-            IResult::Done(_, _) => {}
-            _ => panic!("Expected successful parse."),
+        match parse("{count,plural,other{Many}}") {
+            Ok(_) => {}
+            Err(e) => panic!("Expected successful parse: Got {}", e),
         }
     }
 
+    #[test]
+    fn plural_arms_work() {
+        let ctx = Context::default();
+        let m = parse("{count, plural, =0 {none} one {one item} other {many items}}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("count", 0))), "none");
+        assert_eq!(ctx.format(&m, Some(&arg("count", 1))), "one item");
+        assert_eq!(ctx.format(&m, Some(&arg("count", 5))), "many items");
+    }
+
+    #[test]
+    fn nested_plural_works() {
+        let ctx = Context::default();
+        let m = parse("{a, plural, one {{b, plural, one {1/1} other {1/{b}}}} other {{a}/{b}}}")
+            .unwrap();
+        let args = arg("a", 1);
+        let args = args.arg("b", 1);
+        assert_eq!(ctx.format(&m, Some(&args)), "1/1");
+        let args = arg("a", 1);
+        let args = args.arg("b", 4);
+        assert_eq!(ctx.format(&m, Some(&args)), "1/4");
+        let args = arg("a", 2);
+        let args = args.arg("b", 4);
+        assert_eq!(ctx.format(&m, Some(&args)), "2/4");
+    }
+
+    #[test]
+    fn plural_requires_other() {
+        assert!(parse("{count, plural, one {one item}}").is_err());
+    }
+
+    #[test]
+    fn plural_rejects_duplicate_arms() {
+        assert!(parse("{count, plural, one {a} one {b} other {c}}").is_err());
+        assert!(parse("{count, plural, =1 {a} =1 {b} other {c}}").is_err());
+    }
+
+    #[test]
+    fn plural_rejects_unknown_keywords() {
+        assert!(parse("{count, plural, some {a} other {c}}").is_err());
+    }
+
+    #[test]
+    fn trailing_garbage_fails() {
+        assert!(parse("Hello {name").is_err());
+        assert!(parse("Hello {name, plural, other}").is_err());
+    }
+
+    #[test]
+    fn plural_offset_works() {
+        let ctx = Context::default();
+        let m = parse(
+            "{n, plural, offset:1 =0 {nobody} =1 {{host}} \
+             one {{host} and # other} other {{host} and # others}}",
+        )
+        .unwrap();
+        let format = |n: i64| {
+            let args = arg("n", n);
+            let args = args.arg("host", "Ann");
+            ctx.format(&m, Some(&args))
+        };
+        assert_eq!(format(0), "nobody");
+        assert_eq!(format(1), "Ann");
+        assert_eq!(format(2), "Ann and 1 other");
+        assert_eq!(format(3), "Ann and 2 others");
+    }
+
+    #[test]
+    fn placeholder_works() {
+        let ctx = Context::default();
+        let m = parse("{count, plural, one {# item} other {# items}}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("count", 1))), "1 item");
+        assert_eq!(ctx.format(&m, Some(&arg("count", 7))), "7 items");
+        assert_eq!(ctx.format(&m, Some(&arg("count", 1234))), "1,234 items");
+
+        // `#` is formatted for the locale, like `{count, number}`.
+        let ctx = Context::new("de".parse().unwrap(), None);
+        assert_eq!(ctx.format(&m, Some(&arg("count", 1234.5))), "1.234,5 items");
+        let ctx = Context::new("ar".parse().unwrap(), None);
+        assert_eq!(ctx.format(&m, Some(&arg("count", 1234))), "١٬٢٣٤ items");
+    }
+
+    #[test]
+    fn hash_outside_plural_is_text() {
+        let ctx = Context::default();
+        let m = parse("Item #{id}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("id", 4))), "Item #4");
+
+        let m = parse("{kind, select, other {#{id}}}").unwrap();
+        let args = arg("kind", "x");
+        let args = args.arg("id", 4);
+        assert_eq!(ctx.format(&m, Some(&args)), "#4");
+
+        // A `#` in a select nested within a plural is also plain text.
+        let m = parse("{count, plural, other {{kind, select, other {#}}}}").unwrap();
+        let args = arg("count", 2);
+        let args = args.arg("kind", "x");
+        assert_eq!(ctx.format(&m, Some(&args)), "#");
+    }
+
+    #[test]
+    fn apostrophes_work() {
+        let ctx = Context::default();
+        let format = |text: &str| ctx.format(&parse(text).unwrap(), Some(&arg("n", 2)));
+        assert_eq!(format("Don't use '{braces}'"), "Don't use {braces}");
+        assert_eq!(format("It''s {n}"), "It's 2");
+        assert_eq!(format("'{'n'}'"), "{n}");
+        assert_eq!(format("'{n} isn''t a format'"), "{n} isn't a format");
+        assert_eq!(format("JSON: '{\"a\": {n}}'"), "JSON: {\"a\": {n}}");
+        assert_eq!(format("unclosed '{quote"), "unclosed {quote");
+        assert_eq!(format("a}b"), "a}b");
+        assert_eq!(format("'#' {n}"), "'#' 2");
+        assert_eq!(format("{n, plural, other {'#' is #}}"), "# is 2");
+        assert_eq!(format("{n, plural, other {'}'}}"), "}");
+    }
+
+    #[test]
+    fn selectordinal_works() {
+        let ctx = Context::default();
+        let m = parse("{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}").unwrap();
+        let format = |place: i64| ctx.format(&m, Some(&arg("place", place)));
+        assert_eq!(format(1), "1st");
+        assert_eq!(format(2), "2nd");
+        assert_eq!(format(3), "3rd");
+        assert_eq!(format(4), "4th");
+        assert_eq!(format(11), "11th");
+        assert_eq!(format(12), "12th");
+        assert_eq!(format(13), "13th");
+        assert_eq!(format(21), "21st");
+        assert_eq!(format(102), "102nd");
+    }
+
     #[test]
     fn select_format_works() {
-        match message_parser("{type,select}") {
-            IResult::Done(_, _) => {}
-            _ => panic!("Expected successful parse."),
+        match parse("{type,select,other{Other}}") {
+            Ok(_) => {}
+            Err(e) => panic!("Expected successful parse: Got {}", e),
         }
     }
+
+    #[test]
+    fn select_arms_work() {
+        let ctx = Context::default();
+        let m = parse("{gender, select, male {He} female {She} other {They}} left.").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("gender", "male"))), "He left.");
+        assert_eq!(ctx.format(&m, Some(&arg("gender", "female"))), "She left.");
+        assert_eq!(
+            ctx.format(&m, Some(&arg("gender", "unknown"))),
+            "They left."
+        );
+    }
+
+    #[test]
+    fn nested_select_works() {
+        let ctx = Context::default();
+        let m = parse(
+            "{gender, select, \
+             female {{count, plural, one {She has one cat.} other {She has {count} cats.}}} \
+             other {{name} has {count, plural, one {one cat} other {{count} cats}}.}}",
+        )
+        .unwrap();
+        let args = arg("gender", "female");
+        let args = args.arg("count", 3);
+        assert_eq!(ctx.format(&m, Some(&args)), "She has 3 cats.");
+        let args = arg("gender", "male");
+        let args = args.arg("count", 1);
+        let args = args.arg("name", "Bob");
+        assert_eq!(ctx.format(&m, Some(&args)), "Bob has one cat.");
+    }
+
+    #[test]
+    fn select_requires_other() {
+        assert!(parse("{gender, select, male {He} female {She}}").is_err());
+    }
+
+    #[test]
+    fn select_rejects_duplicate_arms() {
+        assert!(parse("{gender, select, male {He} male {Him} other {They}}").is_err());
+    }
+
+    #[test]
+    fn number_format_works() {
+        let ctx = Context::new("de".parse().unwrap(), None);
+        let m = parse("{n, number} / {n,number,integer} / {n , number , percent }").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("n", 1234.5))),
+            "1.234,5 / 1.234 / 123.450\u{a0}%"
+        );
+
+        let m = parse("{total, number, currency}").unwrap();
+        let ctx = Context::new("en-IN".parse().unwrap(), None);
+        assert_eq!(
+            ctx.format(&m, Some(&arg("total", 1234567))),
+            "₹12,34,567.00"
+        );
+    }
+
+    #[test]
+    fn number_style_errors() {
+        let e = parse("{n, number, ordinal}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownNumberStyle("ordinal".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 13));
+
+        let e = parse("{n, number, }").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::Expected("a number style".to_string())
+        );
+
+        assert!(parse("{n, number, integer, other}").is_err());
+    }
+
+    #[test]
+    fn number_skeletons_work() {
+        let ctx = Context::default();
+        let m = parse("{price, number, ::currency/EUR precision-currency-cash}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("price", 3.456))), "€3.46");
+
+        let m = parse("{ratio, number, ::percent .00 group-off }").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("ratio", 1234.5))), "1234.50%");
+
+        let m = parse("{n, number, ::%x100 sign-always}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("n", 0.5))), "+50%");
+
+        let m = parse("{d, number, ::unit/kilometer unit-width-full-name}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("d", 1))), "1 kilometer");
+        assert_eq!(ctx.format(&m, Some(&arg("d", 5))), "5 kilometers");
+
+        let m = parse("{n, number, ::compact-long}").unwrap();
+        let ctx = Context::new("de".parse().unwrap(), None);
+        assert_eq!(ctx.format(&m, Some(&arg("n", 1000000))), "1 Million");
+        assert_eq!(ctx.format(&m, Some(&arg("n", 3400000))), "3,4 Millionen");
+    }
+
+    #[test]
+    fn number_skeleton_errors() {
+        let e = parse("{n, number, ::percent .00 bogus/1}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::InvalidNumberSkeleton("bogus/1".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 27));
+
+        let e = parse("{n, number, ::compact-medium}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::InvalidNumberSkeleton("compact-medium".to_string())
+        );
+
+        assert!(parse("{n, number, ::percent").is_err());
+
+        let e = parse("{n, number, :: }").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::Expected("a number skeleton".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 16));
+
+        let e = parse("{n, number, ::percent percent}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::InvalidNumberSkeleton("percent".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 23));
+        assert!(parse("{n, number, ::.00 .00}").is_err());
+        assert!(parse("{n, number, ::currency/usd}").is_err());
+    }
+
+    #[test]
+    fn date_time_format_works() {
+        let ctx = Context::default();
+        let d = DateTime::new(1_709_647_629_000);
+
+        let m = parse("{d, date} / {d, date, short} / {d,time,short}").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("d", d))),
+            "Mar 5, 2024 / 3/5/24 / 2:07\u{202f}PM"
+        );
+
+        let m = parse("{d, date, ::yMMMMEEEEd} {d, time, ::Hmm }").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("d", d))),
+            "Tuesday, March 5, 2024 14:07"
+        );
+
+        let m = parse("{d, date, EEE d MMM y 'at' HH:mm} {d, time}").unwrap();
+        let ctx = Context::new("de".parse().unwrap(), None);
+        assert_eq!(
+            ctx.format(&m, Some(&arg("d", d))),
+            "Di. 5 März 2024 at 14:07 14:07:09"
+        );
+    }
+
+    #[test]
+    fn date_time_style_errors() {
+        let e = parse("{d, date, ::yMMMqd}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::InvalidDateTimeSkeleton("q".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 17));
+
+        let e = parse("{d, time, HH:mm bb}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::InvalidDateTimePattern("bb".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 17));
+
+        let e = parse("{d, date, }").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::Expected("a date/time style".to_string())
+        );
+
+        let e = parse("{d, date, ::}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::Expected("a date/time skeleton".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 13));
+
+        assert!(parse("{d, date, 'at}").is_err());
+        assert!(parse("{d, date, short").is_err());
+    }
+
+    #[test]
+    fn relative_time_format_works() {
+        let ctx = Context::default();
+
+        let m = parse("Updated {delta, relativetime, day}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("delta", -1))), "Updated 1 day ago");
+        assert_eq!(ctx.format(&m, Some(&arg("delta", 3))), "Updated in 3 days");
+
+        let m = parse("{delta, relativetime, day auto}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("delta", -1))), "yesterday");
+
+        let m = parse("{ delta , relativetime , hour short numeric }").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("delta", -2))), "2 hr. ago");
+
+        let m = parse("{delta, relativetime, week narrow auto}").unwrap();
+        let ctx = Context::new("ru".parse().unwrap(), None);
+        assert_eq!(ctx.format(&m, Some(&arg("delta", 0))), "на этой нед.");
+        assert_eq!(ctx.format(&m, Some(&arg("delta", 5))), "через 5 нед.");
+    }
+
+    #[test]
+    fn relative_time_style_errors() {
+        let e = parse("{delta, relativetime, days}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownRelativeTimeStyle("days".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 23));
+
+        let e = parse("{delta, relativetime, day wide}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownRelativeTimeStyle("wide".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 27));
+
+        let e = parse("{delta, relativetime, }").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::Expected("a relative time unit".to_string())
+        );
+
+        assert!(parse("{delta, relativetime}").is_err());
+        assert!(parse("{delta, relativetime, day").is_err());
+    }
+
+    #[test]
+    fn list_format_works() {
+        let ctx = Context::default();
+        let names = vec!["Ana", "Ben", "Chloé"];
+
+        let m = parse("{names, list} / {names, list, disjunction}").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("names", names.clone()))),
+            "Ana, Ben, and Chloé / Ana, Ben, or Chloé"
+        );
+
+        let m = parse("{names, list, conjunction short}").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("names", names.clone()))),
+            "Ana, Ben, & Chloé"
+        );
+
+        let m = parse("{ names , list , unit narrow }").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("names", vec!["5 ft", "2 in"]))),
+            "5 ft 2 in"
+        );
+
+        let m = parse("{names, list, conjunction}").unwrap();
+        let ctx = Context::new("es".parse().unwrap(), None);
+        assert_eq!(
+            ctx.format(&m, Some(&arg("names", vec!["Pablo", "Isabel"]))),
+            "Pablo e Isabel"
+        );
+    }
+
+    #[test]
+    fn list_style_errors() {
+        let e = parse("{names, list, and}").unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::UnknownListStyle("and".to_string()));
+        assert_eq!((e.line, e.column), (1, 15));
+
+        let e = parse("{names, list, disjunction wide}").unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::UnknownListStyle("wide".to_string()));
+        assert_eq!((e.line, e.column), (1, 27));
+
+        let e = parse("{names, list, }").unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::Expected("a list style".to_string()));
+
+        assert!(parse("{names, list, unit").is_err());
+    }
+
+    #[test]
+    fn duration_format_works() {
+        let ctx = Context::default();
+        let elapsed = Duration::from_secs(3907);
+
+        let m = parse("Took {elapsed, duration}").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("elapsed", elapsed))),
+            "Took 1 hr, 5 min, 7 sec"
+        );
+
+        let m = parse("{elapsed, duration, long smallest/minute}").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("elapsed", elapsed))),
+            "1 hour, 5 minutes"
+        );
+
+        let m = parse("{ elapsed , duration , digital largest/minute }").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("elapsed", elapsed))), "65:07");
+
+        let m = parse("{elapsed, duration, narrow}").unwrap();
+        let ctx = Context::new("de".parse().unwrap(), None);
+        assert_eq!(ctx.format(&m, Some(&arg("elapsed", 90))), "1 Min. 30 Sek.");
+    }
+
+    #[test]
+    fn duration_style_errors() {
+        let e = parse("{elapsed, duration, hours}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownDurationStyle("hours".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 21));
+
+        let e = parse("{elapsed, duration, long largest/year}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownDurationStyle("largest/year".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 26));
+
+        let e = parse("{elapsed, duration, }").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::Expected("a duration style".to_string())
+        );
+
+        assert!(parse("{elapsed, duration, long").is_err());
+    }
+
+    #[test]
+    fn rule_based_number_format_works() {
+        let ctx = Context::default();
+
+        let m = parse("{n, spellout} / {n, spellout, %spellout-ordinal} / {n, ordinal}").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("n", 23))),
+            "twenty-three / twenty-third / 23rd"
+        );
+
+        let m = parse("{ elapsed , duration , %with-words }").unwrap();
+        assert_eq!(
+            ctx.format(&m, Some(&arg("elapsed", Duration::from_secs(3907)))),
+            "1 hr., 5 min., 7 sec."
+        );
+
+        let m = parse("{elapsed, duration, %duration}").unwrap();
+        assert_eq!(ctx.format(&m, Some(&arg("elapsed", 3907))), "1:05:07");
+
+        let m = parse(
+            "{n, spellout, %spellout-cardinal-feminine} {n, ordinal, %digits-ordinal-feminine}",
+        )
+        .unwrap();
+        let ctx = Context::new("es".parse().unwrap(), None);
+        assert_eq!(ctx.format(&m, Some(&arg("n", 21))), "veintiuna 21ª");
+        let ctx = Context::new("fr".parse().unwrap(), None);
+        assert_eq!(ctx.format(&m, Some(&arg("n", 21))), "vingt-et-une 21e");
+    }
+
+    #[test]
+    fn rule_set_errors() {
+        let e = parse("{n, spellout, %spellout-roman}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownRuleSet("%spellout-roman".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 15));
+
+        // Private rule sets and rule sets of other types can't be used.
+        let e = parse("{n, spellout, %%tieth}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownRuleSet("%%tieth".to_string())
+        );
+        let e = parse("{n, ordinal, %spellout-ordinal}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownRuleSet("%spellout-ordinal".to_string())
+        );
+
+        let e = parse("{n, spellout, }").unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::Expected("a rule set".to_string()));
+
+        assert!(parse("{n, ordinal, %digits-ordinal").is_err());
+    }
+
+    #[test]
+    fn error_positions() {
+        let e = parse("Hi {count, plural, one {# item}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnclosedArgument {
+                name: "count".to_string(),
+                line: 1,
+                column: 4,
+            }
+        );
+        assert_eq!(e.offset, 31);
+        assert_eq!((e.line, e.column), (1, 32));
+        assert_eq!(e.found, None);
+
+        let e = parse("Line one\n{n, plural, some {x} other {y}}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownPluralKeyword("some".to_string())
+        );
+        assert_eq!(e.offset, 21);
+        assert_eq!((e.line, e.column), (2, 13));
+        assert_eq!(e.found, Some("some".to_string()));
+
+        let e = parse("{n, frobnicate}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnknownArgumentType("frobnicate".to_string())
+        );
+        assert_eq!((e.line, e.column), (1, 5));
+
+        let e = parse("{n, select, a {x} other {y} a {z}}").unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::DuplicateSelector("a".to_string()));
+        assert_eq!((e.line, e.column), (1, 29));
+
+        let e = parse("{n, select, a {x}}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::MissingOther {
+                name: "n".to_string(),
+            }
+        );
+        assert_eq!((e.line, e.column), (1, 18));
+
+        let e = parse("{n, plural, other {x}").unwrap_err();
+        assert_eq!(e.offset, 21);
+
+        let e = parse("{n, plural, other {x").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::UnclosedSubMessage {
+                selector: "other".to_string(),
+                line: 1,
+                column: 19,
+            }
+        );
+
+        let e = parse("{}").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ParseErrorKind::Expected("an argument name".to_string())
+        );
+        assert_eq!(e.found, Some("}".to_string()));
+
+        let e = parse("{a b}").unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::Expected("`,` or `}`".to_string()));
+        assert_eq!(e.found, Some("b".to_string()));
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |depth: usize| "{x, select, other {".repeat(depth) + &"}}".repeat(depth);
+        let m = parse(&nested(100)).unwrap();
+        let ctx = Context::default();
+        assert_eq!(ctx.format(&m, Some(&arg("x", "y"))), "");
+
+        let e = parse(&nested(101)).unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::TooDeeplyNested);
+        assert_eq!(e.offset, 100 * 19 + 18);
+
+        let e = parse(&nested(2000)).unwrap_err();
+        assert_eq!(e.kind, ParseErrorKind::TooDeeplyNested);
+        assert!(e
+            .to_string()
+            .starts_with("1:1919: sub-messages nested more than 100 deep"));
+    }
+
+    #[test]
+    fn error_display() {
+        let e = parse("{count, plural, other {# items}").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "1:32: expected `}` to close argument `count` opened at 1:1, found end of input\n\
+             {count, plural, other {# items}\n\
+             \x20                              ^"
+        );
+
+        let e = parse("Hello\n{n, plural, some {x} other {y}}\nBye").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "2:13: `some` is not a plural category; expected `zero`, `one`, `two`, \
+             `few`, `many`, `other` or `=N`\n\
+             {n, plural, some {x} other {y}}\n\
+             \x20           ^^^^"
+        );
+    }
 }
@@ -91,6 +91,29 @@
 //! For now, this stores the locale that is being used, but in the future,
 //! it will be used for additional features.
 //!
+//! The locale determines the plural rules used when formatting `plural`
+//! and `selectordinal` messages:
+//!
+//! ```
+//! #[macro_use]
+//! extern crate message_format;
+//!
+//! use message_format::{icu, Context};
+//!
+//! fn main() {
+//!     let ctx = Context::new("pl".parse().unwrap(), None);
+//!     let m = icu::parse("{n, plural, one {# plik} few {# pliki} many {# plików} other {# pliku}}")
+//!         .unwrap();
+//!     assert_eq!(format_message!(ctx, &m, n => 1), "1 plik");
+//!     assert_eq!(format_message!(ctx, &m, n => 3), "3 pliki");
+//!     assert_eq!(format_message!(ctx, &m, n => 5), "5 plików");
+//! }
+//! ```
+//!
+//! The context's [`MissingArgumentPolicy`] decides whether an argument
+//! that is missing or has the wrong type stops formatting, or is
+//! replaced by its source text, a marker or the result of a callback.
+//!
 //! ## ICU Formatted Messages
 //!
 //! The simplest way to create an ICU formatted [`Message`] from code is
@@ -106,7 +129,9 @@
 //!
 //! ## Fluent Formatted Messages
 //!
-//! Support for the [Fluent] localization format is under development.
+//! Resources in the [Fluent] localization format can be parsed into a
+//! syntax tree with [`fluent::parse`]. Formatting Fluent messages is
+//! under development.
 //!
 //! ## Formatting a Message
 //!
@@ -157,8 +182,10 @@
 //! [ICU Message Format]: icu/index.html
 //! [`icu` module]: icu/index.html
 //! [Fluent]: http://projectfluent.org/
+//! [`fluent::parse`]: fluent/fn.parse.html
 //! [`parse`]: icu/fn.parse.html
 //! [`Message`]: struct.Message.html
+//! [`MissingArgumentPolicy`]: enum.MissingArgumentPolicy.html
 //! [`Value`]: enum.Value.html
 
 #![warn(missing_docs)]
@@ -166,23 +193,57 @@
         unused_qualifications)]
 
 extern crate language_tags;
-#[macro_use]
-extern crate nom;
 
 mod args;
+mod cldr;
 mod context;
+mod date_time;
+mod date_time_format;
+mod decimal;
+mod duration_format;
+mod list_format;
 mod message;
 mod message_part;
+mod number_format;
+mod number_skeleton;
 mod plural_category;
 mod plural_classifiers;
+mod plural_operands;
+mod plural_rules;
+mod plural_type;
+mod relative_time_format;
+mod rounding_mode;
+mod rule_based_number_format;
+mod time_zone;
 mod value;
 
 pub use self::args::{arg, Args};
-pub use self::context::Context;
+pub use self::context::{Context, MissingArgumentPolicy};
+pub use self::date_time::DateTime;
+pub use self::date_time_format::{DateTimeFormatOptions, DateTimePatternError, DateTimeStyle};
+pub use self::decimal::{Decimal, ParseDecimalError};
+pub use self::duration_format::{DurationFormatOptions, DurationStyle, DurationUnit};
+pub use self::list_format::{ListFormatOptions, ListStyle, ListType};
 pub use self::message::Message;
-pub use self::message_part::MessagePart;
+pub use self::message_part::{FormatError, MessagePart};
+pub use self::number_format::{
+    Grouping, IntegerWidth, Notation, NumberFormatOptions, NumberStyle, NumberUnit, Precision,
+    SignDisplay, UnitWidth,
+};
+pub use self::number_skeleton::NumberSkeletonError;
 pub use self::plural_category::PluralCategory;
 pub use self::plural_classifiers::*;
+pub use self::plural_operands::{ParsePluralOperandsError, PluralOperands};
+pub use self::plural_rules::{PluralRules, PluralRulesError};
+pub use self::plural_type::PluralType;
+pub use self::relative_time_format::{
+    RelativeTimeFormatOptions, RelativeTimeNumeric, RelativeTimeStyle, RelativeTimeUnit,
+};
+pub use self::rounding_mode::RoundingMode;
+pub use self::rule_based_number_format::{
+    NumberRules, NumberRulesError, RuleBasedNumberFormatOptions, RuleBasedNumberType,
+};
+pub use self::time_zone::{TimeZone, TimeZoneError};
 pub use self::value::Value;
 
 #[macro_export]
@@ -282,7 +343,9 @@ macro_rules! message_args {
 
 #[cfg(test)]
 mod tests {
-    use super::{icu, Context};
+    use std::fmt;
+
+    use super::{arg, icu, Context, FormatError, MissingArgumentPolicy};
 
     #[test]
     fn format_without_args() {
@@ -350,6 +413,132 @@ mod tests {
         write_message!(ctx, &m, &mut stream, name => "John").unwrap();
         assert_eq!(stream, "John");
     }
+
+    #[test]
+    fn format_errors() {
+        let ctx = Context::default();
+
+        let m = icu::parse("Hi {name}, you have {count, plural, one {# file} other {# files}}.")
+            .unwrap();
+        assert_eq!(
+            ctx.try_format(&m, Some(&arg("name", "Ana"))),
+            Err(FormatError::MissingArgument {
+                name: "count".to_string(),
+            })
+        );
+        // `format` keeps the parts before the error.
+        assert_eq!(ctx.format(&m, Some(&arg("name", "Ana"))), "Hi Ana, you have ");
+
+        let m = icu::parse("{gender, select, female {{n, number}} other {-}}").unwrap();
+        let gender = arg("gender", "female");
+        assert_eq!(
+            ctx.try_format(&m, Some(&gender.arg("n", "many"))),
+            Err(FormatError::TypeMismatch {
+                name: "n".to_string(),
+                expected: "a number".to_string(),
+                actual: "a string".to_string(),
+            })
+        );
+        assert_eq!(
+            ctx.try_format(&m, Some(&arg("gender", 1))),
+            Err(FormatError::TypeMismatch {
+                name: "gender".to_string(),
+                expected: "a string".to_string(),
+                actual: "a number".to_string(),
+            })
+        );
+        assert_eq!(
+            ctx.try_format(&m, Some(&gender.arg("n", 2))),
+            Ok("2".to_string())
+        );
+    }
+
+    #[test]
+    fn missing_argument_policies() {
+        let m = icu::parse(
+            "Hi {name}, {gender, select, female {she} other {they}} has \
+             {count, plural, one {# file} other {# files}}.",
+        )
+        .unwrap();
+        let args = arg("gender", 3);
+
+        let ctx = Context::default().with_missing_argument_policy(MissingArgumentPolicy::Source);
+        assert_eq!(
+            ctx.try_format(&m, Some(&args)),
+            Ok("Hi {name}, {gender, select, female {she} other {they}} has \
+                {count, plural, one {# file} other {# files}}."
+                .to_string())
+        );
+
+        let ctx = Context::default()
+            .with_missing_argument_policy(MissingArgumentPolicy::Marker("???".to_string()));
+        assert_eq!(
+            ctx.format(&m, Some(&args.arg("count", 2))),
+            "Hi ???, ??? has 2 files."
+        );
+
+        let ctx = Context::default().with_missing_argument_policy(MissingArgumentPolicy::callback(
+            |error, _| match *error {
+                FormatError::TypeMismatch { .. } => "someone".to_string(),
+                _ => "Ana".to_string(),
+            },
+        ));
+        assert_eq!(
+            ctx.format(&m, Some(&args.arg("count", 1))),
+            "Hi Ana, someone has 1 file."
+        );
+
+        // The whole source text of a complex argument is written.
+        let source = "{d, date, short} {count, plural, offset:1 one {# file} other {# files}} \
+                      { n ,number,::percent .00 }";
+        let m = icu::parse(source).unwrap();
+        let ctx = Context::default().with_missing_argument_policy(MissingArgumentPolicy::Source);
+        assert_eq!(ctx.format(&m, None), source);
+        assert_eq!(ctx.format(&m, Some(&arg("d", "today"))), source);
+        assert_eq!(
+            ctx.format(&m, Some(&arg("d", "today").arg("count", "two"))),
+            source
+        );
+
+        // Errors in sub-messages are recovered from within the sub-message.
+        let m = icu::parse("{gender, select, female {Hi {name}!} other {Hi!}}").unwrap();
+        let ctx = Context::default().with_missing_argument_policy(MissingArgumentPolicy::Source);
+        let (output, errors) = ctx.format_with_errors(&m, Some(&arg("gender", "female")));
+        assert_eq!(output, "Hi {name}!");
+        assert_eq!(
+            errors,
+            vec![FormatError::MissingArgument {
+                name: "name".to_string(),
+            }]
+        );
+
+        // Without a policy, the error stops formatting.
+        let ctx = Context::default();
+        let (output, errors) = ctx.format_with_errors(&m, Some(&arg("gender", "female")));
+        assert_eq!(output, "Hi ");
+        assert_eq!(
+            errors,
+            vec![FormatError::MissingArgument {
+                name: "name".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn write_errors() {
+        struct Full;
+
+        impl fmt::Write for Full {
+            fn write_str(&mut self, _: &str) -> fmt::Result {
+                Err(fmt::Error)
+            }
+        }
+
+        let ctx = Context::default();
+
+        let m = icu::parse("Hello!").unwrap();
+        assert_eq!(write_message!(ctx, &m, &mut Full), Err(FormatError::Write));
+    }
 }
 
 pub mod fluent;
@@ -0,0 +1,290 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use language_tags::LanguageTag;
+
+use cldr;
+use cldr::lists::{ListPatterns, PATTERNS};
+
+/// How the items of a list are related.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ListType {
+    /// All of the items, like `A, B, and C`.
+    #[default]
+    Conjunction,
+    /// Any one of the items, like `A, B, or C`.
+    Disjunction,
+    /// The parts of a measurement, like `5 ft, 2 in`.
+    Unit,
+}
+
+impl ListType {
+    /// The name of the type in the CLDR data.
+    fn key(self) -> &'static str {
+        match self {
+            ListType::Conjunction => "standard",
+            ListType::Disjunction => "or",
+            ListType::Unit => "unit",
+        }
+    }
+}
+
+/// How long the words joining the items of a list are.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ListStyle {
+    /// Full words, like `A, B, and C`.
+    #[default]
+    Long,
+    /// Abbreviated words or symbols, like `A, B, & C`.
+    Short,
+    /// The shortest form, which may be only punctuation, like
+    /// `A, B, C`.
+    Narrow,
+}
+
+/// Options for joining the items of a list, like `A, B, and C`, using
+/// the [CLDR] list patterns for a locale.
+///
+/// ```
+/// use message_format::{ListFormatOptions, ListType};
+///
+/// let en = "en".parse().unwrap();
+/// let de = "de".parse().unwrap();
+/// let items = ["Anna", "Ben", "Carla"];
+///
+/// let options = ListFormatOptions::new(ListType::Conjunction);
+/// assert_eq!(options.format(&en, &items), "Anna, Ben, and Carla");
+/// assert_eq!(options.format(&de, &items), "Anna, Ben und Carla");
+///
+/// let options = ListFormatOptions::new(ListType::Disjunction);
+/// assert_eq!(options.format(&en, &items[..2]), "Anna or Ben");
+/// ```
+///
+/// Some languages change the joining word to suit the item that
+/// follows it, as in the Spanish `Pablo e Isabel` rather than
+/// `Pablo y Isabel`, and the Hebrew `ו-` before an item that isn't
+/// written in Hebrew.
+///
+/// [CLDR]: http://cldr.unicode.org/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListFormatOptions {
+    /// How the items are related.
+    pub list_type: ListType,
+    /// How long the joining words are.
+    pub style: ListStyle,
+}
+
+impl ListFormatOptions {
+    /// Construct a `ListFormatOptions` for a type of list, with long
+    /// joining words.
+    pub fn new(list_type: ListType) -> Self {
+        ListFormatOptions {
+            list_type: list_type,
+            style: ListStyle::default(),
+        }
+    }
+
+    /// Join `items` for the locale given by `language_tag`.
+    pub fn format<S: AsRef<str>>(&self, language_tag: &LanguageTag, items: &[S]) -> String {
+        let patterns = list_patterns(language_tag, self.list_type, self.style);
+        let language = language_tag
+            .language
+            .as_ref()
+            .map(|language| language.to_lowercase());
+        let join = |pattern: &str, first: &str, second: &str| {
+            let pattern = contextual(language.as_deref(), pattern, second);
+            join(&pattern, first, second)
+        };
+        match items.len() {
+            0 => String::new(),
+            1 => items[0].as_ref().to_string(),
+            2 => join(patterns.two, items[0].as_ref(), items[1].as_ref()),
+            count => {
+                let mut list = join(
+                    patterns.end,
+                    items[count - 2].as_ref(),
+                    items[count - 1].as_ref(),
+                );
+                for item in items[1..count - 2].iter().rev() {
+                    list = join(patterns.middle, item.as_ref(), &list);
+                }
+                join(patterns.start, items[0].as_ref(), &list)
+            }
+        }
+    }
+}
+
+/// The locale's list patterns for a type, in the given style or the
+/// next longer one that the locale has.
+fn list_patterns(
+    language_tag: &LanguageTag,
+    list_type: ListType,
+    style: ListStyle,
+) -> ListPatterns {
+    let list_type = list_type.key();
+    let keys = match style {
+        ListStyle::Long => vec![list_type.to_string()],
+        ListStyle::Short => vec![format!("{}-short", list_type), list_type.to_string()],
+        ListStyle::Narrow => vec![
+            format!("{}-narrow", list_type),
+            format!("{}-short", list_type),
+            list_type.to_string(),
+        ],
+    };
+    cldr::lookup_keys(PATTERNS, language_tag, &keys).expect("list patterns for the root locale")
+}
+
+/// Replace `{0}` and `{1}` in a pattern, without replacing anything in
+/// the items themselves.
+fn join(pattern: &str, first: &str, second: &str) -> String {
+    pattern
+        .split("{1}")
+        .map(|part| part.replace("{0}", first))
+        .collect::<Vec<_>>()
+        .join(second)
+}
+
+/// Adjust a pattern to the item that follows its joining word, as in
+/// ICU's list formatter.
+fn contextual(language: Option<&str>, pattern: &str, next: &str) -> String {
+    let lower = next.to_lowercase();
+    match language {
+        // `y` becomes `e` before an `i` sound, but not before a
+        // diphthong like `hie` in `hielo`.
+        Some("es") if pattern.contains(" y {1}") => {
+            let e = lower.starts_with('i')
+                || (lower.starts_with("hi")
+                    && !lower.starts_with("hia")
+                    && !lower.starts_with("hie"));
+            if e {
+                return pattern.replace(" y {1}", " e {1}");
+            }
+        }
+        // `o` becomes `u` before an `o` sound, including the numbers
+        // 8, 11, and 11 thousand, million and so on, which are followed
+        // by a multiple of three digits.
+        Some("es") if pattern.contains(" o {1}") => {
+            let eleven = lower.starts_with("11")
+                && lower[2..].chars().take_while(char::is_ascii_digit).count() % 3 == 0;
+            let u = lower.starts_with('o')
+                || lower.starts_with("ho")
+                || lower.starts_with('8')
+                || eleven;
+            if u {
+                return pattern.replace(" o {1}", " u {1}");
+            }
+        }
+        // `ו` is joined with a hyphen to items that don't start with a
+        // Hebrew letter.
+        Some("he") | Some("iw") if pattern.contains("ו{1}") => {
+            let hebrew = next
+                .chars()
+                .next()
+                .is_some_and(|c| ('\u{5d0}'..='\u{5ea}').contains(&c));
+            if !hebrew {
+                return pattern.replace("ו{1}", "ו-{1}");
+            }
+        }
+        _ => {}
+    }
+    pattern.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(options: &ListFormatOptions, locale: &str, items: &[&str]) -> String {
+        options.format(&locale.parse().unwrap(), items)
+    }
+
+    #[test]
+    fn lengths_work() {
+        let and = ListFormatOptions::new(ListType::Conjunction);
+        assert_eq!(format(&and, "en", &[]), "");
+        assert_eq!(format(&and, "en", &["A"]), "A");
+        assert_eq!(format(&and, "en", &["A", "B"]), "A and B");
+        assert_eq!(format(&and, "en", &["A", "B", "C"]), "A, B, and C");
+        assert_eq!(format(&and, "en", &["A", "B", "C", "D"]), "A, B, C, and D");
+        assert_eq!(format(&and, "en-GB", &["A", "B", "C"]), "A, B and C");
+        assert_eq!(format(&and, "ja", &["A", "B", "C"]), "A、B、C");
+        assert_eq!(format(&and, "und", &["A", "B", "C"]), "A, B, C");
+        assert_eq!(format(&and, "zh", &["A", "B", "C"]), "A、B和C");
+        assert_eq!(format(&and, "it", &["A", "B", "C"]), "A, B e C");
+        assert_eq!(format(&and, "ar", &["A", "B", "C"]), "A وB وC");
+        assert_eq!(format(&and, "pt-PT", &["A", "B", "C"]), "A, B e C");
+
+        // Locales without list patterns fall back to the root locale.
+        assert_eq!(format(&and, "ga", &["A", "B", "C"]), "A, B, C");
+
+        // Items are used as is.
+        assert_eq!(format(&and, "en", &["{1}", "{0}"]), "{1} and {0}");
+    }
+
+    #[test]
+    fn types_and_styles_work() {
+        let options = |list_type, style| ListFormatOptions {
+            list_type: list_type,
+            style: style,
+        };
+        let items = ["A", "B", "C"];
+        let cases = [
+            (ListType::Conjunction, ListStyle::Short, "en", "A, B, & C"),
+            (ListType::Conjunction, ListStyle::Narrow, "en", "A, B, C"),
+            (ListType::Conjunction, ListStyle::Narrow, "de", "A, B und C"),
+            (ListType::Disjunction, ListStyle::Long, "en", "A, B, or C"),
+            (ListType::Disjunction, ListStyle::Narrow, "fr", "A, B ou C"),
+            (
+                ListType::Disjunction,
+                ListStyle::Long,
+                "ja",
+                "A、B、またはC",
+            ),
+            (ListType::Unit, ListStyle::Long, "en", "A, B, C"),
+            (ListType::Unit, ListStyle::Narrow, "en", "A B C"),
+            (ListType::Unit, ListStyle::Long, "fr", "A, B et C"),
+        ];
+        for &(list_type, style, locale, expected) in &cases {
+            assert_eq!(
+                format(&options(list_type, style), locale, &items),
+                expected,
+                "{:?} {:?} {}",
+                list_type,
+                style,
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn contextual_words_work() {
+        let and = ListFormatOptions::new(ListType::Conjunction);
+        let or = ListFormatOptions::new(ListType::Disjunction);
+        assert_eq!(format(&and, "es", &["Pablo", "Isabel"]), "Pablo e Isabel");
+        assert_eq!(format(&and, "es", &["agua", "hielo"]), "agua y hielo");
+        assert_eq!(format(&and, "es", &["A", "hijos", "C"]), "A, hijos y C");
+        assert_eq!(
+            format(&and, "es", &["Ana", "Inés", "Hilario"]),
+            "Ana, Inés e Hilario"
+        );
+        assert_eq!(format(&or, "es", &["siete", "ocho"]), "siete u ocho");
+        assert_eq!(format(&or, "es", &["10", "11"]), "10 u 11");
+        assert_eq!(format(&or, "es", &["10", "12"]), "10 o 12");
+        assert_eq!(format(&or, "es", &["a", "11000"]), "a u 11000");
+        assert_eq!(format(&or, "es", &["a", "11.000"]), "a u 11.000");
+        assert_eq!(format(&or, "es", &["a", "11000000"]), "a u 11000000");
+        assert_eq!(format(&or, "es", &["a", "110"]), "a o 110");
+        assert_eq!(format(&or, "es", &["a", "1100"]), "a o 1100");
+        assert_eq!(format(&or, "es", &["mujer", "hombre"]), "mujer u hombre");
+
+        assert_eq!(format(&and, "he", &["א", "ב"]), "א וב");
+        assert_eq!(format(&and, "he", &["א", "Ben"]), "א ו-Ben");
+        assert_eq!(format(&and, "he", &["א", "ב", "3"]), "א, ב ו-3");
+
+        assert_eq!(format(&and, "th", &["ก", "ข", "ค"]), "ก ข และค");
+        assert_eq!(format(&and, "th", &["ก", "ข"]), "กและข");
+    }
+}
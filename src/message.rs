@@ -6,7 +6,7 @@
 
 use std::fmt;
 
-use {Args, Context, MessagePart};
+use {Args, Context, FormatError, MessagePart};
 
 /// A message that has been localized and can be formatted in a
 /// locale-aware manner.
@@ -36,9 +36,14 @@ impl Message {
         ctx: &Context,
         stream: &mut fmt::Write,
         args: Option<&Args<'f>>,
-    ) -> fmt::Result {
+    ) -> Result<(), FormatError> {
         for part in &self.parts {
-            try!(part.apply_format(ctx, stream, args));
+            if let Err(error) = part.apply_format(ctx, stream, args) {
+                match ctx.missing_argument_policy.fallback(&error, part.source()) {
+                    Some(fallback) => try!(stream.write_str(&fallback)),
+                    None => return Err(error),
+                }
+            }
         }
         Ok(())
     }
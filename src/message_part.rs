@@ -4,6 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::error::Error;
 use std::fmt;
 
 use {Args, Context};
@@ -17,5 +18,110 @@ pub trait MessagePart: fmt::Debug {
         ctx: &Context,
         stream: &mut fmt::Write,
         args: Option<&Args<'f>>,
-    ) -> fmt::Result;
+    ) -> Result<(), FormatError>;
+
+    /// The text of this part in the message source, if it is known.
+    /// [`MissingArgumentPolicy::Source`] writes it instead of an
+    /// argument that can't be formatted.
+    ///
+    /// [`MissingArgumentPolicy::Source`]: enum.MissingArgumentPolicy.html#variant.Source
+    fn source(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// An error resulting from formatting a message, which stops the
+/// message from being formatted any further.
+///
+/// ```
+/// use message_format::{icu, Context, FormatError};
+///
+/// let ctx = Context::default();
+/// let m = icu::parse("Hello, {name}!").unwrap();
+/// assert_eq!(
+///     ctx.try_format(&m, None),
+///     Err(FormatError::MissingArgument {
+///         name: "name".to_string(),
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormatError {
+    /// No value was given for an argument. The value of `#` is missing
+    /// outside of a `plural`, where its name is `#`.
+    MissingArgument {
+        /// The name of the argument.
+        name: String,
+    },
+    /// The value of an argument can't be formatted by the argument's
+    /// type, like a string for a `number` argument.
+    TypeMismatch {
+        /// The name of the argument.
+        name: String,
+        /// The kind of value that was expected, like `a number`.
+        expected: String,
+        /// The kind of value that was given, like `a string`.
+        actual: String,
+    },
+    /// No arm of a selector matched the value of an argument, and
+    /// there was no default arm.
+    NoMatchingArm {
+        /// The name of the argument.
+        name: String,
+        /// The value of the argument.
+        value: String,
+    },
+    /// The value of an argument can't be formatted because it is out
+    /// of range, like a timestamp whose local time overflows.
+    ValueOutOfRange {
+        /// The name of the argument.
+        name: String,
+    },
+    /// Writing to the stream failed.
+    Write,
+}
+
+impl Error for FormatError {
+    fn description(&self) -> &str {
+        match *self {
+            FormatError::MissingArgument { .. } => "Missing argument.",
+            FormatError::TypeMismatch { .. } => "Argument type mismatch.",
+            FormatError::NoMatchingArm { .. } => "No matching arm.",
+            FormatError::ValueOutOfRange { .. } => "Argument value out of range.",
+            FormatError::Write => "Write failed.",
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            FormatError::MissingArgument { ref name } => {
+                write!(f, "missing argument `{}`", name)
+            }
+            FormatError::TypeMismatch {
+                ref name,
+                ref expected,
+                ref actual,
+            } => write!(
+                f,
+                "argument `{}` is {}, but {} was expected",
+                name, actual, expected
+            ),
+            FormatError::NoMatchingArm {
+                ref name,
+                ref value,
+            } => write!(f, "no arm matches `{}` for argument `{}`", value, name),
+            FormatError::ValueOutOfRange { ref name } => {
+                write!(f, "the value of argument `{}` is out of range", name)
+            }
+            FormatError::Write => write!(f, "an error occurred when writing the message"),
+        }
+    }
+}
+
+impl From<fmt::Error> for FormatError {
+    fn from(_: fmt::Error) -> Self {
+        FormatError::Write
+    }
 }
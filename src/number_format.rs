@@ -0,0 +1,1377 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use language_tags::LanguageTag;
+
+use cldr::currencies;
+use cldr::numbers::{self, CompactPatterns, NumberData, Symbols};
+use cldr::units;
+use cldr::{self, PluralForms};
+use {Decimal, PluralCategory, PluralRules, RoundingMode};
+
+/// The keyword styles of an ICU `number` argument, as in
+/// `{n, number, percent}`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NumberStyle {
+    /// A decimal number with up to 3 fraction digits, like `1,234.567`.
+    #[default]
+    Decimal,
+    /// A number rounded to an integer, like `1,235`.
+    Integer,
+    /// A percentage, where `1` is `100%`.
+    Percent,
+    /// An amount of money in the local currency, like `$1,234.57`.
+    Currency,
+}
+
+/// How a number is written: as is, or with an exponent.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Notation {
+    /// Write the number as is, like `1,234,567`.
+    #[default]
+    Simple,
+    /// Write the number with an exponent, like `1.235E6`.
+    Scientific {
+        /// Whether the exponent must be a multiple of 3, as in
+        /// `1.235E6` or `123.457E3`.
+        engineering: bool,
+        /// The minimum number of digits in the exponent.
+        minimum_exponent_digits: usize,
+        /// When to show the sign of the exponent.
+        exponent_sign_display: SignDisplay,
+    },
+    /// Write the number with an abbreviation for its power of ten, like
+    /// `1.2K`.
+    CompactShort,
+    /// Write the number with a word for its power of ten, like
+    /// `1.2 thousand`.
+    CompactLong,
+}
+
+/// What a number measures.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum NumberUnit {
+    /// A plain number.
+    #[default]
+    None,
+    /// A percentage. The number isn't multiplied by 100 unless a
+    /// `scale` is also given.
+    Percent,
+    /// A per mille amount, like `5‰`.
+    Permille,
+    /// An amount of money in the currency with this [ISO 4217] code.
+    /// If there is no code, the currency of the locale's region is used.
+    ///
+    /// [ISO 4217]: https://www.iso.org/iso-4217-currency-codes.html
+    Currency(Option<String>),
+    /// An amount of a measurement unit with this [CLDR] identifier,
+    /// like `kilometer`, or of a compound unit like
+    /// `kilometer-per-hour`.
+    ///
+    /// [CLDR]: http://cldr.unicode.org/
+    Measure(String),
+}
+
+/// How a unit, such as a currency or a measurement unit, is displayed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum UnitWidth {
+    /// The shortest symbol, like `$` for any kind of dollar.
+    Narrow,
+    /// The usual symbol, like `$` for US dollars in the US but `US$`
+    /// elsewhere.
+    #[default]
+    Short,
+    /// The ISO code, like `USD`. Measurement units are shown as with
+    /// `Short`.
+    IsoCode,
+    /// The full name, like `US dollars` or `kilometers`, in the plural
+    /// form for the number.
+    FullName,
+    /// Don't show the unit at all.
+    Hidden,
+}
+
+/// How many digits of a number are shown.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Precision {
+    /// The locale's default: up to 3 fraction digits for plain
+    /// numbers, none for percentages, and the usual number for the
+    /// currency.
+    #[default]
+    Default,
+    /// No fraction digits.
+    Integer,
+    /// All of the digits of the number.
+    Unlimited,
+    /// A range of fraction digits. Zeros are added to reach the
+    /// minimum.
+    Fraction {
+        /// The minimum number of fraction digits.
+        minimum: usize,
+        /// The maximum number of fraction digits, if limited.
+        maximum: Option<usize>,
+    },
+    /// A range of significant digits. Zeros are added to reach the
+    /// minimum.
+    Significant {
+        /// The minimum number of significant digits.
+        minimum: usize,
+        /// The maximum number of significant digits, if limited.
+        maximum: Option<usize>,
+    },
+    /// Round to a multiple of an increment, like `0.05`.
+    Increment(Decimal),
+    /// The usual number of fraction digits for the currency.
+    CurrencyStandard,
+    /// The precision used for the currency in cash, like a multiple of
+    /// `0.05` for Swiss francs.
+    CurrencyCash,
+}
+
+/// When to show the sign of a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SignDisplay {
+    /// Show the sign of negative numbers, including those that round
+    /// to zero.
+    #[default]
+    Auto,
+    /// Show the sign of all numbers.
+    Always,
+    /// Never show the sign.
+    Never,
+    /// Show the sign of numbers that don't round to zero.
+    ExceptZero,
+    /// Show the sign of negative numbers that don't round to zero.
+    Negative,
+    /// Like `Auto`, but use the locale's accounting format for
+    /// currency amounts, which is often `($5.00)`.
+    Accounting,
+    /// Like `Always`, with the accounting format.
+    AccountingAlways,
+    /// Like `ExceptZero`, with the accounting format.
+    AccountingExceptZero,
+    /// Like `Negative`, with the accounting format.
+    AccountingNegative,
+}
+
+/// When to use grouping separators, like the `,` in `1,234`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Grouping {
+    /// Follow the locale's conventions.
+    #[default]
+    Auto,
+    /// Never group digits.
+    Off,
+    /// Only group digits when there would be at least 2 digits in the
+    /// first group, as in `1234` and `12,345`.
+    Min2,
+    /// Always group digits using the locale's group sizes.
+    OnAligned,
+    /// Always group digits in thousands, even where the locale uses
+    /// other group sizes.
+    Thousands,
+}
+
+/// The number of integer digits to show.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntegerWidth {
+    /// The minimum number of integer digits. Zeros are added to reach
+    /// this.
+    pub minimum: usize,
+    /// The maximum number of integer digits, if limited. Digits beyond
+    /// this are removed from the start of the number.
+    pub maximum: Option<usize>,
+}
+
+impl Default for IntegerWidth {
+    fn default() -> Self {
+        IntegerWidth {
+            minimum: 1,
+            maximum: None,
+        }
+    }
+}
+
+/// Options for formatting a number for a locale.
+///
+/// The symbols, grouping and numbering system come from the [CLDR]
+/// data for the locale, so the same number is written differently in
+/// each language:
+///
+/// ```
+/// use message_format::{Decimal, NumberFormatOptions, NumberStyle};
+///
+/// let value = Decimal::from(1234567);
+/// let options = NumberFormatOptions::default();
+/// assert_eq!(options.format(&"en".parse().unwrap(), &value), "1,234,567");
+/// assert_eq!(options.format(&"de".parse().unwrap(), &value), "1.234.567");
+/// assert_eq!(options.format(&"hi".parse().unwrap(), &value), "12,34,567");
+/// assert_eq!(options.format(&"ar".parse().unwrap(), &value), "١٬٢٣٤٬٥٦٧");
+///
+/// let options = NumberFormatOptions::new(NumberStyle::Percent);
+/// let value: Decimal = "0.25".parse().unwrap();
+/// assert_eq!(options.format(&"en".parse().unwrap(), &value), "25%");
+/// ```
+///
+/// A `-u-nu-` extension in the language tag selects a different
+/// numbering system, as in `ar-EG-u-nu-latn`.
+///
+/// The options are those of ICU's [number skeletons], and can be
+/// parsed from one with [`from_skeleton`].
+///
+/// [CLDR]: http://cldr.unicode.org/
+/// [number skeletons]: https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html
+/// [`from_skeleton`]: #method.from_skeleton
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NumberFormatOptions {
+    /// Whether to write the number with an exponent.
+    pub notation: Notation,
+    /// What the number measures.
+    pub unit: NumberUnit,
+    /// How the unit is displayed.
+    pub unit_width: UnitWidth,
+    /// How many digits are shown.
+    pub precision: Precision,
+    /// How the number is rounded to the digits that are shown.
+    pub rounding_mode: RoundingMode,
+    /// When to show the sign.
+    pub sign_display: SignDisplay,
+    /// When to use grouping separators.
+    pub grouping: Grouping,
+    /// How many integer digits are shown.
+    pub integer_width: IntegerWidth,
+    /// A number to multiply by before formatting, like `100` for
+    /// percentages.
+    pub scale: Option<Decimal>,
+    /// The numbering system to use instead of the locale's, like
+    /// `latn` or `arab`.
+    pub numbering_system: Option<String>,
+}
+
+impl NumberFormatOptions {
+    /// Construct a `NumberFormatOptions` for a keyword style.
+    pub fn new(style: NumberStyle) -> Self {
+        match style {
+            NumberStyle::Decimal => NumberFormatOptions::default(),
+            NumberStyle::Integer => NumberFormatOptions {
+                precision: Precision::Integer,
+                ..NumberFormatOptions::default()
+            },
+            NumberStyle::Percent => NumberFormatOptions {
+                unit: NumberUnit::Percent,
+                scale: Some(Decimal::from(100)),
+                ..NumberFormatOptions::default()
+            },
+            NumberStyle::Currency => NumberFormatOptions {
+                unit: NumberUnit::Currency(None),
+                ..NumberFormatOptions::default()
+            },
+        }
+    }
+
+    /// Format `value` for the locale given by `language_tag`.
+    pub fn format(&self, language_tag: &LanguageTag, value: &Decimal) -> String {
+        let locale = Locale::new(language_tag, self.numbering_system.as_ref());
+        let currency = match self.unit {
+            NumberUnit::Currency(ref code) => Some(currency_code(code.as_ref(), language_tag)),
+            _ => None,
+        };
+        let currency = currency.as_deref();
+        let value = match self.scale {
+            Some(ref scale) => value * scale,
+            None => value.clone(),
+        };
+
+        let accounting = matches!(
+            self.sign_display,
+            SignDisplay::Accounting
+                | SignDisplay::AccountingAlways
+                | SignDisplay::AccountingExceptZero
+                | SignDisplay::AccountingNegative
+        );
+        // Currency names are written with a plain number, then put in
+        // the locale's pattern for a number and a name.
+        let full_name = self.unit_width == UnitWidth::FullName && currency.is_some();
+        let pattern = Pattern::new(match self.unit {
+            _ if full_name => locale.data.decimal_pattern,
+            NumberUnit::None | NumberUnit::Measure(_) => locale.data.decimal_pattern,
+            NumberUnit::Percent | NumberUnit::Permille => locale.data.percent_pattern,
+            NumberUnit::Currency(_) if accounting => locale
+                .data
+                .accounting_pattern
+                .unwrap_or(locale.data.currency_pattern),
+            NumberUnit::Currency(_) => locale.data.currency_pattern,
+        });
+
+        let (rounded, minimum_fraction_digits, exponent, compact) = match self.notation {
+            Notation::Simple => {
+                let (rounded, minimum) = self.round(&self.precision, &value, &pattern, currency);
+                (rounded, minimum, None, None)
+            }
+            Notation::Scientific { engineering, .. } => {
+                let step = if engineering { 3 } else { 1 };
+                let mut exponent = (magnitude(&value) - 1).div_euclid(step) * step;
+                let (mut rounded, mut minimum) =
+                    self.round(&self.precision, &value.shift(-exponent), &pattern, currency);
+                if magnitude(&rounded) > step {
+                    // Rounding carried into another digit, as when
+                    // 9.9999 is rounded to 10.
+                    exponent += step;
+                    let result =
+                        self.round(&self.precision, &value.shift(-exponent), &pattern, currency);
+                    rounded = result.0;
+                    minimum = result.1;
+                }
+                (rounded, minimum, Some(exponent), None)
+            }
+            Notation::CompactShort | Notation::CompactLong => {
+                let (rounded, minimum, compact) =
+                    self.compact(language_tag, &value, &pattern, currency);
+                (rounded, minimum, None, compact)
+            }
+        };
+
+        let grouping = match (self.notation, self.grouping) {
+            (Notation::Scientific { .. }, _) => Grouping::Off,
+            (Notation::CompactShort, Grouping::Auto) | (Notation::CompactLong, Grouping::Auto) => {
+                Grouping::Min2
+            }
+            (_, grouping) => grouping,
+        };
+        let (integer, fraction) = self.digits(&rounded, minimum_fraction_digits);
+        let mut number = self.number(&locale, &pattern, &integer, &fraction, grouping);
+        // The plural category depends on the digits that are shown, so
+        // that German has `1 Million` but `1,5 Millionen`.
+        let category = || {
+            let shown = if fraction.is_empty() {
+                format!("0{}", integer)
+            } else {
+                format!("0{}.{}", integer, fraction)
+            };
+            let shown: Decimal = shown.parse().expect("a valid decimal");
+            PluralRules::cardinal(language_tag).select(&shown)
+        };
+        if let Some(counts) = compact {
+            let compact_pattern = cldr::plural_form(counts, category()).unwrap_or("0");
+            let (prefix, _, suffix) = split_pattern(compact_pattern);
+            number = format!(
+                "{}{}{}",
+                expand_affix(prefix, &locale.symbols, "", ""),
+                number,
+                expand_affix(suffix, &locale.symbols, "", "")
+            );
+        }
+        if let (
+            Some(exponent),
+            Notation::Scientific {
+                minimum_exponent_digits,
+                exponent_sign_display,
+                ..
+            },
+        ) = (exponent, self.notation)
+        {
+            number.push_str(locale.symbols.exponential);
+            match sign(exponent_sign_display, exponent < 0, exponent == 0) {
+                Sign::Minus => number.push_str(locale.symbols.minus),
+                Sign::Plus => number.push_str(locale.symbols.plus),
+                Sign::None => {}
+            }
+            let digits = format!("{:01$}", exponent.unsigned_abs(), minimum_exponent_digits);
+            number.push_str(&locale.digits(&digits));
+        }
+
+        let (prefix, suffix) = pattern.affixes(sign(
+            self.sign_display,
+            value.is_negative(),
+            rounded.is_zero(),
+        ));
+        let percent = match self.unit {
+            NumberUnit::Permille => locale.symbols.permille,
+            _ => locale.symbols.percent,
+        };
+        // Measurement units are written around the whole number, in the
+        // plural form for the number, as in `-5 kilometers`.
+        if let NumberUnit::Measure(ref unit) = self.unit {
+            let number = format!(
+                "{}{}{}",
+                expand_affix(&prefix, &locale.symbols, "", ""),
+                number,
+                expand_affix(&suffix, &locale.symbols, "", "")
+            );
+            if self.unit_width == UnitWidth::Hidden {
+                return number;
+            }
+            return measure_pattern(language_tag, unit, self.unit_width, category())
+                .replace("{0}", &number);
+        }
+        if let (true, Some(code)) = (full_name, currency) {
+            let name = cldr::fallbacks(language_tag)
+                .iter()
+                .filter_map(|locale| cldr::find(currencies::NAMES, locale))
+                .filter_map(|names| cldr::find(names, code))
+                .next()
+                .and_then(|forms| cldr::plural_form(forms, category()))
+                .unwrap_or(code);
+            let name_pattern =
+                cldr::lookup(currencies::NAME_PATTERNS, language_tag).unwrap_or("{0} {1}");
+            let number = format!(
+                "{}{}{}",
+                expand_affix(&prefix, &locale.symbols, "", ""),
+                number,
+                expand_affix(&suffix, &locale.symbols, "", "")
+            );
+            return name_pattern.replace("{0}", &number).replace("{1}", name);
+        }
+        let currency_text =
+            currency.map_or_else(String::new, |code| self.currency_text(language_tag, code));
+        let mut expanded_prefix = expand_affix(&prefix, &locale.symbols, percent, &currency_text);
+        let mut expanded_suffix = expand_affix(&suffix, &locale.symbols, percent, &currency_text);
+        if currency.is_some() && currency_text.is_empty() {
+            expanded_prefix = expanded_prefix.trim_end().to_string();
+            expanded_suffix = expanded_suffix.trim_start().to_string();
+        }
+
+        // Separate currency codes and other alphabetic symbols from the
+        // digits, as in `CHF 5.00`.
+        let is_alphabetic = |c: Option<char>| c.is_some_and(char::is_alphabetic);
+        if prefix.ends_with('¤') && is_alphabetic(currency_text.chars().last()) {
+            expanded_prefix.push('\u{a0}');
+        }
+        if suffix.starts_with('¤') && is_alphabetic(currency_text.chars().next()) {
+            expanded_suffix.insert(0, '\u{a0}');
+        }
+
+        format!("{}{}{}", expanded_prefix, number, expanded_suffix)
+    }
+
+    /// Round `value` for compact notation, returning the rounded value
+    /// divided by the power of ten its compact pattern stands for, the
+    /// minimum number of fraction digits, and the compact patterns for
+    /// each plural category, if there are any for its size.
+    fn compact(
+        &self,
+        language_tag: &LanguageTag,
+        value: &Decimal,
+        pattern: &Pattern,
+        currency: Option<&str>,
+    ) -> (Decimal, usize, Option<PluralForms>) {
+        let short = cldr::lookup(numbers::COMPACT_SHORT, language_tag);
+        let patterns: CompactPatterns = match self.notation {
+            Notation::CompactLong => cldr::lookup(numbers::COMPACT_LONG, language_tag).or(short),
+            _ => short,
+        }
+        .unwrap_or(&[]);
+        let round = |exponent: isize| {
+            let counts = patterns
+                .iter()
+                .rev()
+                .find(|&&(minimum, _)| minimum as isize <= exponent)
+                .map(|&(minimum, counts)| (minimum, counts));
+            // A pattern like `00K` shows two digits of thousands.
+            let shift = counts.map_or(0, |(minimum, counts)| {
+                let digits = counts
+                    .first()
+                    .map_or(1, |&(_, pattern)| split_pattern(pattern).1.len());
+                minimum as isize + 1 - digits as isize
+            });
+            let scaled = value.shift(-shift);
+            let precision = match self.precision {
+                // Compact numbers are rounded to an integer by default,
+                // or to 2 significant digits if that would leave them
+                // with only one.
+                Precision::Default if magnitude(&scaled) > 1 => Precision::Integer,
+                Precision::Default => Precision::Significant {
+                    minimum: 1,
+                    maximum: Some(2),
+                },
+                ref precision => precision.clone(),
+            };
+            let (rounded, minimum) = self.round(&precision, &scaled, pattern, currency);
+            (rounded, minimum, shift, counts.map(|(_, counts)| counts))
+        };
+
+        let exponent = magnitude(value) - 1;
+        let (rounded, minimum, shift, counts) = round(exponent);
+        if magnitude(&rounded) - 1 + shift > exponent {
+            // Rounding carried into the next power of ten, as when
+            // 999,999 is rounded to 1000K, which may need another
+            // pattern.
+            let (rounded, minimum, _, counts) = round(exponent + 1);
+            (rounded, minimum, counts)
+        } else {
+            (rounded, minimum, counts)
+        }
+    }
+
+    /// Round `value` to the digits that will be shown with `precision`,
+    /// returning the rounded value and the minimum number of fraction
+    /// digits.
+    fn round(
+        &self,
+        precision: &Precision,
+        value: &Decimal,
+        pattern: &Pattern,
+        currency: Option<&str>,
+    ) -> (Decimal, usize) {
+        let mode = self.rounding_mode;
+        let fraction = |minimum, maximum| (value.round_with(maximum, mode), minimum);
+        let increment = |increment: &Decimal| {
+            (
+                value.round_to_increment(increment, mode),
+                increment.fraction_digits().len(),
+            )
+        };
+        match (precision, currency) {
+            (Precision::Integer, _) => fraction(0, 0),
+            (Precision::Unlimited, _) => (value.clone(), 0),
+            (&Precision::Fraction { minimum, maximum }, _) => match maximum {
+                Some(maximum) => fraction(minimum, maximum),
+                None => (value.clone(), minimum),
+            },
+            (&Precision::Significant { minimum, maximum }, _) => {
+                let rounded = match maximum {
+                    Some(maximum) => {
+                        let digits = maximum as isize - magnitude(value);
+                        if digits >= 0 {
+                            value.round_with(digits as usize, mode)
+                        } else {
+                            value.shift(digits).round_with(0, mode).shift(-digits)
+                        }
+                    }
+                    None => value.clone(),
+                };
+                let minimum = (minimum as isize - magnitude(&rounded)).max(0) as usize;
+                (rounded, minimum)
+            }
+            (Precision::Increment(amount), _) => increment(amount),
+            (Precision::CurrencyCash, Some(code)) => {
+                match cldr::find(currencies::CASH_INCREMENTS, code) {
+                    Some(amount) => increment(&amount.parse().expect("a valid cash increment")),
+                    None => fraction(currency_digits(code), currency_digits(code)),
+                }
+            }
+            (_, Some(code)) => fraction(currency_digits(code), currency_digits(code)),
+            (_, None) => fraction(
+                pattern.minimum_fraction_digits,
+                pattern.maximum_fraction_digits,
+            ),
+        }
+    }
+
+    /// The ASCII integer and fraction digits to show for a rounded
+    /// number.
+    fn digits(&self, value: &Decimal, minimum_fraction_digits: usize) -> (String, String) {
+        let mut integer = value.integer_digits();
+        if let Some(maximum) = self.integer_width.maximum {
+            if integer.len() > maximum {
+                integer = &integer[integer.len() - maximum..];
+            }
+        }
+        let mut integer = integer.trim_start_matches('0').to_string();
+        while integer.len() < self.integer_width.minimum {
+            integer.insert(0, '0');
+        }
+        let mut fraction = value.fraction_digits().to_string();
+        while fraction.len() > minimum_fraction_digits && fraction.ends_with('0') {
+            fraction.pop();
+        }
+        while fraction.len() < minimum_fraction_digits {
+            fraction.push('0');
+        }
+        (integer, fraction)
+    }
+
+    /// Write the digits and separators of a rounded number.
+    fn number(
+        &self,
+        locale: &Locale,
+        pattern: &Pattern,
+        integer: &str,
+        fraction: &str,
+        grouping: Grouping,
+    ) -> String {
+        let (primary, secondary) = (pattern.primary_group, pattern.secondary_group);
+        let (primary, secondary, minimum) = match grouping {
+            Grouping::Auto => (primary, secondary, locale.data.minimum_grouping_digits),
+            Grouping::Off => (0, 0, 0),
+            Grouping::Min2 => (primary, secondary, 2),
+            Grouping::OnAligned => (primary, secondary, 1),
+            Grouping::Thousands => (3, 3, 1),
+        };
+        let mut number = group(locale, integer, primary, secondary, minimum);
+        if !fraction.is_empty() {
+            number.push_str(locale.symbols.decimal);
+            number.push_str(&locale.digits(fraction));
+        }
+        number
+    }
+
+    /// The text shown for a currency, depending on the unit width.
+    fn currency_text(&self, language_tag: &LanguageTag, code: &str) -> String {
+        match self.unit_width {
+            UnitWidth::Narrow => match cldr::find(currencies::NARROW_SYMBOLS, code) {
+                Some(symbol) => symbol.to_string(),
+                None => currency_symbol(language_tag, code),
+            },
+            UnitWidth::Short => currency_symbol(language_tag, code),
+            UnitWidth::IsoCode => code.to_string(),
+            UnitWidth::FullName | UnitWidth::Hidden => String::new(),
+        }
+    }
+}
+
+/// The currency code to use: the one given, or the one for the
+/// locale's region, or `XXX` (no currency) if that isn't known.
+fn currency_code(code: Option<&String>, language_tag: &LanguageTag) -> String {
+    match code {
+        Some(code) => code.to_uppercase(),
+        None => cldr::region(language_tag)
+            .and_then(|region| cldr::find(currencies::REGION_CURRENCIES, &region))
+            .unwrap_or("XXX")
+            .to_string(),
+    }
+}
+
+/// The number of fraction digits normally shown for a currency.
+fn currency_digits(code: &str) -> usize {
+    cldr::find(currencies::DIGITS, code)
+        .or_else(|| cldr::find(currencies::DIGITS, "root"))
+        .unwrap_or(2)
+}
+
+/// The symbol for `currency` in the locale given by `language_tag`,
+/// or the currency code if it has no symbol.
+fn currency_symbol(language_tag: &LanguageTag, currency: &str) -> String {
+    cldr::fallbacks(language_tag)
+        .iter()
+        .filter_map(|locale| cldr::find(currencies::SYMBOLS, locale))
+        .filter_map(|symbols| cldr::find(symbols, currency))
+        .next()
+        .unwrap_or(currency)
+        .to_string()
+}
+
+/// The pattern for an amount of a measurement unit, where `{0}` is the
+/// amount, in the form for its plural category.
+///
+/// A compound unit like `kilocalorie-per-hour` without its own pattern
+/// is built from the pattern for the first unit and the locale's
+/// pattern for an amount per the second unit, like `{0}/h`, or per any
+/// unit, like `{0} per {1}`. Unknown units are shown by identifier.
+fn measure_pattern(
+    language_tag: &LanguageTag,
+    unit: &str,
+    width: UnitWidth,
+    category: PluralCategory,
+) -> String {
+    let keys = |unit: &str| match width {
+        UnitWidth::FullName => vec![unit.to_string()],
+        UnitWidth::Narrow => vec![
+            format!("{}-narrow", unit),
+            format!("{}-short", unit),
+            unit.to_string(),
+        ],
+        _ => vec![format!("{}-short", unit), unit.to_string()],
+    };
+    let forms = |unit: &str| cldr::lookup_keys(units::PATTERNS, language_tag, &keys(unit));
+    if let Some(forms) = forms(unit) {
+        return cldr::plural_form(forms, category)
+            .unwrap_or("{0}")
+            .to_string();
+    }
+    let (numerator, denominator) = match unit.split_once("-per-") {
+        Some(units) => units,
+        None => return format!("{{0}} {}", unit),
+    };
+    let numerator = measure_pattern(language_tag, numerator, width, category);
+    if let Some(pattern) =
+        cldr::lookup_keys(units::PER_UNIT_PATTERNS, language_tag, &keys(denominator))
+    {
+        return pattern.replace("{0}", &numerator);
+    }
+    // The name of the unit is its singular form without the amount.
+    let name = forms(denominator)
+        .and_then(|forms| cldr::plural_form(forms, PluralCategory::One))
+        .map_or(denominator.to_string(), |form| {
+            form.replace("{0}", "").trim().to_string()
+        });
+    cldr::lookup_keys(units::PER_PATTERNS, language_tag, &keys("per"))
+        .unwrap_or("{0}/{1}")
+        .replace("{1}", &name)
+        .replace("{0}", &numerator)
+}
+
+/// The position of the most significant digit of a number, where the
+/// units digit is `1` and the tenths digit is `0`. Zero is treated
+/// like a single units digit.
+fn magnitude(value: &Decimal) -> isize {
+    let integer = value.integer_digits();
+    if integer != "0" {
+        return integer.len() as isize;
+    }
+    match value.fraction_digits().find(|c| c != '0') {
+        Some(zeros) => -(zeros as isize),
+        None => 1,
+    }
+}
+
+/// Insert grouping separators into the integer digits, with the
+/// `primary` sized group nearest the decimal point and `secondary`
+/// sized groups before it. Numbers with fewer than `minimum` digits
+/// before the first separator aren't grouped.
+fn group(
+    locale: &Locale,
+    integer: &str,
+    primary: usize,
+    secondary: usize,
+    minimum: usize,
+) -> String {
+    if primary == 0 || integer.len() < primary + minimum {
+        return locale.digits(integer);
+    }
+    let mut groups = vec![];
+    let (mut rest, last) = integer.split_at(integer.len() - primary);
+    groups.push(last);
+    while rest.len() > secondary {
+        let (head, group) = rest.split_at(rest.len() - secondary);
+        groups.push(group);
+        rest = head;
+    }
+    if !rest.is_empty() {
+        groups.push(rest);
+    }
+    groups
+        .iter()
+        .rev()
+        .map(|group| locale.digits(group))
+        .collect::<Vec<_>>()
+        .join(locale.symbols.group)
+}
+
+/// The sign shown with a number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Sign {
+    None,
+    Minus,
+    Plus,
+}
+
+/// The sign to show for a number, given whether it is negative and
+/// whether it rounds to zero.
+fn sign(display: SignDisplay, negative: bool, zero: bool) -> Sign {
+    let signed = if negative { Sign::Minus } else { Sign::Plus };
+    match display {
+        SignDisplay::Auto | SignDisplay::Accounting if negative => Sign::Minus,
+        SignDisplay::Always | SignDisplay::AccountingAlways => signed,
+        SignDisplay::ExceptZero | SignDisplay::AccountingExceptZero if !zero => signed,
+        SignDisplay::Negative | SignDisplay::AccountingNegative if negative && !zero => Sign::Minus,
+        _ => Sign::None,
+    }
+}
+
+/// The number data, symbols and digits for a locale.
+struct Locale {
+    data: NumberData,
+    symbols: Symbols,
+    digits: Vec<char>,
+}
+
+impl Locale {
+    /// Find the data for a locale, using `numbering_system`, or the
+    /// one in a `-u-nu-` extension of the language tag, if known.
+    fn new(language_tag: &LanguageTag, numbering_system: Option<&String>) -> Self {
+        let data =
+            cldr::lookup(numbers::NUMBERS, language_tag).expect("number data for the root locale");
+        let requested = numbering_system
+            .cloned()
+            .or_else(|| cldr::unicode_extension(language_tag, "nu"))
+            .and_then(|name| {
+                cldr::find(numbers::NUMBERING_SYSTEMS, &name).map(|system| (name, system))
+            });
+        let (symbols, system) = match requested {
+            Some((ref name, system)) if name != data.numbering_system => {
+                // The locale's symbols are for its default numbering
+                // system, so use the ones for the requested system.
+                let symbols = system
+                    .symbols
+                    .unwrap_or(if data.numbering_system == "latn" {
+                        data.symbols
+                    } else {
+                        numbers::LATN
+                    });
+                (symbols, system)
+            }
+            _ => (
+                data.symbols,
+                cldr::find(numbers::NUMBERING_SYSTEMS, data.numbering_system)
+                    .expect("a known numbering system"),
+            ),
+        };
+        Locale {
+            data: data,
+            symbols: symbols,
+            digits: system.digits.chars().collect(),
+        }
+    }
+
+    /// Write ASCII digits using the locale's numbering system.
+    fn digits(&self, ascii: &str) -> String {
+        ascii
+            .bytes()
+            .map(|b| self.digits[usize::from(b - b'0')])
+            .collect()
+    }
+}
+
+/// A parsed CLDR number pattern, like `#,##0.00 ¤`.
+#[derive(Clone, Debug, PartialEq)]
+struct Pattern {
+    prefix: String,
+    suffix: String,
+    /// The prefix and suffix for negative numbers, if the pattern
+    /// has them rather than using a minus sign before the prefix.
+    negative: Option<(String, String)>,
+    minimum_fraction_digits: usize,
+    maximum_fraction_digits: usize,
+    /// The size of the group of digits nearest the decimal point, or
+    /// `0` if the digits aren't grouped.
+    primary_group: usize,
+    /// The size of the other groups, which is `2` for Indian grouping.
+    secondary_group: usize,
+}
+
+impl Pattern {
+    fn new(pattern: &str) -> Self {
+        let mut subpatterns = pattern.splitn(2, ';');
+        let positive = subpatterns.next().unwrap_or("");
+        let (prefix, number, suffix) = split_pattern(positive);
+
+        let (integer, fraction) = match number.find('.') {
+            Some(index) => (&number[..index], &number[index + 1..]),
+            None => (number, ""),
+        };
+        let groups: Vec<&str> = integer.split(',').collect();
+        let (primary_group, secondary_group) = match groups.len() {
+            0 | 1 => (0, 0),
+            2 => (groups[1].len(), groups[1].len()),
+            n => (groups[n - 1].len(), groups[n - 2].len()),
+        };
+
+        Pattern {
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+            negative: subpatterns.next().map(|negative| {
+                let (prefix, _, suffix) = split_pattern(negative);
+                (prefix.to_string(), suffix.to_string())
+            }),
+            minimum_fraction_digits: fraction.matches('0').count(),
+            maximum_fraction_digits: fraction.len(),
+            primary_group: primary_group,
+            secondary_group: secondary_group,
+        }
+    }
+
+    /// The unexpanded prefix and suffix for a number with `sign`.
+    fn affixes(&self, sign: Sign) -> (String, String) {
+        match (sign, self.negative.as_ref()) {
+            (Sign::None, _) => (self.prefix.clone(), self.suffix.clone()),
+            (Sign::Minus, Some((prefix, suffix))) => (prefix.clone(), suffix.clone()),
+            (Sign::Minus, None) => (format!("-{}", self.prefix), self.suffix.clone()),
+            // A plus sign goes where the negative pattern has its minus
+            // sign, if it has one.
+            (Sign::Plus, Some((prefix, suffix)))
+                if prefix.contains('-') || suffix.contains('-') =>
+            {
+                (prefix.replace('-', "+"), suffix.replace('-', "+"))
+            }
+            (Sign::Plus, _) => (format!("+{}", self.prefix), self.suffix.clone()),
+        }
+    }
+}
+
+/// Split a pattern into its prefix, number and suffix.
+fn split_pattern(pattern: &str) -> (&str, &str, &str) {
+    let is_number_char = |c| "#0,.".contains(c);
+    let start = pattern.find(is_number_char).unwrap_or(pattern.len());
+    let end = pattern[start..]
+        .find(|c| !is_number_char(c))
+        .map_or(pattern.len(), |index| start + index);
+    (&pattern[..start], &pattern[start..end], &pattern[end..])
+}
+
+/// Replace the special characters in a pattern's prefix or suffix
+/// with the symbols they stand for. Text within apostrophes is
+/// literal, and `''` is an apostrophe.
+fn expand_affix(affix: &str, symbols: &Symbols, percent: &str, currency: &str) -> String {
+    let mut expanded = String::new();
+    let mut quoted = false;
+    let mut chars = affix.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                expanded.push('\'');
+            }
+            '\'' => quoted = !quoted,
+            _ if quoted => expanded.push(c),
+            '%' => expanded.push_str(percent),
+            '-' => expanded.push_str(symbols.minus),
+            '+' => expanded.push_str(symbols.plus),
+            '¤' => expanded.push_str(currency),
+            _ => expanded.push(c),
+        }
+    }
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(language: &str, style: NumberStyle, value: &str) -> String {
+        NumberFormatOptions::new(style).format(&language.parse().unwrap(), &value.parse().unwrap())
+    }
+
+    fn format_with(language: &str, options: &NumberFormatOptions, value: &str) -> String {
+        options.format(&language.parse().unwrap(), &value.parse().unwrap())
+    }
+
+    #[test]
+    fn patterns_work() {
+        let pattern = Pattern::new("#,##,##0.00 ¤;(¤#)");
+        assert_eq!(pattern.prefix, "");
+        assert_eq!(pattern.suffix, " ¤");
+        assert_eq!(pattern.negative, Some(("(¤".to_string(), ")".to_string())));
+        assert_eq!(pattern.minimum_fraction_digits, 2);
+        assert_eq!(pattern.maximum_fraction_digits, 2);
+        assert_eq!((pattern.primary_group, pattern.secondary_group), (3, 2));
+
+        let pattern = Pattern::new("%#0.###");
+        assert_eq!(pattern.prefix, "%");
+        assert_eq!(pattern.primary_group, 0);
+        assert_eq!(pattern.maximum_fraction_digits, 3);
+
+        let symbols = numbers::LATN;
+        assert_eq!(expand_affix("'%'% ''", &symbols, "%", "$"), "%% '");
+    }
+
+    #[test]
+    fn decimals_work() {
+        use self::NumberStyle::Decimal;
+
+        assert_eq!(format("en", Decimal, "1234567.891"), "1,234,567.891");
+        assert_eq!(format("en", Decimal, "-0.12345"), "-0.123");
+        assert_eq!(format("en", Decimal, "2.50"), "2.5");
+        assert_eq!(format("de", Decimal, "1234567.5"), "1.234.567,5");
+        assert_eq!(
+            format("fr", Decimal, "1234567.5"),
+            "1\u{202f}234\u{202f}567,5"
+        );
+        assert_eq!(format("de-CH", Decimal, "1234567.5"), "1’234’567.5");
+        assert_eq!(format("sv", Decimal, "-1234.5"), "\u{2212}1\u{a0}234,5");
+        assert_eq!(format("hi", Decimal, "123456789"), "12,34,56,789");
+        assert_eq!(format("en-IN", Decimal, "1234"), "1,234");
+    }
+
+    #[test]
+    fn minimum_grouping_works() {
+        use self::NumberStyle::Decimal;
+
+        assert_eq!(format("es", Decimal, "1234"), "1234");
+        assert_eq!(format("es", Decimal, "12345"), "12.345");
+        assert_eq!(format("pl", Decimal, "1234"), "1234");
+        assert_eq!(format("pl", Decimal, "12345"), "12\u{a0}345");
+    }
+
+    #[test]
+    fn numbering_systems_work() {
+        use self::NumberStyle::Decimal;
+
+        assert_eq!(format("ar", Decimal, "-1234.5"), "\u{61c}-١٬٢٣٤٫٥");
+        assert_eq!(format("ar-MA", Decimal, "1234.5"), "1.234,5");
+        assert_eq!(format("ar-u-nu-latn", Decimal, "1234.5"), "1,234.5");
+        assert_eq!(format("fa", Decimal, "1234.5"), "۱٬۲۳۴٫۵");
+        assert_eq!(format("bn", Decimal, "1234567"), "১২,৩৪,৫৬৭");
+        assert_eq!(format("en-u-nu-deva", Decimal, "1234"), "१,२३४");
+        assert_eq!(format("en-u-nu-bogus", Decimal, "1234"), "1,234");
+
+        let options = NumberFormatOptions {
+            numbering_system: Some("thai".to_string()),
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &options, "1234"), "๑,๒๓๔");
+    }
+
+    #[test]
+    fn integers_work() {
+        use self::NumberStyle::Integer;
+
+        assert_eq!(format("en", Integer, "1234.5"), "1,234");
+        assert_eq!(format("en", Integer, "1235.5"), "1,236");
+        assert_eq!(format("en", Integer, "-0.4"), "-0");
+    }
+
+    #[test]
+    fn percents_work() {
+        use self::NumberStyle::Percent;
+
+        assert_eq!(format("en", Percent, "0.256"), "26%");
+        assert_eq!(format("en", Percent, "-12.5"), "-1,250%");
+        assert_eq!(format("de", Percent, "0.5"), "50\u{a0}%");
+        assert_eq!(format("tr", Percent, "0.5"), "%50");
+        assert_eq!(format("ar", Percent, "0.5"), "٥٠٪\u{61c}");
+
+        let options = NumberFormatOptions {
+            unit: NumberUnit::Permille,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &options, "5"), "5‰");
+        assert_eq!(format_with("ar", &options, "5"), "٥\u{609}\u{61c}");
+    }
+
+    #[test]
+    fn currencies_work() {
+        use self::NumberStyle::Currency;
+
+        assert_eq!(format("en", Currency, "1234.5"), "$1,234.50");
+        assert_eq!(format("en", Currency, "-3"), "-$3.00");
+        assert_eq!(format("en-GB", Currency, "3"), "£3.00");
+        assert_eq!(format("de", Currency, "1234.5"), "1.234,50\u{a0}€");
+        assert_eq!(format("ja", Currency, "1234.5"), "￥1,234");
+        assert_eq!(format("nl", Currency, "-3"), "€\u{a0}-3,00");
+        assert_eq!(format("en-IN", Currency, "1234567"), "₹12,34,567.00");
+        assert_eq!(format("und", Currency, "3"), "¤3.00");
+
+        let currency = |code: &str, width| NumberFormatOptions {
+            unit: NumberUnit::Currency(Some(code.to_string())),
+            unit_width: width,
+            ..NumberFormatOptions::default()
+        };
+        let options = currency("bhd", UnitWidth::Short);
+        assert_eq!(format_with("en", &options, "1.5"), "BHD\u{a0}1.500");
+        let options = currency("CAD", UnitWidth::Short);
+        assert_eq!(format_with("en", &options, "5"), "CA$5.00");
+        let options = currency("CAD", UnitWidth::Narrow);
+        assert_eq!(format_with("en", &options, "5"), "$5.00");
+        let options = currency("EUR", UnitWidth::IsoCode);
+        assert_eq!(format_with("en", &options, "5"), "EUR\u{a0}5.00");
+        assert_eq!(format_with("de", &options, "5"), "5,00\u{a0}EUR");
+        let options = currency("USD", UnitWidth::FullName);
+        assert_eq!(format_with("en", &options, "1"), "1.00 US dollars");
+        assert_eq!(
+            format_with("en", &options, "-1234.5"),
+            "-1,234.50 US dollars"
+        );
+        assert_eq!(format_with("ru", &options, "5"), "5,00 доллара США");
+        assert_eq!(format_with("ja", &options, "5"), "5.00米ドル");
+        assert_eq!(
+            format_with("en", &currency("XYZ", UnitWidth::FullName), "5"),
+            "5.00 XYZ"
+        );
+        let options = NumberFormatOptions {
+            precision: Precision::Integer,
+            ..currency("USD", UnitWidth::FullName)
+        };
+        assert_eq!(format_with("en", &options, "1"), "1 US dollar");
+        assert_eq!(format_with("ru", &options, "5"), "5 долларов США");
+        assert_eq!(format_with("ru", &options, "21"), "21 доллар США");
+        let options = NumberFormatOptions {
+            precision: Precision::Integer,
+            ..currency("JPY", UnitWidth::FullName)
+        };
+        assert_eq!(format_with("de", &options, "1"), "1 Japanischer Yen");
+        assert_eq!(format_with("de-AT", &options, "3"), "3 Japanische Yen");
+        let options = currency("EUR", UnitWidth::Hidden);
+        assert_eq!(format_with("en", &options, "5"), "5.00");
+        assert_eq!(format_with("de", &options, "5"), "5,00");
+    }
+
+    #[test]
+    fn measure_units_work() {
+        let measure = |unit: &str, width| NumberFormatOptions {
+            unit: NumberUnit::Measure(unit.to_string()),
+            unit_width: width,
+            ..NumberFormatOptions::default()
+        };
+        let options = measure("kilometer", UnitWidth::FullName);
+        assert_eq!(format_with("en", &options, "1"), "1 kilometer");
+        assert_eq!(format_with("en", &options, "5"), "5 kilometers");
+        assert_eq!(format_with("en", &options, "1.0"), "1 kilometer");
+        assert_eq!(
+            format_with("en", &options, "-1234.5"),
+            "-1,234.5 kilometers"
+        );
+        assert_eq!(format_with("fr", &options, "1.5"), "1,5 kilomètre");
+        assert_eq!(format_with("ru", &options, "22"), "22 километра");
+        assert_eq!(format_with("ru", &options, "25"), "25 километров");
+        assert_eq!(format_with("ru", &options, "1.5"), "1,5 километра");
+        assert_eq!(format_with("und", &options, "5"), "5 km");
+
+        let options = measure("foot", UnitWidth::Short);
+        assert_eq!(format_with("en", &options, "6"), "6 ft");
+        let options = measure("foot", UnitWidth::Narrow);
+        assert_eq!(format_with("en", &options, "6"), "6′");
+        assert_eq!(format_with("de", &options, "6"), "6 ft");
+        let options = measure("celsius", UnitWidth::FullName);
+        assert_eq!(format_with("ja", &options, "20"), "摂氏 20 度");
+        let options = measure("kilogram", UnitWidth::Hidden);
+        assert_eq!(format_with("en", &options, "1234"), "1,234");
+
+        let options = NumberFormatOptions {
+            notation: Notation::CompactLong,
+            ..measure("meter", UnitWidth::FullName)
+        };
+        assert_eq!(format_with("en", &options, "1200"), "1.2 thousand meters");
+    }
+
+    #[test]
+    fn compound_units_work() {
+        let measure = |unit: &str, width| NumberFormatOptions {
+            unit: NumberUnit::Measure(unit.to_string()),
+            unit_width: width,
+            ..NumberFormatOptions::default()
+        };
+        // Units with their own patterns.
+        let options = measure("kilometer-per-hour", UnitWidth::Short);
+        assert_eq!(format_with("en", &options, "50"), "50 km/h");
+        let options = measure("mile-per-hour", UnitWidth::FullName);
+        assert_eq!(format_with("en", &options, "1"), "1 mile per hour");
+        assert_eq!(format_with("ru", &options, "5"), "5 миль в час");
+
+        // Units with a pattern for an amount per unit.
+        let options = measure("kilocalorie-per-hour", UnitWidth::FullName);
+        assert_eq!(
+            format_with("en", &options, "300"),
+            "300 kilocalories per hour"
+        );
+        assert_eq!(format_with("de", &options, "1"), "1 Kilokalorie pro Stunde");
+        let options = measure("kilocalorie-per-hour", UnitWidth::Narrow);
+        assert_eq!(format_with("en", &options, "300"), "300kcal/h");
+
+        // Other units.
+        let options = measure("kilocalorie-per-mile", UnitWidth::FullName);
+        assert_eq!(
+            format_with("en", &options, "100"),
+            "100 kilocalories per mile"
+        );
+        assert_eq!(
+            format_with("fr", &options, "100"),
+            "100 kilocalories par mille"
+        );
+        let options = measure("kilocalorie-per-mile", UnitWidth::Short);
+        assert_eq!(format_with("en", &options, "100"), "100 kcal/mi");
+
+        let options = measure("furlong-per-fortnight", UnitWidth::Short);
+        assert_eq!(format_with("en", &options, "3"), "3 furlong/fortnight");
+    }
+
+    #[test]
+    fn precision_works() {
+        let precision = |precision| NumberFormatOptions {
+            precision: precision,
+            ..NumberFormatOptions::default()
+        };
+        let options = precision(Precision::Unlimited);
+        assert_eq!(format_with("en", &options, "1.234567"), "1.234567");
+        let options = precision(Precision::Fraction {
+            minimum: 2,
+            maximum: Some(4),
+        });
+        assert_eq!(format_with("en", &options, "1.5"), "1.50");
+        assert_eq!(format_with("en", &options, "1.23456"), "1.2346");
+
+        let options = precision(Precision::Significant {
+            minimum: 1,
+            maximum: Some(2),
+        });
+        assert_eq!(format_with("en", &options, "1234"), "1,200");
+        assert_eq!(format_with("en", &options, "0.012345"), "0.012");
+        assert_eq!(format_with("en", &options, "9.96"), "10");
+        let options = precision(Precision::Significant {
+            minimum: 3,
+            maximum: Some(3),
+        });
+        assert_eq!(format_with("en", &options, "1"), "1.00");
+        assert_eq!(format_with("en", &options, "0"), "0.00");
+
+        let options = precision(Precision::Increment("0.25".parse().unwrap()));
+        assert_eq!(format_with("en", &options, "1.13"), "1.25");
+
+        let options = NumberFormatOptions {
+            precision: Precision::Integer,
+            rounding_mode: RoundingMode::Ceiling,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &options, "1.01"), "2");
+
+        let options = NumberFormatOptions {
+            unit: NumberUnit::Currency(Some("CHF".to_string())),
+            precision: Precision::CurrencyCash,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("de-CH", &options, "3.42"), "CHF\u{a0}3.40");
+        let options = NumberFormatOptions {
+            unit: NumberUnit::Currency(Some("EUR".to_string())),
+            precision: Precision::CurrencyCash,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &options, "3.424"), "€3.42");
+    }
+
+    #[test]
+    fn sign_display_works() {
+        let sign = |display| NumberFormatOptions {
+            sign_display: display,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &sign(SignDisplay::Always), "5"), "+5");
+        assert_eq!(format_with("en", &sign(SignDisplay::Always), "0"), "+0");
+        assert_eq!(format_with("en", &sign(SignDisplay::Never), "-5"), "5");
+        assert_eq!(format_with("en", &sign(SignDisplay::ExceptZero), "0"), "0");
+        assert_eq!(format_with("en", &sign(SignDisplay::ExceptZero), "5"), "+5");
+        assert_eq!(format_with("en", &sign(SignDisplay::Auto), "-0.0001"), "-0");
+        assert_eq!(
+            format_with("en", &sign(SignDisplay::Negative), "-0.0001"),
+            "0"
+        );
+        assert_eq!(
+            format_with("ar", &sign(SignDisplay::Always), "5"),
+            "\u{61c}+٥"
+        );
+
+        let accounting = |display| NumberFormatOptions {
+            unit: NumberUnit::Currency(Some("USD".to_string())),
+            sign_display: display,
+            ..NumberFormatOptions::default()
+        };
+        let options = accounting(SignDisplay::Accounting);
+        assert_eq!(format_with("en", &options, "-5"), "($5.00)");
+        assert_eq!(format_with("en", &options, "5"), "$5.00");
+        let options = accounting(SignDisplay::AccountingAlways);
+        assert_eq!(format_with("en", &options, "5"), "+$5.00");
+    }
+
+    #[test]
+    fn grouping_works() {
+        let grouping = |grouping| NumberFormatOptions {
+            grouping: grouping,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(
+            format_with("en", &grouping(Grouping::Off), "1234567"),
+            "1234567"
+        );
+        assert_eq!(format_with("en", &grouping(Grouping::Min2), "1234"), "1234");
+        assert_eq!(
+            format_with("en", &grouping(Grouping::Min2), "12345"),
+            "12,345"
+        );
+        assert_eq!(
+            format_with("es", &grouping(Grouping::OnAligned), "1234"),
+            "1.234"
+        );
+        assert_eq!(
+            format_with("hi", &grouping(Grouping::Thousands), "1234567"),
+            "1,234,567"
+        );
+    }
+
+    #[test]
+    fn integer_width_works() {
+        let width = |minimum, maximum| NumberFormatOptions {
+            integer_width: IntegerWidth {
+                minimum: minimum,
+                maximum: maximum,
+            },
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &width(3, None), "5"), "005");
+        assert_eq!(format_with("en", &width(1, Some(2)), "1234.5"), "34.5");
+        assert_eq!(format_with("en", &width(0, Some(0)), "0.5"), ".5");
+    }
+
+    #[test]
+    fn scientific_works() {
+        let scientific = |engineering, digits, sign| NumberFormatOptions {
+            notation: Notation::Scientific {
+                engineering: engineering,
+                minimum_exponent_digits: digits,
+                exponent_sign_display: sign,
+            },
+            ..NumberFormatOptions::default()
+        };
+        let options = scientific(false, 1, SignDisplay::Auto);
+        assert_eq!(format_with("en", &options, "123456"), "1.235E5");
+        assert_eq!(format_with("en", &options, "0.00123"), "1.23E-3");
+        assert_eq!(format_with("en", &options, "9.9999"), "1E1");
+        assert_eq!(format_with("en", &options, "0"), "0E0");
+        assert_eq!(format_with("sv", &options, "1500"), "1,5×10^3");
+
+        let options = scientific(true, 2, SignDisplay::Always);
+        assert_eq!(format_with("en", &options, "123456"), "123.456E+03");
+        assert_eq!(format_with("en", &options, "-0.0123"), "-12.3E-03");
+    }
+
+    #[test]
+    fn compact_works() {
+        let short = NumberFormatOptions {
+            notation: Notation::CompactShort,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &short, "999"), "999");
+        assert_eq!(format_with("en", &short, "1234"), "1.2K");
+        assert_eq!(format_with("en", &short, "12345"), "12K");
+        assert_eq!(format_with("en", &short, "-1500000"), "-1.5M");
+        assert_eq!(format_with("en", &short, "999999"), "1M");
+        assert_eq!(format_with("en", &short, "1234567890000000"), "1235T");
+        assert_eq!(format_with("en", &short, "5.678"), "5.7");
+        assert_eq!(format_with("de", &short, "1234"), "1234");
+        assert_eq!(format_with("de", &short, "1234567"), "1,2\u{a0}Mio.");
+        assert_eq!(
+            format_with("es", &short, "12345678901"),
+            "12\u{a0}mil\u{a0}M"
+        );
+        assert_eq!(format_with("ja", &short, "123456"), "12万");
+        assert_eq!(format_with("und", &short, "3000000000"), "3G");
+
+        let long = NumberFormatOptions {
+            notation: Notation::CompactLong,
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &long, "3400000"), "3.4 million");
+        assert_eq!(format_with("de", &long, "1000000"), "1 Million");
+        assert_eq!(format_with("de", &long, "2000000"), "2 Millionen");
+        assert_eq!(format_with("de", &long, "1500000"), "1,5 Millionen");
+        assert_eq!(format_with("ru", &long, "21000"), "21 тысяча");
+        assert_eq!(format_with("ru", &long, "5000000"), "5 миллионов");
+        assert_eq!(format_with("ja", &long, "20000"), "2万");
+
+        let options = NumberFormatOptions {
+            notation: Notation::CompactShort,
+            unit: NumberUnit::Currency(Some("USD".to_string())),
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &options, "1234567"), "$1.2M");
+        let options = NumberFormatOptions {
+            notation: Notation::CompactShort,
+            precision: Precision::Fraction {
+                minimum: 2,
+                maximum: Some(2),
+            },
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &options, "1234"), "1.23K");
+    }
+
+    #[test]
+    fn scale_works() {
+        let options = NumberFormatOptions {
+            scale: Some("0.001".parse().unwrap()),
+            ..NumberFormatOptions::default()
+        };
+        assert_eq!(format_with("en", &options, "1500"), "1.5");
+    }
+}
@@ -0,0 +1,670 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+use std::fmt;
+
+use cldr;
+use cldr::units;
+use {
+    Decimal, Grouping, IntegerWidth, Notation, NumberFormatOptions, NumberUnit, Precision,
+    RoundingMode, SignDisplay, UnitWidth,
+};
+
+/// An error resulting from `NumberFormatOptions::from_skeleton`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberSkeletonError {
+    /// The byte offset into the skeleton of the invalid token.
+    pub offset: usize,
+    /// The invalid token, including any options, or an empty string
+    /// for a skeleton without any tokens.
+    pub token: String,
+}
+
+impl Error for NumberSkeletonError {
+    fn description(&self) -> &str {
+        "Invalid number skeleton."
+    }
+}
+
+impl fmt::Display for NumberSkeletonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if self.token.is_empty() {
+            write!(f, "empty number skeleton")
+        } else {
+            write!(f, "invalid number skeleton token `{}`", self.token)
+        }
+    }
+}
+
+impl NumberFormatOptions {
+    /// Parse an ICU [number skeleton], like `currency/EUR .00` or
+    /// `percent sign-always`.
+    ///
+    /// Skeletons are made of whitespace separated stems, some of which
+    /// take options separated by `/`. Both the long stems and the
+    /// concise ones, like `%` for `percent`, are supported.
+    ///
+    /// Measurement units are given by their CLDR identifier, as in
+    /// `unit/kilometer` or `unit/kilometer-per-hour`, or by type and
+    /// identifier, as in `measure-unit/length-kilometer
+    /// per-measure-unit/duration-hour`.
+    ///
+    /// As in ICU, a skeleton must have at least one stem, and may not
+    /// have two stems for the same setting, like `percent currency/EUR`
+    /// or `.00 .0`.
+    ///
+    /// ```
+    /// use message_format::{Decimal, NumberFormatOptions};
+    ///
+    /// let options = NumberFormatOptions::from_skeleton("currency/EUR .00 group-off").unwrap();
+    /// let value: Decimal = "1234.5".parse().unwrap();
+    /// assert_eq!(options.format(&"de".parse().unwrap(), &value), "1234,50\u{a0}€");
+    ///
+    /// let e = NumberFormatOptions::from_skeleton("percent bogus").unwrap_err();
+    /// assert_eq!(e.offset, 8);
+    /// let e = NumberFormatOptions::from_skeleton("percent .00 .0").unwrap_err();
+    /// assert_eq!(e.token, ".0");
+    /// ```
+    ///
+    /// [number skeleton]: https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html
+    pub fn from_skeleton(skeleton: &str) -> Result<Self, NumberSkeletonError> {
+        let tokens = tokens(skeleton);
+        if tokens.is_empty() {
+            return Err(NumberSkeletonError {
+                offset: 0,
+                token: String::new(),
+            });
+        }
+        let mut options = NumberFormatOptions::default();
+        let mut seen = vec![];
+        for (offset, token) in tokens {
+            let mut parts = token.split('/');
+            let stem = parts.next().unwrap_or("");
+            let args: Vec<&str> = parts.collect();
+            let settings = settings(stem);
+            let repeated = settings.iter().any(|setting| seen.contains(setting));
+            if repeated || apply_stem(&mut options, stem, &args).is_none() {
+                return Err(NumberSkeletonError {
+                    offset: offset,
+                    token: token.to_string(),
+                });
+            }
+            seen.extend_from_slice(settings);
+        }
+        Ok(options)
+    }
+}
+
+/// Split a skeleton into its whitespace separated tokens and their
+/// byte offsets.
+fn tokens(skeleton: &str) -> Vec<(usize, &str)> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (index, c) in skeleton.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(begin)) => {
+                tokens.push((begin, &skeleton[begin..index]));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        tokens.push((begin, &skeleton[begin..]));
+    }
+    tokens
+}
+
+/// The settings that a stem changes, which no other stem in the same
+/// skeleton may change.
+fn settings(stem: &str) -> &'static [&'static str] {
+    match stem {
+        "%x100" => &["unit", "scale"],
+        "currency" | "unit" | "measure-unit" | "base-unit" | "percent" | "%" | "permille" => {
+            &["unit"]
+        }
+        "per-measure-unit" => &["per-unit"],
+        "scale" => &["scale"],
+        "numbering-system" | "latin" => &["numbering-system"],
+        "decimal-auto" | "decimal-always" => &["decimal"],
+        _ if sign_display(stem).is_some() => &["sign"],
+        _ if stem.starts_with("unit-width-") => &["unit-width"],
+        _ if stem.starts_with("rounding-mode-") => &["rounding-mode"],
+        _ if stem.starts_with("integer-width") || stem.starts_with('0') => &["integer-width"],
+        _ if stem.starts_with("group-") || stem.starts_with(',') => &["group"],
+        _ if stem.starts_with("precision-") || stem.starts_with('.') || stem.starts_with('@') => {
+            &["precision"]
+        }
+        "scientific" | "engineering" | "notation-simple" | "compact-short" | "K"
+        | "compact-long" | "KK" => &["notation"],
+        _ if stem.starts_with('E') => &["notation"],
+        _ => &[],
+    }
+}
+
+/// Update `options` for a stem and its options, or return `None` if
+/// they aren't valid.
+fn apply_stem(options: &mut NumberFormatOptions, stem: &str, args: &[&str]) -> Option<()> {
+    // Stems that take options.
+    match stem {
+        "scientific" | "engineering" => {
+            options.notation = scientific(stem == "engineering", args)?;
+            return Some(());
+        }
+        "currency" => {
+            let code = single(args)?;
+            if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+                return None;
+            }
+            options.unit = NumberUnit::Currency(Some(code.to_string()));
+            return Some(());
+        }
+        "unit" => {
+            options.unit = measure_unit(single(args)?)?;
+            return Some(());
+        }
+        // The type, like `length` in `length-meter`, isn't needed.
+        "measure-unit" => {
+            let (_, unit) = single(args)?.split_once('-')?;
+            options.unit = measure_unit(unit)?;
+            return Some(());
+        }
+        "per-measure-unit" => {
+            let (_, per) = single(args)?.split_once('-')?;
+            let unit = match options.unit {
+                NumberUnit::Measure(ref unit) if !unit.contains("-per-") => {
+                    format!("{}-per-{}", unit, per)
+                }
+                _ => return None,
+            };
+            options.unit = measure_unit(&unit)?;
+            return Some(());
+        }
+        "precision-increment" => {
+            let increment: Decimal = single(args)?.parse().ok()?;
+            if increment.is_negative() || increment.is_zero() {
+                return None;
+            }
+            options.precision = Precision::Increment(increment);
+            return Some(());
+        }
+        "integer-width" => {
+            options.integer_width = integer_width(single(args)?)?;
+            return Some(());
+        }
+        "scale" => {
+            options.scale = Some(single(args)?.parse().ok()?);
+            return Some(());
+        }
+        "numbering-system" => {
+            let name = single(args)?;
+            if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                return None;
+            }
+            options.numbering_system = Some(name.to_lowercase());
+            return Some(());
+        }
+        _ if !args.is_empty() => return None,
+        _ => {}
+    }
+
+    if let Some(sign) = sign_display(stem) {
+        options.sign_display = sign;
+        return Some(());
+    }
+    match stem {
+        "notation-simple" => options.notation = Notation::Simple,
+        "compact-short" | "K" => options.notation = Notation::CompactShort,
+        "compact-long" | "KK" => options.notation = Notation::CompactLong,
+        "base-unit" => options.unit = NumberUnit::None,
+        "percent" | "%" => options.unit = NumberUnit::Percent,
+        "permille" => options.unit = NumberUnit::Permille,
+        "%x100" => {
+            options.unit = NumberUnit::Percent;
+            options.scale = Some(Decimal::from(100));
+        }
+        "unit-width-narrow" => options.unit_width = UnitWidth::Narrow,
+        "unit-width-short" => options.unit_width = UnitWidth::Short,
+        "unit-width-iso-code" => options.unit_width = UnitWidth::IsoCode,
+        "unit-width-hidden" => options.unit_width = UnitWidth::Hidden,
+        "unit-width-full-name" => options.unit_width = UnitWidth::FullName,
+        "precision-integer" => options.precision = Precision::Integer,
+        "precision-unlimited" => options.precision = Precision::Unlimited,
+        "precision-currency-standard" => options.precision = Precision::CurrencyStandard,
+        "precision-currency-cash" => options.precision = Precision::CurrencyCash,
+        "rounding-mode-ceiling" => options.rounding_mode = RoundingMode::Ceiling,
+        "rounding-mode-floor" => options.rounding_mode = RoundingMode::Floor,
+        "rounding-mode-down" => options.rounding_mode = RoundingMode::Down,
+        "rounding-mode-up" => options.rounding_mode = RoundingMode::Up,
+        "rounding-mode-half-even" => options.rounding_mode = RoundingMode::HalfEven,
+        "rounding-mode-half-down" => options.rounding_mode = RoundingMode::HalfDown,
+        "rounding-mode-half-up" => options.rounding_mode = RoundingMode::HalfUp,
+        "integer-width-trunc" => {
+            options.integer_width = IntegerWidth {
+                minimum: 0,
+                maximum: Some(0),
+            }
+        }
+        "group-off" | ",_" => options.grouping = Grouping::Off,
+        "group-min2" | ",?" => options.grouping = Grouping::Min2,
+        "group-auto" => options.grouping = Grouping::Auto,
+        "group-on-aligned" | ",!" => options.grouping = Grouping::OnAligned,
+        "group-thousands" | ",=" => options.grouping = Grouping::Thousands,
+        "latin" => options.numbering_system = Some("latn".to_string()),
+        "decimal-auto" => {}
+        _ if stem.starts_with('.') => options.precision = fraction(&stem[1..])?,
+        _ if stem.starts_with('@') => options.precision = significant(stem)?,
+        _ if stem.starts_with('E') => options.notation = concise_scientific(&stem[1..])?,
+        _ if stem.starts_with('0') => options.integer_width = integer_width(&format!("*{}", stem))?,
+        _ => return None,
+    }
+    Some(())
+}
+
+/// The identifier of a known measurement unit, like `kilometer`, or
+/// of a compound unit of two known units, like `kilometer-per-hour`.
+fn measure_unit(unit: &str) -> Option<NumberUnit> {
+    let known = |unit: &str| {
+        cldr::find(units::PATTERNS, "root")
+            .and_then(|patterns| cldr::find(patterns, unit))
+            .is_some()
+    };
+    let compound = unit
+        .split_once("-per-")
+        .is_some_and(|(numerator, denominator)| known(numerator) && known(denominator));
+    if known(unit) || compound {
+        Some(NumberUnit::Measure(unit.to_string()))
+    } else {
+        None
+    }
+}
+
+/// The only option of a stem.
+fn single<'a>(args: &[&'a str]) -> Option<&'a str> {
+    match *args {
+        [arg] => Some(arg),
+        _ => None,
+    }
+}
+
+/// Parse a sign display stem, like `sign-always` or `+!`.
+fn sign_display(stem: &str) -> Option<SignDisplay> {
+    Some(match stem {
+        "sign-auto" => SignDisplay::Auto,
+        "sign-always" | "+!" => SignDisplay::Always,
+        "sign-never" | "+_" => SignDisplay::Never,
+        "sign-except-zero" | "+?" => SignDisplay::ExceptZero,
+        "sign-negative" | "+-" => SignDisplay::Negative,
+        "sign-accounting" | "()" => SignDisplay::Accounting,
+        "sign-accounting-always" | "()!" => SignDisplay::AccountingAlways,
+        "sign-accounting-except-zero" | "()?" => SignDisplay::AccountingExceptZero,
+        "sign-accounting-negative" | "()-" => SignDisplay::AccountingNegative,
+        _ => return None,
+    })
+}
+
+/// Parse the options of a `scientific` or `engineering` stem, like
+/// `scientific/*ee/sign-always`.
+fn scientific(engineering: bool, args: &[&str]) -> Option<Notation> {
+    let mut minimum_exponent_digits = 1;
+    let mut exponent_sign_display = SignDisplay::Auto;
+    for arg in args {
+        if let Some(sign) = sign_display(arg) {
+            exponent_sign_display = sign;
+        } else {
+            let digits = arg.strip_prefix('*').or_else(|| arg.strip_prefix('+'))?;
+            if digits.is_empty() || !digits.bytes().all(|b| b == b'e') {
+                return None;
+            }
+            minimum_exponent_digits = digits.len();
+        }
+    }
+    Some(Notation::Scientific {
+        engineering: engineering,
+        minimum_exponent_digits: minimum_exponent_digits,
+        exponent_sign_display: exponent_sign_display,
+    })
+}
+
+/// Parse a concise scientific stem after its `E`, like the `E+!00`
+/// in `EE+!00`.
+fn concise_scientific(stem: &str) -> Option<Notation> {
+    let (engineering, stem) = match stem.strip_prefix('E') {
+        Some(rest) => (true, rest),
+        None => (false, stem),
+    };
+    let (exponent_sign_display, digits) = if let Some(rest) = stem.strip_prefix("+!") {
+        (SignDisplay::Always, rest)
+    } else if let Some(rest) = stem.strip_prefix("+?") {
+        (SignDisplay::ExceptZero, rest)
+    } else {
+        (SignDisplay::Auto, stem)
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b == b'0') {
+        return None;
+    }
+    Some(Notation::Scientific {
+        engineering: engineering,
+        minimum_exponent_digits: digits.len(),
+        exponent_sign_display: exponent_sign_display,
+    })
+}
+
+/// Parse a fraction precision stem after its `.`, like the `00#` in
+/// `.00#`.
+fn fraction(stem: &str) -> Option<Precision> {
+    let zeros = stem.bytes().take_while(|&b| b == b'0').count();
+    let rest = &stem[zeros..];
+    let hashes = rest.bytes().take_while(|&b| b == b'#').count();
+    match &rest[hashes..] {
+        "+" if hashes == 0 => Some(Precision::Fraction {
+            minimum: zeros,
+            maximum: None,
+        }),
+        "" if zeros + hashes == 0 => Some(Precision::Integer),
+        "" => Some(Precision::Fraction {
+            minimum: zeros,
+            maximum: Some(zeros + hashes),
+        }),
+        _ => None,
+    }
+}
+
+/// Parse a significant digits precision stem, like `@@#` or `@@+`.
+fn significant(stem: &str) -> Option<Precision> {
+    let ats = stem.bytes().take_while(|&b| b == b'@').count();
+    let rest = &stem[ats..];
+    let hashes = rest.bytes().take_while(|&b| b == b'#').count();
+    match &rest[hashes..] {
+        "+" if hashes == 0 => Some(Precision::Significant {
+            minimum: ats,
+            maximum: None,
+        }),
+        "" => Some(Precision::Significant {
+            minimum: ats,
+            maximum: Some(ats + hashes),
+        }),
+        _ => None,
+    }
+}
+
+/// Parse the option of an `integer-width` stem, like `*000` or `##0`.
+fn integer_width(arg: &str) -> Option<IntegerWidth> {
+    if let Some(zeros) = arg.strip_prefix('*').or_else(|| arg.strip_prefix('+')) {
+        if !zeros.bytes().all(|b| b == b'0') {
+            return None;
+        }
+        return Some(IntegerWidth {
+            minimum: zeros.len(),
+            maximum: None,
+        });
+    }
+    let hashes = arg.bytes().take_while(|&b| b == b'#').count();
+    let zeros = &arg[hashes..];
+    if arg.is_empty() || !zeros.bytes().all(|b| b == b'0') {
+        return None;
+    }
+    Some(IntegerWidth {
+        minimum: zeros.len(),
+        maximum: Some(arg.len()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(skeleton: &str) -> NumberFormatOptions {
+        NumberFormatOptions::from_skeleton(skeleton).unwrap()
+    }
+
+    fn format(skeleton: &str, language: &str, value: &str) -> String {
+        parse(skeleton).format(&language.parse().unwrap(), &value.parse().unwrap())
+    }
+
+    #[test]
+    fn stems_work() {
+        assert_eq!(
+            parse("  percent   .00  "),
+            NumberFormatOptions {
+                unit: NumberUnit::Percent,
+                precision: Precision::Fraction {
+                    minimum: 2,
+                    maximum: Some(2),
+                },
+                ..NumberFormatOptions::default()
+            }
+        );
+        assert_eq!(parse("%x100").scale, Some(Decimal::from(100)));
+        assert_eq!(
+            parse("currency/EUR").unit,
+            NumberUnit::Currency(Some("EUR".to_string()))
+        );
+        assert_eq!(
+            parse("precision-currency-cash").precision,
+            Precision::CurrencyCash
+        );
+        assert_eq!(
+            parse("precision-increment/0.05").precision,
+            Precision::Increment("0.05".parse().unwrap())
+        );
+        assert_eq!(
+            parse("rounding-mode-floor").rounding_mode,
+            RoundingMode::Floor
+        );
+        assert_eq!(parse("()!").sign_display, SignDisplay::AccountingAlways);
+        assert_eq!(parse("group-min2").grouping, Grouping::Min2);
+        assert_eq!(parse(",_").grouping, Grouping::Off);
+        assert_eq!(parse("latin").numbering_system, Some("latn".to_string()));
+        assert_eq!(
+            parse("numbering-system/arab").numbering_system,
+            Some("arab".to_string())
+        );
+        assert_eq!(parse("unit-width-iso-code").unit_width, UnitWidth::IsoCode);
+        assert_eq!(
+            parse("unit-width-full-name").unit_width,
+            UnitWidth::FullName
+        );
+    }
+
+    #[test]
+    fn unit_stems_work() {
+        let measure = |unit: &str| NumberUnit::Measure(unit.to_string());
+        assert_eq!(parse("unit/kilometer").unit, measure("kilometer"));
+        assert_eq!(
+            parse("unit/kilocalorie-per-hour").unit,
+            measure("kilocalorie-per-hour")
+        );
+        assert_eq!(parse("measure-unit/mass-pound").unit, measure("pound"));
+        assert_eq!(
+            parse("measure-unit/length-mile per-measure-unit/duration-hour").unit,
+            measure("mile-per-hour")
+        );
+        assert_eq!(
+            format("unit/kilometer unit-width-full-name", "en", "5"),
+            "5 kilometers"
+        );
+        assert_eq!(format("unit/kilometer-per-hour", "de", "50"), "50 km/h");
+    }
+
+    #[test]
+    fn precision_stems_work() {
+        let fraction = |minimum, maximum| Precision::Fraction {
+            minimum: minimum,
+            maximum: maximum,
+        };
+        let significant = |minimum, maximum| Precision::Significant {
+            minimum: minimum,
+            maximum: maximum,
+        };
+        assert_eq!(parse(".").precision, Precision::Integer);
+        assert_eq!(parse(".0#").precision, fraction(1, Some(2)));
+        assert_eq!(parse(".##").precision, fraction(0, Some(2)));
+        assert_eq!(parse(".00+").precision, fraction(2, None));
+        assert_eq!(parse(".+").precision, fraction(0, None));
+        assert_eq!(parse("@@#").precision, significant(2, Some(3)));
+        assert_eq!(parse("@@+").precision, significant(2, None));
+    }
+
+    #[test]
+    fn notation_stems_work() {
+        let scientific = |engineering, digits, sign| Notation::Scientific {
+            engineering: engineering,
+            minimum_exponent_digits: digits,
+            exponent_sign_display: sign,
+        };
+        assert_eq!(
+            parse("scientific").notation,
+            scientific(false, 1, SignDisplay::Auto)
+        );
+        assert_eq!(
+            parse("engineering/*ee/sign-always").notation,
+            scientific(true, 2, SignDisplay::Always)
+        );
+        assert_eq!(
+            parse("E0").notation,
+            scientific(false, 1, SignDisplay::Auto)
+        );
+        assert_eq!(
+            parse("EE+?00").notation,
+            scientific(true, 2, SignDisplay::ExceptZero)
+        );
+        assert_eq!(parse("compact-short").notation, Notation::CompactShort);
+        assert_eq!(parse("KK").notation, Notation::CompactLong);
+    }
+
+    #[test]
+    fn integer_width_stems_work() {
+        let width = |minimum, maximum| IntegerWidth {
+            minimum: minimum,
+            maximum: maximum,
+        };
+        assert_eq!(parse("integer-width/*000").integer_width, width(3, None));
+        assert_eq!(parse("integer-width/##0").integer_width, width(1, Some(3)));
+        assert_eq!(parse("integer-width/00").integer_width, width(2, Some(2)));
+        assert_eq!(
+            parse("integer-width-trunc").integer_width,
+            width(0, Some(0))
+        );
+        assert_eq!(parse("000").integer_width, width(3, None));
+    }
+
+    #[test]
+    fn formatting_works() {
+        assert_eq!(format("percent .00 group-off", "en", "12.3456"), "12.35%");
+        assert_eq!(format("%x100", "en", "0.5"), "50%");
+        assert_eq!(
+            format("currency/EUR precision-currency-cash", "de", "3.456"),
+            "3,46\u{a0}€"
+        );
+        assert_eq!(
+            format("currency/CHF precision-currency-cash", "de-CH", "3.42"),
+            "CHF\u{a0}3.40"
+        );
+        assert_eq!(
+            format("scientific/+ee/sign-always", "en", "1234"),
+            "1.234E+03"
+        );
+        assert_eq!(format("@@ rounding-mode-up", "en", "1234"), "1,300");
+        assert_eq!(
+            format("sign-accounting currency/USD", "en", "-5"),
+            "($5.00)"
+        );
+        assert_eq!(format("numbering-system/arab", "en", "12"), "١٢");
+    }
+
+    #[test]
+    fn errors_work() {
+        let error = |skeleton| NumberFormatOptions::from_skeleton(skeleton).unwrap_err();
+        assert_eq!(
+            error("percent  bogus"),
+            NumberSkeletonError {
+                offset: 9,
+                token: "bogus".to_string(),
+            }
+        );
+        assert_eq!(error("currency").token, "currency");
+        assert_eq!(error("currency/EURO").token, "currency/EURO");
+        assert_eq!(error("percent/x").token, "percent/x");
+        assert_eq!(error(".0+#").token, ".0+#");
+        assert_eq!(error("scale/abc").token, "scale/abc");
+        assert_eq!(
+            error("precision-increment/0").token,
+            "precision-increment/0"
+        );
+        assert_eq!(error("unit/furlong").token, "unit/furlong");
+        assert_eq!(
+            error("unit/kilometer-per-fortnight").token,
+            "unit/kilometer-per-fortnight"
+        );
+        assert_eq!(error("measure-unit/meter").token, "measure-unit/meter");
+        assert_eq!(
+            error("per-measure-unit/duration-hour").token,
+            "per-measure-unit/duration-hour"
+        );
+        assert_eq!(error("decimal-always").token, "decimal-always");
+        assert_eq!(error("x").to_string(), "invalid number skeleton token `x`");
+        assert_eq!(error("currency/usd").token, "currency/usd");
+    }
+
+    #[test]
+    fn empty_skeletons_are_errors() {
+        let error = |skeleton| NumberFormatOptions::from_skeleton(skeleton).unwrap_err();
+        assert_eq!(
+            error(""),
+            NumberSkeletonError {
+                offset: 0,
+                token: String::new(),
+            }
+        );
+        assert_eq!(error("  ").token, "");
+        assert_eq!(error("").to_string(), "empty number skeleton");
+    }
+
+    #[test]
+    fn repeated_settings_are_errors() {
+        let error = |skeleton| NumberFormatOptions::from_skeleton(skeleton).unwrap_err();
+        assert_eq!(
+            error("percent percent"),
+            NumberSkeletonError {
+                offset: 8,
+                token: "percent".to_string(),
+            }
+        );
+        assert_eq!(error(".00 .00").offset, 4);
+        assert_eq!(error("percent currency/EUR").token, "currency/EUR");
+        assert_eq!(error("% unit/meter").token, "unit/meter");
+        assert_eq!(error(".00 @@").token, "@@");
+        assert_eq!(error("precision-integer .00").token, ".00");
+        assert_eq!(error("sign-always +_").token, "+_");
+        assert_eq!(error("group-off ,?").token, ",?");
+        assert_eq!(error("K scientific").token, "scientific");
+        assert_eq!(error("E0 KK").token, "KK");
+        assert_eq!(error("%x100 scale/2").token, "scale/2");
+        assert_eq!(
+            error("latin numbering-system/arab").token,
+            "numbering-system/arab"
+        );
+        assert_eq!(error("000 integer-width/##0").token, "integer-width/##0");
+        assert_eq!(
+            error("unit-width-narrow unit-width-short").token,
+            "unit-width-short"
+        );
+        assert_eq!(
+            error("rounding-mode-up rounding-mode-floor").token,
+            "rounding-mode-floor"
+        );
+
+        // Stems for different settings may be combined.
+        assert!(NumberFormatOptions::from_skeleton(
+            "measure-unit/length-meter per-measure-unit/duration-second .0 sign-always"
+        )
+        .is_ok());
+        assert!(NumberFormatOptions::from_skeleton("%x100 .00 group-off").is_ok());
+    }
+}
@@ -6,12 +6,12 @@
 
 /// The set of [grammatical numbers] that we support.
 ///
-/// These are used by the [ICU `PluralFormat`]. See also
-/// [`english_cardinal_classifier`].
+/// These are used by the [ICU `PluralFormat`] and are selected by
+/// [`PluralRules`].
 ///
 /// [grammatical numbers]: https://en.wikipedia.org/wiki/Grammatical_number
 /// [ICU `PluralFormat`]: icu/ast/struct.PluralFormat.html
-/// [`english_cardinal_classifier`]: fn.english_cardinal_classifier.html
+/// [`PluralRules`]: struct.PluralRules.html
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PluralCategory {
     /// Value is `0`.
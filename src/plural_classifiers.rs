@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::PluralCategory;
+use super::{PluralCategory, PluralOperands};
 
 /// English cardinal plural classifier.
 ///
@@ -12,15 +12,128 @@ use super::PluralCategory;
 /// with all other numbers mapped to `PluralCategory::Other`.
 ///
 /// ```
+/// # #![allow(deprecated)]
 /// use message_format::{english_cardinal_classifier, PluralCategory};
 ///
 /// assert_eq!(english_cardinal_classifier(0), PluralCategory::Other);
 /// assert_eq!(english_cardinal_classifier(1), PluralCategory::One);
 /// assert_eq!(english_cardinal_classifier(2), PluralCategory::Other);
 /// ```
-pub fn english_cardinal_classifier(value: i64) -> PluralCategory {
-    match value {
-        1 => PluralCategory::One,
+///
+/// Numbers with visible fraction digits are never mapped to
+/// `PluralCategory::One` ("1.0 items"):
+///
+/// ```
+/// # #![allow(deprecated)]
+/// use message_format::{english_cardinal_classifier, PluralCategory, PluralOperands};
+///
+/// let operands: PluralOperands = "1.0".parse().unwrap();
+/// assert_eq!(english_cardinal_classifier(operands), PluralCategory::Other);
+/// ```
+#[deprecated(note = "use `PluralRules::for_language` with `PluralType::Cardinal`")]
+pub fn english_cardinal_classifier<T: Into<PluralOperands>>(value: T) -> PluralCategory {
+    let operands = value.into();
+    match (operands.i, operands.v) {
+        (1, 0) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// English ordinal plural classifier.
+///
+/// In English, ordinals ending in 1, 2 or 3 (but not 11, 12 or 13)
+/// are mapped to `PluralCategory::One`, `PluralCategory::Two` and
+/// `PluralCategory::Few` respectively ("1st", "22nd", "103rd"),
+/// with all other numbers mapped to `PluralCategory::Other` ("11th").
+///
+/// ```
+/// # #![allow(deprecated)]
+/// use message_format::{english_ordinal_classifier, PluralCategory};
+///
+/// assert_eq!(english_ordinal_classifier(1), PluralCategory::One);
+/// assert_eq!(english_ordinal_classifier(2), PluralCategory::Two);
+/// assert_eq!(english_ordinal_classifier(3), PluralCategory::Few);
+/// assert_eq!(english_ordinal_classifier(4), PluralCategory::Other);
+/// assert_eq!(english_ordinal_classifier(11), PluralCategory::Other);
+/// assert_eq!(english_ordinal_classifier(21), PluralCategory::One);
+/// assert_eq!(english_ordinal_classifier(112), PluralCategory::Other);
+/// ```
+#[deprecated(note = "use `PluralRules::for_language` with `PluralType::Ordinal`")]
+pub fn english_ordinal_classifier<T: Into<PluralOperands>>(value: T) -> PluralCategory {
+    let operands = value.into();
+    match (operands.f, operands.i % 10, operands.i % 100) {
+        (0, 1, n) if n != 11 => PluralCategory::One,
+        (0, 2, n) if n != 12 => PluralCategory::Two,
+        (0, 3, n) if n != 13 => PluralCategory::Few,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// French ordinal plural classifier.
+///
+/// In French, only `1` ("1er") is mapped to `PluralCategory::One`,
+/// with all other numbers mapped to `PluralCategory::Other` ("2e").
+///
+/// ```
+/// # #![allow(deprecated)]
+/// use message_format::{french_ordinal_classifier, PluralCategory};
+///
+/// assert_eq!(french_ordinal_classifier(1), PluralCategory::One);
+/// assert_eq!(french_ordinal_classifier(2), PluralCategory::Other);
+/// assert_eq!(french_ordinal_classifier(21), PluralCategory::Other);
+/// ```
+#[deprecated(note = "use `PluralRules::for_language` with `PluralType::Ordinal`")]
+pub fn french_ordinal_classifier<T: Into<PluralOperands>>(value: T) -> PluralCategory {
+    let operands = value.into();
+    match (operands.f, operands.i) {
+        (0, 1) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Italian ordinal plural classifier.
+///
+/// In Italian, `8`, `11`, `80` and `800` are mapped to
+/// `PluralCategory::Many` ("l'11°"), with all other numbers mapped
+/// to `PluralCategory::Other` ("il 12°").
+///
+/// ```
+/// # #![allow(deprecated)]
+/// use message_format::{italian_ordinal_classifier, PluralCategory};
+///
+/// assert_eq!(italian_ordinal_classifier(8), PluralCategory::Many);
+/// assert_eq!(italian_ordinal_classifier(11), PluralCategory::Many);
+/// assert_eq!(italian_ordinal_classifier(12), PluralCategory::Other);
+/// ```
+#[deprecated(note = "use `PluralRules::for_language` with `PluralType::Ordinal`")]
+pub fn italian_ordinal_classifier<T: Into<PluralOperands>>(value: T) -> PluralCategory {
+    let operands = value.into();
+    match (operands.f, operands.i) {
+        (0, 8) | (0, 11) | (0, 80) | (0, 800) => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Swedish ordinal plural classifier.
+///
+/// In Swedish, ordinals ending in 1 or 2 (but not 11 or 12) are
+/// mapped to `PluralCategory::One` ("1:a", "22:a"), with all other
+/// numbers mapped to `PluralCategory::Other` ("3:e", "11:e").
+///
+/// ```
+/// # #![allow(deprecated)]
+/// use message_format::{swedish_ordinal_classifier, PluralCategory};
+///
+/// assert_eq!(swedish_ordinal_classifier(1), PluralCategory::One);
+/// assert_eq!(swedish_ordinal_classifier(22), PluralCategory::One);
+/// assert_eq!(swedish_ordinal_classifier(3), PluralCategory::Other);
+/// assert_eq!(swedish_ordinal_classifier(12), PluralCategory::Other);
+/// ```
+#[deprecated(note = "use `PluralRules::for_language` with `PluralType::Ordinal`")]
+pub fn swedish_ordinal_classifier<T: Into<PluralOperands>>(value: T) -> PluralCategory {
+    let operands = value.into();
+    match (operands.f, operands.i % 10, operands.i % 100) {
+        (0, 1, n) | (0, 2, n) if n != 11 && n != 12 => PluralCategory::One,
         _ => PluralCategory::Other,
     }
 }
@@ -0,0 +1,315 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The operands of a number that [plural rules] are written in terms of.
+///
+/// These depend on how a number is written as well as its value, so
+/// `1` and `1.0` have different operands and may have different plural
+/// categories. Operands can be created from integers, floats and
+/// decimal strings:
+///
+/// ```
+/// use message_format::PluralOperands;
+///
+/// let operands: PluralOperands = "-1.50".parse().unwrap();
+/// assert_eq!(operands.n, 1.5);
+/// assert_eq!(operands.i, 1);
+/// assert_eq!(operands.v, 2);
+/// assert_eq!(operands.w, 1);
+/// assert_eq!(operands.f, 50);
+/// assert_eq!(operands.t, 5);
+///
+/// let operands: PluralOperands = "1.2c6".parse().unwrap();
+/// assert_eq!(operands.i, 1_200_000);
+/// assert_eq!(operands.c, 6);
+///
+/// assert_eq!(PluralOperands::from(2.5), "2.5".parse().unwrap());
+/// assert_eq!(PluralOperands::from(42), "42".parse().unwrap());
+/// ```
+///
+/// Floats are converted using their shortest representation, so
+/// `1.0_f64` has the same operands as `1`. Use a decimal string to
+/// keep trailing zeros.
+///
+/// [plural rules]: http://unicode.org/reports/tr35/tr35-numbers.html#Operands
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PluralOperands {
+    /// The absolute value of the number.
+    pub n: f64,
+    /// The integer digits of `n`.
+    pub i: u64,
+    /// The number of visible fraction digits in `n`, with trailing zeros.
+    pub v: usize,
+    /// The number of visible fraction digits in `n`, without trailing zeros.
+    pub w: usize,
+    /// The visible fraction digits in `n`, with trailing zeros. Only
+    /// the first 18 digits are kept.
+    pub f: u64,
+    /// The visible fraction digits in `n`, without trailing zeros. Only
+    /// the first 18 digits are kept.
+    pub t: u64,
+    /// The exponent of `n` in compact or scientific notation, such as
+    /// `6` for `1.2c6`. This is also known as `e`.
+    pub c: usize,
+}
+
+/// An error resulting from parsing `PluralOperands` from a string
+/// that isn't a decimal number.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsePluralOperandsError {
+    input: String,
+}
+
+impl Error for ParsePluralOperandsError {
+    fn description(&self) -> &str {
+        "Invalid decimal number."
+    }
+}
+
+impl fmt::Display for ParsePluralOperandsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "invalid decimal number `{}`", self.input)
+    }
+}
+
+/// The largest exponent that a decimal string may have, so that
+/// applying it takes a bounded amount of time and memory.
+const MAX_EXPONENT: usize = 1000;
+
+impl FromStr for PluralOperands {
+    type Err = ParsePluralOperandsError;
+
+    /// Parse a decimal number like `-1.50`, optionally followed by an
+    /// exponent using `c` or `e` as in `1.2c6`. Exponents above 1000
+    /// are rejected.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = || ParsePluralOperandsError {
+            input: text.to_string(),
+        };
+        let unsigned = text.strip_prefix('-').unwrap_or(text);
+        let (mantissa, c) = match unsigned.find(&['c', 'e'][..]) {
+            Some(index) => (
+                &unsigned[..index],
+                try!(unsigned[index + 1..].parse().map_err(|_| error())),
+            ),
+            None => (unsigned, 0),
+        };
+        if c > MAX_EXPONENT {
+            return Err(error());
+        }
+        let (integer, fraction) = match mantissa.find('.') {
+            Some(index) => (&mantissa[..index], &mantissa[index + 1..]),
+            None => (mantissa, ""),
+        };
+        if integer.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(error());
+        }
+
+        Ok(from_digits(integer, fraction, c))
+    }
+}
+
+/// The operands of a number with the given integer and fraction
+/// digits, multiplied by 10 to the power of `c`.
+pub fn from_digits(integer: &str, fraction: &str, c: usize) -> PluralOperands {
+    // Apply the exponent by moving digits from the fraction into the
+    // integer, padding with zeros as needed.
+    let shift = c.min(fraction.len());
+    let integer = format!("{}{}{}", integer, &fraction[..shift], "0".repeat(c - shift));
+    let integer = integer.trim_start_matches('0');
+    let fraction = &fraction[shift..];
+    let trimmed = fraction.trim_end_matches('0');
+    PluralOperands {
+        n: format!("0{}.{}0", integer, fraction).parse().unwrap_or(0.0),
+        i: integer_digits(integer),
+        v: fraction.len(),
+        w: trimmed.len(),
+        f: fraction_digits(fraction),
+        t: fraction_digits(trimmed),
+        c: c,
+    }
+}
+
+/// The value of the first 18 fraction digits of a number, which is as
+/// many as always fit in a `u64`.
+fn fraction_digits(fraction: &str) -> u64 {
+    fraction[..fraction.len().min(18)].parse().unwrap_or(0)
+}
+
+/// The value of the integer digits of a number.
+///
+/// If these don't fit in a `u64`, only the last 18 digits are kept,
+/// offset by 10^18 so that the value is still large and non-zero.
+/// This keeps the result of every modulus used by the CLDR rules.
+fn integer_digits(integer: &str) -> u64 {
+    if integer.is_empty() {
+        0
+    } else if let Ok(value) = integer.parse() {
+        value
+    } else {
+        let last = &integer[integer.len() - 18..];
+        1_000_000_000_000_000_000 + last.parse::<u64>().unwrap_or(0)
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                #[allow(trivial_numeric_casts)]
+                fn from(value: $t) -> Self {
+                    PluralOperands {
+                        n: value as f64,
+                        i: value as u64,
+                        ..PluralOperands::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                #[allow(trivial_numeric_casts)]
+                fn from(value: $t) -> Self {
+                    // This can't overflow, unlike `abs`.
+                    let value = (value as i64).wrapping_abs() as u64;
+                    PluralOperands {
+                        n: value as f64,
+                        i: value,
+                        ..PluralOperands::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, usize);
+from_signed!(i8, i16, i32, i64, isize);
+
+impl From<f64> for PluralOperands {
+    fn from(value: f64) -> Self {
+        if value.is_finite() {
+            // `Display` gives the shortest representation that
+            // round-trips, without an exponent.
+            format!("{}", value)
+                .parse()
+                .unwrap_or_else(|_| PluralOperands::default())
+        } else {
+            PluralOperands {
+                n: value.abs(),
+                ..PluralOperands::default()
+            }
+        }
+    }
+}
+
+impl From<f32> for PluralOperands {
+    fn from(value: f32) -> Self {
+        if value.is_finite() {
+            format!("{}", value)
+                .parse()
+                .unwrap_or_else(|_| PluralOperands::default())
+        } else {
+            PluralOperands {
+                n: f64::from(value.abs()),
+                ..PluralOperands::default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operands(n: f64, i: u64, v: usize, w: usize, f: u64, t: u64, c: usize) -> PluralOperands {
+        PluralOperands {
+            n: n,
+            i: i,
+            v: v,
+            w: w,
+            f: f,
+            t: t,
+            c: c,
+        }
+    }
+
+    #[test]
+    fn from_str_works() {
+        assert_eq!("0".parse(), Ok(operands(0.0, 0, 0, 0, 0, 0, 0)));
+        assert_eq!("1.0".parse(), Ok(operands(1.0, 1, 1, 0, 0, 0, 0)));
+        assert_eq!("1.50".parse(), Ok(operands(1.5, 1, 2, 1, 50, 5, 0)));
+        assert_eq!("-0.05".parse(), Ok(operands(0.05, 0, 2, 2, 5, 5, 0)));
+        assert_eq!("1.2c3".parse(), Ok(operands(1200.0, 1200, 0, 0, 0, 0, 3)));
+        assert_eq!(
+            "1.0001e3".parse(),
+            Ok(operands(1000.1, 1000, 1, 1, 1, 1, 3))
+        );
+        assert_eq!(
+            "123456789012345678901".parse::<PluralOperands>().unwrap().i % 1_000_000,
+            678_901
+        );
+        assert!("".parse::<PluralOperands>().is_err());
+        assert!(".5".parse::<PluralOperands>().is_err());
+        assert!("1.x".parse::<PluralOperands>().is_err());
+        assert!("1c".parse::<PluralOperands>().is_err());
+
+        // Long fractions keep their digit counts, but only the first
+        // 18 digits of `f` and `t`.
+        assert_eq!(
+            "0.00000000000000000001".parse(),
+            Ok(operands(1e-20, 0, 20, 20, 0, 0, 0))
+        );
+        assert_eq!(
+            "1.12345678901234567890".parse(),
+            Ok(operands(
+                1.1234567890123457,
+                1,
+                20,
+                19,
+                123_456_789_012_345_678,
+                123_456_789_012_345_678,
+                0
+            ))
+        );
+
+        // Exponents are bounded.
+        assert_eq!("1c1000".parse::<PluralOperands>().unwrap().c, 1000);
+        assert!("1c1001".parse::<PluralOperands>().is_err());
+        assert!("1c4000000000".parse::<PluralOperands>().is_err());
+    }
+
+    #[test]
+    fn from_numbers_works() {
+        assert_eq!(PluralOperands::from(-3), operands(3.0, 3, 0, 0, 0, 0, 0));
+        assert_eq!(PluralOperands::from(i64::MIN).i, 9_223_372_036_854_775_808);
+        assert_eq!(PluralOperands::from(u64::MAX).i, u64::MAX);
+        assert_eq!(PluralOperands::from(1.0), operands(1.0, 1, 0, 0, 0, 0, 0));
+        assert_eq!(
+            PluralOperands::from(-0.25),
+            operands(0.25, 0, 2, 2, 25, 25, 0)
+        );
+        assert_eq!(
+            PluralOperands::from(1.5_f32),
+            operands(1.5, 1, 1, 1, 5, 5, 0)
+        );
+        assert_eq!(PluralOperands::from(1e20).i % 1_000_000, 0);
+    }
+}
@@ -0,0 +1,638 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use language_tags::LanguageTag;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use cldr;
+use {PluralCategory, PluralOperands, PluralType};
+
+/// A set of plural rules written in the [CLDR plural rule syntax].
+///
+/// Rules are separated by `;` and each maps a plural category to a
+/// condition, optionally followed by `@integer` and `@decimal` samples:
+///
+/// ```
+/// use message_format::{PluralCategory, PluralRules};
+///
+/// let rules = PluralRules::parse(
+///     "one: i = 1 and v = 0 @integer 1; \
+///      few: i = 2..4 and v = 0 @integer 2~4; \
+///      other: @integer 0, 5~19, 100, 1000",
+/// ).unwrap();
+/// assert_eq!(rules.select(1), PluralCategory::One);
+/// assert_eq!(rules.select(3), PluralCategory::Few);
+/// assert_eq!(rules.select(7), PluralCategory::Other);
+/// assert!(rules.verify_samples().is_ok());
+/// ```
+///
+/// The rules for a language can be found with [`PluralRules::cardinal`]
+/// and [`PluralRules::ordinal`].
+///
+/// [CLDR plural rule syntax]: http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules
+/// [`PluralRules::cardinal`]: #method.cardinal
+/// [`PluralRules::ordinal`]: #method.ordinal
+#[derive(Clone, Debug)]
+pub struct PluralRules {
+    rules: Arc<Vec<Rule>>,
+}
+
+/// An error resulting from parsing or verifying `PluralRules`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PluralRulesError {
+    /// The rules could not be parsed.
+    Syntax {
+        /// The byte offset into the rules at which the error was detected.
+        offset: usize,
+        /// A description of what was expected.
+        expected: String,
+    },
+    /// A sample value did not select the category it was listed for.
+    SampleMismatch {
+        /// The sample value.
+        sample: String,
+        /// The category whose rule listed the sample.
+        expected: PluralCategory,
+        /// The category that the rules selected for the sample.
+        actual: PluralCategory,
+    },
+}
+
+impl Error for PluralRulesError {
+    fn description(&self) -> &str {
+        match *self {
+            PluralRulesError::Syntax { .. } => "Invalid plural rules.",
+            PluralRulesError::SampleMismatch { .. } => "Plural rule sample mismatch.",
+        }
+    }
+}
+
+impl fmt::Display for PluralRulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            PluralRulesError::Syntax {
+                offset,
+                ref expected,
+            } => write!(f, "expected {} at offset {}", expected, offset),
+            PluralRulesError::SampleMismatch {
+                ref sample,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "sample {} is listed for {:?} but selects {:?}",
+                sample, expected, actual
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    category: PluralCategory,
+    /// The conditions joined by `or`, each of which is a set of
+    /// relations joined by `and`.
+    condition: Vec<Vec<Relation>>,
+    samples: Vec<SampleRange>,
+}
+
+#[derive(Debug)]
+struct Relation {
+    operand: char,
+    modulus: Option<u64>,
+    negated: bool,
+    /// `within` also matches values with a fraction between the ends
+    /// of a range, while `in`, `=` and `is` only match integers.
+    within: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+#[derive(Debug)]
+struct SampleRange {
+    start: String,
+    end: Option<String>,
+}
+
+impl Relation {
+    fn matches(&self, operands: &PluralOperands) -> bool {
+        // The value of `n` is taken as its integer digits and whether
+        // it has a non-zero fraction, which is all that the rules can
+        // distinguish, to avoid floating point rounding.
+        let (value, has_fraction) = match self.operand {
+            'n' => (operands.i, operands.f != 0),
+            'i' => (operands.i, false),
+            'v' => (operands.v as u64, false),
+            'w' => (operands.w as u64, false),
+            'f' => (operands.f, false),
+            't' => (operands.t, false),
+            _ => (operands.c as u64, false),
+        };
+        let value = self.modulus.map_or(value, |modulus| value % modulus);
+        let found = self.ranges.iter().any(|&(low, high)| {
+            if has_fraction {
+                self.within && low <= value && value < high
+            } else {
+                low <= value && value <= high
+            }
+        });
+        found != self.negated
+    }
+}
+
+impl Rule {
+    fn matches(&self, operands: &PluralOperands) -> bool {
+        self.condition.is_empty()
+            || self
+                .condition
+                .iter()
+                .any(|relations| relations.iter().all(|relation| relation.matches(operands)))
+    }
+}
+
+impl PluralRules {
+    /// Parse plural rules written in the CLDR plural rule syntax.
+    pub fn parse(rules: &str) -> Result<Self, PluralRulesError> {
+        Parser::new(rules).parse().map(|rules| PluralRules {
+            rules: Arc::new(rules),
+        })
+    }
+
+    /// The CLDR cardinal plural rules for a language.
+    ///
+    /// These are the rules for the most specific locale matching
+    /// `language_tag`, so `pt-BR` uses the rules for `pt`. If there
+    /// are no rules for the language, the root rules are used, which
+    /// map every number to `PluralCategory::Other`.
+    ///
+    /// ```
+    /// use message_format::{PluralCategory, PluralRules};
+    ///
+    /// let rules = PluralRules::cardinal(&"ru".parse().unwrap());
+    /// assert_eq!(rules.select(1), PluralCategory::One);
+    /// assert_eq!(rules.select(3), PluralCategory::Few);
+    /// assert_eq!(rules.select(5), PluralCategory::Many);
+    /// assert_eq!(rules.select(21), PluralCategory::One);
+    /// ```
+    pub fn cardinal(language_tag: &LanguageTag) -> Self {
+        PluralRules::for_language(language_tag, PluralType::Cardinal)
+    }
+
+    /// The CLDR ordinal plural rules for a language.
+    ///
+    /// As with [`PluralRules::cardinal`], this falls back to less
+    /// specific locales and finally to the root rules.
+    ///
+    /// ```
+    /// use message_format::{PluralCategory, PluralRules};
+    ///
+    /// let rules = PluralRules::ordinal(&"en".parse().unwrap());
+    /// assert_eq!(rules.select(1), PluralCategory::One);
+    /// assert_eq!(rules.select(2), PluralCategory::Two);
+    /// assert_eq!(rules.select(3), PluralCategory::Few);
+    /// assert_eq!(rules.select(11), PluralCategory::Other);
+    /// ```
+    ///
+    /// [`PluralRules::cardinal`]: #method.cardinal
+    pub fn ordinal(language_tag: &LanguageTag) -> Self {
+        PluralRules::for_language(language_tag, PluralType::Ordinal)
+    }
+
+    /// The CLDR plural rules of the given type for a language.
+    pub fn for_language(language_tag: &LanguageTag, plural_type: PluralType) -> Self {
+        let table = match plural_type {
+            PluralType::Cardinal => cldr::plurals::CARDINAL,
+            PluralType::Ordinal => cldr::plurals::ORDINAL,
+        };
+        let text = cldr::lookup(table, language_tag).unwrap_or("");
+
+        // Parsing the rules for every message formatted would be
+        // wasteful, so they are cached.
+        thread_local! {
+            static CACHE: RefCell<HashMap<&'static str, PluralRules>> = RefCell::new(HashMap::new());
+        }
+        CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .entry(text)
+                .or_insert_with(|| {
+                    PluralRules::parse(text).expect("CLDR plural rules must be valid")
+                })
+                .clone()
+        })
+    }
+
+    /// Determine the plural category of a value.
+    ///
+    /// The category of the first rule whose condition matches is used.
+    /// If no rule matches, this is `PluralCategory::Other`.
+    ///
+    /// Since the rules depend on how a number is written, the value
+    /// may be anything that can be converted to `PluralOperands`:
+    ///
+    /// ```
+    /// use message_format::{PluralCategory, PluralOperands, PluralRules};
+    ///
+    /// let rules = PluralRules::cardinal(&"en".parse().unwrap());
+    /// assert_eq!(rules.select(1), PluralCategory::One);
+    /// assert_eq!(rules.select(1.5), PluralCategory::Other);
+    ///
+    /// let operands: PluralOperands = "1.0".parse().unwrap();
+    /// assert_eq!(rules.select(operands), PluralCategory::Other);
+    /// ```
+    pub fn select<T: Into<PluralOperands>>(&self, value: T) -> PluralCategory {
+        let operands = value.into();
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(&operands))
+            .map_or(PluralCategory::Other, |rule| rule.category)
+    }
+
+    /// The categories that these rules can select, in the order that
+    /// they are given.
+    pub fn categories(&self) -> Vec<PluralCategory> {
+        let mut categories: Vec<PluralCategory> =
+            self.rules.iter().map(|rule| rule.category).collect();
+        if !categories.contains(&PluralCategory::Other) {
+            categories.push(PluralCategory::Other);
+        }
+        categories
+    }
+
+    /// Check that every `@integer` and `@decimal` sample selects the
+    /// category of the rule that it is listed with.
+    ///
+    /// Sample ranges like `0~15` or `0.0~1.5` are expanded, stepping by
+    /// the last visible digit.
+    pub fn verify_samples(&self) -> Result<(), PluralRulesError> {
+        for rule in self.rules.iter() {
+            for range in &rule.samples {
+                for sample in range.expand() {
+                    // Samples were validated while parsing.
+                    let operands: PluralOperands = sample.parse().unwrap();
+                    let actual = self.select(operands);
+                    if actual != rule.category {
+                        return Err(PluralRulesError::SampleMismatch {
+                            sample: sample,
+                            expected: rule.category,
+                            actual: actual,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SampleRange {
+    /// The samples in this range, with the same number of fraction
+    /// digits as the ends of the range.
+    fn expand(&self) -> Vec<String> {
+        let end = match self.end {
+            Some(ref end) => end,
+            None => return vec![self.start.clone()],
+        };
+        let digits = self.start.find('.').map_or(0, |i| self.start.len() - i - 1);
+        let scaled = |text: &str| text.replace('.', "").parse::<u64>().unwrap_or(0);
+        let divisor = 10u64.pow(digits as u32);
+        (scaled(&self.start)..scaled(end) + 1)
+            .map(|value| {
+                if digits == 0 {
+                    value.to_string()
+                } else {
+                    format!(
+                        "{}.{:0width$}",
+                        value / divisor,
+                        value % divisor,
+                        width = digits
+                    )
+                }
+            })
+            .collect()
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Parser<'a> {
+        Parser {
+            source: source,
+            pos: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn get_ws(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, expected: &str) -> PluralRulesError {
+        PluralRulesError::Syntax {
+            offset: self.pos,
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Consume `token` if it is next, after any whitespace.
+    fn eat(&mut self, token: &str) -> bool {
+        self.get_ws();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume the keyword `word` if it is next, after any whitespace.
+    fn eat_word(&mut self, word: &str) -> bool {
+        self.get_ws();
+        let rest = self.rest();
+        if rest.starts_with(word)
+            && !rest[word.len()..].starts_with(|c: char| c.is_ascii_alphanumeric())
+        {
+            self.pos += word.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn take_while<P: Fn(char) -> bool>(&mut self, pred: P) -> &'a str {
+        self.get_ws();
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn parse(&mut self) -> Result<Vec<Rule>, PluralRulesError> {
+        let mut rules: Vec<Rule> = vec![];
+        loop {
+            self.get_ws();
+            if self.rest().is_empty() {
+                break;
+            }
+            let start = self.pos;
+            let rule = try!(self.get_rule());
+            if rules.iter().any(|r| r.category == rule.category) {
+                self.pos = start;
+                return Err(self.error("a category without an earlier rule"));
+            }
+            rules.push(rule);
+            if !self.eat(";") {
+                self.get_ws();
+                if !self.rest().is_empty() {
+                    return Err(self.error("`;`"));
+                }
+            }
+        }
+        Ok(rules)
+    }
+
+    fn get_rule(&mut self) -> Result<Rule, PluralRulesError> {
+        let category = match self.take_while(|c| c.is_ascii_alphabetic()) {
+            "zero" => PluralCategory::Zero,
+            "one" => PluralCategory::One,
+            "two" => PluralCategory::Two,
+            "few" => PluralCategory::Few,
+            "many" => PluralCategory::Many,
+            "other" => PluralCategory::Other,
+            _ => return Err(self.error("a plural category")),
+        };
+        if !self.eat(":") {
+            return Err(self.error("`:`"));
+        }
+
+        let mut condition = vec![];
+        self.get_ws();
+        if !self.rest().starts_with('@') && !self.rest().starts_with(';') && !self.rest().is_empty()
+        {
+            loop {
+                let mut relations = vec![try!(self.get_relation())];
+                while self.eat_word("and") {
+                    relations.push(try!(self.get_relation()));
+                }
+                condition.push(relations);
+                if !self.eat_word("or") {
+                    break;
+                }
+            }
+        }
+        if category == PluralCategory::Other && !condition.is_empty() {
+            return Err(self.error("no condition for `other`"));
+        }
+
+        let mut samples = vec![];
+        if self.eat("@integer") {
+            try!(self.get_samples(&mut samples));
+        }
+        if self.eat("@decimal") {
+            try!(self.get_samples(&mut samples));
+        }
+
+        Ok(Rule {
+            category: category,
+            condition: condition,
+            samples: samples,
+        })
+    }
+
+    fn get_relation(&mut self) -> Result<Relation, PluralRulesError> {
+        let operand = match self.take_while(|c| c.is_ascii_alphabetic()) {
+            "n" => 'n',
+            "i" => 'i',
+            "v" => 'v',
+            "w" => 'w',
+            "f" => 'f',
+            "t" => 't',
+            "e" | "c" => 'e',
+            _ => return Err(self.error("an operand")),
+        };
+        let modulus = if self.eat_word("mod") || self.eat("%") {
+            Some(try!(self.get_value()))
+        } else {
+            None
+        };
+
+        let (negated, within, is) = if self.eat("!=") {
+            (true, false, false)
+        } else if self.eat("=") {
+            (false, false, false)
+        } else if self.eat_word("is") {
+            (self.eat_word("not"), false, true)
+        } else {
+            let negated = self.eat_word("not");
+            if self.eat_word("in") {
+                (negated, false, false)
+            } else if self.eat_word("within") {
+                (negated, true, false)
+            } else {
+                return Err(self.error("`=`, `!=`, `is`, `in` or `within`"));
+            }
+        };
+
+        let mut ranges = vec![];
+        loop {
+            let low = try!(self.get_value());
+            let high = if !is && self.eat("..") {
+                try!(self.get_value())
+            } else {
+                low
+            };
+            ranges.push((low, high));
+            if is || !self.eat(",") {
+                break;
+            }
+        }
+
+        Ok(Relation {
+            operand: operand,
+            modulus: modulus,
+            negated: negated,
+            within: within,
+            ranges: ranges,
+        })
+    }
+
+    fn get_value(&mut self) -> Result<u64, PluralRulesError> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits.parse().map_err(|_| self.error("a number"))
+    }
+
+    fn get_samples(&mut self, samples: &mut Vec<SampleRange>) -> Result<(), PluralRulesError> {
+        let is_sample_char = |c: char| c.is_ascii_digit() || c == '.' || c == 'c' || c == 'e';
+        loop {
+            if self.eat("…") || self.eat("...") {
+                break;
+            }
+            let start = self.take_while(is_sample_char);
+            if start.parse::<PluralOperands>().is_err() {
+                return Err(self.error("a sample value"));
+            }
+            let end = if self.eat("~") {
+                let end = self.take_while(is_sample_char);
+                if end.parse::<PluralOperands>().is_err() {
+                    return Err(self.error("a sample value"));
+                }
+                Some(end.to_string())
+            } else {
+                None
+            };
+            samples.push(SampleRange {
+                start: start.to_string(),
+                end: end,
+            });
+            if !self.eat(",") {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cldr::plurals::{CARDINAL, ORDINAL};
+
+    #[test]
+    fn relations_work() {
+        let rules = PluralRules::parse(
+            "one: n % 10 = 1 and n % 100 != 11; \
+             two: n within 2..3; \
+             few: i is not 0 and n in 4..9, 12",
+        )
+        .unwrap();
+        assert_eq!(rules.select(1), PluralCategory::One);
+        assert_eq!(rules.select(11), PluralCategory::Other);
+        assert_eq!(rules.select(21), PluralCategory::One);
+        assert_eq!(rules.select(3), PluralCategory::Two);
+        assert_eq!(rules.select(12), PluralCategory::Few);
+        assert_eq!(rules.select(13), PluralCategory::Other);
+        let select = |text: &str| rules.select(text.parse::<PluralOperands>().unwrap());
+        assert_eq!(select("2.5"), PluralCategory::Two);
+        assert_eq!(select("3.5"), PluralCategory::Other);
+        assert_eq!(select("4.5"), PluralCategory::Other);
+        assert_eq!(select("1.0"), PluralCategory::One);
+        assert_eq!(
+            rules.categories(),
+            vec![
+                PluralCategory::One,
+                PluralCategory::Two,
+                PluralCategory::Few,
+                PluralCategory::Other,
+            ]
+        );
+    }
+
+    #[test]
+    fn syntax_errors() {
+        assert_eq!(
+            PluralRules::parse("one: n = 1; some: n = 2").unwrap_err(),
+            PluralRulesError::Syntax {
+                offset: 16,
+                expected: "a plural category".to_string(),
+            }
+        );
+        assert!(PluralRules::parse("one n = 1").is_err());
+        assert!(PluralRules::parse("one: x = 1").is_err());
+        assert!(PluralRules::parse("one: n == 1").is_err());
+        assert!(PluralRules::parse("one: n = 1; one: n = 2").is_err());
+        assert!(PluralRules::parse("other: n = 1").is_err());
+        assert!(PluralRules::parse("one: n = 1 @integer 1x").is_err());
+    }
+
+    #[test]
+    fn sample_mismatch() {
+        let rules =
+            PluralRules::parse("one: n = 1 @integer 1, 21; other: @integer 0, 2~5").unwrap();
+        assert_eq!(
+            rules.verify_samples(),
+            Err(PluralRulesError::SampleMismatch {
+                sample: "21".to_string(),
+                expected: PluralCategory::One,
+                actual: PluralCategory::Other,
+            })
+        );
+    }
+
+    #[test]
+    fn cldr_samples_verify() {
+        for &(locales, text) in CARDINAL.iter().chain(ORDINAL.iter()) {
+            let rules = match PluralRules::parse(text) {
+                Ok(rules) => rules,
+                Err(e) => panic!("Rules for {} failed to parse: {}", locales, e),
+            };
+            if let Err(e) = rules.verify_samples() {
+                panic!("Rules for {} failed to verify: {}", locales, e);
+            }
+        }
+    }
+
+    #[test]
+    fn language_fallback_works() {
+        let rules = PluralRules::cardinal(&"pt-BR".parse().unwrap());
+        assert_eq!(rules.select(0), PluralCategory::One);
+        let rules = PluralRules::cardinal(&"pt-PT".parse().unwrap());
+        assert_eq!(rules.select(0), PluralCategory::Other);
+        let rules = PluralRules::cardinal(&LanguageTag::default());
+        assert_eq!(rules.select(1), PluralCategory::Other);
+        assert_eq!(rules.categories(), vec![PluralCategory::Other]);
+    }
+}
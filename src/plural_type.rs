@@ -0,0 +1,21 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// The kind of plural rules used to classify a number.
+///
+/// Cardinal numbers are used for counting ("1 item", "2 items")
+/// while ordinal numbers are used for ranking ("1st", "2nd").
+/// These are classified differently, so a [`PluralFormat`] must
+/// know which kind it is using.
+///
+/// [`PluralFormat`]: icu/ast/struct.PluralFormat.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PluralType {
+    /// Plural rules for counting, as used by ICU's `plural`.
+    Cardinal,
+    /// Plural rules for ranking, as used by ICU's `selectordinal`.
+    Ordinal,
+}
@@ -0,0 +1,294 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use language_tags::LanguageTag;
+
+use cldr;
+use cldr::relative_times::{RelativeTimes, FIELDS};
+use {Decimal, NumberFormatOptions, PluralRules};
+
+/// The unit of a relative time, as in `in 3 days`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RelativeTimeUnit {
+    /// Years, as in `in 3 years`.
+    Year,
+    /// Quarters of a year, as in `in 3 quarters`.
+    Quarter,
+    /// Months, as in `in 3 months`.
+    Month,
+    /// Weeks, as in `in 3 weeks`.
+    Week,
+    /// Days, as in `in 3 days`.
+    Day,
+    /// Hours, as in `in 3 hours`.
+    Hour,
+    /// Minutes, as in `in 3 minutes`.
+    Minute,
+    /// Seconds, as in `in 3 seconds`.
+    Second,
+}
+
+impl RelativeTimeUnit {
+    /// The name of the unit in the CLDR data.
+    fn key(self) -> &'static str {
+        match self {
+            RelativeTimeUnit::Year => "year",
+            RelativeTimeUnit::Quarter => "quarter",
+            RelativeTimeUnit::Month => "month",
+            RelativeTimeUnit::Week => "week",
+            RelativeTimeUnit::Day => "day",
+            RelativeTimeUnit::Hour => "hour",
+            RelativeTimeUnit::Minute => "minute",
+            RelativeTimeUnit::Second => "second",
+        }
+    }
+}
+
+/// How long the names of relative time units are.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RelativeTimeStyle {
+    /// Full names, like `in 3 months`.
+    #[default]
+    Long,
+    /// Abbreviated names, like `in 3 mo.`.
+    Short,
+    /// The shortest names, like `in 3mo`.
+    Narrow,
+}
+
+/// Whether to use names like `yesterday` for particular relative
+/// times.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RelativeTimeNumeric {
+    /// Always use a number, as in `1 day ago`.
+    #[default]
+    Always,
+    /// Use a name where the locale has one, as in `yesterday`, and a
+    /// number otherwise.
+    Auto,
+}
+
+/// Options for formatting a time relative to now, like `3 days ago`
+/// or `in 2 hours`, using the [CLDR] data for a locale.
+///
+/// Negative values are in the past and other values in the future.
+/// The unit's name agrees with the number, using the locale's plural
+/// rules:
+///
+/// ```
+/// use message_format::{Decimal, RelativeTimeFormatOptions, RelativeTimeUnit};
+///
+/// let en = "en".parse().unwrap();
+/// let ru = "ru".parse().unwrap();
+/// let options = RelativeTimeFormatOptions::new(RelativeTimeUnit::Day);
+///
+/// assert_eq!(options.format(&en, &Decimal::from(-1)), "1 day ago");
+/// assert_eq!(options.format(&en, &Decimal::from(3)), "in 3 days");
+/// assert_eq!(options.format(&ru, &Decimal::from(2)), "через 2 дня");
+/// assert_eq!(options.format(&ru, &Decimal::from(5)), "через 5 дней");
+/// ```
+///
+/// [CLDR]: http://cldr.unicode.org/
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelativeTimeFormatOptions {
+    /// The unit of the value.
+    pub unit: RelativeTimeUnit,
+    /// How long the unit's name is.
+    pub style: RelativeTimeStyle,
+    /// Whether to use names like `yesterday`.
+    pub numeric: RelativeTimeNumeric,
+}
+
+impl RelativeTimeFormatOptions {
+    /// Construct a `RelativeTimeFormatOptions` for a unit, with long
+    /// names and always using numbers.
+    pub fn new(unit: RelativeTimeUnit) -> Self {
+        RelativeTimeFormatOptions {
+            unit: unit,
+            style: RelativeTimeStyle::default(),
+            numeric: RelativeTimeNumeric::default(),
+        }
+    }
+
+    /// Format `value` for the locale given by `language_tag`.
+    ///
+    /// ```
+    /// use message_format::{Decimal, RelativeTimeFormatOptions, RelativeTimeNumeric,
+    ///                      RelativeTimeStyle, RelativeTimeUnit};
+    ///
+    /// let options = RelativeTimeFormatOptions {
+    ///     unit: RelativeTimeUnit::Day,
+    ///     style: RelativeTimeStyle::Long,
+    ///     numeric: RelativeTimeNumeric::Auto,
+    /// };
+    /// let de = "de".parse().unwrap();
+    /// assert_eq!(options.format(&de, &Decimal::from(-2)), "vorgestern");
+    /// assert_eq!(options.format(&de, &Decimal::from(-3)), "vor 3 Tagen");
+    /// ```
+    pub fn format(&self, language_tag: &LanguageTag, value: &Decimal) -> String {
+        let times = relative_times(language_tag, self.unit, self.style);
+        if self.numeric == RelativeTimeNumeric::Auto
+            && value.fraction_digits().chars().all(|c| c == '0')
+        {
+            let distance = value.integer_digits().parse::<i64>().ok().map(|distance| {
+                if value.is_negative() {
+                    -distance
+                } else {
+                    distance
+                }
+            });
+            let name = times
+                .relative
+                .iter()
+                .find(|&&(relative, _)| Some(relative) == distance);
+            if let Some(&(_, name)) = name {
+                return name.to_string();
+            }
+        }
+
+        let (patterns, magnitude) = if value.is_negative() {
+            (times.past, &Decimal::from(0) - value)
+        } else {
+            (times.future, value.clone())
+        };
+        let category = PluralRules::cardinal(language_tag).select(&magnitude);
+        let number = NumberFormatOptions::default().format(language_tag, &magnitude);
+        cldr::plural_form(patterns, category)
+            .unwrap_or("{0}")
+            .replace("{0}", &number)
+    }
+}
+
+/// The locale's relative times for a unit, in the given style or the
+/// next longer one that the locale has.
+fn relative_times(
+    language_tag: &LanguageTag,
+    unit: RelativeTimeUnit,
+    style: RelativeTimeStyle,
+) -> RelativeTimes {
+    let unit = unit.key();
+    let keys = match style {
+        RelativeTimeStyle::Long => vec![unit.to_string()],
+        RelativeTimeStyle::Short => vec![format!("{}-short", unit), unit.to_string()],
+        RelativeTimeStyle::Narrow => vec![
+            format!("{}-narrow", unit),
+            format!("{}-short", unit),
+            unit.to_string(),
+        ],
+    };
+    cldr::lookup_keys(FIELDS, language_tag, &keys).expect("relative time data for the root locale")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(options: &RelativeTimeFormatOptions, locale: &str, value: &str) -> String {
+        options.format(&locale.parse().unwrap(), &value.parse().unwrap())
+    }
+
+    #[test]
+    fn numbers_work() {
+        let days = RelativeTimeFormatOptions::new(RelativeTimeUnit::Day);
+        assert_eq!(format(&days, "en", "1"), "in 1 day");
+        assert_eq!(format(&days, "en", "-1"), "1 day ago");
+        assert_eq!(format(&days, "en", "0"), "in 0 days");
+        assert_eq!(format(&days, "en", "-1.5"), "1.5 days ago");
+        assert_eq!(format(&days, "en", "1234"), "in 1,234 days");
+        assert_eq!(format(&days, "de", "-1"), "vor 1 Tag");
+        assert_eq!(format(&days, "de", "-2"), "vor 2 Tagen");
+        assert_eq!(format(&days, "fr", "0"), "dans 0 jour");
+        assert_eq!(format(&days, "fr", "-2"), "il y a 2 jours");
+        assert_eq!(format(&days, "ja", "-3"), "3 日前");
+
+        // Russian has a form for `one`, `few`, `many` and `other`.
+        let hours = RelativeTimeFormatOptions::new(RelativeTimeUnit::Hour);
+        assert_eq!(format(&hours, "ru", "-21"), "21 час назад");
+        assert_eq!(format(&hours, "ru", "-22"), "22 часа назад");
+        assert_eq!(format(&hours, "ru", "-25"), "25 часов назад");
+        assert_eq!(format(&hours, "ru", "1.5"), "через 1,5 часа");
+
+        // Locales without data use the root locale's.
+        assert_eq!(format(&hours, "und", "-3"), "-3 h");
+    }
+
+    #[test]
+    fn auto_works() {
+        let options = |unit| RelativeTimeFormatOptions {
+            numeric: RelativeTimeNumeric::Auto,
+            ..RelativeTimeFormatOptions::new(unit)
+        };
+        let days = options(RelativeTimeUnit::Day);
+        assert_eq!(format(&days, "en", "-1"), "yesterday");
+        assert_eq!(format(&days, "en", "0"), "today");
+        assert_eq!(format(&days, "en", "1"), "tomorrow");
+        assert_eq!(format(&days, "en", "2"), "in 2 days");
+        assert_eq!(format(&days, "en", "1.0"), "tomorrow");
+        assert_eq!(format(&days, "en", "1.5"), "in 1.5 days");
+        assert_eq!(format(&days, "es", "2"), "pasado mañana");
+        assert_eq!(format(&days, "fr", "0"), "aujourd’hui");
+
+        assert_eq!(
+            format(&options(RelativeTimeUnit::Year), "en", "-1"),
+            "last year"
+        );
+        assert_eq!(
+            format(&options(RelativeTimeUnit::Second), "de", "0"),
+            "jetzt"
+        );
+        assert_eq!(
+            format(&options(RelativeTimeUnit::Second), "de", "-10"),
+            "vor 10 Sekunden"
+        );
+    }
+
+    #[test]
+    fn styles_work() {
+        let options = |unit, style| RelativeTimeFormatOptions {
+            style: style,
+            ..RelativeTimeFormatOptions::new(unit)
+        };
+        let months = |style| options(RelativeTimeUnit::Month, style);
+        assert_eq!(
+            format(&months(RelativeTimeStyle::Long), "en", "3"),
+            "in 3 months"
+        );
+        assert_eq!(
+            format(&months(RelativeTimeStyle::Short), "en", "3"),
+            "in 3 mo."
+        );
+        assert_eq!(
+            format(&months(RelativeTimeStyle::Narrow), "en", "3"),
+            "in 3mo"
+        );
+        assert_eq!(
+            format(&months(RelativeTimeStyle::Short), "de", "-3"),
+            "vor 3 Mon."
+        );
+        assert_eq!(
+            format(&months(RelativeTimeStyle::Short), "ru", "-5"),
+            "5 мес. назад"
+        );
+
+        // Missing widths fall back to longer ones.
+        assert_eq!(
+            format(&months(RelativeTimeStyle::Narrow), "de", "-3"),
+            "vor 3 Mon."
+        );
+        assert_eq!(
+            format(
+                &options(RelativeTimeUnit::Day, RelativeTimeStyle::Short),
+                "en",
+                "-2"
+            ),
+            "2 days ago"
+        );
+        assert_eq!(
+            format(&months(RelativeTimeStyle::Narrow), "ja", "2"),
+            "2 か月後"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// How to round a number that has more digits than can be shown.
+///
+/// These are the [ICU rounding modes]. The "half" modes round to the
+/// nearest value, and differ only in what they do with a value that is
+/// exactly halfway between two others.
+///
+/// [ICU rounding modes]: http://userguide.icu-project.org/formatparse/numbers/rounding-modes
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RoundingMode {
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round to the nearest value, or to the even value if halfway.
+    #[default]
+    HalfEven,
+    /// Round to the nearest value, or towards zero if halfway.
+    HalfDown,
+    /// Round to the nearest value, or away from zero if halfway.
+    HalfUp,
+}
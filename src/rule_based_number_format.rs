@@ -0,0 +1,1017 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use language_tags::LanguageTag;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use cldr;
+use cldr::rbnf;
+use {
+    Decimal, Grouping, IntegerWidth, NumberFormatOptions, PluralCategory, PluralRules, PluralType,
+    Precision, RoundingMode,
+};
+
+/// The kinds of rules that each locale has for formatting numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RuleBasedNumberType {
+    /// Numbers in words, like `forty-two` or `forty-second`.
+    #[default]
+    Spellout,
+    /// Ordinal numbers written with digits, like `42nd`.
+    Ordinal,
+    /// Numbers of seconds, like `1:05:07`.
+    Duration,
+}
+
+impl RuleBasedNumberType {
+    /// The vendored rules of this type, keyed by locale.
+    fn table(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            RuleBasedNumberType::Spellout => rbnf::SPELLOUT,
+            RuleBasedNumberType::Ordinal => rbnf::ORDINAL,
+            RuleBasedNumberType::Duration => rbnf::DURATION,
+        }
+    }
+
+    /// The names of the public rule sets of this type that any locale
+    /// has, like `%spellout-cardinal-feminine`.
+    ///
+    /// ```
+    /// use message_format::RuleBasedNumberType;
+    ///
+    /// let names = RuleBasedNumberType::Ordinal.rule_set_names();
+    /// assert!(names.contains(&"%digits-ordinal-feminine".to_string()));
+    /// ```
+    pub fn rule_set_names(self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        for &(_, text) in self.table() {
+            for name in NumberRules::cached(text).rule_set_names() {
+                if !names.iter().any(|known| known == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names
+    }
+}
+
+/// Rules for formatting numbers, written in ICU's [rule-based number
+/// format syntax].
+///
+/// The rules are grouped into named rule sets. Each rule maps numbers
+/// from its base value up to the next rule's base value to text, in
+/// which `<<` is replaced by the number divided by a power of 10 and
+/// `>>` by the remainder:
+///
+/// ```
+/// use message_format::{Decimal, NumberRules};
+///
+/// let rules = NumberRules::parse(
+///     "%tally: \
+///      0: none; 1: one; 2: two; 3: a few; \
+///      10: << tens[ and >>]; \
+///      100: lots;",
+/// ).unwrap();
+/// let und = "und".parse().unwrap();
+/// assert_eq!(rules.format(&und, None, &Decimal::from(2)), "two");
+/// assert_eq!(rules.format(&und, None, &Decimal::from(30)), "a few tens");
+/// assert_eq!(rules.format(&und, None, &Decimal::from(42)), "a few tens and two");
+/// assert_eq!(rules.format(&und, None, &Decimal::from(365)), "lots");
+/// ```
+///
+/// The rules for a locale can be found with [`NumberRules::for_locale`].
+///
+/// [rule-based number format syntax]: https://unicode-org.github.io/icu/userguide/format_parse/numbers/rbnf.html
+/// [`NumberRules::for_locale`]: #method.for_locale
+#[derive(Clone, Debug)]
+pub struct NumberRules {
+    rule_sets: Arc<Vec<RuleSet>>,
+    /// The index of the rule set used when none is named.
+    default: usize,
+}
+
+/// An error resulting from parsing `NumberRules`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberRulesError {
+    /// The rule, or the name of the rule set, that could not be parsed.
+    pub rule: String,
+    /// A description of what was expected.
+    pub expected: String,
+}
+
+impl NumberRulesError {
+    fn new(rule: &str, expected: &str) -> Self {
+        NumberRulesError {
+            rule: rule.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+}
+
+impl Error for NumberRulesError {
+    fn description(&self) -> &str {
+        "Invalid number rules."
+    }
+}
+
+impl fmt::Display for NumberRulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "expected {} in `{}`", self.expected, self.rule)
+    }
+}
+
+#[derive(Debug)]
+struct RuleSet {
+    name: String,
+    /// The rules for integers, in order of their base values.
+    rules: Vec<Rule>,
+    /// The `-x` rule, for negative numbers.
+    negative: Option<Rule>,
+    /// The `x.x` rule, for numbers with a fraction.
+    fraction: Option<Rule>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    base: u128,
+    divisor: u128,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug)]
+enum Part {
+    Text(String),
+    /// Parts between `[` and `]`, which are left out for multiples of
+    /// the rule's divisor.
+    Optional(Vec<Part>),
+    Substitution(Substitution, Target),
+    /// Text for each plural category of the number divided by the
+    /// rule's divisor, as in `$(ordinal,one{st}other{th})$`.
+    Plural(PluralType, Vec<(PluralCategory, String)>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Substitution {
+    /// `<<`: the number divided by the rule's divisor, or the integer
+    /// part of a number with a fraction.
+    Quotient,
+    /// `>>`: the remainder of dividing by the rule's divisor, or the
+    /// fraction digits of a number with a fraction.
+    Remainder,
+    /// `==`: the number itself.
+    Same,
+}
+
+#[derive(Debug)]
+enum Target {
+    /// The rule set that the rule belongs to, as in `>>`.
+    SameRuleSet,
+    /// The rule before this one, as in `>>>`.
+    PrecedingRule,
+    /// Another rule set, as in `>%%th>`.
+    RuleSet(String),
+    /// A decimal format pattern, as in `=#,##0=`.
+    Pattern(NumberFormatOptions),
+}
+
+impl Rule {
+    fn has_remainder(parts: &[Part]) -> bool {
+        parts.iter().any(|part| match *part {
+            Part::Optional(ref parts) => Rule::has_remainder(parts),
+            Part::Substitution(substitution, _) => substitution == Substitution::Remainder,
+            _ => false,
+        })
+    }
+
+    fn rule_set_names(parts: &[Part]) -> Vec<&str> {
+        parts
+            .iter()
+            .flat_map(|part| match *part {
+                Part::Optional(ref parts) => Rule::rule_set_names(parts),
+                Part::Substitution(_, Target::RuleSet(ref name)) => vec![&name[..]],
+                _ => vec![],
+            })
+            .collect()
+    }
+}
+
+impl NumberRules {
+    /// Parse number rules written in ICU's rule-based number format
+    /// syntax.
+    ///
+    /// When no rule set is named, numbers are formatted with the
+    /// `%spellout-numbering` rule set if there is one, and otherwise
+    /// with the last public rule set, as in ICU.
+    pub fn parse(description: &str) -> Result<Self, NumberRulesError> {
+        let mut rule_sets: Vec<RuleSet> = vec![];
+        for text in description.split(';') {
+            let mut rule = text.trim();
+            if rule.is_empty() {
+                continue;
+            }
+            if rule.starts_with('%') {
+                let (name, rest) = try!(rule
+                    .split_once(':')
+                    .ok_or_else(|| NumberRulesError::new(rule, "`:` after the rule set name")));
+                let name = name.trim();
+                let valid = name
+                    .trim_start_matches('%')
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-');
+                if !valid || name.len() - name.trim_start_matches('%').len() > 2 {
+                    return Err(NumberRulesError::new(name, "a rule set name"));
+                }
+                if rule_sets.iter().any(|set| set.name == name) {
+                    return Err(NumberRulesError::new(name, "a new rule set name"));
+                }
+                rule_sets.push(RuleSet {
+                    name: name.to_string(),
+                    rules: vec![],
+                    negative: None,
+                    fraction: None,
+                });
+                rule = rest.trim();
+            }
+            let set = try!(rule_sets
+                .last_mut()
+                .ok_or_else(|| NumberRulesError::new(rule, "a rule set name")));
+            try!(set.add(rule));
+        }
+
+        for set in &rule_sets {
+            let rules = set.rules.iter().chain(&set.negative).chain(&set.fraction);
+            for rule in rules {
+                for name in Rule::rule_set_names(&rule.parts) {
+                    if !rule_sets.iter().any(|set| set.name == name) {
+                        return Err(NumberRulesError::new(name, "a defined rule set"));
+                    }
+                }
+            }
+            if set.rules.is_empty() {
+                return Err(NumberRulesError::new(&set.name, "a rule for integers"));
+            }
+        }
+
+        let default = try!(rule_sets
+            .iter()
+            .position(|set| set.name == "%spellout-numbering")
+            .or_else(|| {
+                rule_sets
+                    .iter()
+                    .rposition(|set| !set.name.starts_with("%%"))
+            })
+            .ok_or_else(|| NumberRulesError::new(description, "a public rule set")));
+        Ok(NumberRules {
+            rule_sets: Arc::new(rule_sets),
+            default: default,
+        })
+    }
+
+    /// The vendored rules of the given type for a locale.
+    ///
+    /// These are the rules for the most specific locale matching
+    /// `language_tag`, so `fr-CA` uses the rules for `fr`. Locales
+    /// without rules use the root rules, which write numbers with
+    /// digits.
+    ///
+    /// ```
+    /// use message_format::{Decimal, NumberRules, RuleBasedNumberType};
+    ///
+    /// let es = "es".parse().unwrap();
+    /// let rules = NumberRules::for_locale(&es, RuleBasedNumberType::Spellout);
+    /// let value = Decimal::from(21);
+    /// assert_eq!(rules.format(&es, None, &value), "veintiuno");
+    /// assert_eq!(
+    ///     rules.format(&es, Some("%spellout-cardinal-feminine"), &value),
+    ///     "veintiuna"
+    /// );
+    /// ```
+    pub fn for_locale(language_tag: &LanguageTag, rule_type: RuleBasedNumberType) -> Self {
+        let text = cldr::lookup(rule_type.table(), language_tag).unwrap_or("");
+        NumberRules::cached(text)
+    }
+
+    /// The parsed vendored rules.
+    fn cached(text: &'static str) -> Self {
+        // Parsing the rules for every message formatted would be
+        // wasteful, so they are cached.
+        thread_local! {
+            static CACHE: RefCell<HashMap<&'static str, NumberRules>> = RefCell::new(HashMap::new());
+        }
+        CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .entry(text)
+                .or_insert_with(|| {
+                    NumberRules::parse(text).expect("vendored number rules must be valid")
+                })
+                .clone()
+        })
+    }
+
+    /// The names of the public rule sets, which can be used to format
+    /// numbers. Rule sets whose names start with `%%` are private.
+    pub fn rule_set_names(&self) -> Vec<&str> {
+        self.rule_sets
+            .iter()
+            .map(|set| &set.name[..])
+            .filter(|name| !name.starts_with("%%"))
+            .collect()
+    }
+
+    /// Format `value` for the locale given by `language_tag`, using
+    /// the public rule set named `rule_set`.
+    ///
+    /// If `rule_set` is `None` or isn't the name of a public rule set,
+    /// the default rule set is used.
+    pub fn format(
+        &self,
+        language_tag: &LanguageTag,
+        rule_set: Option<&str>,
+        value: &Decimal,
+    ) -> String {
+        let set = rule_set
+            .filter(|name| !name.starts_with("%%"))
+            .and_then(|name| self.rule_sets.iter().find(|set| set.name == name))
+            .unwrap_or(&self.rule_sets[self.default]);
+        let formatter = Formatter {
+            rule_sets: &self.rule_sets,
+            language_tag: language_tag,
+        };
+        let mut output = String::new();
+        formatter.format(set, value, &mut output);
+        output
+    }
+}
+
+impl RuleSet {
+    /// Parse a rule and add it to the rule set.
+    fn add(&mut self, text: &str) -> Result<(), NumberRulesError> {
+        let descriptor = text
+            .split_once(':')
+            .map(|(descriptor, body)| (descriptor.trim(), body))
+            .filter(|&(descriptor, _)| is_descriptor(descriptor));
+        let (descriptor, body) = descriptor.unwrap_or(("", text));
+        let body = body.trim();
+        let body = body.strip_prefix('\'').unwrap_or(body);
+        let parts = try!(parse_parts(body, text));
+
+        let rule = |base, divisor| Rule {
+            base: base,
+            divisor: divisor,
+            parts: parts,
+        };
+        match descriptor {
+            "-x" => self.negative = Some(rule(0, 1)),
+            "x.x" => self.fraction = Some(rule(0, 1)),
+            // Rules for proper fractions, infinity and NaN are accepted,
+            // but only `x.x` is used for fractions.
+            "0.x" | "x.0" | "Inf" | "NaN" => {}
+            _ => {
+                let previous = self.rules.last().map(|rule| rule.base);
+                let (base, divisor) = if descriptor.is_empty() {
+                    let base = previous.map_or(0, |base| base + 1);
+                    (base, divisor(base, 10, 0))
+                } else {
+                    let shifts = descriptor.len() - descriptor.trim_end_matches('>').len();
+                    let descriptor = descriptor.trim_end_matches('>');
+                    let (base, radix) = descriptor.split_once('/').unwrap_or((descriptor, "10"));
+                    let base = base.replace(',', "").parse::<u128>();
+                    let radix = radix.parse::<u128>().ok().filter(|&radix| radix > 1);
+                    match (base, radix) {
+                        (Ok(base), Some(radix)) => (base, divisor(base, radix, shifts)),
+                        _ => return Err(NumberRulesError::new(text, "a base value")),
+                    }
+                };
+                if previous.is_some_and(|previous| previous >= base) {
+                    return Err(NumberRulesError::new(text, "a larger base value"));
+                }
+                let rule = rule(base, divisor);
+                if self.rules.is_empty() && uses_preceding_rule(&rule.parts) {
+                    return Err(NumberRulesError::new(text, "a preceding rule"));
+                }
+                self.rules.push(rule);
+                return Ok(());
+            }
+        }
+        let special = self.negative.iter().chain(&self.fraction);
+        if special
+            .into_iter()
+            .any(|rule| uses_preceding_rule(&rule.parts))
+        {
+            return Err(NumberRulesError::new(text, "a rule for integers"));
+        }
+        Ok(())
+    }
+}
+
+/// Whether text before a `:` is a rule descriptor, like `100`,
+/// `60/20`, `1000>` or `-x`.
+fn is_descriptor(text: &str) -> bool {
+    match text {
+        "-x" | "x.x" | "0.x" | "x.0" | "Inf" | "NaN" => true,
+        _ => {
+            let text = text.trim_end_matches('>');
+            let (base, radix) = text.split_once('/').unwrap_or((text, "0"));
+            base.starts_with(|c: char| c.is_ascii_digit())
+                && base.chars().all(|c| c.is_ascii_digit() || c == ',')
+                && !radix.is_empty()
+                && radix.chars().all(|c| c.is_ascii_digit())
+        }
+    }
+}
+
+/// The highest power of `radix` that isn't more than `base`, divided
+/// by `radix` once for each `>` in the rule's descriptor.
+fn divisor(base: u128, radix: u128, shifts: usize) -> u128 {
+    let mut divisor = 1u128;
+    while let Some(next) = divisor.checked_mul(radix).filter(|&next| next <= base) {
+        divisor = next;
+    }
+    for _ in 0..shifts {
+        if divisor >= radix {
+            divisor /= radix;
+        }
+    }
+    divisor
+}
+
+fn uses_preceding_rule(parts: &[Part]) -> bool {
+    parts.iter().any(|part| match *part {
+        Part::Optional(ref parts) => uses_preceding_rule(parts),
+        Part::Substitution(_, Target::PrecedingRule) => true,
+        _ => false,
+    })
+}
+
+/// Parse the body of a rule, after its descriptor.
+fn parse_parts(body: &str, rule: &str) -> Result<Vec<Part>, NumberRulesError> {
+    let error = |expected| NumberRulesError::new(rule, expected);
+    let mut stack: Vec<Vec<Part>> = vec![vec![]];
+    let mut text = String::new();
+    let mut rest = body;
+    while let Some(c) = rest.chars().next() {
+        let token = match c {
+            '[' | ']' | '<' | '>' | '=' | '←' | '→' => true,
+            '$' => rest.starts_with("$("),
+            _ => false,
+        };
+        if !token {
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        if !text.is_empty() {
+            stack.last_mut().unwrap().push(Part::Text(text.clone()));
+            text.clear();
+        }
+        let part = match c {
+            '[' => {
+                stack.push(vec![]);
+                rest = &rest[1..];
+                continue;
+            }
+            ']' => {
+                if stack.len() < 2 {
+                    return Err(error("`[` before `]`"));
+                }
+                rest = &rest[1..];
+                Part::Optional(stack.pop().unwrap())
+            }
+            '$' => {
+                let end = try!(rest.find(")$").ok_or_else(|| error("`)$` after `$(`")));
+                let part = try!(parse_plural(&rest[2..end]).ok_or_else(|| error("plural forms")));
+                rest = &rest[end + 2..];
+                part
+            }
+            _ => {
+                let substitution = match c {
+                    '<' | '←' => Substitution::Quotient,
+                    '>' | '→' => Substitution::Remainder,
+                    _ => Substitution::Same,
+                };
+                let after = &rest[c.len_utf8()..];
+                let end = try!(after
+                    .find(c)
+                    .ok_or_else(|| error("the end of a substitution")));
+                let inner = &after[..end];
+                rest = &after[end + c.len_utf8()..];
+                let target = if inner.is_empty() && c == '>' && rest.starts_with('>') {
+                    rest = &rest[1..];
+                    Target::PrecedingRule
+                } else if inner.is_empty() && substitution != Substitution::Same {
+                    Target::SameRuleSet
+                } else if inner.starts_with('%') {
+                    Target::RuleSet(inner.to_string())
+                } else {
+                    Target::Pattern(try!(
+                        pattern_options(inner).ok_or_else(|| error("a rule set or pattern"))
+                    ))
+                };
+                Part::Substitution(substitution, target)
+            }
+        };
+        stack.last_mut().unwrap().push(part);
+    }
+    if !text.is_empty() {
+        stack.last_mut().unwrap().push(Part::Text(text));
+    }
+    if stack.len() > 1 {
+        return Err(error("`]` after `[`"));
+    }
+    Ok(stack.pop().unwrap())
+}
+
+/// Parse plural forms like `ordinal,one{st}two{nd}other{th}`.
+fn parse_plural(text: &str) -> Option<Part> {
+    let (plural_type, mut rest) = text.split_once(',')?;
+    let plural_type = match plural_type {
+        "cardinal" => PluralType::Cardinal,
+        "ordinal" => PluralType::Ordinal,
+        _ => return None,
+    };
+    let mut forms = vec![];
+    while !rest.is_empty() {
+        let (keyword, after) = rest.split_once('{')?;
+        let (form, after) = after.split_once('}')?;
+        let category = match keyword.trim() {
+            "zero" => PluralCategory::Zero,
+            "one" => PluralCategory::One,
+            "two" => PluralCategory::Two,
+            "few" => PluralCategory::Few,
+            "many" => PluralCategory::Many,
+            "other" => PluralCategory::Other,
+            _ => return None,
+        };
+        forms.push((category, form.to_string()));
+        rest = after;
+    }
+    if !forms
+        .iter()
+        .any(|&(category, _)| category == PluralCategory::Other)
+    {
+        return None;
+    }
+    Some(Part::Plural(plural_type, forms))
+}
+
+/// The options for a decimal format pattern like `#,##0` or `00`.
+fn pattern_options(pattern: &str) -> Option<NumberFormatOptions> {
+    if pattern.is_empty() || !pattern.chars().all(|c| "#0,.".contains(c)) {
+        return None;
+    }
+    let (integer, fraction) = pattern.split_once('.').unwrap_or((pattern, ""));
+    let maximum = fraction.len();
+    let minimum = fraction.chars().filter(|&c| c == '0').count();
+    Some(NumberFormatOptions {
+        precision: Precision::Fraction {
+            minimum: minimum,
+            maximum: Some(maximum),
+        },
+        rounding_mode: RoundingMode::Down,
+        grouping: if integer.contains(',') {
+            Grouping::Auto
+        } else {
+            Grouping::Off
+        },
+        integer_width: IntegerWidth {
+            minimum: integer.chars().filter(|&c| c == '0').count(),
+            maximum: None,
+        },
+        ..NumberFormatOptions::default()
+    })
+}
+
+struct Formatter<'a> {
+    rule_sets: &'a [RuleSet],
+    language_tag: &'a LanguageTag,
+}
+
+impl<'a> Formatter<'a> {
+    fn rule_set(&self, name: &str) -> &'a RuleSet {
+        self.rule_sets
+            .iter()
+            .find(|set| set.name == name)
+            .expect("rule sets are checked when parsed")
+    }
+
+    fn format(&self, set: &'a RuleSet, value: &Decimal, output: &mut String) {
+        if value.is_negative() {
+            let magnitude = &Decimal::from(0) - value;
+            match set.negative {
+                Some(ref rule) => self.apply_special(set, &rule.parts, &magnitude, false, output),
+                None => {
+                    output.push('-');
+                    self.format(set, &magnitude, output);
+                }
+            }
+            return;
+        }
+        let whole = value.fraction_digits().chars().all(|c| c == '0');
+        if let (false, Some(rule)) = (whole, set.fraction.as_ref()) {
+            self.apply_special(set, &rule.parts, value, true, output);
+            return;
+        }
+        match value.integer_digits().parse::<u128>() {
+            Ok(value) => self.format_integer(set, value, output),
+            Err(_) => output.push_str(
+                &NumberFormatOptions::default()
+                    .format(self.language_tag, &value.round_with(0, RoundingMode::Down)),
+            ),
+        }
+    }
+
+    fn format_integer(&self, set: &'a RuleSet, value: u128, output: &mut String) {
+        let mut index = set
+            .rules
+            .iter()
+            .rposition(|rule| rule.base <= value)
+            .unwrap_or(0);
+        // As in ICU, a multiple of the divisor uses the preceding rule
+        // when the rule's base value isn't a multiple itself, so that
+        // `200` isn't `ciento cien`.
+        let rule = &set.rules[index];
+        if index > 0
+            && Rule::has_remainder(&rule.parts)
+            && value.is_multiple_of(rule.divisor)
+            && !rule.base.is_multiple_of(rule.divisor)
+        {
+            index -= 1;
+        }
+        self.apply(set, index, value, output);
+    }
+
+    /// Apply a rule for integers to `value`.
+    fn apply(&self, set: &'a RuleSet, index: usize, value: u128, output: &mut String) {
+        self.apply_parts(set, index, &set.rules[index].parts, value, output);
+    }
+
+    fn apply_parts(
+        &self,
+        set: &'a RuleSet,
+        index: usize,
+        parts: &[Part],
+        value: u128,
+        output: &mut String,
+    ) {
+        let divisor = set.rules[index].divisor;
+        for part in parts {
+            match *part {
+                Part::Text(ref text) => output.push_str(text),
+                Part::Optional(ref parts) => {
+                    if !value.is_multiple_of(divisor) {
+                        self.apply_parts(set, index, parts, value, output);
+                    }
+                }
+                Part::Plural(plural_type, ref forms) => {
+                    let plural_rules = PluralRules::for_language(self.language_tag, plural_type);
+                    output.push_str(plural_form(
+                        forms,
+                        plural_rules.select(Decimal::from(value / divisor)),
+                    ));
+                }
+                Part::Substitution(substitution, ref target) => {
+                    let value = match substitution {
+                        Substitution::Quotient => value / divisor,
+                        Substitution::Remainder => value % divisor,
+                        Substitution::Same => value,
+                    };
+                    match *target {
+                        Target::SameRuleSet => self.format_integer(set, value, output),
+                        Target::PrecedingRule => self.apply(set, index - 1, value, output),
+                        Target::RuleSet(ref name) => {
+                            self.format_integer(self.rule_set(name), value, output)
+                        }
+                        Target::Pattern(ref options) => output
+                            .push_str(&options.format(self.language_tag, &Decimal::from(value))),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Apply the parts of a `-x` rule to the magnitude of a negative
+    /// number, or of an `x.x` rule to a number with a fraction.
+    fn apply_special(
+        &self,
+        set: &'a RuleSet,
+        parts: &[Part],
+        value: &Decimal,
+        fraction: bool,
+        output: &mut String,
+    ) {
+        for part in parts {
+            match *part {
+                Part::Text(ref text) => output.push_str(text),
+                Part::Optional(ref parts) => {
+                    self.apply_special(set, parts, value, fraction, output)
+                }
+                Part::Plural(plural_type, ref forms) => {
+                    let plural_rules = PluralRules::for_language(self.language_tag, plural_type);
+                    output.push_str(plural_form(forms, plural_rules.select(value)));
+                }
+                Part::Substitution(Substitution::Quotient, ref target) => {
+                    let integer = value.round_with(0, RoundingMode::Down);
+                    self.format_target(set, target, &integer, output);
+                }
+                // In an `x.x` rule, the fraction digits are formatted
+                // one at a time, as in `three point one four`.
+                Part::Substitution(Substitution::Remainder, Target::Pattern(ref options))
+                    if fraction =>
+                {
+                    if let Ok(digits) = value.fraction_digits().parse::<Decimal>() {
+                        output.push_str(&options.format(self.language_tag, &digits));
+                    }
+                }
+                Part::Substitution(Substitution::Remainder, ref target) if fraction => {
+                    let digits = value.fraction_digits();
+                    let digits = if digits.is_empty() { "0" } else { digits };
+                    for (i, digit) in digits.chars().enumerate() {
+                        if i > 0 {
+                            output.push(' ');
+                        }
+                        let digit = Decimal::from(digit.to_digit(10).unwrap_or(0));
+                        self.format_target(set, target, &digit, output);
+                    }
+                }
+                Part::Substitution(_, ref target) => self.format_target(set, target, value, output),
+            }
+        }
+    }
+
+    fn format_target(
+        &self,
+        set: &'a RuleSet,
+        target: &Target,
+        value: &Decimal,
+        output: &mut String,
+    ) {
+        match *target {
+            Target::RuleSet(ref name) => self.format(self.rule_set(name), value, output),
+            Target::Pattern(ref options) => {
+                output.push_str(&options.format(self.language_tag, value))
+            }
+            _ => self.format(set, value, output),
+        }
+    }
+}
+
+fn plural_form(forms: &[(PluralCategory, String)], category: PluralCategory) -> &str {
+    forms
+        .iter()
+        .find(|&&(form_category, _)| form_category == category)
+        .or_else(|| {
+            forms
+                .iter()
+                .find(|&&(form_category, _)| form_category == PluralCategory::Other)
+        })
+        .map_or("", |(_, form)| &form[..])
+}
+
+/// Options for formatting a number with a locale's rule-based number
+/// format rules, like `forty-two`, `42nd` or `1:05:07`.
+///
+/// The rules are adapted from ICU's, for a set of locales. Each type
+/// of rules has a default rule set, and a locale may have others,
+/// like `%spellout-ordinal` or `%spellout-cardinal-feminine`:
+///
+/// ```
+/// use message_format::{Decimal, RuleBasedNumberFormatOptions, RuleBasedNumberType};
+///
+/// let en = "en".parse().unwrap();
+/// let fr = "fr".parse().unwrap();
+/// let value = Decimal::from(42);
+///
+/// let options = RuleBasedNumberFormatOptions::new(RuleBasedNumberType::Spellout);
+/// assert_eq!(options.format(&en, &value), "forty-two");
+///
+/// let options = RuleBasedNumberFormatOptions {
+///     rule_type: RuleBasedNumberType::Spellout,
+///     rule_set: Some("%spellout-ordinal".to_string()),
+/// };
+/// assert_eq!(options.format(&en, &value), "forty-second");
+///
+/// let options = RuleBasedNumberFormatOptions::new(RuleBasedNumberType::Ordinal);
+/// assert_eq!(options.format(&en, &value), "42nd");
+/// assert_eq!(options.format(&fr, &Decimal::from(1)), "1er");
+///
+/// let options = RuleBasedNumberFormatOptions::new(RuleBasedNumberType::Duration);
+/// assert_eq!(options.format(&en, &Decimal::from(3907)), "1:05:07");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RuleBasedNumberFormatOptions {
+    /// The type of rules to use.
+    pub rule_type: RuleBasedNumberType,
+    /// The name of the rule set to use, like `%spellout-ordinal`, or
+    /// `None` for the default one. A locale without a rule set of
+    /// that name uses its default one.
+    pub rule_set: Option<String>,
+}
+
+impl RuleBasedNumberFormatOptions {
+    /// Construct a `RuleBasedNumberFormatOptions` for a type of rules,
+    /// using the default rule set.
+    pub fn new(rule_type: RuleBasedNumberType) -> Self {
+        RuleBasedNumberFormatOptions {
+            rule_type: rule_type,
+            rule_set: None,
+        }
+    }
+
+    /// Format `value` for the locale given by `language_tag`.
+    pub fn format(&self, language_tag: &LanguageTag, value: &Decimal) -> String {
+        NumberRules::for_locale(language_tag, self.rule_type).format(
+            language_tag,
+            self.rule_set.as_ref().map(|name| &name[..]),
+            value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spellout(locale: &str, rule_set: &str, value: &str) -> String {
+        let options = RuleBasedNumberFormatOptions {
+            rule_type: RuleBasedNumberType::Spellout,
+            rule_set: Some(rule_set.to_string()),
+        };
+        options.format(&locale.parse().unwrap(), &value.parse().unwrap())
+    }
+
+    #[test]
+    fn parsing_works() {
+        assert!(NumberRules::parse("%a: 0: zero; 1: =#,##0=; 10/20>: <<-[>>];").is_ok());
+        assert!(NumberRules::parse("%a: 0: x; %%b: 0: y; 1: =%a=;").is_ok());
+
+        let error = |description: &str| NumberRules::parse(description).unwrap_err().expected;
+        assert_eq!(error("0: zero;"), "a rule set name");
+        assert_eq!(error("%a: 0: x; %a: 0: y;"), "a new rule set name");
+        assert_eq!(error("%a: 0: x; 10: <%b<;"), "a defined rule set");
+        assert_eq!(error("%a: 10: x; 5: y;"), "a larger base value");
+        assert_eq!(error("%a: 0: x[y;"), "`]` after `[`");
+        assert_eq!(error("%a: 0: x<y;"), "the end of a substitution");
+        assert_eq!(error("%a: 0: =abc=;"), "a rule set or pattern");
+        assert_eq!(error("%a: 0: >>>;"), "a preceding rule");
+        assert_eq!(error("%a: 0: $(ordinal,one{st})$;"), "plural forms");
+        assert_eq!(error("%%a: 0: x;"), "a public rule set");
+    }
+
+    #[test]
+    fn english_works() {
+        let cases = [
+            ("0", "zero"),
+            ("7", "seven"),
+            ("13", "thirteen"),
+            ("20", "twenty"),
+            ("42", "forty-two"),
+            ("100", "one hundred"),
+            ("101", "one hundred one"),
+            ("999", "nine hundred ninety-nine"),
+            ("1000", "one thousand"),
+            ("2021", "two thousand twenty-one"),
+            ("1000000", "one million"),
+            (
+                "1234567",
+                "one million two hundred thirty-four thousand five hundred sixty-seven",
+            ),
+            ("-5", "minus five"),
+            ("3.14", "three point one four"),
+            ("1000000000000000000", "1,000,000,000,000,000,000"),
+        ];
+        for &(value, expected) in &cases {
+            assert_eq!(spellout("en", "%spellout-numbering", value), expected);
+        }
+        let ordinals = [
+            ("1", "first"),
+            ("2", "second"),
+            ("12", "twelfth"),
+            ("15", "fifteenth"),
+            ("20", "twentieth"),
+            ("21", "twenty-first"),
+            ("100", "one hundredth"),
+            ("103", "one hundred third"),
+        ];
+        for &(value, expected) in &ordinals {
+            assert_eq!(spellout("en", "%spellout-ordinal", value), expected);
+        }
+    }
+
+    #[test]
+    fn gendered_rule_sets_work() {
+        assert_eq!(spellout("de", "%spellout-numbering", "1"), "eins");
+        assert_eq!(spellout("de", "%spellout-cardinal-feminine", "1"), "eine");
+        assert_eq!(spellout("de", "%spellout-cardinal-masculine", "1"), "ein");
+        assert_eq!(spellout("de", "%spellout-numbering", "21"), "einundzwanzig");
+        assert_eq!(
+            spellout("de", "%spellout-numbering", "101"),
+            "einhunderteins"
+        );
+        assert_eq!(
+            spellout("de", "%spellout-numbering", "2000000"),
+            "zwei Millionen"
+        );
+        assert_eq!(spellout("de", "%spellout-ordinal", "3"), "dritte");
+        assert_eq!(spellout("de", "%spellout-ordinal", "20"), "zwanzigste");
+
+        assert_eq!(
+            spellout("es", "%spellout-cardinal-masculine", "21"),
+            "veintiún"
+        );
+        assert_eq!(
+            spellout("es", "%spellout-cardinal-feminine", "21"),
+            "veintiuna"
+        );
+        assert_eq!(
+            spellout("es", "%spellout-cardinal-feminine", "200"),
+            "doscientas"
+        );
+        assert_eq!(spellout("es", "%spellout-numbering", "100"), "cien");
+        assert_eq!(spellout("es", "%spellout-numbering", "101"), "ciento uno");
+        assert_eq!(spellout("es", "%spellout-numbering", "200"), "doscientos");
+        assert_eq!(spellout("es", "%spellout-ordinal-feminine", "3"), "tercera");
+        assert_eq!(
+            spellout("es", "%spellout-ordinal-masculine", "21"),
+            "vigésimo primero"
+        );
+
+        assert_eq!(spellout("fr", "%spellout-numbering", "21"), "vingt-et-un");
+        assert_eq!(
+            spellout("fr", "%spellout-cardinal-feminine", "21"),
+            "vingt-et-une"
+        );
+        assert_eq!(
+            spellout("fr", "%spellout-numbering", "71"),
+            "soixante-et-onze"
+        );
+        assert_eq!(spellout("fr", "%spellout-numbering", "80"), "quatre-vingts");
+        assert_eq!(
+            spellout("fr", "%spellout-numbering", "99"),
+            "quatre-vingt-dix-neuf"
+        );
+        assert_eq!(spellout("fr", "%spellout-numbering", "200"), "deux cents");
+        assert_eq!(
+            spellout("fr", "%spellout-numbering", "80000"),
+            "quatre-vingt mille"
+        );
+        assert_eq!(
+            spellout("fr", "%spellout-ordinal-feminine", "1"),
+            "première"
+        );
+        assert_eq!(
+            spellout("fr", "%spellout-ordinal-masculine", "21"),
+            "vingt-et-unième"
+        );
+        assert_eq!(
+            spellout("fr", "%spellout-ordinal-masculine", "80"),
+            "quatre-vingtième"
+        );
+
+        // Locales without the rule set use their default one.
+        assert_eq!(spellout("en", "%spellout-cardinal-feminine", "1"), "one");
+        assert_eq!(spellout("und", "%spellout-numbering", "1234"), "1,234");
+    }
+
+    #[test]
+    fn ordinals_and_durations_work() {
+        let format = |rule_type, rule_set: Option<&str>, locale: &str, value: &str| {
+            let options = RuleBasedNumberFormatOptions {
+                rule_type: rule_type,
+                rule_set: rule_set.map(|name| name.to_string()),
+            };
+            options.format(&locale.parse().unwrap(), &value.parse().unwrap())
+        };
+        let ordinal =
+            |locale, rule_set, value| format(RuleBasedNumberType::Ordinal, rule_set, locale, value);
+        assert_eq!(ordinal("en", None, "1"), "1st");
+        assert_eq!(ordinal("en", None, "12"), "12th");
+        assert_eq!(ordinal("en", None, "23"), "23rd");
+        assert_eq!(ordinal("en", None, "1001"), "1,001st");
+        assert_eq!(ordinal("de", None, "3"), "3.");
+        assert_eq!(ordinal("es", Some("%digits-ordinal-feminine"), "3"), "3ª");
+        assert_eq!(ordinal("fr", Some("%digits-ordinal-feminine"), "1"), "1re");
+        assert_eq!(ordinal("fr", None, "2"), "2e");
+
+        let duration = |locale, rule_set, value| {
+            format(RuleBasedNumberType::Duration, rule_set, locale, value)
+        };
+        assert_eq!(duration("en", None, "45"), "45 sec.");
+        assert_eq!(duration("en", None, "65"), "1:05");
+        assert_eq!(duration("en", None, "3600"), "1:00:00");
+        assert_eq!(duration("en", Some("%with-words"), "65"), "1 min., 5 sec.");
+        assert_eq!(duration("en", Some("%with-words"), "3600"), "1 hr.");
+        assert_eq!(
+            duration("en", Some("%with-words"), "3665"),
+            "1 hr., 1 min., 5 sec."
+        );
+        assert_eq!(duration("de", None, "3907"), "1:05:07");
+    }
+}
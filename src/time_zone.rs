@@ -0,0 +1,723 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use cldr;
+use cldr::time_zones;
+use date_time::{civil_from_days, days_from_civil};
+
+/// The directories searched for zoneinfo files when `TZDIR` isn't set
+/// or doesn't have the zone.
+const ZONEINFO_DIRECTORIES: &[&str] = &[
+    "/usr/share/zoneinfo",
+    "/usr/lib/zoneinfo",
+    "/usr/share/lib/zoneinfo",
+    "/etc/zoneinfo",
+];
+
+/// An error resulting from creating a `TimeZone`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeZoneError {
+    /// There is no zone with this identifier in the system's zoneinfo
+    /// directory or the vendored rules.
+    Unknown(String),
+    /// The TZif data or POSIX rule is not valid.
+    Invalid,
+}
+
+impl Error for TimeZoneError {
+    fn description(&self) -> &str {
+        match *self {
+            TimeZoneError::Unknown(_) => "Unknown time zone.",
+            TimeZoneError::Invalid => "Invalid time zone data.",
+        }
+    }
+}
+
+impl fmt::Display for TimeZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            TimeZoneError::Unknown(ref id) => write!(f, "unknown time zone `{}`", id),
+            TimeZoneError::Invalid => write!(f, "invalid time zone data"),
+        }
+    }
+}
+
+/// A time zone from the [IANA time zone database], like
+/// `America/New_York`, giving the offset from UTC at any point in time.
+///
+/// Zones are read from the system's zoneinfo directory, which has
+/// their full history. Where that isn't available, as on Windows, the
+/// current rules of the most widely used zones are built in.
+///
+/// ```
+/// use message_format::{DateTime, TimeZone};
+///
+/// let zone = TimeZone::new("Europe/Paris").unwrap();
+/// let summer = DateTime::new(1_719_835_200_000);
+/// assert_eq!(zone.offset(summer.timestamp), 2 * 3600);
+/// assert!(zone.is_daylight(summer.timestamp));
+///
+/// assert!(TimeZone::new("Mars/Olympus_Mons").is_err());
+/// ```
+///
+/// [IANA time zone database]: https://www.iana.org/time-zones
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeZone {
+    /// The zone's identifier.
+    id: String,
+    /// The local time type before the first transition.
+    initial: LocalType,
+    /// The times, in seconds since the Unix epoch, from which each
+    /// local time type is used.
+    transitions: Arc<[(i64, LocalType)]>,
+    /// The rule for times after the last transition.
+    rule: Option<Rule>,
+}
+
+impl TimeZone {
+    /// Find the zone with an IANA identifier, like `Europe/Berlin`.
+    pub fn new(id: &str) -> Result<Self, TimeZoneError> {
+        if let Some(zone) = read_zoneinfo(id).and_then(|data| TimeZone::from_tzif(id, &data).ok()) {
+            return Ok(zone);
+        }
+        match cldr::find(time_zones::RULES, id) {
+            Some(rule) => TimeZone::from_posix(id, rule),
+            None => Err(TimeZoneError::Unknown(id.to_string())),
+        }
+    }
+
+    /// Coordinated Universal Time.
+    pub fn utc() -> Self {
+        TimeZone {
+            id: "Etc/UTC".to_string(),
+            initial: LocalType {
+                offset: 0,
+                daylight: false,
+            },
+            transitions: Arc::new([]),
+            rule: None,
+        }
+    }
+
+    /// Construct a zone from a [POSIX `TZ` rule], like
+    /// `CET-1CEST,M3.5.0,M10.5.0/3`, which is used at all times.
+    ///
+    /// [POSIX `TZ` rule]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html
+    pub fn from_posix(id: &str, rule: &str) -> Result<Self, TimeZoneError> {
+        let rule = try!(Rule::parse(rule).ok_or(TimeZoneError::Invalid));
+        Ok(TimeZone {
+            id: id.to_string(),
+            initial: LocalType {
+                offset: rule.standard,
+                daylight: false,
+            },
+            transitions: Arc::new([]),
+            rule: Some(rule),
+        })
+    }
+
+    /// Construct a zone from the contents of a [TZif] file, as found in
+    /// zoneinfo directories.
+    ///
+    /// [TZif]: https://www.rfc-editor.org/rfc/rfc8536
+    pub fn from_tzif(id: &str, data: &[u8]) -> Result<Self, TimeZoneError> {
+        parse_tzif(id, data).ok_or(TimeZoneError::Invalid)
+    }
+
+    /// The zone's IANA identifier.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The offset from UTC in seconds, positive east of Greenwich, at
+    /// a number of milliseconds since the Unix epoch.
+    pub fn offset(&self, timestamp: i64) -> i32 {
+        self.local_type(timestamp).offset
+    }
+
+    /// Whether daylight saving time is in effect at a number of
+    /// milliseconds since the Unix epoch.
+    pub fn is_daylight(&self, timestamp: i64) -> bool {
+        self.local_type(timestamp).daylight
+    }
+
+    /// The local time type at a number of milliseconds since the Unix
+    /// epoch.
+    fn local_type(&self, timestamp: i64) -> LocalType {
+        let seconds = timestamp.div_euclid(1000);
+        let index = self
+            .transitions
+            .partition_point(|&(time, _)| time <= seconds);
+        if index == 0 && !self.transitions.is_empty() {
+            return self.initial;
+        }
+        match self.rule {
+            Some(rule) if index == self.transitions.len() => rule.local_type(seconds),
+            _ if index == 0 => self.initial,
+            _ => self.transitions[index - 1].1,
+        }
+    }
+}
+
+/// An offset from UTC and whether it is for daylight saving time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LocalType {
+    /// The offset from UTC in seconds.
+    offset: i32,
+    /// Whether this is daylight saving time.
+    daylight: bool,
+}
+
+/// A POSIX `TZ` rule.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rule {
+    /// The standard offset from UTC in seconds.
+    standard: i32,
+    /// The daylight saving offset from UTC in seconds, and when it
+    /// starts and ends in each year.
+    daylight: Option<(i32, Change, Change)>,
+}
+
+/// A day in the year and a time on that day, in the local time in
+/// effect before the change, at which a rule changes the offset.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Change {
+    day: RuleDay,
+    /// Seconds from the start of the day, which may be negative or
+    /// more than a day.
+    time: i64,
+}
+
+/// A day in the year.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RuleDay {
+    /// `Jn`: The day of the year from 1 to 365, never counting
+    /// February 29th.
+    Julian(i64),
+    /// `n`: The day of the year from 0 to 365, counting February 29th
+    /// in leap years.
+    Ordinal(i64),
+    /// `Mm.w.d`: Weekday `d` (from 0 for Sunday) of week `w` (from 1,
+    /// where 5 is the last) of month `m`.
+    Weekday(u32, u32, u32),
+}
+
+impl Rule {
+    /// Parse a rule like `EST5EDT,M3.2.0,M11.1.0`.
+    fn parse(rule: &str) -> Option<Rule> {
+        let mut parser = RuleParser { rest: rule };
+        parser.name()?;
+        let standard = -parser.offset()?;
+        if parser.rest.is_empty() {
+            return Some(Rule {
+                standard: standard,
+                daylight: None,
+            });
+        }
+        parser.name()?;
+        let daylight = if parser.rest.starts_with(',') || parser.rest.is_empty() {
+            standard + 3600
+        } else {
+            -parser.offset()?
+        };
+        let (start, end) = if parser.rest.is_empty() {
+            // The rules of the United States, as assumed by most
+            // implementations.
+            (
+                Change {
+                    day: RuleDay::Weekday(3, 2, 0),
+                    time: 7200,
+                },
+                Change {
+                    day: RuleDay::Weekday(11, 1, 0),
+                    time: 7200,
+                },
+            )
+        } else {
+            (parser.change()?, parser.change()?)
+        };
+        if !parser.rest.is_empty() {
+            return None;
+        }
+        Some(Rule {
+            standard: standard,
+            daylight: Some((daylight, start, end)),
+        })
+    }
+
+    /// The local time type at a number of seconds since the Unix
+    /// epoch.
+    fn local_type(&self, seconds: i64) -> LocalType {
+        let standard = LocalType {
+            offset: self.standard,
+            daylight: false,
+        };
+        let (offset, start, end) = match self.daylight {
+            Some(daylight) => daylight,
+            None => return standard,
+        };
+        let (year, _, _) = civil_from_days((seconds + i64::from(self.standard)).div_euclid(86_400));
+        let start = start.day.days(year) * 86_400 + start.time - i64::from(self.standard);
+        let end = end.day.days(year) * 86_400 + end.time - i64::from(offset);
+        // In the southern hemisphere, daylight saving time spans the
+        // end of the year.
+        let daylight = if start < end {
+            start <= seconds && seconds < end
+        } else {
+            !(end <= seconds && seconds < start)
+        };
+        if daylight {
+            LocalType {
+                offset: offset,
+                daylight: true,
+            }
+        } else {
+            standard
+        }
+    }
+}
+
+impl RuleDay {
+    /// The number of days since 1970-01-01 of this day in `year`.
+    fn days(self, year: i64) -> i64 {
+        match self {
+            RuleDay::Julian(day) => {
+                let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+                days_from_civil(year, 1, 1) + day - 1 + if leap && day >= 60 { 1 } else { 0 }
+            }
+            RuleDay::Ordinal(day) => days_from_civil(year, 1, 1) + day,
+            RuleDay::Weekday(month, week, weekday) => {
+                let first = days_from_civil(year, month, 1);
+                let next = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                // 1970-01-01 was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + 7 * (i64::from(week) - 1);
+                while day >= next {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+/// A parser for the parts of a POSIX `TZ` rule.
+struct RuleParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> RuleParser<'a> {
+    /// Skip a zone abbreviation, like `EST` or `<+0530>`.
+    fn name(&mut self) -> Option<()> {
+        let length = if self.rest.starts_with('<') {
+            self.rest.find('>')? + 1
+        } else {
+            let length = self
+                .rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(self.rest.len());
+            if length < 3 {
+                return None;
+            }
+            length
+        };
+        self.rest = &self.rest[length..];
+        Some(())
+    }
+
+    /// Parse an offset or time like `-5:30`, in seconds.
+    fn offset(&mut self) -> Option<i32> {
+        let sign = if self.rest.starts_with('-') { -1 } else { 1 };
+        if self.rest.starts_with('-') || self.rest.starts_with('+') {
+            self.rest = &self.rest[1..];
+        }
+        let mut seconds = 0;
+        for (index, unit) in [3600, 60, 1].iter().enumerate() {
+            if index > 0 {
+                if !self.rest.starts_with(':') {
+                    break;
+                }
+                self.rest = &self.rest[1..];
+            }
+            let length = self
+                .rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(self.rest.len());
+            if length == 0 || length > 3 {
+                return None;
+            }
+            let value: i32 = self.rest[..length].parse().ok()?;
+            seconds += value * unit;
+            self.rest = &self.rest[length..];
+        }
+        Some(sign * seconds)
+    }
+
+    /// Parse a `,` followed by a day and an optional time.
+    fn change(&mut self) -> Option<Change> {
+        self.rest = self.rest.strip_prefix(',')?;
+        let day = if let Some(rest) = self.rest.strip_prefix('M') {
+            self.rest = rest;
+            let month = self.number()?;
+            self.rest = self.rest.strip_prefix('.')?;
+            let week = self.number()?;
+            self.rest = self.rest.strip_prefix('.')?;
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                return None;
+            }
+            RuleDay::Weekday(month as u32, week as u32, weekday as u32)
+        } else if let Some(rest) = self.rest.strip_prefix('J') {
+            self.rest = rest;
+            let day = self.number()?;
+            if !(1..=365).contains(&day) {
+                return None;
+            }
+            RuleDay::Julian(day)
+        } else {
+            let day = self.number()?;
+            if day > 365 {
+                return None;
+            }
+            RuleDay::Ordinal(day)
+        };
+        let time = match self.rest.strip_prefix('/') {
+            Some(rest) => {
+                self.rest = rest;
+                i64::from(self.offset()?)
+            }
+            None => 7200,
+        };
+        Some(Change {
+            day: day,
+            time: time,
+        })
+    }
+
+    /// Parse a non-negative decimal number.
+    fn number(&mut self) -> Option<i64> {
+        let length = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len());
+        let number = self.rest[..length].parse().ok()?;
+        self.rest = &self.rest[length..];
+        Some(number)
+    }
+}
+
+/// Read the TZif file for `id` from the system's zoneinfo directory.
+fn read_zoneinfo(id: &str) -> Option<Vec<u8>> {
+    // Only accept identifiers, not arbitrary paths.
+    let valid = id.split('/').all(|part| {
+        !part.is_empty()
+            && !part.starts_with('.')
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_-+".contains(c))
+    });
+    if !valid {
+        return None;
+    }
+    env::var_os("TZDIR")
+        .map(PathBuf::from)
+        .into_iter()
+        .chain(ZONEINFO_DIRECTORIES.iter().map(PathBuf::from))
+        .filter_map(|directory| fs::read(directory.join(id)).ok())
+        .next()
+}
+
+/// Parse TZif data, using the 64 bit data of version 2 and later files
+/// along with their footer rule.
+fn parse_tzif(id: &str, data: &[u8]) -> Option<TimeZone> {
+    let mut reader = Reader { data: data };
+    let (version, counts) = reader.header()?;
+    let (time_size, counts) = if version >= b'2' {
+        reader.skip(block_length(&counts, 4)?)?;
+        (8, reader.header()?.1)
+    } else {
+        (4, counts)
+    };
+    let [ut_count, std_count, leap_count, time_count, type_count, char_count] = counts;
+    if type_count == 0 {
+        return None;
+    }
+
+    let mut times = vec![];
+    for _ in 0..time_count {
+        times.push(reader.int(time_size)?);
+    }
+    let indices = reader.take(time_count)?.to_vec();
+    let mut types = vec![];
+    for _ in 0..type_count {
+        let offset = reader.int(4)? as i32;
+        let daylight = reader.take(1)?[0] != 0;
+        reader.skip(1)?;
+        types.push(LocalType {
+            offset: offset,
+            daylight: daylight,
+        });
+    }
+    // The rest of the block follows the local time types.
+    let rest = [ut_count, std_count, leap_count, 0, 0, char_count];
+    reader.skip(block_length(&rest, time_size)?)?;
+
+    let mut transitions = vec![];
+    for (&time, &index) in times.iter().zip(indices.iter()) {
+        transitions.push((time, *types.get(usize::from(index))?));
+    }
+    let rule = if version >= b'2' {
+        let footer = reader.data.strip_prefix(b"\n")?;
+        let end = footer.iter().position(|&b| b == b'\n')?;
+        let footer = ::std::str::from_utf8(&footer[..end]).ok()?;
+        if footer.is_empty() {
+            None
+        } else {
+            Some(Rule::parse(footer)?)
+        }
+    } else {
+        None
+    };
+    Some(TimeZone {
+        id: id.to_string(),
+        initial: types[0],
+        transitions: transitions.into(),
+        rule: rule,
+    })
+}
+
+/// The length of the data block following a TZif header, or `None`
+/// if it overflows.
+fn block_length(counts: &[usize; 6], time_size: usize) -> Option<usize> {
+    let [ut_count, std_count, leap_count, time_count, type_count, char_count] = *counts;
+    time_count
+        .checked_mul(time_size + 1)?
+        .checked_add(type_count.checked_mul(6)?)?
+        .checked_add(char_count)?
+        .checked_add(leap_count.checked_mul(time_size + 4)?)?
+        .checked_add(std_count)?
+        .checked_add(ut_count)
+}
+
+/// A reader for big-endian TZif data.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Read a header, returning the version and the counts of UT/local
+    /// indicators, standard/wall indicators, leap seconds, transitions,
+    /// local time types and abbreviation characters.
+    fn header(&mut self) -> Option<(u8, [usize; 6])> {
+        if self.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = self.take(1)?[0];
+        self.skip(15)?;
+        let mut counts = [0; 6];
+        for count in counts.iter_mut() {
+            *count = self.u32()? as usize;
+        }
+        Some((version, counts))
+    }
+
+    /// Read a signed integer of `size` bytes.
+    fn int(&mut self, size: usize) -> Option<i64> {
+        let bytes = self.take(size)?;
+        let value = bytes
+            .iter()
+            .fold(0_i64, |value, &b| (value << 8) | i64::from(b));
+        // Sign extend 32 bit values.
+        Some(if size == 4 {
+            i64::from(value as u32 as i32)
+        } else {
+            value
+        })
+    }
+
+    /// Read an unsigned 32 bit integer.
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.take(4)?;
+        Some(
+            bytes
+                .iter()
+                .fold(0, |value, &b| (value << 8) | u32::from(b)),
+        )
+    }
+
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if length > self.data.len() {
+            return None;
+        }
+        let (taken, rest) = self.data.split_at(length);
+        self.data = rest;
+        Some(taken)
+    }
+
+    fn skip(&mut self, length: usize) -> Option<()> {
+        self.take(length).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-03-31T00:59:59Z, 2024-03-31T01:00:00Z and 2024-10-27T01:00:00Z.
+    const BEFORE_SUMMER: i64 = 1_711_846_799_000;
+    const SUMMER: i64 = 1_711_846_800_000;
+    const WINTER: i64 = 1_729_990_800_000;
+
+    #[test]
+    fn posix_rules_work() {
+        let zone = TimeZone::from_posix("Europe/Berlin", "CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(zone.offset(BEFORE_SUMMER), 3600);
+        assert_eq!(zone.offset(SUMMER), 7200);
+        assert!(zone.is_daylight(SUMMER));
+        assert_eq!(zone.offset(WINTER - 1), 7200);
+        assert_eq!(zone.offset(WINTER), 3600);
+
+        // 2024-03-10T07:00:00Z is 2:00 in New York.
+        let zone = TimeZone::from_posix("America/New_York", "EST5EDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(zone.offset(1_710_053_999_000), -5 * 3600);
+        assert_eq!(zone.offset(1_710_054_000_000), -4 * 3600);
+
+        // The southern hemisphere, on 2024-01-15 and 2024-07-15.
+        let zone =
+            TimeZone::from_posix("Australia/Sydney", "AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert_eq!(zone.offset(1_705_276_800_000), 11 * 3600);
+        assert_eq!(zone.offset(1_721_001_600_000), 10 * 3600);
+
+        let zone = TimeZone::from_posix("Asia/Kolkata", "IST-5:30").unwrap();
+        assert_eq!(zone.offset(SUMMER), 19_800);
+        assert!(!zone.is_daylight(SUMMER));
+        let zone = TimeZone::from_posix("Asia/Kathmandu", "<+0545>-5:45").unwrap();
+        assert_eq!(zone.offset(SUMMER), 20_700);
+
+        // Julian and zero-based days, with times past midnight.
+        let zone = TimeZone::from_posix("Test", "XST3XDT,J60/25,300").unwrap();
+        assert_eq!(zone.offset(SUMMER), -2 * 3600);
+
+        assert_eq!(
+            TimeZone::from_posix("Bad", "EST"),
+            Err(TimeZoneError::Invalid)
+        );
+        assert_eq!(
+            TimeZone::from_posix("Bad", "EST5EDT,M13.1.0,M11.1.0"),
+            Err(TimeZoneError::Invalid)
+        );
+    }
+
+    /// Build TZif data with one transition and the given footer.
+    fn tzif(version: u8, footer: &str) -> Vec<u8> {
+        let mut data = vec![];
+        let header = |data: &mut Vec<u8>| {
+            data.extend_from_slice(b"TZif");
+            data.push(version);
+            data.extend_from_slice(&[0; 15]);
+            for &count in &[0_u32, 0, 0, 1, 2, 8] {
+                data.extend_from_slice(&count.to_be_bytes());
+            }
+        };
+        let block = |data: &mut Vec<u8>, wide: bool| {
+            // From 2000-01-01T00:00:00Z, switch from LMT to CET.
+            if wide {
+                data.extend_from_slice(&946_684_800_i64.to_be_bytes());
+            } else {
+                data.extend_from_slice(&946_684_800_i32.to_be_bytes());
+            }
+            data.push(1);
+            data.extend_from_slice(&3208_i32.to_be_bytes());
+            data.extend_from_slice(&[0, 0]);
+            data.extend_from_slice(&3600_i32.to_be_bytes());
+            data.extend_from_slice(&[0, 4]);
+            data.extend_from_slice(b"LMT\0CET\0");
+        };
+        header(&mut data);
+        block(&mut data, false);
+        if version != 0 {
+            header(&mut data);
+            block(&mut data, true);
+            data.extend_from_slice(format!("\n{}\n", footer).as_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn tzif_works() {
+        let zone = TimeZone::from_tzif("Europe/Berlin", &tzif(b'2', "CET-1CEST,M3.5.0,M10.5.0/3"))
+            .unwrap();
+        assert_eq!(zone.id(), "Europe/Berlin");
+        assert_eq!(zone.offset(946_684_799_999), 3208);
+        assert_eq!(zone.offset(946_684_800_000), 3600);
+        assert_eq!(zone.offset(BEFORE_SUMMER), 3600);
+        assert_eq!(zone.offset(SUMMER), 7200);
+
+        let zone = TimeZone::from_tzif("Europe/Berlin", &tzif(0, "")).unwrap();
+        assert_eq!(zone.offset(0), 3208);
+        assert_eq!(zone.offset(SUMMER), 3600);
+
+        let zone = TimeZone::from_tzif("Europe/Berlin", &tzif(b'3', "")).unwrap();
+        assert_eq!(zone.offset(SUMMER), 3600);
+
+        let mut data = tzif(b'2', "");
+        data.truncate(60);
+        assert_eq!(
+            TimeZone::from_tzif("Europe/Berlin", &data),
+            Err(TimeZoneError::Invalid)
+        );
+
+        // Counts that are too large for the data.
+        for &(version, start) in &[(0, 20), (0, 32), (0, 36), (b'2', 20), (b'2', 40)] {
+            let mut data = tzif(version, "");
+            for b in &mut data[start..start + 4] {
+                *b = 0xff;
+            }
+            assert_eq!(
+                TimeZone::from_tzif("Europe/Berlin", &data),
+                Err(TimeZoneError::Invalid)
+            );
+        }
+    }
+
+    #[test]
+    fn new_works() {
+        // This finds the zone either in the system's zoneinfo directory
+        // or in the vendored rules.
+        let zone = TimeZone::new("America/Los_Angeles").unwrap();
+        assert_eq!(zone.offset(SUMMER), -7 * 3600);
+        assert_eq!(zone.offset(WINTER + 86_400_000 * 14), -8 * 3600);
+
+        assert_eq!(TimeZone::utc().offset(SUMMER), 0);
+        assert_eq!(
+            TimeZone::new("../etc/passwd"),
+            Err(TimeZoneError::Unknown("../etc/passwd".to_string()))
+        );
+        assert!(TimeZone::new("Nowhere/Special").is_err());
+    }
+
+    #[test]
+    fn vendored_rules_are_valid() {
+        for &(ids, rule) in time_zones::RULES {
+            for id in ids.split_whitespace() {
+                assert!(TimeZone::from_posix(id, rule).is_ok(), "{}", id);
+            }
+        }
+    }
+}
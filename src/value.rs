@@ -5,6 +5,9 @@
 // except according to those terms.
 
 use std::fmt;
+use std::time::Duration;
+
+use {DateTime, Decimal};
 
 /// A wrapper around a value, used with [`Args`] so that a [`MessagePart`]
 /// can access the original value when necessary.
@@ -18,37 +21,229 @@ use std::fmt;
 pub enum Value<'a> {
     /// Wrap an `i64`.
     Number(i64),
+    /// Wrap a `u64`, which may be too large for an `i64`.
+    Unsigned(u64),
+    /// Wrap an `i128`.
+    I128(i128),
+    /// Wrap a `u128`.
+    U128(u128),
+    /// Wrap an `f64`.
+    Float(f64),
+    /// Wrap a `Decimal`, which keeps trailing zeros such as in `2.50`.
+    Decimal(Decimal),
     /// Wrap an `&str`.
     Str(&'a str),
+    /// An amount of money in a currency.
+    ///
+    /// A `number` argument formats this as an amount of `code`, with
+    /// that currency's symbol and number of fraction digits:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate message_format;
+    /// # fn main() {
+    /// use message_format::{icu, Context, Value};
+    ///
+    /// let ctx = Context::new("ja".parse().unwrap(), None);
+    /// let m = icu::parse("{total, number, currency}").unwrap();
+    /// let total = Value::Currency {
+    ///     amount: "1234.5".parse().unwrap(),
+    ///     code: "EUR",
+    /// };
+    /// assert_eq!(format_message!(ctx, &m, total => total), "€1,234.50");
+    /// # }
+    /// ```
+    Currency {
+        /// The amount of money.
+        amount: Decimal,
+        /// The [ISO 4217] code of the currency, like `USD`.
+        ///
+        /// [ISO 4217]: https://www.iso.org/iso-4217-currency-codes.html
+        code: &'a str,
+    },
+    /// An amount of a measurement unit.
+    ///
+    /// A `number` argument formats this as an amount of `unit`, in the
+    /// plural form for the amount:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate message_format;
+    /// # fn main() {
+    /// use message_format::{icu, Context, Value};
+    ///
+    /// let ctx = Context::default();
+    /// let m = icu::parse("You ran {distance, number, ::unit-width-full-name}").unwrap();
+    /// let distance = Value::Measure {
+    ///     amount: "5.2".parse().unwrap(),
+    ///     unit: "kilometer",
+    /// };
+    /// assert_eq!(format_message!(ctx, &m, distance), "You ran 5.2 kilometers");
+    /// # }
+    /// ```
+    Measure {
+        /// The amount of the unit.
+        amount: Decimal,
+        /// The [CLDR] identifier of the unit, like `kilometer`, or of a
+        /// compound unit, like `kilometer-per-hour`.
+        ///
+        /// [CLDR]: http://cldr.unicode.org/
+        unit: &'a str,
+    },
+    /// A point in time, for `date` and `time` arguments.
+    DateTime(DateTime),
+    /// A list of values, for `list` arguments.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate message_format;
+    /// # fn main() {
+    /// use message_format::{icu, Context};
+    ///
+    /// let ctx = Context::default();
+    /// let m = icu::parse("Shared with {names, list}").unwrap();
+    /// let names = vec!["Ana", "Ben", "Chloé"];
+    /// assert_eq!(format_message!(ctx, &m, names), "Shared with Ana, Ben, and Chloé");
+    /// # }
+    /// ```
+    List(Vec<Value<'a>>),
+    /// A length of time, for `duration` arguments.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate message_format;
+    /// # fn main() {
+    /// use message_format::{icu, Context};
+    /// use std::time::Duration;
+    ///
+    /// let ctx = Context::default();
+    /// let m = icu::parse("Took {elapsed, duration, long}").unwrap();
+    /// let elapsed = Duration::from_secs(3907);
+    /// assert_eq!(format_message!(ctx, &m, elapsed), "Took 1 hour, 5 minutes, 7 seconds");
+    /// # }
+    /// ```
+    Duration(Duration),
 }
 
-impl<'a> From<i32> for Value<'a> {
-    fn from(value: i32) -> Value<'a> {
-        Value::Number(i64::from(value))
+impl<'a> Value<'a> {
+    /// The value as a `Decimal`, if it is a finite number.
+    ///
+    /// ```
+    /// use message_format::{Decimal, Value};
+    ///
+    /// assert_eq!(Value::from(1.5).to_decimal(), Some("1.5".parse().unwrap()));
+    /// assert_eq!(Value::from(u64::MAX).to_decimal(), Some(Decimal::from(u64::MAX)));
+    /// assert_eq!(Value::from("1.5").to_decimal(), None);
+    /// ```
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        match *self {
+            Value::Number(n) => Some(Decimal::from(n)),
+            Value::Unsigned(n) => Some(Decimal::from(n)),
+            Value::I128(n) => Some(Decimal::from(n)),
+            Value::U128(n) => Some(Decimal::from(n)),
+            Value::Float(n) => Decimal::from_f64(n),
+            Value::Decimal(ref n) => Some(n.clone()),
+            Value::Str(_) => None,
+            Value::Currency { ref amount, .. } => Some(amount.clone()),
+            Value::Measure { ref amount, .. } => Some(amount.clone()),
+            Value::DateTime(_) => None,
+            Value::List(_) => None,
+            Value::Duration(_) => None,
+        }
     }
-}
 
-impl<'a> From<u32> for Value<'a> {
-    fn from(value: u32) -> Value<'a> {
-        Value::Number(i64::from(value))
+    /// The kind of value, like `a number` or `a string`, as used in
+    /// a [`FormatError::TypeMismatch`].
+    ///
+    /// [`FormatError::TypeMismatch`]: enum.FormatError.html#variant.TypeMismatch
+    pub fn type_name(&self) -> &'static str {
+        match *self {
+            Value::Float(n) if !n.is_finite() => "a number that isn't finite",
+            Value::Number(_)
+            | Value::Unsigned(_)
+            | Value::I128(_)
+            | Value::U128(_)
+            | Value::Float(_)
+            | Value::Decimal(_) => "a number",
+            Value::Str(_) => "a string",
+            Value::Currency { .. } => "an amount of money",
+            Value::Measure { .. } => "a measurement",
+            Value::DateTime(_) => "a date and time",
+            Value::List(_) => "a list",
+            Value::Duration(_) => "a duration",
+        }
     }
-}
 
-impl<'a> From<i64> for Value<'a> {
-    fn from(value: i64) -> Value<'a> {
-        Value::Number(value)
+    /// The currency code, if the value is an amount of money.
+    pub fn currency_code(&self) -> Option<&'a str> {
+        match *self {
+            Value::Currency { code, .. } => Some(code),
+            _ => None,
+        }
     }
+
+    /// The measurement unit, if the value is an amount of one.
+    pub fn measure_unit(&self) -> Option<&'a str> {
+        match *self {
+            Value::Measure { unit, .. } => Some(unit),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! from_number {
+    ($($t:ty => $variant:ident),*) => {
+        $(
+            impl<'a> From<$t> for Value<'a> {
+                fn from(value: $t) -> Value<'a> {
+                    Value::$variant(value.into())
+                }
+            }
+        )*
+    };
 }
 
-impl<'a> From<u64> for Value<'a> {
-    fn from(value: u64) -> Value<'a> {
+from_number!(
+    i8 => Number,
+    i16 => Number,
+    i32 => Number,
+    i64 => Number,
+    u8 => Number,
+    u16 => Number,
+    u32 => Number,
+    u64 => Unsigned,
+    i128 => I128,
+    u128 => U128,
+    f32 => Float,
+    f64 => Float,
+    Decimal => Decimal,
+    DateTime => DateTime,
+    Duration => Duration
+);
+
+impl<'a> From<isize> for Value<'a> {
+    fn from(value: isize) -> Value<'a> {
         Value::Number(value as i64)
     }
 }
 
 impl<'a> From<usize> for Value<'a> {
     fn from(value: usize) -> Value<'a> {
-        Value::Number(value as i64)
+        Value::Unsigned(value as u64)
+    }
+}
+
+impl<'a, T> From<Vec<T>> for Value<'a>
+where
+    Value<'a>: From<T>,
+{
+    fn from(values: Vec<T>) -> Value<'a> {
+        Value::List(values.into_iter().map(Value::from).collect())
+    }
+}
+
+impl<'a, 'b, T: Clone> From<&'b [T]> for Value<'a>
+where
+    Value<'a>: From<T>,
+{
+    fn from(values: &'b [T]) -> Value<'a> {
+        Value::List(values.iter().cloned().map(Value::from).collect())
     }
 }
 
@@ -63,7 +258,25 @@ impl<'a> fmt::Display for Value<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Value::Number(i) => i.fmt(f),
+            Value::Unsigned(i) => i.fmt(f),
+            Value::I128(i) => i.fmt(f),
+            Value::U128(i) => i.fmt(f),
+            Value::Float(n) => n.fmt(f),
+            Value::Decimal(ref n) => n.fmt(f),
             Value::Str(s) => s.fmt(f),
+            Value::Currency { ref amount, code } => write!(f, "{} {}", amount, code),
+            Value::Measure { ref amount, unit } => write!(f, "{} {}", amount, unit),
+            Value::DateTime(ref date_time) => date_time.fmt(f),
+            Value::List(ref values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        try!(f.write_str(", "));
+                    }
+                    try!(value.fmt(f));
+                }
+                Ok(())
+            }
+            Value::Duration(ref duration) => write!(f, "{:?}", duration),
         }
     }
 }